@@ -3,8 +3,10 @@
 //! This module defines the CLI structure using clap for parsing command-line
 //! arguments and providing a clean interface for various MCP debugging operations.
 
+use crate::config::{Config, ConfigLoader};
 use clap::{Parser, Subcommand, ValueEnum};
 use mcp_probe_core::transport::TransportConfig;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use url::Url;
 
@@ -29,6 +31,21 @@ pub struct Cli {
     #[arg(long, value_enum, default_value = "pretty")]
     pub output: OutputFormat,
 
+    /// Export request spans and metrics to an OTLP/HTTP collector
+    /// (builds with the `otel` feature)
+    #[arg(long, global = true, value_name = "URL")]
+    pub otel_endpoint: Option<String>,
+
+    /// Append request spans and metrics to a file as OTLP/JSON
+    /// (builds with the `otel` feature)
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        conflicts_with = "otel_endpoint"
+    )]
+    pub otel_file: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -52,6 +69,15 @@ pub enum Commands {
 
     /// Show MCP Probe directory structure and cleanup options
     Paths(PathsArgs),
+
+    /// Load-test an MCP server with concurrent clients
+    Bench(BenchArgs),
+
+    /// Manage named server profiles (use them as `@name`)
+    Servers(ServersArgs),
+
+    /// Periodically health-check servers and serve Prometheus metrics
+    Monitor(MonitorArgs),
 }
 
 /// Arguments for the debug command
@@ -81,6 +107,18 @@ pub struct DebugArgs {
     #[arg(long)]
     pub replay_session: Option<PathBuf>,
 
+    /// Restart or reconnect to the server when the connection is lost
+    #[arg(long)]
+    pub reconnect: bool,
+
+    /// Watch files or directories and restart the server when they change
+    #[arg(long, value_name = "PATH", num_args = 1.., value_delimiter = ',')]
+    pub watch: Vec<PathBuf>,
+
+    /// Also connect to these servers (`@profile` or an HTTP URL), as a host would
+    #[arg(long = "with", value_name = "SERVER", value_delimiter = ',')]
+    pub with: Vec<ServerRef>,
+
     /// Connection timeout in seconds
     #[arg(long, default_value = "30")]
     pub timeout: u64,
@@ -101,7 +139,7 @@ pub struct TestArgs {
     #[command(flatten)]
     pub transport: TransportArgs,
 
-    /// Configuration file with test definitions
+    /// Configuration file to load
     #[arg(short, long)]
     pub config: Option<PathBuf>,
 
@@ -124,6 +162,16 @@ pub struct TestArgs {
     /// Automatically discover and test all MCP endpoints for a base URL
     #[arg(long, value_name = "BASE_URL")]
     pub discover: Option<String>,
+
+    /// Watch files or directories and restart the server and rerun the tests when they change
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 1..,
+        value_delimiter = ',',
+        conflicts_with = "fail_fast"
+    )]
+    pub watch: Vec<PathBuf>,
 }
 
 /// Arguments for the config command
@@ -156,6 +204,10 @@ pub enum ConfigAction {
     Show {
         /// Configuration file to display
         config: Option<PathBuf>,
+
+        /// Show the resolved settings from all layers and where each came from
+        #[arg(long)]
+        effective: bool,
     },
 }
 
@@ -206,6 +258,183 @@ pub struct ExportArgs {
     pub include_timing: bool,
 }
 
+/// Arguments for the bench command
+#[derive(Parser, Debug)]
+pub struct BenchArgs {
+    /// Transport configuration
+    #[command(flatten)]
+    pub transport: TransportArgs,
+
+    /// Number of concurrent clients (each with its own connection)
+    #[arg(short, long, default_value = "4")]
+    pub clients: usize,
+
+    /// Target request rate in requests/second across all clients (default: unthrottled)
+    #[arg(long)]
+    pub rate: Option<f64>,
+
+    /// Benchmark duration in seconds
+    #[arg(short, long, default_value = "30")]
+    pub duration: u64,
+
+    /// Weighted request mix (method=weight, comma separated)
+    #[arg(long, default_value = "tools/call=40,resources/read=30,ping=30")]
+    pub mix: String,
+
+    /// Tool to call for tools/call requests (default: first listed tool)
+    #[arg(long)]
+    pub tool: Option<String>,
+
+    /// JSON arguments for tools/call requests
+    #[arg(long, default_value = "{}")]
+    pub tool_args: String,
+
+    /// Resource URI for resources/read requests (default: first listed resource)
+    #[arg(long)]
+    pub resource: Option<String>,
+
+    /// Per-request timeout in seconds
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Output benchmark report (default: auto-saved in ~/.mcp-probe/reports/)
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+}
+
+/// Arguments for the monitor command
+#[derive(Parser, Debug)]
+pub struct MonitorArgs {
+    /// Server profiles to monitor (default: all profiles, else `[transport]`)
+    #[arg(value_name = "@PROFILE", value_parser = parse_profile_ref)]
+    pub servers: Vec<String>,
+
+    /// Address to serve metrics on (at /metrics)
+    #[arg(long, default_value = "127.0.0.1:9464")]
+    pub listen: SocketAddr,
+
+    /// Seconds between checks
+    #[arg(short, long, default_value = "30")]
+    pub interval: u64,
+
+    /// Per-request timeout in seconds
+    #[arg(long, default_value = "10")]
+    pub timeout: u64,
+
+    /// Tool to call as a canary on every check
+    #[arg(long, value_name = "TOOL")]
+    pub canary: Option<String>,
+
+    /// JSON arguments for the canary tool call
+    #[arg(long, default_value = "{}", requires = "canary")]
+    pub canary_args: String,
+
+    /// Also run the validation suite (without the protocol version matrix)
+    /// every N checks (0: never, the default)
+    #[arg(long, value_name = "N", default_value = "0")]
+    pub validate_every: u64,
+
+    /// Check once, print the metrics and exit (fails if any server is down)
+    #[arg(long)]
+    pub once: bool,
+}
+
+/// Arguments for the servers command
+#[derive(Parser, Debug)]
+pub struct ServersArgs {
+    #[command(subcommand)]
+    pub action: ServersAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ServersAction {
+    /// List server profiles from all configuration layers
+    List,
+
+    /// Add a server profile to the registry
+    Add(Box<ServerAddArgs>),
+
+    /// Remove a server profile from the registry
+    Remove {
+        /// Profile name
+        name: String,
+    },
+
+    /// Rename a server profile in the registry
+    Rename {
+        /// Current profile name
+        from: String,
+
+        /// New profile name
+        to: String,
+    },
+
+    /// Import profiles from an MCP host config file (`mcpServers` / `servers` JSON)
+    Import {
+        /// Host configuration file (e.g. claude_desktop_config.json, .vscode/mcp.json)
+        file: PathBuf,
+
+        /// Prefix added to every imported profile name
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Overwrite existing profiles with the same name
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Arguments for `servers add`
+#[derive(Parser, Debug)]
+pub struct ServerAddArgs {
+    /// Profile name (letters, digits, '-' and '_')
+    pub name: String,
+
+    /// Use stdio transport with command
+    #[arg(long, value_name = "COMMAND")]
+    pub stdio: Option<String>,
+
+    /// Command arguments for stdio transport
+    #[arg(long, requires = "stdio", allow_hyphen_values = true)]
+    pub args: Vec<String>,
+
+    /// Working directory for stdio command
+    #[arg(long, requires = "stdio")]
+    pub working_dir: Option<PathBuf>,
+
+    /// Use HTTP+SSE transport with URL
+    #[arg(long, value_name = "URL")]
+    pub http_sse: Option<Url>,
+
+    /// Use HTTP streaming transport with URL
+    #[arg(long, value_name = "URL")]
+    pub http_stream: Option<Url>,
+
+    /// Environment variable for the server process (KEY=VALUE, repeatable)
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub env: Vec<String>,
+
+    /// HTTP header (KEY=VALUE, repeatable)
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub header: Vec<String>,
+
+    /// Bearer token for HTTP transports
+    #[arg(long, value_name = "TOKEN")]
+    pub bearer: Option<String>,
+
+    /// Default transport timeout (e.g. 30s, 2m)
+    #[arg(long, value_parser = humantime_serde::re::humantime::parse_duration)]
+    pub timeout: Option<std::time::Duration>,
+
+    /// Description shown by `servers list`
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Overwrite an existing profile with the same name
+    #[arg(long)]
+    pub force: bool,
+}
+
 /// Arguments for the paths command
 #[derive(Parser, Debug)]
 pub struct PathsArgs {
@@ -236,6 +465,14 @@ pub enum PathsAction {
 /// Transport configuration arguments
 #[derive(Parser, Clone, Debug)]
 pub struct TransportArgs {
+    /// Server profile to connect to (`@name`, see `mcp-probe servers list`)
+    #[arg(
+        value_name = "@PROFILE",
+        value_parser = parse_profile_ref,
+        conflicts_with_all = ["stdio", "http_sse", "http_stream"]
+    )]
+    pub profile: Option<String>,
+
     /// Use stdio transport with command
     #[arg(long, value_name = "COMMAND")]
     pub stdio: Option<String>,
@@ -263,6 +500,10 @@ pub struct TransportArgs {
     /// Custom headers for HTTP transports (key=value format)
     #[arg(long, requires = "http_sse")]
     pub headers: Vec<String>,
+
+    /// Copy stdio server stderr to a log file under ~/.mcp-probe/logs
+    #[arg(long)]
+    pub stderr_log: bool,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
@@ -318,7 +559,95 @@ pub enum ExportFormat {
     Csv,
 }
 
+impl Cli {
+    /// Build the configuration loader for this invocation.
+    ///
+    /// Adds the command's `--config` file and maps global and command flags
+    /// onto configuration keys so they take precedence over every other layer.
+    pub fn config_loader(&self) -> ConfigLoader {
+        let explicit_file = match &self.command {
+            Commands::Debug(cmd) => cmd.config.clone(),
+            Commands::Test(args) => args.config.clone(),
+            Commands::Validate(args) => args.config.clone(),
+            Commands::Config(ConfigArgs {
+                action: ConfigAction::Show { config, .. },
+            }) => config.clone(),
+            _ => None,
+        };
+
+        let mut loader = ConfigLoader::discover().explicit_file(explicit_file);
+
+        match self.verbose {
+            0 => {}
+            1 => loader = loader.flag("logging.level", "debug", "-v"),
+            _ => loader = loader.flag("logging.level", "trace", "-vv"),
+        }
+
+        if self.no_color {
+            loader = loader.flag("tui.color_scheme", "monochrome", "--no-color");
+        }
+
+        if let Some(endpoint) = &self.otel_endpoint {
+            loader = loader.flag(
+                "telemetry.otlp_endpoint",
+                endpoint.as_str(),
+                "--otel-endpoint",
+            );
+        }
+        if let Some(file) = &self.otel_file {
+            let file = file.display().to_string();
+            loader = loader.flag("telemetry.file", file, "--otel-file");
+        }
+
+        if let Commands::Debug(cmd) = &self.command {
+            if cmd.show_raw {
+                loader = loader.flag("debug.show_raw_messages", true, "--show-raw");
+            }
+            if cmd.reconnect {
+                loader = loader.flag("client.reconnect", true, "--reconnect");
+            }
+        }
+
+        loader
+    }
+}
+
 impl TransportArgs {
+    /// Whether any transport was given on the command line
+    pub fn is_specified(&self) -> bool {
+        self.profile.is_some()
+            || self.stdio.is_some()
+            || self.http_sse.is_some()
+            || self.http_stream.is_some()
+    }
+
+    /// Resolve the transport from a `@profile`, the transport flags or the
+    /// configured default transport, in that order
+    pub fn resolve(&self, config: &Config) -> anyhow::Result<TransportConfig> {
+        let mut transport = if let Some(profile) = &self.profile {
+            config.server_profile(profile)?.transport_config()
+        } else {
+            match &config.transport {
+                Some(transport) if !self.is_specified() => transport.clone(),
+                _ => self.to_transport_config()?,
+            }
+        };
+
+        if let (true, TransportConfig::Stdio(stdio)) = (self.stderr_log, &mut transport) {
+            let paths = crate::paths::get_mcp_probe_paths()?;
+            stdio.stderr_log = Some(paths.log_file("server-stderr"));
+        }
+        Ok(transport)
+    }
+
+    /// Name of the server in a multi-server session: the profile name, or one
+    /// derived from the transport
+    pub fn server_name(&self, transport: &TransportConfig) -> String {
+        self.profile
+            .clone()
+            .unwrap_or_else(|| transport_name(transport))
+    }
+
     /// Convert transport arguments to TransportConfig
     pub fn to_transport_config(&self) -> anyhow::Result<TransportConfig> {
         match (&self.stdio, &self.http_sse, &self.http_stream) {
@@ -330,7 +659,9 @@ impl TransportArgs {
             (None, Some(url), None) => Ok(TransportConfig::http_sse(url.as_str())?),
             (None, None, Some(url)) => Ok(TransportConfig::http_stream(url.clone())?),
             (None, None, None) => {
-                anyhow::bail!("No transport specified. Use --stdio, --http-sse, or --http-stream")
+                anyhow::bail!(
+                    "No transport specified. Use --stdio, --http-sse, or --http-stream, or set [transport] in the configuration file"
+                )
             }
             _ => {
                 anyhow::bail!("Only one transport type can be specified at a time")
@@ -339,6 +670,80 @@ impl TransportArgs {
     }
 }
 
+/// Another server of a multi-server debug session, given with `--with`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerRef {
+    /// A server profile, `@name`
+    Profile(String),
+    /// A streamable HTTP endpoint
+    Url(Url),
+}
+
+impl ServerRef {
+    /// Name and transport of the server
+    pub fn resolve(&self, config: &Config) -> anyhow::Result<(String, TransportConfig)> {
+        match self {
+            Self::Profile(name) => Ok((
+                name.clone(),
+                config.server_profile(name)?.transport_config(),
+            )),
+            Self::Url(url) => {
+                let transport = TransportConfig::http_stream(url.as_str())?;
+                Ok((transport_name(&transport), transport))
+            }
+        }
+    }
+}
+
+/// Name for a server given by its transport rather than a profile: the
+/// command's file name or the URL's host
+pub fn transport_name(transport: &TransportConfig) -> String {
+    let url = match transport {
+        TransportConfig::Stdio(config) => {
+            return std::path::Path::new(&config.command)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| config.command.clone());
+        }
+        TransportConfig::HttpSse(config) => &config.base_url,
+        TransportConfig::HttpStream(config) => &config.base_url,
+    };
+    match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_string(),
+        (None, _) => url.to_string(),
+    }
+}
+
+/// Parse a `--with` server: a `@name` profile or an `http(s)://` URL
+impl std::str::FromStr for ServerRef {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.starts_with('@') {
+            return parse_profile_ref(value).map(Self::Profile);
+        }
+        match Url::parse(value) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(Self::Url(url)),
+            _ => Err(format!(
+                "expected a server profile as @name or an http(s) URL, got '{}'",
+                value
+            )),
+        }
+    }
+}
+
+/// Parse a `@name` server profile reference into the bare profile name
+fn parse_profile_ref(value: &str) -> Result<String, String> {
+    match value.strip_prefix('@') {
+        Some(name) if !name.is_empty() => Ok(name.to_string()),
+        _ => Err(format!(
+            "expected a server profile as @name, got '{}' (use --stdio, --http-sse or --http-stream for ad-hoc servers)",
+            value
+        )),
+    }
+}
+
 impl std::fmt::Display for OutputFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,786 @@
+//! Command language for the TUI's interactive terminal
+//!
+//! A command is one of:
+//!
+//! - `tools.<name> {json arguments}`
+//! - `resources.<uri>` (everything after the first `.` is the URI)
+//! - `prompts.<name> {json arguments}`
+//! - a meta-command: `:ping`, `:raw <json-rpc>`, `:set-level <level>` or `:subscribe <uri>`
+//!
+//! Before a command is parsed, `${VAR}` is replaced with a session variable
+//! (set with F5, falling back to the process environment) and
+//! `$last.<path>` with part of the previous response, e.g.
+//! `$last.result.content[0].text`. Inside a JSON string the value is inserted
+//! escaped; outside one, `$last` values are inserted as JSON and variables as
+//! plain text, so `{"count": ${N}}` and `{"text": "${GREETING}"}` both work.
+//!
+//! This module also keeps the persistent command history and provides
+//! tab-completion of command, tool, resource and prompt names and of argument
+//! keys taken from tool schemas and prompt definitions.
+
+use mcp_probe_core::{messages::logging::LogLevel, secrets};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::tui::{PromptInfo, ResourceInfo, ToolInfo};
+
+/// Meta-commands understood by the terminal
+pub const META_COMMANDS: &[&str] = &[":ping", ":raw", ":set-level", ":subscribe"];
+
+/// Command categories understood by the terminal
+const CATEGORIES: &[&str] = &["tools.", "resources.", "prompts."];
+
+/// Maximum number of commands kept in the history file
+const HISTORY_LIMIT: usize = 1000;
+
+/// A parsed terminal command
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `tools.<name> {args}`
+    Tool { name: String, arguments: Value },
+    /// `resources.<uri>`
+    Resource { uri: String },
+    /// `prompts.<name> {args}`
+    Prompt { name: String, arguments: Value },
+    /// `:ping`
+    Ping,
+    /// `:raw {"method": ..., "params": ...}`
+    Raw { method: String, params: Value },
+    /// `:set-level <level>`
+    SetLevel(LogLevel),
+    /// `:subscribe <uri>`
+    Subscribe(String),
+}
+
+/// Values available for substitution in a command
+pub struct Context<'a> {
+    /// Session variables, looked up before the process environment
+    pub variables: &'a HashMap<String, String>,
+    /// The previous response payload, wrapped as `{"result": ...}`
+    pub last: Option<&'a Value>,
+}
+
+/// Substitute references and parse a command line
+pub fn parse(input: &str, context: &Context<'_>) -> Result<Command, String> {
+    let input = input.trim();
+    let (head, rest) = match input.split_once(char::is_whitespace) {
+        Some((head, rest)) => (head, rest.trim()),
+        None => (input, ""),
+    };
+    let head = substitute(head, context, false)?;
+
+    if let Some(meta) = head.strip_prefix(':') {
+        return parse_meta(meta, rest, context);
+    }
+
+    let Some((category, name)) = head.split_once('.') else {
+        return Err(format!(
+            "Invalid command '{}'. Use tools.<name> {{args}}, resources.<uri>, prompts.<name> {{args}} or {}",
+            head,
+            META_COMMANDS.join(", ")
+        ));
+    };
+    if name.is_empty() {
+        return Err(format!("Missing name after '{}.'", category));
+    }
+
+    match category {
+        "tools" => Ok(Command::Tool {
+            name: name.to_string(),
+            arguments: parse_arguments(rest, context)?,
+        }),
+        "resources" if rest.is_empty() => Ok(Command::Resource {
+            uri: name.to_string(),
+        }),
+        "resources" => Err("resources.<uri> takes no arguments".to_string()),
+        "prompts" => Ok(Command::Prompt {
+            name: name.to_string(),
+            arguments: parse_arguments(rest, context)?,
+        }),
+        other => Err(format!(
+            "Unknown category '{}' (expected tools, resources or prompts)",
+            other
+        )),
+    }
+}
+
+fn parse_meta(meta: &str, rest: &str, context: &Context<'_>) -> Result<Command, String> {
+    match meta {
+        "ping" if rest.is_empty() => Ok(Command::Ping),
+        "ping" => Err(":ping takes no arguments".to_string()),
+        "raw" => {
+            let message: Value = serde_json::from_str(&substitute(rest, context, true)?)
+                .map_err(|e| format!("Invalid JSON-RPC message: {}", e))?;
+            let method = message
+                .get("method")
+                .and_then(Value::as_str)
+                .ok_or(":raw needs a JSON object with a \"method\" string")?;
+            Ok(Command::Raw {
+                method: method.to_string(),
+                params: message
+                    .get("params")
+                    .cloned()
+                    .unwrap_or_else(|| Value::Object(Map::new())),
+            })
+        }
+        "set-level" => {
+            let level = substitute(rest, context, false)?;
+            serde_json::from_value(Value::String(level.to_lowercase()))
+                .map(Command::SetLevel)
+                .map_err(|_| {
+                    format!(
+                        "Unknown log level '{}' (expected one of {})",
+                        level,
+                        log_levels().join(", ")
+                    )
+                })
+        }
+        "subscribe" if rest.is_empty() => Err(":subscribe needs a resource URI".to_string()),
+        "subscribe" => Ok(Command::Subscribe(substitute(rest, context, false)?)),
+        other => Err(format!(
+            "Unknown meta-command ':{}' (expected one of {})",
+            other,
+            META_COMMANDS.join(", ")
+        )),
+    }
+}
+
+fn parse_arguments(text: &str, context: &Context<'_>) -> Result<Value, String> {
+    if text.is_empty() {
+        return Ok(Value::Object(Map::new()));
+    }
+    let arguments: Value = serde_json::from_str(&substitute(text, context, true)?)
+        .map_err(|e| format!("Invalid JSON arguments: {}", e))?;
+    if !arguments.is_object() {
+        return Err("Arguments must be a JSON object".to_string());
+    }
+    Ok(arguments)
+}
+
+fn log_levels() -> Vec<String> {
+    LogLevel::all().iter().map(ToString::to_string).collect()
+}
+
+/// Replace `${VAR}` and `$last.<path>` references.
+///
+/// With `json` set, the text is scanned as JSON so values inside string
+/// literals are escaped. Unknown variables and paths are errors.
+pub fn substitute(text: &str, context: &Context<'_>, json: bool) -> Result<String, String> {
+    let mut output = String::with_capacity(text.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if in_string && escaped {
+            escaped = false;
+        } else if json && c == '"' {
+            in_string = !in_string;
+        } else if in_string && c == '\\' {
+            escaped = true;
+        } else if c == '$' {
+            if let Some((reference, length)) = parse_reference(rest) {
+                output.push_str(&render(&reference, context, json, in_string)?);
+                rest = &rest[length..];
+                continue;
+            }
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    Ok(output)
+}
+
+/// A `${VAR}` or `$last…` reference
+#[derive(Debug, PartialEq)]
+enum Reference {
+    Variable(String),
+    Last(Vec<PathSegment>),
+}
+
+#[derive(Debug, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a reference at the start of `text`, returning it and its length
+fn parse_reference(text: &str) -> Option<(Reference, usize)> {
+    if let Some(body) = text.strip_prefix("${") {
+        let end = body.find('}')?;
+        let name = &body[..end];
+        let valid = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        // `${env:…}` and other secret references are left to the transport
+        return valid.then(|| (Reference::Variable(name.to_string()), end + 3));
+    }
+
+    let body = text.strip_prefix("$last")?;
+    if body
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    let mut segments = Vec::new();
+    let mut consumed = "$last".len();
+    let mut rest = body;
+    loop {
+        if let Some(after) = rest.strip_prefix('.') {
+            let key_len = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(after.len());
+            if key_len == 0 {
+                break;
+            }
+            segments.push(PathSegment::Key(after[..key_len].to_string()));
+            consumed += 1 + key_len;
+            rest = &after[key_len..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let Some(end) = after.find(']') else { break };
+            let Ok(index) = after[..end].trim().parse() else {
+                break;
+            };
+            segments.push(PathSegment::Index(index));
+            consumed += end + 2;
+            rest = &after[end + 1..];
+        } else {
+            break;
+        }
+    }
+
+    Some((Reference::Last(segments), consumed))
+}
+
+fn render(
+    reference: &Reference,
+    context: &Context<'_>,
+    json: bool,
+    in_string: bool,
+) -> Result<String, String> {
+    let value = match reference {
+        Reference::Variable(name) => {
+            let text = context
+                .variables
+                .get(name)
+                .cloned()
+                .or_else(|| std::env::var(name).ok())
+                .ok_or_else(|| format!("Unknown variable ${{{}}} (set it with F5)", name))?;
+            return Ok(if in_string { escape_json(&text) } else { text });
+        }
+        Reference::Last(path) => {
+            let last = context
+                .last
+                .ok_or("$last is not available yet: no response received")?;
+            lookup(last, path).ok_or_else(|| {
+                format!(
+                    "$last{} does not exist in the previous response",
+                    path_label(path)
+                )
+            })?
+        }
+    };
+
+    Ok(match value {
+        Value::String(text) if in_string => escape_json(text),
+        Value::String(text) if !json => text.clone(),
+        other if in_string => escape_json(&other.to_string()),
+        other => other.to_string(),
+    })
+}
+
+fn lookup<'v>(value: &'v Value, path: &[PathSegment]) -> Option<&'v Value> {
+    path.iter()
+        .try_fold(value, |current, segment| match segment {
+            PathSegment::Key(key) => current.get(key.as_str()),
+            PathSegment::Index(index) => current.get(*index),
+        })
+}
+
+fn path_label(path: &[PathSegment]) -> String {
+    path.iter()
+        .map(|segment| match segment {
+            PathSegment::Key(key) => format!(".{}", key),
+            PathSegment::Index(index) => format!("[{}]", index),
+        })
+        .collect()
+}
+
+/// Escape text for insertion inside a JSON string literal
+fn escape_json(text: &str) -> String {
+    let quoted = Value::String(text.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+/// Persistent command history with Up/Down recall
+#[derive(Debug, Default)]
+pub struct CommandHistory {
+    entries: Vec<String>,
+    /// Position while recalling; `None` when editing a new command
+    cursor: Option<usize>,
+    /// The unfinished command to restore after recalling past the newest entry
+    draft: String,
+    path: Option<PathBuf>,
+}
+
+impl CommandHistory {
+    /// Load the history from a file, which need not exist yet
+    pub fn load(path: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .map(|content| {
+                content
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            entries,
+            path: Some(path),
+            ..Self::default()
+        }
+    }
+
+    /// Record an executed command and append it to the history file
+    pub fn push(&mut self, command: &str) {
+        self.cursor = None;
+        self.draft.clear();
+
+        // Never persist a secret that was typed in directly
+        let command = secrets::redact(command.trim());
+        if command.is_empty() || self.entries.last() == Some(&command) {
+            return;
+        }
+        self.entries.push(command);
+        if self.entries.len() > HISTORY_LIMIT {
+            self.entries.drain(..self.entries.len() - HISTORY_LIMIT);
+        }
+
+        if let Some(path) = &self.path {
+            let content = self.entries.join("\n") + "\n";
+            if let Err(e) = std::fs::write(path, content) {
+                tracing::warn!(
+                    "Failed to save command history to {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    /// Step back to an older command; `current` is kept as the draft
+    pub fn previous(&mut self, current: &str) -> Option<&str> {
+        let index = match self.cursor {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Step forward to a newer command, ending at the draft
+    pub fn next(&mut self) -> Option<&str> {
+        let index = self.cursor?;
+        if index + 1 < self.entries.len() {
+            self.cursor = Some(index + 1);
+            self.entries.get(index + 1).map(String::as_str)
+        } else {
+            self.cursor = None;
+            Some(&self.draft)
+        }
+    }
+}
+
+/// Capabilities offered for completion
+pub struct Catalog<'a> {
+    pub tools: &'a [ToolInfo],
+    pub resources: &'a [ResourceInfo],
+    pub prompts: &'a [PromptInfo],
+}
+
+/// Result of completing the end of a command line
+#[derive(Debug, PartialEq)]
+pub struct Completion {
+    /// The input extended by the longest common prefix of the candidates
+    pub line: String,
+    /// Every matching candidate
+    pub candidates: Vec<String>,
+}
+
+/// Complete the word at the end of `input`
+pub fn complete(input: &str, catalog: &Catalog<'_>) -> Option<Completion> {
+    let (head, rest) = match input.split_once(char::is_whitespace) {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (input, None),
+    };
+
+    let Some(rest) = rest else {
+        let candidates: Vec<String> = if let Some(name) = head.strip_prefix("tools.") {
+            filter(catalog.tools.iter().map(|tool| tool.name.as_str()), name)
+                .map(|name| format!("tools.{}", name))
+                .collect()
+        } else if let Some(uri) = head.strip_prefix("resources.") {
+            filter(catalog.resources.iter().map(|r| r.uri.as_str()), uri)
+                .map(|uri| format!("resources.{}", uri))
+                .collect()
+        } else if let Some(name) = head.strip_prefix("prompts.") {
+            filter(catalog.prompts.iter().map(|p| p.name.as_str()), name)
+                .map(|name| format!("prompts.{}", name))
+                .collect()
+        } else {
+            filter(CATEGORIES.iter().chain(META_COMMANDS).copied(), head)
+                .map(str::to_string)
+                .collect()
+        };
+        return extend(input, head, candidates);
+    };
+
+    match head {
+        ":set-level" => {
+            let levels = log_levels();
+            let candidates = filter(levels.iter().map(String::as_str), rest)
+                .map(str::to_string)
+                .collect();
+            extend(input, rest, candidates)
+        }
+        ":subscribe" => {
+            let candidates = filter(catalog.resources.iter().map(|r| r.uri.as_str()), rest)
+                .map(str::to_string)
+                .collect();
+            extend(input, rest, candidates)
+        }
+        _ => {
+            let keys = argument_keys(head, catalog);
+            complete_key(input, rest, &keys)
+        }
+    }
+}
+
+/// Argument names for a tool (from its input schema) or prompt
+fn argument_keys(head: &str, catalog: &Catalog<'_>) -> Vec<String> {
+    if let Some(name) = head.strip_prefix("tools.") {
+        catalog
+            .tools
+            .iter()
+            .find(|tool| tool.name == name || tool.full_name == name)
+            .and_then(|tool| tool.parameters.as_ref())
+            .and_then(|schema| schema.get("properties"))
+            .and_then(Value::as_object)
+            .map(|properties| properties.keys().cloned().collect())
+            .unwrap_or_default()
+    } else if let Some(name) = head.strip_prefix("prompts.") {
+        catalog
+            .prompts
+            .iter()
+            .find(|prompt| prompt.name == name)
+            .and_then(|prompt| prompt.arguments.as_ref())
+            .and_then(Value::as_array)
+            .map(|arguments| {
+                arguments
+                    .iter()
+                    .filter_map(|argument| argument.get("name").and_then(Value::as_str))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Complete an object key being typed at the end of JSON arguments
+fn complete_key(input: &str, arguments: &str, keys: &[String]) -> Option<Completion> {
+    if keys.is_empty() {
+        return None;
+    }
+
+    // Find where the current token starts, tracking string literals
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut string_start = 0;
+    let mut last_structural = None;
+    for (index, c) in arguments.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+            string_start = index;
+        } else if !c.is_whitespace() {
+            last_structural = Some(c);
+        }
+    }
+
+    // Keys follow `{` or `,`; an open string after them is a partial key
+    if !matches!(last_structural, Some('{') | Some(',')) {
+        return None;
+    }
+    let (partial, start) = if in_string {
+        (&arguments[string_start + 1..], string_start)
+    } else if arguments.ends_with(|c: char| c == '{' || c == ',' || c.is_whitespace()) {
+        ("", arguments.len())
+    } else {
+        return None;
+    };
+
+    let used: Vec<String> = keys
+        .iter()
+        .filter(|key| arguments[..start].contains(&format!("\"{}\"", key)))
+        .cloned()
+        .collect();
+    let candidates: Vec<String> = filter(
+        keys.iter()
+            .filter(|key| !used.contains(key))
+            .map(String::as_str),
+        partial,
+    )
+    .map(str::to_string)
+    .collect();
+
+    let prefix = &input[..input.len() - arguments.len() + start];
+    match candidates.as_slice() {
+        [] => None,
+        [key] => Some(Completion {
+            line: format!("{}\"{}\": ", prefix, key),
+            candidates,
+        }),
+        _ => {
+            let common = common_prefix(&candidates);
+            Some(Completion {
+                line: format!("{}\"{}", prefix, common),
+                candidates,
+            })
+        }
+    }
+}
+
+fn filter<'i>(
+    items: impl Iterator<Item = &'i str>,
+    prefix: &'i str,
+) -> impl Iterator<Item = &'i str> {
+    items.filter(move |item| item.starts_with(prefix))
+}
+
+/// Replace the trailing `word` of `input` by the candidates' common prefix
+fn extend(input: &str, word: &str, mut candidates: Vec<String>) -> Option<Completion> {
+    if candidates.is_empty() {
+        return None;
+    }
+    candidates.sort();
+    candidates.dedup();
+
+    let mut completed = common_prefix(&candidates);
+    // A unique command name is ready for its argument
+    if candidates.len() == 1 && !completed.ends_with('.') {
+        completed.push(' ');
+    }
+    let line = format!("{}{}", &input[..input.len() - word.len()], completed);
+    Some(Completion { line, candidates })
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+    let mut prefix = first.as_str();
+    for candidate in &candidates[1..] {
+        while !candidate.starts_with(prefix) {
+            let last = prefix.chars().last().map_or(0, char::len_utf8);
+            prefix = &prefix[..prefix.len() - last];
+        }
+    }
+    prefix.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn context<'a>(variables: &'a HashMap<String, String>, last: Option<&'a Value>) -> Context<'a> {
+        Context { variables, last }
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        let variables = HashMap::new();
+        let ctx = context(&variables, None);
+
+        assert_eq!(
+            parse(r#"tools.echo {"text": "hi"}"#, &ctx).unwrap(),
+            Command::Tool {
+                name: "echo".to_string(),
+                arguments: json!({"text": "hi"})
+            }
+        );
+        // Resource URIs may contain dots
+        assert_eq!(
+            parse("resources.file:///tmp/notes.txt", &ctx).unwrap(),
+            Command::Resource {
+                uri: "file:///tmp/notes.txt".to_string()
+            }
+        );
+        assert_eq!(
+            parse("prompts.review", &ctx).unwrap(),
+            Command::Prompt {
+                name: "review".to_string(),
+                arguments: json!({})
+            }
+        );
+        assert_eq!(parse(":ping", &ctx).unwrap(), Command::Ping);
+        assert_eq!(
+            parse(
+                r#":raw {"jsonrpc": "2.0", "method": "tools/list", "params": {}}"#,
+                &ctx
+            )
+            .unwrap(),
+            Command::Raw {
+                method: "tools/list".to_string(),
+                params: json!({})
+            }
+        );
+        assert_eq!(
+            parse(":set-level WARNING", &ctx).unwrap(),
+            Command::SetLevel(LogLevel::Warning)
+        );
+        assert_eq!(
+            parse(":subscribe file:///log", &ctx).unwrap(),
+            Command::Subscribe("file:///log".to_string())
+        );
+
+        assert!(parse("tools.echo {broken", &ctx).is_err());
+        assert!(parse("tools.echo [1]", &ctx).is_err());
+        assert!(parse("echo", &ctx).is_err());
+        assert!(parse(":set-level loud", &ctx).is_err());
+        assert!(parse(":frobnicate", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_substitution() {
+        let variables = HashMap::from([
+            ("CITY".to_string(), "Paris \"FR\"".to_string()),
+            ("COUNT".to_string(), "3".to_string()),
+        ]);
+        let last =
+            json!({"result": {"content": [{"type": "text", "text": "line\nbreak"}], "n": 7}});
+        let ctx = context(&variables, Some(&last));
+
+        assert_eq!(
+            parse(
+                r#"tools.weather {"city": "${CITY}", "days": ${COUNT}, "note": "$last.result.content[0].text", "n": $last.result.n}"#,
+                &ctx
+            )
+            .unwrap(),
+            Command::Tool {
+                name: "weather".to_string(),
+                arguments: json!({"city": "Paris \"FR\"", "days": 3, "note": "line\nbreak", "n": 7})
+            }
+        );
+        // Outside a string, $last values are inserted as JSON
+        assert_eq!(
+            substitute("{\"item\": $last.result.content[0]}", &ctx, true).unwrap(),
+            r#"{"item": {"text":"line\nbreak","type":"text"}}"#
+        );
+        // Secret references and unknown `$` sequences are left alone
+        assert_eq!(
+            substitute("${env:TOKEN} costs $5 $lastly", &ctx, false).unwrap(),
+            "${env:TOKEN} costs $5 $lastly"
+        );
+
+        assert!(substitute("${MCP_PROBE_UNSET_TEST_VAR}", &ctx, false).is_err());
+        assert!(substitute("$last.result.missing", &ctx, false).is_err());
+        assert!(substitute("$last", &context(&variables, None), false).is_err());
+    }
+
+    #[test]
+    fn test_history_persists_and_recalls() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history");
+
+        let mut history = CommandHistory::load(path.clone());
+        history.push("tools.echo {}");
+        history.push("tools.echo {}");
+        history.push(":ping");
+
+        let mut history = CommandHistory::load(path);
+        assert_eq!(history.previous("draft"), Some(":ping"));
+        assert_eq!(history.previous(""), Some("tools.echo {}"));
+        assert_eq!(history.previous(""), Some("tools.echo {}"));
+        assert_eq!(history.next(), Some(":ping"));
+        assert_eq!(history.next(), Some("draft"));
+        assert_eq!(history.next(), None);
+    }
+
+    #[test]
+    fn test_completion() {
+        let tools = vec![ToolInfo {
+            name: "weather".to_string(),
+            full_name: "weather".to_string(),
+            description: None,
+            parameters: Some(json!({
+                "type": "object",
+                "properties": {"city": {"type": "string"}, "country": {"type": "string"}, "days": {"type": "integer"}}
+            })),
+            title: None,
+            output_schema: None,
+            annotations: None,
+            server: 0,
+        }];
+        let resources = vec![ResourceInfo {
+            uri: "file:///notes.txt".to_string(),
+            name: None,
+            description: None,
+            mime_type: None,
+            server: 0,
+        }];
+        let catalog = Catalog {
+            tools: &tools,
+            resources: &resources,
+            prompts: &[],
+        };
+
+        let line = |input: &str| complete(input, &catalog).map(|completion| completion.line);
+        assert_eq!(line("to").as_deref(), Some("tools."));
+        assert_eq!(line("tools.w").as_deref(), Some("tools.weather "));
+        assert_eq!(
+            line("resources.").as_deref(),
+            Some("resources.file:///notes.txt ")
+        );
+        assert_eq!(line(":set-level w").as_deref(), Some(":set-level warning "));
+        assert_eq!(line(":s").as_deref(), Some(":s"));
+        assert_eq!(
+            line("tools.weather {\"c").as_deref(),
+            Some("tools.weather {\"c")
+        );
+        assert_eq!(
+            complete("tools.weather {\"c", &catalog).unwrap().candidates,
+            vec!["city", "country"]
+        );
+        assert_eq!(
+            line("tools.weather {\"ci").as_deref(),
+            Some("tools.weather {\"city\": ")
+        );
+        assert_eq!(
+            line("tools.weather {\"city\": \"x\", \"country\": \"y\", ").as_deref(),
+            Some("tools.weather {\"city\": \"x\", \"country\": \"y\", \"days\": ")
+        );
+        assert_eq!(line("tools.weather {\"city\": \"P"), None);
+        assert_eq!(line("nothing"), None);
+    }
+}
@@ -0,0 +1,843 @@
+//! Bench command implementation for load-testing MCP servers
+//!
+//! Drives a server with N concurrent clients (each with its own transport
+//! connection) for a fixed duration, optionally throttled to a target request
+//! rate, using a weighted mix of `tools/call`, `resources/read` and `ping`.
+
+use crate::cli::BenchArgs;
+use crate::config::Config;
+use anyhow::{Context, Result};
+use mcp_probe_core::{
+    client::{ClientConfig, DefaultNotificationHandler, McpClient},
+    error::{McpError, ProtocolError},
+    messages::{
+        resources::{ListResourcesRequest, ListResourcesResponse, ReadResourceRequest},
+        tools::{CallToolRequest, ListToolsRequest, ListToolsResponse},
+        Implementation, PingRequest,
+    },
+    secrets,
+    transport::TransportConfig,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tabled::{Table, Tabled};
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Request types the benchmark can issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum BenchOperation {
+    #[serde(rename = "tools/call")]
+    ToolsCall,
+    #[serde(rename = "resources/read")]
+    ResourcesRead,
+    #[serde(rename = "ping")]
+    Ping,
+}
+
+impl BenchOperation {
+    /// JSON-RPC method name for this operation
+    pub fn method(&self) -> &'static str {
+        match self {
+            Self::ToolsCall => "tools/call",
+            Self::ResourcesRead => "resources/read",
+            Self::Ping => "ping",
+        }
+    }
+
+    fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "tools/call" => Some(Self::ToolsCall),
+            "resources/read" => Some(Self::ResourcesRead),
+            "ping" => Some(Self::Ping),
+            _ => None,
+        }
+    }
+}
+
+/// Weighted mix of operations issued during a benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationMix {
+    entries: Vec<(BenchOperation, u32)>,
+}
+
+impl OperationMix {
+    /// Parse a mix specification such as `tools/call=60,resources/read=20,ping=20`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut entries: Vec<(BenchOperation, u32)> = Vec::new();
+
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (method, weight) = match part.split_once('=') {
+                Some((method, weight)) => {
+                    let weight = weight
+                        .trim()
+                        .parse::<u32>()
+                        .with_context(|| format!("Invalid weight in mix entry '{}'", part))?;
+                    (method.trim(), weight)
+                }
+                None => (part, 1),
+            };
+
+            let operation = BenchOperation::from_method(method).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unsupported bench method '{}' (expected tools/call, resources/read or ping)",
+                    method
+                )
+            })?;
+
+            if weight == 0 {
+                continue;
+            }
+
+            match entries.iter_mut().find(|(op, _)| *op == operation) {
+                Some(entry) => entry.1 += weight,
+                None => entries.push((operation, weight)),
+            }
+        }
+
+        if entries.is_empty() {
+            anyhow::bail!("Request mix '{}' does not contain any operations", spec);
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Drop an operation from the mix (e.g. when the server has no tools)
+    pub fn remove(&mut self, operation: BenchOperation) {
+        self.entries.retain(|(op, _)| *op != operation);
+    }
+
+    /// Whether the mix contains the given operation
+    pub fn contains(&self, operation: BenchOperation) -> bool {
+        self.entries.iter().any(|(op, _)| *op == operation)
+    }
+
+    /// Whether the mix has no operations left
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn total_weight(&self) -> u64 {
+        self.entries.iter().map(|(_, w)| *w as u64).sum()
+    }
+
+    /// Select the operation for the given global sequence number.
+    ///
+    /// The sequence is scattered with a prime stride so that operations are
+    /// interleaved while every window of `total_weight` requests still matches
+    /// the configured proportions exactly.
+    pub fn pick(&self, sequence: u64) -> BenchOperation {
+        let total = self.total_weight();
+        let mut slot = if total % 7919 == 0 {
+            sequence % total
+        } else {
+            sequence.wrapping_mul(7919) % total
+        };
+
+        for (operation, weight) in &self.entries {
+            if slot < *weight as u64 {
+                return *operation;
+            }
+            slot -= *weight as u64;
+        }
+
+        self.entries[0].0
+    }
+
+    fn describe(&self) -> String {
+        self.entries
+            .iter()
+            .map(|(op, w)| format!("{}={}", op.method(), w))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Latency distribution for a set of requests
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LatencySummary {
+    pub count: usize,
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencySummary {
+    /// Summarize a set of latencies using the nearest-rank percentile method
+    pub fn from_latencies(latencies: &mut [Duration]) -> Self {
+        if latencies.is_empty() {
+            return Self::default();
+        }
+
+        latencies.sort_unstable();
+        let total_nanos: u128 = latencies.iter().map(|d| d.as_nanos()).sum();
+
+        Self {
+            count: latencies.len(),
+            mean: Duration::from_nanos((total_nanos / latencies.len() as u128) as u64),
+            p50: percentile(latencies, 50.0),
+            p90: percentile(latencies, 90.0),
+            p99: percentile(latencies, 99.0),
+            max: latencies[latencies.len() - 1],
+        }
+    }
+}
+
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Resource usage of spawned stdio server processes
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProcessUsage {
+    pub pids: Vec<u32>,
+    pub peak_rss_kb: u64,
+    pub cpu_seconds: f64,
+    pub cpu_percent: f64,
+}
+
+/// Outcome of a single benchmark request
+#[derive(Debug, Clone)]
+struct Sample {
+    operation: BenchOperation,
+    latency: Duration,
+    error_category: Option<&'static str>,
+}
+
+/// Complete benchmark report
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub transport_type: String,
+    pub clients: usize,
+    pub connected_clients: usize,
+    pub target_rate: Option<f64>,
+    pub mix: String,
+    pub duration: Duration,
+    pub total_requests: usize,
+    pub failed_requests: usize,
+    pub throughput_rps: f64,
+    pub error_rate: f64,
+    pub latency: LatencySummary,
+    pub per_operation: BTreeMap<BenchOperation, LatencySummary>,
+    pub errors_by_category: BTreeMap<String, usize>,
+    pub process: Option<ProcessUsage>,
+}
+
+/// Targets resolved before the benchmark starts
+#[derive(Debug, Clone)]
+struct BenchTargets {
+    tool: Option<String>,
+    tool_args: Value,
+    resource: Option<String>,
+}
+
+/// Table row for the per-operation latency table
+#[derive(Tabled)]
+struct LatencyTableRow {
+    #[tabled(rename = "Operation")]
+    operation: String,
+    #[tabled(rename = "Requests")]
+    requests: String,
+    #[tabled(rename = "Mean")]
+    mean: String,
+    #[tabled(rename = "p50")]
+    p50: String,
+    #[tabled(rename = "p90")]
+    p90: String,
+    #[tabled(rename = "p99")]
+    p99: String,
+    #[tabled(rename = "Max")]
+    max: String,
+}
+
+/// Execute the bench command
+pub async fn run(args: BenchArgs, config: &Config) -> Result<()> {
+    if args.clients == 0 {
+        anyhow::bail!("--clients must be at least 1");
+    }
+    if let Some(rate) = args.rate {
+        if !(rate.is_finite() && rate > 0.0) {
+            anyhow::bail!("--rate must be a positive number of requests per second");
+        }
+    }
+
+    let transport_config = args.transport.resolve(config)?;
+    let mut mix = OperationMix::parse(&args.mix)?;
+    let tool_args: Value =
+        serde_json::from_str(&args.tool_args).context("--tool-args must be valid JSON")?;
+
+    tracing::info!(
+        "Starting benchmark: {} clients, {}s, mix {}",
+        args.clients,
+        args.duration,
+        mix.describe()
+    );
+
+    println!("🏋️  MCP Benchmark");
+    println!("🔌 Transport: {}", transport_config.transport_type());
+    println!("👥 Clients: {}", args.clients);
+    match args.rate {
+        Some(rate) => println!("🎯 Target rate: {:.1} req/s", rate),
+        None => println!("🎯 Target rate: unthrottled"),
+    }
+    println!("⏱️  Duration: {}s", args.duration);
+
+    let client_config = ClientConfig {
+        request_timeout: Duration::from_secs(args.timeout),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let targets = resolve_targets(
+        &transport_config,
+        &client_config,
+        &args,
+        tool_args,
+        &mut mix,
+    )
+    .await?;
+    println!("📋 Mix: {}", mix.describe());
+    println!();
+
+    let duration = Duration::from_secs(args.duration);
+    let limiter = args.rate.map(|rate| {
+        let mut interval = tokio::time::interval(Duration::from_secs_f64(1.0 / rate));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Arc::new(Mutex::new(interval))
+    });
+    let sequence = Arc::new(AtomicU64::new(0));
+    let (pid_sender, pid_receiver) = mpsc::unbounded_channel();
+    let sampler = tokio::spawn(sample_process_usage(pid_receiver));
+
+    let start = Instant::now();
+    let deadline = start + duration;
+    let mut handles = Vec::with_capacity(args.clients);
+    for worker_id in 0..args.clients {
+        handles.push(tokio::spawn(run_worker(
+            worker_id,
+            transport_config.clone(),
+            client_config.clone(),
+            targets.clone(),
+            mix.clone(),
+            deadline,
+            limiter.clone(),
+            sequence.clone(),
+            pid_sender.clone(),
+        )));
+    }
+    drop(pid_sender);
+
+    let mut samples = Vec::new();
+    let mut connected_clients = 0;
+    for handle in handles {
+        match handle.await? {
+            Ok(worker_samples) => {
+                connected_clients += 1;
+                samples.extend(worker_samples);
+            }
+            Err(e) => {
+                eprintln!("⚠️  Client failed to connect: {}", e);
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+    let process = sampler.await?.map(|mut usage| {
+        usage.cpu_percent = usage.cpu_seconds / elapsed.as_secs_f64() * 100.0;
+        usage
+    });
+
+    if connected_clients == 0 {
+        anyhow::bail!("No benchmark clients could connect to the server");
+    }
+
+    let report = build_report(
+        &transport_config,
+        &args,
+        &mix,
+        connected_clients,
+        elapsed,
+        samples,
+        process,
+    );
+    print_report(&report);
+
+    let report_path = match &args.report {
+        Some(path) => path.clone(),
+        None => crate::paths::get_mcp_probe_paths()?.report_file("bench-report", "json"),
+    };
+    if let Some(parent) = report_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(
+        &report_path,
+        secrets::redact(&serde_json::to_string_pretty(&report)?),
+    )?;
+    println!("📄 Benchmark report saved to: {}", report_path.display());
+
+    Ok(())
+}
+
+fn client_info() -> Implementation {
+    Implementation {
+        name: "mcp-probe-bench".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        metadata: std::collections::HashMap::new(),
+    }
+}
+
+async fn connect_client(
+    transport_config: &TransportConfig,
+    client_config: &ClientConfig,
+) -> Result<McpClient> {
+    let mut client = McpClient::new(
+        transport_config.clone(),
+        client_config.clone(),
+        Box::new(DefaultNotificationHandler),
+    )
+    .await?;
+    client.connect(client_info()).await?;
+    Ok(client)
+}
+
+/// Connect once up front to pick the tool and resource to exercise, removing
+/// operations from the mix that the server cannot serve.
+async fn resolve_targets(
+    transport_config: &TransportConfig,
+    client_config: &ClientConfig,
+    args: &BenchArgs,
+    tool_args: Value,
+    mix: &mut OperationMix,
+) -> Result<BenchTargets> {
+    let mut targets = BenchTargets {
+        tool: args.tool.clone(),
+        tool_args,
+        resource: args.resource.clone(),
+    };
+
+    let needs_tool = mix.contains(BenchOperation::ToolsCall) && targets.tool.is_none();
+    let needs_resource = mix.contains(BenchOperation::ResourcesRead) && targets.resource.is_none();
+
+    if needs_tool || needs_resource {
+        let mut client = connect_client(transport_config, client_config)
+            .await
+            .context("Failed to connect for target discovery")?;
+
+        if needs_tool {
+            let response = client
+                .send_request("tools/list", ListToolsRequest { cursor: None })
+                .await?;
+            targets.tool = response
+                .result
+                .and_then(|r| serde_json::from_value::<ListToolsResponse>(r).ok())
+                .and_then(|list| list.tools.into_iter().next())
+                .map(|tool| tool.name);
+        }
+
+        if needs_resource {
+            let response = client
+                .send_request("resources/list", ListResourcesRequest { cursor: None })
+                .await?;
+            targets.resource = response
+                .result
+                .and_then(|r| serde_json::from_value::<ListResourcesResponse>(r).ok())
+                .and_then(|list| list.resources.into_iter().next())
+                .map(|resource| resource.uri);
+        }
+
+        let _ = client.disconnect().await;
+    }
+
+    match &targets.tool {
+        Some(tool) if mix.contains(BenchOperation::ToolsCall) => {
+            println!("🛠️  Tool: {}", tool)
+        }
+        None if mix.contains(BenchOperation::ToolsCall) => {
+            println!("⚠️  Server exposes no tools - skipping tools/call");
+            mix.remove(BenchOperation::ToolsCall);
+        }
+        _ => {}
+    }
+
+    match &targets.resource {
+        Some(uri) if mix.contains(BenchOperation::ResourcesRead) => {
+            println!("📁 Resource: {}", uri)
+        }
+        None if mix.contains(BenchOperation::ResourcesRead) => {
+            println!("⚠️  Server exposes no resources - skipping resources/read");
+            mix.remove(BenchOperation::ResourcesRead);
+        }
+        _ => {}
+    }
+
+    if mix.is_empty() {
+        anyhow::bail!(
+            "Nothing left to benchmark - add 'ping' to --mix or specify --tool/--resource"
+        );
+    }
+
+    Ok(targets)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_worker(
+    worker_id: usize,
+    transport_config: TransportConfig,
+    client_config: ClientConfig,
+    targets: BenchTargets,
+    mix: OperationMix,
+    deadline: Instant,
+    limiter: Option<Arc<Mutex<Interval>>>,
+    sequence: Arc<AtomicU64>,
+    pid_sender: mpsc::UnboundedSender<u32>,
+) -> Result<Vec<Sample>> {
+    let mut client = connect_client(&transport_config, &client_config).await?;
+    tracing::debug!("Bench client {} connected", worker_id);
+
+    if let Some(pid) = client
+        .transport_info()
+        .metadata
+        .get("process_id")
+        .and_then(|v| v.as_u64())
+    {
+        let _ = pid_sender.send(pid as u32);
+    }
+
+    let mut samples = Vec::new();
+    while Instant::now() < deadline {
+        if let Some(limiter) = &limiter {
+            let tick = async { limiter.lock().await.tick().await };
+            if tokio::time::timeout_at(deadline.into(), tick)
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+
+        let operation = mix.pick(sequence.fetch_add(1, Ordering::Relaxed));
+        let started = Instant::now();
+        let result = match operation {
+            BenchOperation::ToolsCall => {
+                let request = CallToolRequest {
+                    name: targets.tool.clone().unwrap_or_default(),
+                    arguments: Some(targets.tool_args.clone()),
+                };
+                client.send_request(operation.method(), request).await
+            }
+            BenchOperation::ResourcesRead => {
+                let request = ReadResourceRequest {
+                    uri: targets.resource.clone().unwrap_or_default(),
+                };
+                client.send_request(operation.method(), request).await
+            }
+            BenchOperation::Ping => {
+                client
+                    .send_request(operation.method(), PingRequest::new())
+                    .await
+            }
+        };
+        let latency = started.elapsed();
+
+        let error_category = match result {
+            Ok(response) => response.error.map(|error| {
+                McpError::Protocol(ProtocolError::ServerError {
+                    code: error.code,
+                    message: error.message,
+                })
+                .category()
+            }),
+            Err(e) => Some(e.category()),
+        };
+
+        samples.push(Sample {
+            operation,
+            latency,
+            error_category,
+        });
+    }
+
+    let _ = client.disconnect().await;
+    Ok(samples)
+}
+
+/// Periodically sample RSS and CPU time of spawned stdio server processes.
+///
+/// Each server runs in its own process group, so the whole group is measured:
+/// servers started through wrappers like `npx`, `uv` or `sh -c` are counted
+/// together with the processes they start. Returns `None` when no processes
+/// were reported or the platform does not expose per-process statistics.
+async fn sample_process_usage(mut pids: mpsc::UnboundedReceiver<u32>) -> Option<ProcessUsage> {
+    let mut usage = ProcessUsage::default();
+    let mut cpu_ticks: BTreeMap<u32, u64> = BTreeMap::new();
+    let mut ticker = tokio::time::interval(Duration::from_millis(250));
+    let mut open = true;
+
+    loop {
+        tokio::select! {
+            pid = pids.recv(), if open => match pid {
+                Some(pid) => usage.pids.push(pid),
+                None => open = false,
+            },
+            _ = ticker.tick() => {
+                let mut total_rss = 0;
+                let mut alive = 0;
+                for pgid in &usage.pids {
+                    for (pid, rss_kb, ticks) in read_group_stats(*pgid) {
+                        alive += 1;
+                        total_rss += rss_kb;
+                        cpu_ticks.insert(pid, ticks);
+                    }
+                }
+                usage.peak_rss_kb = usage.peak_rss_kb.max(total_rss);
+                if !open && alive == 0 {
+                    break;
+                }
+            }
+        }
+    }
+
+    if usage.pids.is_empty() || cpu_ticks.is_empty() {
+        return None;
+    }
+
+    // /proc reports CPU time in USER_HZ, which the kernel ABI fixes at 100
+    usage.cpu_seconds = cpu_ticks.values().sum::<u64>() as f64 / 100.0;
+    Some(usage)
+}
+
+/// Read PID, resident memory (KiB) and total CPU ticks for every process in
+/// the process group `pgid`
+#[cfg(target_os = "linux")]
+fn read_group_stats(pgid: u32) -> Vec<(u32, u64, u64)> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter_map(|pid| {
+            let (pgrp, rss_kb, ticks) = read_process_stats(pid)?;
+            (pgrp == pgid).then_some((pid, rss_kb, ticks))
+        })
+        .collect()
+}
+
+/// Read process group, resident memory (KiB) and total CPU ticks for a process
+#[cfg(target_os = "linux")]
+fn read_process_stats(pid: u32) -> Option<(u32, u64, u64)> {
+    // Fields after the parenthesised command name; pgrp, utime and stime are
+    // the 5th, 14th and 15th fields of the full line
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    let after_comm = &stat[stat.rfind(')')? + 2..];
+    let fields: Vec<&str> = after_comm.split_whitespace().collect();
+    let pgrp = fields.get(2)?.parse::<u32>().ok()?;
+    let utime = fields.get(11)?.parse::<u64>().ok()?;
+    let stime = fields.get(12)?.parse::<u64>().ok()?;
+
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let rss_kb = status
+        .lines()
+        .find(|line| line.starts_with("VmRSS:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|kb| kb.parse::<u64>().ok())?;
+
+    Some((pgrp, rss_kb, utime + stime))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_group_stats(_pgid: u32) -> Vec<(u32, u64, u64)> {
+    Vec::new()
+}
+
+fn build_report(
+    transport_config: &TransportConfig,
+    args: &BenchArgs,
+    mix: &OperationMix,
+    connected_clients: usize,
+    elapsed: Duration,
+    samples: Vec<Sample>,
+    process: Option<ProcessUsage>,
+) -> BenchReport {
+    let total_requests = samples.len();
+    let mut errors_by_category: BTreeMap<String, usize> = BTreeMap::new();
+    let mut all_latencies = Vec::with_capacity(total_requests);
+    let mut by_operation: BTreeMap<BenchOperation, Vec<Duration>> = BTreeMap::new();
+
+    for sample in &samples {
+        all_latencies.push(sample.latency);
+        by_operation
+            .entry(sample.operation)
+            .or_default()
+            .push(sample.latency);
+        if let Some(category) = sample.error_category {
+            *errors_by_category.entry(category.to_string()).or_default() += 1;
+        }
+    }
+
+    let failed_requests = errors_by_category.values().sum();
+    let seconds = elapsed.as_secs_f64();
+
+    BenchReport {
+        generated_at: chrono::Utc::now(),
+        transport_type: transport_config.transport_type().to_string(),
+        clients: args.clients,
+        connected_clients,
+        target_rate: args.rate,
+        mix: mix.describe(),
+        duration: elapsed,
+        total_requests,
+        failed_requests,
+        throughput_rps: if seconds > 0.0 {
+            total_requests as f64 / seconds
+        } else {
+            0.0
+        },
+        error_rate: if total_requests > 0 {
+            failed_requests as f64 / total_requests as f64 * 100.0
+        } else {
+            0.0
+        },
+        latency: LatencySummary::from_latencies(&mut all_latencies),
+        per_operation: by_operation
+            .into_iter()
+            .map(|(op, mut latencies)| (op, LatencySummary::from_latencies(&mut latencies)))
+            .collect(),
+        errors_by_category,
+        process,
+    }
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn latency_row(operation: &str, summary: &LatencySummary) -> LatencyTableRow {
+    LatencyTableRow {
+        operation: operation.to_string(),
+        requests: summary.count.to_string(),
+        mean: format_ms(summary.mean),
+        p50: format_ms(summary.p50),
+        p90: format_ms(summary.p90),
+        p99: format_ms(summary.p99),
+        max: format_ms(summary.max),
+    }
+}
+
+fn print_report(report: &BenchReport) {
+    println!("\n📊 MCP Benchmark Results");
+    println!("════════════════════════");
+
+    let mut rows: Vec<LatencyTableRow> = report
+        .per_operation
+        .iter()
+        .map(|(op, summary)| latency_row(op.method(), summary))
+        .collect();
+    rows.push(latency_row("all", &report.latency));
+
+    let table = Table::new(rows)
+        .with(tabled::settings::Style::rounded())
+        .with(tabled::settings::Padding::new(1, 1, 0, 0))
+        .to_string();
+    println!("{}", table);
+
+    println!("\n📈 Summary");
+    println!("═══════════");
+    println!(
+        "Clients: {}/{} connected",
+        report.connected_clients, report.clients
+    );
+    println!("Duration: {:.2}s", report.duration.as_secs_f64());
+    println!("Requests: {}", report.total_requests);
+    println!("Throughput: {:.1} req/s", report.throughput_rps);
+    println!(
+        "Errors: {} ({:.2}%)",
+        report.failed_requests, report.error_rate
+    );
+    for (category, count) in &report.errors_by_category {
+        println!("  → {}: {}", category, count);
+    }
+
+    if let Some(process) = &report.process {
+        println!(
+            "Server processes: {} | peak RSS {:.1} MiB | CPU {:.2}s ({:.1}%)",
+            process.pids.len(),
+            process.peak_rss_kb as f64 / 1024.0,
+            process.cpu_seconds,
+            process.cpu_percent
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix_parsing() {
+        let mix = OperationMix::parse("tools/call=60, resources/read=20,ping").unwrap();
+        assert_eq!(mix.total_weight(), 81);
+        assert!(mix.contains(BenchOperation::ToolsCall));
+        assert!(mix.contains(BenchOperation::Ping));
+
+        assert!(OperationMix::parse("prompts/get=10").is_err());
+        assert!(OperationMix::parse("ping=abc").is_err());
+        assert!(OperationMix::parse("ping=0").is_err());
+    }
+
+    #[test]
+    fn test_mix_proportions() {
+        let mix = OperationMix::parse("tools/call=3,resources/read=1,ping=1").unwrap();
+        let picks: Vec<_> = (0..5).map(|seq| mix.pick(seq)).collect();
+
+        let tool_calls = picks
+            .iter()
+            .filter(|op| **op == BenchOperation::ToolsCall)
+            .count();
+        assert_eq!(tool_calls, 3);
+        assert!(picks.contains(&BenchOperation::ResourcesRead));
+        assert!(picks.contains(&BenchOperation::Ping));
+    }
+
+    #[test]
+    fn test_latency_percentiles() {
+        let mut latencies: Vec<Duration> = (1..=100).rev().map(Duration::from_millis).collect();
+        let summary = LatencySummary::from_latencies(&mut latencies);
+
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.p50, Duration::from_millis(50));
+        assert_eq!(summary.p90, Duration::from_millis(90));
+        assert_eq!(summary.p99, Duration::from_millis(99));
+        assert_eq!(summary.max, Duration::from_millis(100));
+        assert_eq!(
+            LatencySummary::from_latencies(&mut []).max,
+            Duration::default()
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_group_stats_include_wrapped_processes() {
+        use std::os::unix::process::CommandExt;
+
+        let mut wrapper = std::process::Command::new("sh")
+            .args(["-c", "sleep 2 & wait"])
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(200));
+
+        let members = read_group_stats(wrapper.id());
+        let _ = wrapper.kill();
+        let _ = wrapper.wait();
+
+        assert!(members.len() >= 2, "{:?}", members);
+        assert!(members.iter().any(|(pid, _, _)| *pid == wrapper.id()));
+    }
+}
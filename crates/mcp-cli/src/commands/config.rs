@@ -1,14 +1,19 @@
 //! Configuration management command implementation
 
 use crate::cli::{ConfigAction, ConfigArgs, ConfigTemplate};
+use crate::config::{Config, ResolvedConfig};
 use anyhow::Result;
+use mcp_probe_core::secrets;
 
 /// Execute the config command
-pub async fn run(args: ConfigArgs) -> Result<()> {
+pub async fn run(args: ConfigArgs, resolved: &ResolvedConfig) -> Result<()> {
     match args.action {
         ConfigAction::Init { output, template } => init_config(output, template).await,
         ConfigAction::Validate { config } => validate_config(config).await,
-        ConfigAction::Show { config } => show_config(config).await,
+        ConfigAction::Show {
+            effective: true, ..
+        } => show_effective_config(resolved),
+        ConfigAction::Show { config, .. } => show_config(config).await,
     }
 }
 
@@ -52,7 +57,7 @@ async fn validate_config(config: std::path::PathBuf) -> Result<()> {
     }
 
     let content = std::fs::read_to_string(&config)?;
-    let _parsed: toml::Value = toml::from_str(&content)?;
+    let _parsed: Config = toml::from_str(&content)?;
 
     println!("✅ Configuration is valid");
     Ok(())
@@ -69,8 +74,49 @@ async fn show_config(config: Option<std::path::PathBuf>) -> Result<()> {
         return Ok(());
     }
 
+    // Show the parsed file with credentials masked; fall back to the raw text
+    // (scrubbed of known secrets) when it does not parse
     let content = std::fs::read_to_string(&config_path)?;
-    println!("\n{}", content);
+    let redacted = toml::from_str::<serde_json::Value>(&content)
+        .ok()
+        .map(|mut value| {
+            secrets::redact_json(&mut value);
+            value
+        })
+        .and_then(|value| toml::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| secrets::redact(&content));
+    println!("\n{}", redacted);
+
+    Ok(())
+}
+
+/// Show the resolved configuration with the layer each value came from
+fn show_effective_config(resolved: &ResolvedConfig) -> Result<()> {
+    println!("📄 Effective configuration");
+    println!("   (defaults < user file < project file < --config < MCP_PROBE_* env < flags)\n");
+
+    let entries = resolved.entries();
+    let key_width = entries
+        .iter()
+        .map(|(key, _, _)| key.len())
+        .max()
+        .unwrap_or(0);
+    let value_width = entries
+        .iter()
+        .map(|(_, value, _)| value.chars().count().min(48))
+        .max()
+        .unwrap_or(0);
+
+    for (key, value, source) in entries {
+        println!(
+            "{:key_width$} = {:value_width$}  # {}",
+            key,
+            value,
+            source,
+            key_width = key_width,
+            value_width = value_width
+        );
+    }
 
     Ok(())
 }
@@ -86,11 +132,6 @@ args = ["server.py"]
 
 [client]
 name = "mcp-probe"
-version = "0.1.0"
-
-[timeouts]
-connection = "30s"
-request = "10s"
 "#
     .to_string()
 }
@@ -98,6 +139,9 @@ request = "10s"
 /// Generate full configuration template
 fn generate_full_config() -> String {
     r#"# MCP Probe Configuration (Full)
+#
+# Layers (later wins): defaults, ~/.mcp-probe/config/mcp-probe.toml,
+# ./mcp-probe.toml, --config, MCP_PROBE_* environment variables, CLI flags.
 
 [transport]
 type = "stdio"
@@ -105,6 +149,11 @@ command = "python"
 args = ["server.py"]
 working_dir = "/path/to/server"
 timeout = "30s"
+stderr_lines = 200
+# stderr_log = "/path/to/server-stderr.log"
+# Shutdown: close stdin, then SIGTERM the process group, then SIGKILL
+stdin_close_timeout = "2s"
+terminate_timeout = "2s"
 
 [transport.environment]
 PYTHONPATH = "/usr/local/lib/python3.9/site-packages"
@@ -112,27 +161,36 @@ PYTHONPATH = "/usr/local/lib/python3.9/site-packages"
 [client]
 name = "mcp-probe"
 version = "0.1.0"
-
-[capabilities]
-tools = true
-resources = true
-prompts = true
-logging = true
-
-[timeouts]
-connection = "30s"
-request = "10s"
-initialization = "60s"
+# Restart or reconnect to the server when the connection is lost
+reconnect = false
 
 [debug]
 show_raw_messages = false
-save_session = true
-session_dir = "./sessions"
+auto_save_sessions = true
+session_directory = "./sessions"
+max_saved_sessions = 100
 
 [logging]
 level = "info"
 format = "pretty"
 file = "mcp-probe.log"
+stderr = false
+
+[tui]
+color_scheme = "default"
+keymap = "default"
+refresh_rate_ms = 100
+show_help = true
+
+[tui.key_bindings]
+# quit = "q"
+# help = "f1, h"
+
+# OpenTelemetry spans and metrics (builds with the `otel` feature)
+[telemetry]
+# otlp_endpoint = "http://localhost:4318"
+# file = "telemetry.jsonl"
+service_name = "mcp-probe"
 "#
     .to_string()
 }
@@ -144,22 +202,26 @@ fn generate_dev_config() -> String {
 [transport]
 type = "stdio"
 command = "python"
-args = ["-m", "pip", "install", "-e", ".", "&&", "python", "server.py"]
+args = ["server.py"]
 working_dir = "./dev-server"
 timeout = "60s"
 
 [client]
 name = "mcp-probe-dev"
 version = "0.1.0-dev"
+reconnect = true
 
 [debug]
 show_raw_messages = true
-save_session = true
-session_dir = "./dev-sessions"
+auto_save_sessions = true
+session_directory = "./dev-sessions"
 
 [logging]
 level = "debug"
 format = "pretty"
+
+[tui]
+refresh_rate_ms = 50
 "#
     .to_string()
 }
@@ -179,16 +241,14 @@ timeout = "120s"
 
 [transport.auth]
 type = "bearer"
-token = "${MCP_AUTH_TOKEN}"
+token = "${env:MCP_AUTH_TOKEN}"
 
 [client]
 name = "mcp-probe"
 version = "0.1.0"
 
-[timeouts]
-connection = "60s"
-request = "30s"
-initialization = "120s"
+[debug]
+auto_save_sessions = false
 
 [logging]
 level = "info"
@@ -204,7 +264,7 @@ mod tests {
 
     #[test]
     fn test_config_templates_valid_toml() {
-        // Test that all templates generate valid TOML
+        // Test that all templates generate valid TOML matching the Config schema
         let templates = [
             generate_minimal_config(),
             generate_full_config(),
@@ -214,6 +274,8 @@ mod tests {
 
         for template in &templates {
             toml::from_str::<toml::Value>(template).expect("Template should generate valid TOML");
+            let config: Config = toml::from_str(template).expect("Template should match Config");
+            assert!(config.transport.is_some());
         }
     }
 }
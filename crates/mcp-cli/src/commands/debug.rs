@@ -3,7 +3,11 @@
 //! This command provides a comprehensive interactive debugging experience for MCP servers
 //! using a rich terminal user interface (TUI) built with ratatui.
 
-use crate::{cli::DebugArgs, tui::DebuggerApp};
+use crate::{
+    cli::DebugArgs,
+    config::Config,
+    tui::{name_collisions, DebuggerApp},
+};
 use anyhow::Result;
 use clap::Parser;
 use mcp_probe_core::{
@@ -90,6 +94,18 @@ pub struct DebugCommand {
     #[arg(long)]
     pub replay_session: Option<std::path::PathBuf>,
 
+    /// Restart or reconnect to the server when the connection is lost
+    #[arg(long)]
+    pub reconnect: bool,
+
+    /// Watch files or directories and restart the server when they change
+    #[arg(long, value_name = "PATH", num_args = 1.., value_delimiter = ',')]
+    pub watch: Vec<std::path::PathBuf>,
+
+    /// Also connect to these servers (`@profile` or an HTTP URL), as a host would
+    #[arg(long = "with", value_name = "SERVER", value_delimiter = ',')]
+    pub with: Vec<crate::cli::ServerRef>,
+
     /// Connection timeout in seconds
     #[arg(long, default_value = "30")]
     pub timeout: u64,
@@ -100,83 +116,202 @@ pub struct DebugCommand {
 }
 
 impl DebugCommand {
-    /// Execute the debug command
-    pub async fn execute(&self) -> Result<()> {
+    /// Execute the debug command with the resolved configuration
+    pub async fn execute(&self, config: &Config) -> Result<()> {
         // Create client info
-        let client_info = Implementation {
-            name: "mcp-probe".to_string(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-            metadata: std::collections::HashMap::new(),
-        };
+        let client_info = config.client.implementation();
 
         // Build transport configuration
-        let transport_config = self.transport.to_transport_config()?;
+        let transport_config = self.transport.resolve(config)?;
+        if !self.watch.is_empty() {
+            crate::watch::check_transport(&transport_config)?;
+        }
+        let (name, others) = self.session_servers(config, &transport_config)?;
 
         // Convert to DebugArgs structure
         let debug_args = DebugArgs {
             transport: self.transport.clone(),
             config: self.config.clone(),
             non_interactive: self.non_interactive,
-            show_raw: self.show_raw,
+            show_raw: config.debug.show_raw_messages,
             save_session: self.save_session.clone(),
             replay_session: self.replay_session.clone(),
+            reconnect: config.client.reconnect,
+            watch: self.watch.clone(),
+            with: self.with.clone(),
             timeout: self.timeout,
             max_retries: self.max_retries,
         };
 
         if self.non_interactive {
             // Run in simple non-interactive mode
-            self.run_non_interactive(transport_config, client_info)
+            self.run_non_interactive(transport_config, name, others, client_info, debug_args)
                 .await
         } else {
             // Launch the rich TUI experience
-            self.run_interactive_tui(transport_config, client_info, debug_args)
-                .await
+            self.run_interactive_tui(
+                transport_config,
+                name,
+                others,
+                client_info,
+                debug_args,
+                config,
+            )
+            .await
         }
     }
 
+    /// Names of all servers in the session, the main transport first, and
+    /// the `--with` servers with their transports. Repeated names get a
+    /// numeric suffix so every server can be addressed.
+    fn session_servers(
+        &self,
+        config: &Config,
+        transport_config: &TransportConfig,
+    ) -> Result<(String, Vec<(String, TransportConfig)>)> {
+        let mut names = vec![self.transport.server_name(transport_config)];
+        let mut servers = Vec::new();
+        for server in &self.with {
+            let (name, transport) = server.resolve(config)?;
+            let mut unique = name.clone();
+            let mut suffix = 2;
+            while names.contains(&unique) {
+                unique = format!("{}-{}", name, suffix);
+                suffix += 1;
+            }
+            names.push(unique.clone());
+            servers.push((unique, transport));
+        }
+        Ok((names.swap_remove(0), servers))
+    }
+
     /// Run in non-interactive mode with simple output
     async fn run_non_interactive(
         &self,
         transport_config: TransportConfig,
+        name: String,
+        others: Vec<(String, TransportConfig)>,
         client_info: Implementation,
+        debug_args: DebugArgs,
     ) -> Result<()> {
         println!("🔍 MCP Probe - Non-Interactive Debug Mode");
-        println!("🔌 Transport: {}", transport_config.transport_type());
+        if others.is_empty() {
+            println!("🔌 Transport: {}", transport_config.transport_type());
+        }
         println!("📡 Client: {} v{}", client_info.name, client_info.version);
         println!();
 
+        if debug_args.save_session.is_some() {
+            println!("⚠️  --save-session is only supported in interactive mode");
+        }
+
+        if others.is_empty() {
+            Self::describe_server(transport_config, client_info, &debug_args).await?;
+            println!("\n✅ Debug session completed successfully!");
+            return Ok(());
+        }
+
+        // Every server is listed even if an earlier one is down
+        let servers: Vec<_> = std::iter::once((name, transport_config))
+            .chain(others)
+            .collect();
+        let mut tools = Vec::new();
+        let mut failed = Vec::new();
+        for (name, transport_config) in &servers {
+            println!(
+                "\n🖥️  Server: {} ({})",
+                name,
+                transport_config.transport_type()
+            );
+            match Self::describe_server(transport_config.clone(), client_info.clone(), &debug_args)
+                .await
+            {
+                Ok(names) => tools.extend(names.into_iter().map(|tool| (name.as_str(), tool))),
+                Err(e) => {
+                    println!("❌ Failed to connect to {}: {}", name, e);
+                    failed.push(name.as_str());
+                }
+            }
+        }
+
+        let collisions =
+            name_collisions(tools.iter().map(|(server, tool)| (*server, tool.as_str())));
+        if !collisions.is_empty() {
+            println!("\n⚠️  Tool name collisions ({}):", collisions.len());
+            for (tool, servers) in &collisions {
+                println!("  → {} on {}", tool, servers.join(", "));
+            }
+        }
+
+        if !failed.is_empty() {
+            anyhow::bail!(
+                "{} of {} servers failed to connect: {}",
+                failed.len(),
+                servers.len(),
+                failed.join(", ")
+            );
+        }
+        println!("\n✅ Debug session completed successfully!");
+        Ok(())
+    }
+
+    /// Connect to one server and print its capabilities, returning the
+    /// names of its tools
+    async fn describe_server(
+        transport_config: TransportConfig,
+        client_info: Implementation,
+        debug_args: &DebugArgs,
+    ) -> Result<Vec<String>> {
         // Create and connect client
         let mut client = mcp_probe_core::client::McpClient::with_defaults(transport_config).await?;
-        let _server_info = client.connect(client_info).await?;
+        let server_info = client.connect(client_info).await?;
 
         println!("✅ Connected to MCP server successfully!");
 
+        if debug_args.show_raw {
+            println!("\n📨 Raw initialize result:");
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "protocolVersion": server_info.protocol_version,
+                    "capabilities": server_info.capabilities,
+                    "serverInfo": server_info.implementation,
+                }))?
+            );
+        }
+
         // List capabilities
         println!("\n🛠️  Server Capabilities:");
 
+        let mut tool_names = Vec::new();
         match client.list_tools().await {
             Ok(tools) => {
                 println!("📋 Tools ({}):", tools.len());
-                for tool in tools {
+                for tool in &tools {
                     println!("  → {} - {}", tool.name, tool.description);
                 }
+                if debug_args.show_raw {
+                    println!("{}", serde_json::to_string_pretty(&tools)?);
+                }
+                tool_names = tools.into_iter().map(|tool| tool.name).collect();
             }
             Err(e) => {
                 println!("❌ Failed to list tools: {}", e);
             }
         }
-
         match client.list_resources().await {
             Ok(resources) => {
                 println!("📁 Resources ({}):", resources.len());
-                for resource in resources {
+                for resource in &resources {
                     println!(
                         "  → {} - {}",
                         resource.uri,
-                        resource.description.unwrap_or_default()
+                        resource.description.as_deref().unwrap_or_default()
                     );
                 }
+                if debug_args.show_raw {
+                    println!("{}", serde_json::to_string_pretty(&resources)?);
+                }
             }
             Err(e) => {
                 if e.to_string().contains("Method not found") {
@@ -190,9 +325,12 @@ impl DebugCommand {
         match client.list_prompts().await {
             Ok(prompts) => {
                 println!("💬 Prompts ({}):", prompts.len());
-                for prompt in prompts {
+                for prompt in &prompts {
                     println!("  → {} - {}", prompt.name, prompt.description);
                 }
+                if debug_args.show_raw {
+                    println!("{}", serde_json::to_string_pretty(&prompts)?);
+                }
             }
             Err(e) => {
                 if e.to_string().contains("Method not found") {
@@ -203,19 +341,25 @@ impl DebugCommand {
             }
         }
 
-        println!("\n✅ Debug session completed successfully!");
-        Ok(())
+        Ok(tool_names)
     }
 
     /// Run the interactive TUI experience
     async fn run_interactive_tui(
         &self,
         transport_config: TransportConfig,
+        name: String,
+        others: Vec<(String, TransportConfig)>,
         client_info: Implementation,
-        _debug_args: DebugArgs,
+        debug_args: DebugArgs,
+        config: &Config,
     ) -> Result<()> {
         // Create and run the TUI application
-        let mut app = DebuggerApp::new(transport_config, client_info)?;
+        let mut app = DebuggerApp::new(transport_config, client_info)?
+            .with_config(config.clone())
+            .with_session_file(debug_args.save_session)
+            .with_watch(debug_args.watch)
+            .with_servers(name, others);
         app.run().await?;
 
         Ok(())
@@ -232,6 +376,7 @@ mod tests {
     async fn test_debug_command_creation() {
         let cmd = DebugCommand {
             transport: crate::cli::TransportArgs {
+                profile: None,
                 stdio: Some("test-server".to_string()),
                 args: vec![],
                 working_dir: None,
@@ -239,12 +384,16 @@ mod tests {
                 http_stream: None,
                 auth_header: None,
                 headers: vec![],
+                stderr_log: false,
             },
             config: None,
             non_interactive: true,
             show_raw: false,
             save_session: None,
             replay_session: None,
+            reconnect: false,
+            watch: vec![],
+            with: vec![],
             timeout: 30,
             max_retries: 3,
         };
@@ -258,6 +407,7 @@ mod tests {
     #[test]
     fn test_transport_config_conversion() {
         let transport_args = crate::cli::TransportArgs {
+            profile: None,
             stdio: Some("test-server".to_string()),
             args: vec!["--arg1".to_string(), "--arg2".to_string()],
             working_dir: Some(PathBuf::from("/tmp")),
@@ -265,6 +415,7 @@ mod tests {
             http_stream: None,
             auth_header: None,
             headers: vec![],
+            stderr_log: false,
         };
 
         let config = transport_args.to_transport_config().unwrap();
@@ -280,6 +431,7 @@ mod tests {
     #[test]
     fn test_http_sse_transport_config() {
         let transport_args = crate::cli::TransportArgs {
+            profile: None,
             stdio: None,
             args: vec![],
             working_dir: None,
@@ -287,6 +439,7 @@ mod tests {
             http_stream: None,
             auth_header: Some("Bearer token123".to_string()),
             headers: vec!["Content-Type=application/json".to_string()],
+            stderr_log: false,
         };
 
         let config = transport_args.to_transport_config().unwrap();
@@ -301,6 +454,7 @@ mod tests {
     #[test]
     fn test_http_stream_transport_config() {
         let transport_args = crate::cli::TransportArgs {
+            profile: None,
             stdio: None,
             args: vec![],
             working_dir: None,
@@ -308,6 +462,7 @@ mod tests {
             http_stream: Some("http://localhost:3000".parse::<Url>().unwrap()),
             auth_header: None,
             headers: vec![],
+            stderr_log: false,
         };
 
         let config = transport_args.to_transport_config().unwrap();
@@ -2,6 +2,7 @@
 
 use crate::cli::{ExportArgs, ExportFormat};
 use anyhow::Result;
+use mcp_probe_core::secrets;
 
 /// Execute the export command
 pub async fn run(args: ExportArgs) -> Result<()> {
@@ -15,7 +16,7 @@ pub async fn run(args: ExportArgs) -> Result<()> {
     println!("📝 Format: {:?}", args.format);
 
     // Read and parse session data
-    let session_data = std::fs::read_to_string(&args.session)?;
+    let session_data = redact_session(&std::fs::read_to_string(&args.session)?);
 
     // Convert to requested format
     let exported_data = match args.format {
@@ -50,6 +51,28 @@ pub async fn run(args: ExportArgs) -> Result<()> {
     Ok(())
 }
 
+/// Mask credentials in a recorded session so exports can be shared.
+///
+/// Transport settings are masked by key; messages are server data, so only
+/// known secret values are scrubbed from them.
+fn redact_session(session_data: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(session_data) {
+        Ok(mut session) => {
+            secrets::redact_payload(&mut session);
+            if let Some(transport) = session.get_mut("transport") {
+                secrets::redact_json(transport);
+            }
+            if let Some(servers) = session.get_mut("servers").and_then(|s| s.as_array_mut()) {
+                for transport in servers.iter_mut().filter_map(|s| s.get_mut("transport")) {
+                    secrets::redact_json(transport);
+                }
+            }
+            serde_json::to_string_pretty(&session).unwrap_or_else(|_| session.to_string())
+        }
+        Err(_) => secrets::redact(session_data),
+    }
+}
+
 /// Export session data as JSON
 fn export_as_json(session_data: &str, args: &ExportArgs) -> Result<String> {
     let mut export = serde_json::json!({
@@ -1,14 +1,17 @@
 //! Command implementations for MCP Probe CLI
 //!
 //! This module contains the implementation of all CLI commands including
-//! debug, test, config, validate, and export operations.
+//! debug, test, bench, monitor, config, servers, validate, and export operations.
 
 use anyhow::Result;
 
+pub mod bench;
 pub mod config;
 pub mod debug;
 pub mod export;
+pub mod monitor;
 pub mod paths;
+pub mod servers;
 pub mod test;
 pub mod validate;
 
@@ -0,0 +1,1029 @@
+//! Monitor command implementation for synthetic monitoring of MCP servers
+//!
+//! Health-checks a set of servers on an interval (connect and `initialize`,
+//! `ping`, `tools/list` and an optional canary tool call, plus the full
+//! validation suite every N checks) and serves the results as Prometheus
+//! text-format metrics on a local HTTP port.
+
+use crate::cli::MonitorArgs;
+use crate::commands::validation::{ValidationConfig, ValidationEngine, ValidationSummary};
+use crate::config::Config;
+use anyhow::{Context, Result};
+use mcp_probe_core::{
+    changes::{ListDiff, ListKind},
+    client::{ClientConfig, ClientState, DefaultNotificationHandler, McpClient},
+    events::ClientEventKind,
+    messages::{tools::CallToolRequest, Implementation, JsonRpcResponse, PingRequest},
+    secrets,
+    transport::TransportConfig,
+    McpResult, ServerInfo,
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::error::TryRecvError;
+use tokio::time::MissedTickBehavior;
+
+/// Upper bounds of the request latency histogram buckets, in seconds
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Largest HTTP request head read from a scraper
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// Time a scraper gets to send its request and read the response
+const SCRAPE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Pause after a failed accept, e.g. when out of file descriptors
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// What a check does besides connecting and pinging
+#[derive(Debug, Clone)]
+pub struct CheckOptions {
+    /// Client info sent in `initialize`
+    pub client_info: Implementation,
+    /// Per-request timeout
+    pub timeout: Duration,
+    /// Tool to call as a canary, with its arguments
+    pub canary: Option<(String, Value)>,
+}
+
+/// Result of one health check of a server
+#[derive(Debug, Clone, Default)]
+pub struct CheckOutcome {
+    /// Time to connect and complete the `initialize` handshake
+    pub handshake: Option<Duration>,
+    /// Latency of every answered request, by method
+    pub latencies: Vec<(String, Duration)>,
+    /// Methods of requests that failed or were answered with an error
+    pub request_errors: Vec<String>,
+    /// Failed stages with the reason
+    pub failures: Vec<(&'static str, String)>,
+    /// Tools listed by the server (`None` if not listed)
+    pub tools: Option<Vec<Value>>,
+    /// Requests sent by the client during the check
+    pub requests: u64,
+    /// Summary of the validation suite, if it ran
+    pub validation: Option<ValidationSummary>,
+}
+
+impl CheckOutcome {
+    fn fail(&mut self, stage: &'static str, reason: impl std::fmt::Display) {
+        self.failures
+            .push((stage, secrets::redact(&reason.to_string())));
+    }
+
+    /// Whether every stage succeeded
+    pub fn is_up(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Request latency histogram with [`LATENCY_BUCKETS`]
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Observations per bucket (not cumulative), plus one for `+Inf`
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; LATENCY_BUCKETS.len() + 1];
+        }
+        let index = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[index] += 1;
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// Metrics of one monitored server
+#[derive(Debug, Clone, Default)]
+pub struct ServerMetrics {
+    up: bool,
+    checks: u64,
+    last_check: Option<SystemTime>,
+    handshake: Option<Duration>,
+    requests: u64,
+    latencies: BTreeMap<String, Histogram>,
+    request_errors: BTreeMap<String, u64>,
+    failures: BTreeMap<&'static str, u64>,
+    tools: Option<Vec<Value>>,
+    baseline_tools: Option<usize>,
+    tool_changes: BTreeMap<&'static str, u64>,
+    validation: Option<ValidationSummary>,
+}
+
+impl ServerMetrics {
+    /// Add the outcome of a check.
+    pub fn record(&mut self, outcome: CheckOutcome) {
+        self.up = outcome.is_up();
+        self.checks += 1;
+        self.last_check = Some(SystemTime::now());
+        if outcome.handshake.is_some() {
+            self.handshake = outcome.handshake;
+        }
+        self.requests += outcome.requests;
+        for (method, latency) in outcome.latencies {
+            self.latencies
+                .entry(method)
+                .or_default()
+                .observe(latency.as_secs_f64());
+        }
+        for method in outcome.request_errors {
+            *self.request_errors.entry(method).or_default() += 1;
+        }
+        for (stage, _) in outcome.failures {
+            *self.failures.entry(stage).or_default() += 1;
+        }
+
+        if let Some(tools) = outcome.tools {
+            if let Some(previous) = &self.tools {
+                let diff = ListDiff::between(ListKind::Tools, previous, &tools);
+                for (change, count) in [
+                    ("added", diff.added.len()),
+                    ("removed", diff.removed.len()),
+                    ("modified", diff.modified.len()),
+                ] {
+                    *self.tool_changes.entry(change).or_default() += count as u64;
+                }
+            }
+            self.baseline_tools.get_or_insert(tools.len());
+            self.tools = Some(tools);
+        }
+        if outcome.validation.is_some() {
+            self.validation = outcome.validation;
+        }
+    }
+}
+
+/// Metrics of all monitored servers, by server name
+#[derive(Debug, Clone, Default)]
+pub struct MonitorMetrics {
+    servers: BTreeMap<String, ServerMetrics>,
+}
+
+impl MonitorMetrics {
+    /// Start reporting the given servers, all down until checked
+    pub fn new<'a>(servers: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            servers: servers
+                .into_iter()
+                .map(|name| (name.to_string(), ServerMetrics::default()))
+                .collect(),
+        }
+    }
+
+    /// Add the outcome of a check of a server.
+    pub fn record(&mut self, server: &str, outcome: CheckOutcome) {
+        self.servers
+            .entry(server.to_string())
+            .or_default()
+            .record(outcome);
+    }
+
+    /// Metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let servers = &self.servers;
+
+        family(
+            &mut out,
+            "mcp_probe_up",
+            "gauge",
+            "Whether every stage of the last check succeeded",
+        );
+        for (server, m) in servers {
+            sample(
+                &mut out,
+                "mcp_probe_up",
+                &[("server", server)],
+                u8::from(m.up),
+            );
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_checks_total",
+            "counter",
+            "Health checks run",
+        );
+        for (server, m) in servers {
+            sample(
+                &mut out,
+                "mcp_probe_checks_total",
+                &[("server", server)],
+                m.checks,
+            );
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_last_check_timestamp_seconds",
+            "gauge",
+            "Unix time of the last check",
+        );
+        for (server, m) in servers {
+            if let Some(at) = m.last_check {
+                let seconds = at
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs_f64();
+                sample(
+                    &mut out,
+                    "mcp_probe_last_check_timestamp_seconds",
+                    &[("server", server)],
+                    seconds,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_handshake_duration_seconds",
+            "gauge",
+            "Time to connect and initialize in the last successful handshake",
+        );
+        for (server, m) in servers {
+            if let Some(handshake) = m.handshake {
+                sample(
+                    &mut out,
+                    "mcp_probe_handshake_duration_seconds",
+                    &[("server", server)],
+                    handshake.as_secs_f64(),
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_request_duration_seconds",
+            "histogram",
+            "Latency of answered requests",
+        );
+        for (server, m) in servers {
+            for (method, histogram) in &m.latencies {
+                let labels = [("server", server.as_str()), ("method", method.as_str())];
+                let mut cumulative = 0;
+                for (i, count) in histogram.buckets.iter().enumerate() {
+                    cumulative += count;
+                    let le = LATENCY_BUCKETS
+                        .get(i)
+                        .map_or_else(|| "+Inf".to_string(), |bound| bound.to_string());
+                    let mut bucket_labels = labels.to_vec();
+                    bucket_labels.push(("le", &le));
+                    sample(
+                        &mut out,
+                        "mcp_probe_request_duration_seconds_bucket",
+                        &bucket_labels,
+                        cumulative,
+                    );
+                }
+                sample(
+                    &mut out,
+                    "mcp_probe_request_duration_seconds_sum",
+                    &labels,
+                    histogram.sum,
+                );
+                sample(
+                    &mut out,
+                    "mcp_probe_request_duration_seconds_count",
+                    &labels,
+                    histogram.count,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_requests_total",
+            "counter",
+            "Requests sent during checks",
+        );
+        for (server, m) in servers {
+            sample(
+                &mut out,
+                "mcp_probe_requests_total",
+                &[("server", server)],
+                m.requests,
+            );
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_request_errors_total",
+            "counter",
+            "Requests that failed or were answered with a JSON-RPC error",
+        );
+        for (server, m) in servers {
+            for (method, count) in &m.request_errors {
+                sample(
+                    &mut out,
+                    "mcp_probe_request_errors_total",
+                    &[("server", server), ("method", method)],
+                    *count,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_check_failures_total",
+            "counter",
+            "Failed check stages (connect, initialize, ping, tools/list, canary)",
+        );
+        for (server, m) in servers {
+            for (stage, count) in &m.failures {
+                sample(
+                    &mut out,
+                    "mcp_probe_check_failures_total",
+                    &[("server", server), ("stage", stage)],
+                    *count,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_tools",
+            "gauge",
+            "Tools listed in the last check",
+        );
+        for (server, m) in servers {
+            if let Some(tools) = &m.tools {
+                sample(
+                    &mut out,
+                    "mcp_probe_tools",
+                    &[("server", server)],
+                    tools.len(),
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_tool_count_drift",
+            "gauge",
+            "Tools listed in the last check minus tools listed in the first",
+        );
+        for (server, m) in servers {
+            if let (Some(tools), Some(baseline)) = (&m.tools, m.baseline_tools) {
+                sample(
+                    &mut out,
+                    "mcp_probe_tool_count_drift",
+                    &[("server", server)],
+                    tools.len() as i64 - baseline as i64,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_tool_changes_total",
+            "counter",
+            "Tools added, removed or modified between checks",
+        );
+        for (server, m) in servers {
+            for (change, count) in &m.tool_changes {
+                sample(
+                    &mut out,
+                    "mcp_probe_tool_changes_total",
+                    &[("server", server), ("change", change)],
+                    *count,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_validation_compliance_ratio",
+            "gauge",
+            "Share of passed validation tests in the last validation run",
+        );
+        for (server, m) in servers {
+            if let Some(summary) = &m.validation {
+                sample(
+                    &mut out,
+                    "mcp_probe_validation_compliance_ratio",
+                    &[("server", server)],
+                    summary.compliance_percentage / 100.0,
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "mcp_probe_validation_results",
+            "gauge",
+            "Validation test results of the last validation run, by status",
+        );
+        for (server, m) in servers {
+            let Some(summary) = &m.validation else {
+                continue;
+            };
+            for (status, count) in [
+                ("pass", summary.passed),
+                ("info", summary.info),
+                ("warning", summary.warnings),
+                ("error", summary.errors),
+                ("critical", summary.critical),
+                ("skipped", summary.skipped),
+            ] {
+                sample(
+                    &mut out,
+                    "mcp_probe_validation_results",
+                    &[("server", server), ("status", status)],
+                    count,
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/// Write the `HELP` and `TYPE` lines of a metric family
+fn family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Write one sample line
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    let labels = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+        .collect::<Vec<_>>()
+        .join(",");
+    let _ = writeln!(out, "{}{{{}}} {}", name, labels, value);
+}
+
+/// Escape a label value for the text exposition format
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Run one health check against a server.
+pub async fn check_server(transport: TransportConfig, options: &CheckOptions) -> CheckOutcome {
+    let mut outcome = CheckOutcome::default();
+    let client_config = ClientConfig {
+        request_timeout: options.timeout,
+        max_retries: 0,
+        ..Default::default()
+    };
+    let mut client = match McpClient::new(
+        transport,
+        client_config,
+        Box::new(DefaultNotificationHandler),
+    )
+    .await
+    {
+        Ok(client) => client,
+        Err(e) => {
+            outcome.fail("connect", e);
+            return outcome;
+        }
+    };
+    let mut events = client.subscribe();
+
+    let started = Instant::now();
+    let handshake_error = match client.connect(options.client_info.clone()).await {
+        Ok(server_info) => {
+            outcome.handshake = Some(started.elapsed());
+            run_stages(&mut client, &server_info, options, &mut outcome).await;
+            None
+        }
+        Err(e) => Some(e),
+    };
+    outcome.requests = client.stats().await.requests_sent;
+    let _ = client.disconnect().await;
+
+    // The transport is up once the client starts initializing
+    let mut initializing = false;
+    loop {
+        match events.try_recv() {
+            Ok(event) => match event.kind {
+                ClientEventKind::StateChanged {
+                    state: ClientState::Initializing,
+                    ..
+                } => initializing = true,
+                ClientEventKind::ResponseReceived {
+                    method,
+                    latency,
+                    error_code,
+                    ..
+                } => {
+                    if error_code.is_some() {
+                        outcome.request_errors.push(method.clone());
+                    }
+                    outcome.latencies.push((method, latency));
+                }
+                ClientEventKind::RequestFailed { method, .. } => {
+                    outcome.request_errors.push(method);
+                }
+                _ => {}
+            },
+            Err(TryRecvError::Lagged(_)) => continue,
+            Err(_) => break,
+        }
+    }
+    if let Some(e) = handshake_error {
+        outcome.fail(
+            if initializing {
+                "initialize"
+            } else {
+                "connect"
+            },
+            e,
+        );
+    }
+    outcome
+}
+
+/// `ping`, `tools/list` and the canary call on a connected client
+async fn run_stages(
+    client: &mut McpClient,
+    server_info: &ServerInfo,
+    options: &CheckOptions,
+    outcome: &mut CheckOutcome,
+) {
+    let ping = client.send_request("ping", PingRequest::new()).await;
+    if let Err(reason) = answered(ping) {
+        outcome.fail("ping", reason);
+    }
+
+    if server_info.capabilities.standard.tools.is_some() {
+        match list_tools(client).await {
+            Ok(tools) => outcome.tools = Some(tools),
+            Err(reason) => outcome.fail("tools/list", reason),
+        }
+    }
+
+    if let Some((tool, arguments)) = &options.canary {
+        let request = CallToolRequest {
+            name: tool.clone(),
+            arguments: Some(arguments.clone()),
+        };
+        match answered(client.send_request("tools/call", request).await) {
+            Ok(result) if result.get("isError") == Some(&Value::Bool(true)) => {
+                outcome.fail("canary", format!("Tool '{}' reported an error", tool))
+            }
+            Ok(_) => {}
+            Err(reason) => outcome.fail("canary", reason),
+        }
+    }
+}
+
+/// Every page of `tools/list`, following `nextCursor`
+async fn list_tools(client: &mut McpClient) -> Result<Vec<Value>, String> {
+    let mut tools = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let params = match &cursor {
+            Some(cursor) => serde_json::json!({ "cursor": cursor }),
+            None => serde_json::json!({}),
+        };
+        let result = answered(
+            client
+                .send_request(ListKind::Tools.list_method(), params)
+                .await,
+        )?;
+        match result.get(ListKind::Tools.result_field()) {
+            Some(Value::Array(page)) => tools.extend(page.iter().cloned()),
+            _ => return Err("Response has no tools array".to_string()),
+        }
+        match result.get("nextCursor").and_then(|v| v.as_str()) {
+            Some(next) if !next.is_empty() => cursor = Some(next.to_string()),
+            _ => return Ok(tools),
+        }
+    }
+}
+
+/// The result of an answered request, or why there is none
+fn answered(response: McpResult<JsonRpcResponse>) -> Result<Value, String> {
+    let response = response.map_err(|e| e.to_string())?;
+    match response.error {
+        Some(error) => Err(format!("{} (code {})", error.message, error.code)),
+        None => Ok(response.result.unwrap_or(Value::Null)),
+    }
+}
+
+/// Serve the metrics over HTTP until the task is aborted.
+pub async fn serve(listener: TcpListener, metrics: Arc<Mutex<MonitorMetrics>>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::warn!("Failed to accept a metrics connection: {}", e);
+                tokio::time::sleep(ACCEPT_BACKOFF).await;
+                continue;
+            }
+        };
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            match tokio::time::timeout(SCRAPE_TIMEOUT, handle_scrape(stream, &metrics)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => tracing::debug!("Metrics request failed: {}", e),
+                Err(_) => tracing::debug!("Metrics request timed out"),
+            }
+        });
+    }
+}
+
+async fn handle_scrape(mut stream: TcpStream, metrics: &Mutex<MonitorMetrics>) -> Result<()> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD {
+            anyhow::bail!("Request head too large");
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET" | "HEAD", "/metrics") => {
+            let body = metrics.lock().unwrap_or_else(|e| e.into_inner()).render();
+            ("200 OK", PROMETHEUS_CONTENT_TYPE, body)
+        }
+        ("GET" | "HEAD", "/") => (
+            "200 OK",
+            "text/plain; charset=utf-8",
+            "mcp-probe monitor: metrics at /metrics\n".to_string(),
+        ),
+        ("GET" | "HEAD", _) => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "Not found\n".to_string(),
+        ),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Method not allowed\n".to_string(),
+        ),
+    };
+
+    let mut response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    if method != "HEAD" {
+        response.push_str(&body);
+    }
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Servers to monitor: the given profiles, else every profile, else the default transport
+fn resolve_targets(args: &MonitorArgs, config: &Config) -> Result<Vec<(String, TransportConfig)>> {
+    if !args.servers.is_empty() {
+        return args
+            .servers
+            .iter()
+            .map(|name| {
+                Ok((
+                    name.clone(),
+                    config.server_profile(name)?.transport_config(),
+                ))
+            })
+            .collect();
+    }
+    if !config.servers.is_empty() {
+        return Ok(config
+            .servers
+            .iter()
+            .map(|(name, profile)| (name.clone(), profile.transport_config()))
+            .collect());
+    }
+    match &config.transport {
+        Some(transport) => Ok(vec![("default".to_string(), transport.clone())]),
+        None => anyhow::bail!(
+            "No servers to monitor. Pass @profile names, add profiles with `mcp-probe servers add`, or set [transport] in the configuration file"
+        ),
+    }
+}
+
+/// Execute the monitor command
+pub async fn run(args: MonitorArgs, config: &Config) -> Result<()> {
+    if args.interval == 0 {
+        anyhow::bail!("--interval must be at least 1 second");
+    }
+    let targets = resolve_targets(&args, config)?;
+    let canary = match &args.canary {
+        Some(tool) => {
+            let arguments: Value = serde_json::from_str(&args.canary_args)
+                .context("--canary-args must be valid JSON")?;
+            Some((tool.clone(), arguments))
+        }
+        None => None,
+    };
+    let options = Arc::new(CheckOptions {
+        client_info: config.client.implementation(),
+        timeout: Duration::from_secs(args.timeout),
+        canary,
+    });
+    let metrics = Arc::new(Mutex::new(MonitorMetrics::new(
+        targets.iter().map(|(name, _)| name.as_str()),
+    )));
+
+    if args.once {
+        let outcomes = check_round(&targets, &options, args.validate_every > 0).await;
+        let down = outcomes
+            .iter()
+            .filter(|(_, outcome)| !outcome.is_up())
+            .count();
+        let mut metrics = metrics.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, outcome) in outcomes {
+            print_outcome(&name, &outcome, true);
+            metrics.record(&name, outcome);
+        }
+        print!("{}", metrics.render());
+        if down > 0 {
+            anyhow::bail!("{} of {} servers down", down, targets.len());
+        }
+        return Ok(());
+    }
+
+    let listener = TcpListener::bind(args.listen)
+        .await
+        .with_context(|| format!("Failed to listen on {}", args.listen))?;
+    println!("📡 MCP Monitor");
+    println!(
+        "🖥️  Servers: {}",
+        targets
+            .iter()
+            .map(|(name, transport)| format!("{} ({})", name, transport.transport_type()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!("⏱️  Interval: {}s", args.interval);
+    if let Some((tool, _)) = &options.canary {
+        println!("🐤 Canary: {}", tool);
+    }
+    if args.validate_every > 0 {
+        println!("🔍 Validation: every {} checks", args.validate_every);
+    }
+    println!("📊 Metrics: http://{}/metrics", listener.local_addr()?);
+    println!("Press Ctrl+C to stop");
+    println!();
+
+    let server = tokio::spawn(serve(listener, Arc::clone(&metrics)));
+    let mut interval = tokio::time::interval(Duration::from_secs(args.interval));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut round = 0u64;
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+        let validate = args.validate_every > 0 && round % args.validate_every == 0;
+        round += 1;
+
+        let outcomes = tokio::select! {
+            outcomes = check_round(&targets, &options, validate) => outcomes,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        let mut metrics = metrics.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, outcome) in outcomes {
+            print_outcome(&name, &outcome, false);
+            metrics.record(&name, outcome);
+        }
+    }
+
+    server.abort();
+    println!("\n👋 Monitor stopped");
+    Ok(())
+}
+
+/// Check every server concurrently.
+///
+/// With `validate`, the validation suite runs too, without the protocol
+/// version matrix: its extra handshakes are not worth repeating every round.
+async fn check_round(
+    targets: &[(String, TransportConfig)],
+    options: &Arc<CheckOptions>,
+    validate: bool,
+) -> Vec<(String, CheckOutcome)> {
+    let checks = targets.iter().map(|(name, transport)| {
+        let options = Arc::clone(options);
+        let transport = transport.clone();
+        let name = name.clone();
+        async move {
+            let mut outcome = check_server(transport.clone(), &options).await;
+            if validate && outcome.handshake.is_some() {
+                let config = ValidationConfig {
+                    test_version_negotiation: false,
+                    ..ValidationConfig::default()
+                };
+                match ValidationEngine::new(transport)
+                    .with_config(config)
+                    .validate()
+                    .await
+                {
+                    Ok(report) => outcome.validation = Some(report.summary),
+                    Err(e) => tracing::warn!("Validation of {} failed: {}", name, e),
+                }
+            }
+            (name, outcome)
+        }
+    });
+    futures::future::join_all(checks).await
+}
+
+/// One status line per check; `--once` output goes to stderr to keep stdout
+/// for the metrics
+fn print_outcome(name: &str, outcome: &CheckOutcome, to_stderr: bool) {
+    let time = chrono::Local::now().format("%H:%M:%S");
+    let mut line = if outcome.is_up() {
+        format!("{} ✅ {} up", time, name)
+    } else {
+        format!("{} ❌ {} down", time, name)
+    };
+    if let Some(handshake) = outcome.handshake {
+        let _ = write!(line, ", handshake {}ms", handshake.as_millis());
+    }
+    if let Some(tools) = &outcome.tools {
+        let _ = write!(line, ", {} tools", tools.len());
+    }
+    if let Some(summary) = &outcome.validation {
+        let _ = write!(line, ", {:.0}% compliant", summary.compliance_percentage);
+    }
+    for (stage, reason) in &outcome.failures {
+        let _ = write!(line, "\n         {}: {}", stage, reason);
+    }
+    if to_stderr {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool(name: &str, description: &str) -> Value {
+        json!({"name": name, "description": description, "inputSchema": {"type": "object"}})
+    }
+
+    #[test]
+    fn test_metrics_render_checks_latency_and_tool_drift() {
+        let mut metrics = MonitorMetrics::new(["api", "idle"]);
+        metrics.record(
+            "api",
+            CheckOutcome {
+                handshake: Some(Duration::from_millis(40)),
+                latencies: vec![
+                    ("initialize".to_string(), Duration::from_millis(30)),
+                    ("ping".to_string(), Duration::from_millis(3)),
+                ],
+                tools: Some(vec![tool("a", "first"), tool("b", "second")]),
+                requests: 3,
+                ..Default::default()
+            },
+        );
+        metrics.record(
+            "api",
+            CheckOutcome {
+                latencies: vec![("ping".to_string(), Duration::from_secs(20))],
+                request_errors: vec!["tools/call".to_string()],
+                failures: vec![("canary", "boom".to_string())],
+                tools: Some(vec![
+                    tool("b", "changed"),
+                    tool("c", "third"),
+                    tool("d", ""),
+                ]),
+                requests: 4,
+                ..Default::default()
+            },
+        );
+
+        let text = metrics.render();
+        let lines: Vec<&str> = text.lines().collect();
+        for expected in [
+            "mcp_probe_up{server=\"api\"} 0",
+            "mcp_probe_up{server=\"idle\"} 0",
+            "mcp_probe_checks_total{server=\"api\"} 2",
+            "mcp_probe_checks_total{server=\"idle\"} 0",
+            "mcp_probe_handshake_duration_seconds{server=\"api\"} 0.04",
+            "mcp_probe_request_duration_seconds_bucket{server=\"api\",method=\"ping\",le=\"0.005\"} 1",
+            "mcp_probe_request_duration_seconds_bucket{server=\"api\",method=\"ping\",le=\"10\"} 1",
+            "mcp_probe_request_duration_seconds_bucket{server=\"api\",method=\"ping\",le=\"+Inf\"} 2",
+            "mcp_probe_request_duration_seconds_count{server=\"api\",method=\"ping\"} 2",
+            "mcp_probe_requests_total{server=\"api\"} 7",
+            "mcp_probe_request_errors_total{server=\"api\",method=\"tools/call\"} 1",
+            "mcp_probe_check_failures_total{server=\"api\",stage=\"canary\"} 1",
+            "mcp_probe_tools{server=\"api\"} 3",
+            "mcp_probe_tool_count_drift{server=\"api\"} 1",
+            "mcp_probe_tool_changes_total{server=\"api\",change=\"added\"} 2",
+            "mcp_probe_tool_changes_total{server=\"api\",change=\"removed\"} 1",
+            "mcp_probe_tool_changes_total{server=\"api\",change=\"modified\"} 1",
+            "# TYPE mcp_probe_request_duration_seconds histogram",
+        ] {
+            assert!(lines.contains(&expected), "missing {expected}:\n{text}");
+        }
+        assert!(!text.contains("mcp_probe_tools{server=\"idle\"}"));
+
+        // Every sample follows the TYPE line of its own family
+        let mut family = "";
+        for line in &lines {
+            if let Some(declared) = line.strip_prefix("# TYPE ") {
+                family = declared.split(' ').next().unwrap();
+            } else if !line.starts_with('#') {
+                let name = line.split('{').next().unwrap();
+                assert!(name.starts_with(family), "{name} outside {family}");
+            }
+        }
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd",);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_check_follows_tools_list_cursor() {
+        // Splits its tools over two pages
+        let script = r#"reply() { id=$(echo "$1" | sed 's/.*"id":"\([^"]*\)".*/\1/'); printf '{"jsonrpc":"2.0","id":"%s","result":%s}\n' "$id" "$2"; }
+read l; reply "$l" '{"protocolVersion":"2024-11-05","capabilities":{"tools":{}},"serverInfo":{"name":"paged","version":"1"}}'
+while read l; do case "$l" in
+  *'"cursor":"page2"'*) reply "$l" '{"tools":[{"name":"b","inputSchema":{"type":"object"}}]}' ;;
+  *tools/list*) reply "$l" '{"tools":[{"name":"a","inputSchema":{"type":"object"}}],"nextCursor":"page2"}' ;;
+  *'"id"'*) reply "$l" '{}' ;;
+esac; done"#;
+        let options = CheckOptions {
+            client_info: Implementation::new("test", "1"),
+            timeout: Duration::from_secs(5),
+            canary: None,
+        };
+        let outcome = check_server(TransportConfig::stdio("sh", &["-c", script]), &options).await;
+
+        assert!(outcome.failures.is_empty(), "{:?}", outcome.failures);
+        let names: Vec<_> = outcome
+            .tools
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_served_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut metrics = MonitorMetrics::new(["api"]);
+        metrics.record(
+            "api",
+            CheckOutcome {
+                handshake: Some(Duration::from_millis(5)),
+                ..Default::default()
+            },
+        );
+        let server = tokio::spawn(serve(listener, Arc::new(Mutex::new(metrics))));
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path);
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        let response = get("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+        assert!(response.contains("\r\n\r\n# HELP mcp_probe_up "));
+        assert!(response.contains("\nmcp_probe_up{server=\"api\"} 1\n"));
+        assert!(get("/other").await.starts_with("HTTP/1.1 404"));
+        server.abort();
+    }
+}
@@ -0,0 +1,448 @@
+//! Server profile registry command implementation
+//!
+//! Profiles are stored in `~/.mcp-probe/config/servers.toml` and can also be
+//! defined under `[servers.<name>]` in any configuration file. Commands accept
+//! them as `@name` in place of `--stdio`/`--http-sse`/`--http-stream`.
+
+use crate::cli::{ServerAddArgs, ServersAction, ServersArgs};
+use crate::config::{validate_profile_name, ResolvedConfig, ServerProfile, ServerRegistry};
+use anyhow::{Context, Result};
+use mcp_probe_core::transport::{
+    AuthConfig, HttpSseConfig, HttpStreamConfig, StdioConfig, TransportConfig,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use tabled::{Table, Tabled};
+
+#[derive(Tabled)]
+struct ServerRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Transport")]
+    transport: String,
+    #[tabled(rename = "Target")]
+    target: String,
+    #[tabled(rename = "Description")]
+    description: String,
+    #[tabled(rename = "Source")]
+    source: String,
+}
+
+/// Execute the servers command
+pub async fn run(args: ServersArgs, resolved: &ResolvedConfig) -> Result<()> {
+    let registry_path = ServerRegistry::default_path();
+
+    match args.action {
+        ServersAction::List => list_servers(resolved),
+        ServersAction::Add(add) => add_server(&registry_path, *add),
+        ServersAction::Remove { name } => remove_server(&registry_path, &name, resolved),
+        ServersAction::Rename { from, to } => rename_server(&registry_path, &from, &to, resolved),
+        ServersAction::Import {
+            file,
+            prefix,
+            force,
+        } => import_servers(&registry_path, &file, prefix.as_deref(), force),
+    }
+}
+
+/// List profiles from every configuration layer
+fn list_servers(resolved: &ResolvedConfig) -> Result<()> {
+    let servers = &resolved.config.servers;
+    if servers.is_empty() {
+        println!("📭 No server profiles configured");
+        println!("   Add one with `mcp-probe servers add <name> --stdio <command>`");
+        println!("   or import a host config with `mcp-probe servers import <file>`");
+        return Ok(());
+    }
+
+    let rows: Vec<ServerRow> = servers
+        .iter()
+        .map(|(name, profile)| ServerRow {
+            name: format!("@{}", name),
+            transport: profile.transport.transport_type().to_string(),
+            target: profile.target(),
+            description: profile.description.clone().unwrap_or_default(),
+            source: resolved
+                .source_of(&format!("servers.{}", name))
+                .map(|source| source.to_string())
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    println!("🗂️  Server profiles ({})\n", rows.len());
+    println!("{}", Table::new(rows));
+    Ok(())
+}
+
+/// Add a profile built from command-line flags
+fn add_server(registry_path: &Path, args: ServerAddArgs) -> Result<()> {
+    validate_profile_name(&args.name)?;
+    let profile = profile_from_args(&args)?;
+
+    let mut registry = ServerRegistry::load(registry_path)?;
+    if registry.servers.contains_key(&args.name) && !args.force {
+        anyhow::bail!(
+            "Server profile '@{}' already exists (use --force to overwrite)",
+            args.name
+        );
+    }
+
+    println!("➕ Adding @{} → {}", args.name, profile.target());
+    let transport = profile.transport_config();
+    if transport.redacted() != transport {
+        println!(
+            "⚠️  Writing literal credentials to {}; consider ${{env:…}}, ${{file:…}} or ${{cmd:…}} references",
+            registry_path.display()
+        );
+    }
+    registry.servers.insert(args.name, profile);
+    registry.save(registry_path)?;
+    println!("✅ Saved to {}", registry_path.display());
+    Ok(())
+}
+
+/// Remove a profile from the registry
+fn remove_server(registry_path: &Path, name: &str, resolved: &ResolvedConfig) -> Result<()> {
+    let name = name.strip_prefix('@').unwrap_or(name);
+    let mut registry = ServerRegistry::load(registry_path)?;
+
+    if registry.servers.remove(name).is_none() {
+        return Err(not_in_registry(name, resolved));
+    }
+
+    registry.save(registry_path)?;
+    println!("🗑️  Removed @{}", name);
+    Ok(())
+}
+
+/// Rename a profile in the registry
+fn rename_server(
+    registry_path: &Path,
+    from: &str,
+    to: &str,
+    resolved: &ResolvedConfig,
+) -> Result<()> {
+    let from = from.strip_prefix('@').unwrap_or(from);
+    let to = to.strip_prefix('@').unwrap_or(to);
+    validate_profile_name(to)?;
+
+    let mut registry = ServerRegistry::load(registry_path)?;
+    if registry.servers.contains_key(to) || resolved.config.servers.contains_key(to) {
+        anyhow::bail!("Server profile '@{}' already exists", to);
+    }
+    let Some(profile) = registry.servers.remove(from) else {
+        return Err(not_in_registry(from, resolved));
+    };
+
+    registry.servers.insert(to.to_string(), profile);
+    registry.save(registry_path)?;
+    println!("✏️  Renamed @{} → @{}", from, to);
+    Ok(())
+}
+
+/// Import profiles from a host configuration file
+fn import_servers(
+    registry_path: &Path,
+    file: &Path,
+    prefix: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let host_config: Value = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not valid JSON", file.display()))?;
+    let profiles = parse_host_config(&host_config)?;
+
+    println!("📥 Importing servers from {}", file.display());
+
+    let mut registry = ServerRegistry::load(registry_path)?;
+    let mut imported = 0;
+    for (name, profile) in profiles {
+        let name = format!("{}{}", prefix.unwrap_or_default(), name);
+        validate_profile_name(&name)?;
+        if registry.servers.contains_key(&name) && !force {
+            println!("  ⏭️  @{} already exists (use --force to overwrite)", name);
+            continue;
+        }
+        println!("  ✅ @{} → {}", name, profile.target());
+        registry.servers.insert(name, profile);
+        imported += 1;
+    }
+
+    registry.save(registry_path)?;
+    println!(
+        "\n✅ Imported {} server(s) into {}",
+        imported,
+        registry_path.display()
+    );
+    Ok(())
+}
+
+fn not_in_registry(name: &str, resolved: &ResolvedConfig) -> anyhow::Error {
+    match resolved.source_of(&format!("servers.{}", name)) {
+        Some(source) => anyhow::anyhow!(
+            "Server profile '@{}' is defined in {}; edit that file instead",
+            name,
+            source
+        ),
+        None => anyhow::anyhow!("Unknown server profile '@{}'", name),
+    }
+}
+
+/// Build a profile from `servers add` flags
+fn profile_from_args(args: &ServerAddArgs) -> Result<ServerProfile> {
+    let headers = parse_key_values(&args.header, "--header")?;
+    let transport = match (&args.stdio, &args.http_sse, &args.http_stream) {
+        (Some(_), None, None) if !headers.is_empty() || args.bearer.is_some() => {
+            anyhow::bail!("--header and --bearer apply to HTTP transports, not --stdio")
+        }
+        (Some(command), None, None) => TransportConfig::Stdio(StdioConfig {
+            command: command.clone(),
+            args: args.args.clone(),
+            working_dir: args
+                .working_dir
+                .as_ref()
+                .map(|dir| dir.display().to_string()),
+            ..StdioConfig::new(command.clone())
+        }),
+        (None, Some(url), None) => {
+            let mut config = HttpSseConfig::new(url.clone());
+            config.headers = headers;
+            TransportConfig::HttpSse(config)
+        }
+        (None, None, Some(url)) => {
+            let mut config = HttpStreamConfig::new(url.clone());
+            config.headers = headers;
+            TransportConfig::HttpStream(config)
+        }
+        (None, None, None) => {
+            anyhow::bail!("No transport specified. Use --stdio, --http-sse, or --http-stream")
+        }
+        _ => anyhow::bail!("Only one transport type can be specified at a time"),
+    };
+
+    Ok(ServerProfile {
+        description: args.description.clone(),
+        auth: args.bearer.as_ref().map(AuthConfig::bearer),
+        env: parse_key_values(&args.env, "--env")?.into_iter().collect(),
+        timeout: args.timeout,
+        ..ServerProfile::new(transport)
+    })
+}
+
+fn parse_key_values(values: &[String], flag: &str) -> Result<HashMap<String, String>> {
+    values
+        .iter()
+        .map(|value| {
+            value
+                .split_once('=')
+                .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+                .ok_or_else(|| anyhow::anyhow!("{} expects KEY=VALUE, got '{}'", flag, value))
+        })
+        .collect()
+}
+
+/// Parse the server map of an MCP host configuration.
+///
+/// Accepts the `mcpServers` object used by Claude Desktop, Cursor and similar
+/// hosts, and the `servers` object used by VS Code. Entries with `command`
+/// become stdio profiles; entries with `url` become HTTP profiles (SSE when
+/// `type` is `sse` or the URL ends in `/sse`, streaming otherwise).
+pub fn parse_host_config(config: &Value) -> Result<Vec<(String, ServerProfile)>> {
+    let servers = config
+        .get("mcpServers")
+        .or_else(|| config.get("servers"))
+        .and_then(Value::as_object)
+        .ok_or_else(|| anyhow::anyhow!("No `mcpServers` or `servers` object found"))?;
+
+    let mut profiles = Vec::new();
+    for (name, entry) in servers {
+        let profile =
+            parse_host_entry(entry).with_context(|| format!("Invalid server entry '{}'", name))?;
+        profiles.push((sanitize_profile_name(name), profile));
+    }
+    Ok(profiles)
+}
+
+fn parse_host_entry(entry: &Value) -> Result<ServerProfile> {
+    let string_map = |key: &str| -> HashMap<String, String> {
+        entry
+            .get(key)
+            .and_then(Value::as_object)
+            .map(|map| {
+                map.iter()
+                    .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let entry_type = entry.get("type").and_then(Value::as_str).unwrap_or("");
+
+    let transport = if let Some(command) = entry.get("command").and_then(Value::as_str) {
+        let args: Vec<String> = entry
+            .get("args")
+            .and_then(Value::as_array)
+            .map(|args| {
+                args.iter()
+                    .filter_map(|arg| arg.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut config = StdioConfig::new(command).args(args);
+        config.working_dir = entry.get("cwd").and_then(Value::as_str).map(str::to_string);
+        TransportConfig::Stdio(config)
+    } else if let Some(url) = entry
+        .get("url")
+        .or_else(|| entry.get("serverUrl"))
+        .and_then(Value::as_str)
+    {
+        let base_url = url
+            .parse()
+            .with_context(|| format!("Invalid URL '{}'", url))?;
+        let headers = string_map("headers");
+        if entry_type == "sse"
+            || (entry_type.is_empty() && url.trim_end_matches('/').ends_with("/sse"))
+        {
+            let mut config = HttpSseConfig::new(base_url);
+            config.headers = headers;
+            TransportConfig::HttpSse(config)
+        } else {
+            let mut config = HttpStreamConfig::new(base_url);
+            config.headers = headers;
+            TransportConfig::HttpStream(config)
+        }
+    } else {
+        anyhow::bail!("entry has neither `command` nor `url`");
+    };
+
+    Ok(ServerProfile {
+        env: string_map("env").into_iter().collect(),
+        ..ServerProfile::new(transport)
+    })
+}
+
+/// Replace characters that are not allowed in profile names with '-'
+fn sanitize_profile_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_host_config_formats() {
+        let claude = json!({
+            "mcpServers": {
+                "github": {
+                    "command": "npx",
+                    "args": ["-y", "@modelcontextprotocol/server-github"],
+                    "env": {"GITHUB_TOKEN": "${env:GITHUB_TOKEN}"}
+                },
+                "remote search": {"url": "https://example.com/mcp/sse"}
+            }
+        });
+        let profiles = parse_host_config(&claude).unwrap();
+        assert_eq!(profiles.len(), 2);
+
+        let (name, github) = &profiles[0];
+        assert_eq!(name, "github");
+        assert_eq!(
+            github.target(),
+            "npx -y @modelcontextprotocol/server-github"
+        );
+        assert_eq!(github.env["GITHUB_TOKEN"], "${env:GITHUB_TOKEN}");
+        match github.transport_config() {
+            TransportConfig::Stdio(config) => {
+                assert_eq!(config.environment["GITHUB_TOKEN"], "${env:GITHUB_TOKEN}")
+            }
+            other => panic!("expected stdio transport, got {:?}", other),
+        }
+
+        let (name, remote) = &profiles[1];
+        assert_eq!(name, "remote-search");
+        assert!(matches!(remote.transport, TransportConfig::HttpSse(_)));
+
+        let vscode = json!({
+            "servers": {
+                "search": {
+                    "type": "http",
+                    "url": "https://example.com/mcp",
+                    "headers": {"X-Api-Key": "abc"}
+                }
+            }
+        });
+        let profiles = parse_host_config(&vscode).unwrap();
+        match &profiles[0].1.transport {
+            TransportConfig::HttpStream(config) => assert_eq!(config.headers["X-Api-Key"], "abc"),
+            other => panic!("expected http stream transport, got {:?}", other),
+        }
+
+        assert!(parse_host_config(&json!({"other": {}})).is_err());
+        assert!(parse_host_config(&json!({"mcpServers": {"bad": {}}})).is_err());
+    }
+
+    #[test]
+    fn test_profile_from_args_rejects_http_flags_with_stdio() -> Result<()> {
+        let parse = |args: &[&str]| {
+            ServerAddArgs::try_parse_from(["add", "local"].iter().chain(args)).unwrap()
+        };
+
+        for flags in [
+            &["--stdio", "python", "--bearer", "${env:TOKEN}"][..],
+            &["--stdio", "python", "--header", "X-Api-Key=1"][..],
+        ] {
+            let error = profile_from_args(&parse(flags)).unwrap_err();
+            assert!(error.to_string().contains("not --stdio"), "{}", error);
+        }
+
+        let profile = profile_from_args(&parse(&[
+            "--http-stream",
+            "https://example.com/mcp",
+            "--bearer",
+            "${env:TOKEN}",
+        ]))?;
+        assert!(profile.auth.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_round_trip() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("servers.toml");
+
+        let mut registry = ServerRegistry::load(&path)?;
+        assert!(registry.servers.is_empty());
+
+        let mut profile = ServerProfile::new(TransportConfig::stdio("python", &["server.py"]));
+        profile.timeout = Some(std::time::Duration::from_secs(90));
+        profile.env.insert("DEBUG".to_string(), "1".to_string());
+        registry
+            .servers
+            .insert("local".to_string(), profile.clone());
+        registry.save(&path)?;
+
+        let loaded = ServerRegistry::load(&path)?;
+        assert_eq!(loaded.servers["local"], profile);
+        match loaded.servers["local"].transport_config() {
+            TransportConfig::Stdio(config) => {
+                assert_eq!(config.timeout, std::time::Duration::from_secs(90));
+                assert_eq!(config.environment["DEBUG"], "1");
+            }
+            other => panic!("expected stdio transport, got {:?}", other),
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 //! Test command implementation for automated MCP server testing
 
 use crate::cli::TestArgs;
+use crate::config::Config;
+use crate::watch::{self, FileWatcher};
 use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
 use mcp_probe_core::{
+    changes::{ListDiff, ListKind},
     client::McpClient,
     messages::{
         prompts::{ListPromptsRequest, ListPromptsResponse, Prompt},
@@ -11,13 +14,17 @@ use mcp_probe_core::{
         tools::{ListToolsRequest, ListToolsResponse, Tool},
         Implementation,
     },
-    transport::TransportConfig,
+    secrets,
+    transport::{ProcessCrash, ProcessLog, TransportConfig},
     McpResult,
 };
 use serde_json::Value;
 use std::time::{Duration, Instant};
 use tabled::{Table, Tabled};
 
+/// Stderr lines shown and reported when the server process crashes
+const CRASH_STDERR_LINES: usize = 20;
+
 /// Test result status
 #[derive(Debug, Clone, PartialEq)]
 pub enum TestStatus {
@@ -172,19 +179,19 @@ impl McpClientExt for McpClient {
 }
 
 /// Execute the test command
-pub async fn run(args: TestArgs) -> Result<()> {
+pub async fn run(args: TestArgs, config: &Config) -> Result<()> {
     // Handle discovery mode
     if let Some(base_url) = &args.discover {
         return run_discovery_tests(base_url, &args).await;
     }
 
-    let start_time = Instant::now();
-    let mut results = Vec::new();
-
     tracing::info!("Starting MCP test suite");
 
-    let transport_config = args.transport.to_transport_config()?;
+    let transport_config = args.transport.resolve(config)?;
     tracing::info!("Using transport: {}", transport_config.transport_type());
+    if !args.watch.is_empty() {
+        watch::check_transport(&transport_config)?;
+    }
 
     println!("🧪 MCP Test Suite");
     println!("🔌 Transport: {}", transport_config.transport_type());
@@ -203,17 +210,40 @@ pub async fn run(args: TestArgs) -> Result<()> {
         println!("⚡ Fail-fast mode enabled");
     }
 
+    if !args.watch.is_empty() {
+        println!("👀 Watch mode: rerunning on changes to the watched files");
+    }
+
     println!();
 
     // Display protocol information
     display_protocol_information(&transport_config);
 
     // Create client info
-    let client_info = Implementation {
-        name: "mcp-probe".to_string(),
-        version: env!("CARGO_PKG_VERSION").to_string(),
-        metadata: std::collections::HashMap::new(),
-    };
+    let client_info = config.client.implementation();
+
+    if !args.watch.is_empty() {
+        return watch_and_rerun(&args, &transport_config, &client_info).await;
+    }
+
+    let results = run_suite(&args, &transport_config, &client_info).await?;
+
+    // Check fail-fast mode
+    if args.fail_fast && results.iter().any(|r| r.status == TestStatus::Fail) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Connect, run the tests, print the results and write the report if requested
+async fn run_suite(
+    args: &TestArgs,
+    transport_config: &TransportConfig,
+    client_info: &Implementation,
+) -> Result<Vec<TestResult>> {
+    let start_time = Instant::now();
+    let mut results = Vec::new();
 
     // Phase 1: Connection - Use connection spinner
     let connection_spinner = create_connection_spinner();
@@ -223,15 +253,37 @@ pub async fn run(args: TestArgs) -> Result<()> {
         "Establishing MCP connection",
     );
 
-    let mut client = match test_connection(&transport_config, &client_info, &mut results).await {
+    let mut process_log = None;
+    let connection = test_connection(
+        transport_config,
+        client_info,
+        &mut results,
+        &mut process_log,
+    )
+    .await;
+    let mut client = match connection {
         Ok(client) => {
             connection_spinner.finish_with_message("✅ Connection established successfully!");
             client
         }
         Err(_) => {
             connection_spinner.finish_with_message("❌ Connection failed - check server status");
-            print_results(&results, start_time.elapsed());
-            return Ok(());
+            let total_duration = start_time.elapsed();
+            print_results(&results, total_duration);
+            let crash = server_crash(process_log.as_ref());
+            if let Some(crash) = &crash {
+                print_server_crash(crash);
+            }
+            if args.report {
+                generate_report(
+                    &results,
+                    total_duration,
+                    transport_config,
+                    crash.as_ref(),
+                    args.output_dir.as_ref(),
+                )?;
+            }
+            return Ok(results);
         }
     };
 
@@ -289,23 +341,144 @@ pub async fn run(args: TestArgs) -> Result<()> {
     // Print final results
     let total_duration = start_time.elapsed();
     print_results(&results, total_duration);
+    let crash = server_crash(process_log.as_ref());
+    if let Some(crash) = &crash {
+        print_server_crash(crash);
+    }
 
     // Generate report if requested
     if args.report {
         generate_report(
             &results,
             total_duration,
-            &transport_config,
+            transport_config,
+            crash.as_ref(),
             args.output_dir.as_ref(),
         )?;
     }
 
-    // Check fail-fast mode
-    if args.fail_fast && results.iter().any(|r| r.status == TestStatus::Fail) {
-        std::process::exit(1);
+    // Stop the server so the next watch-mode run starts a fresh process
+    if let Err(e) = client.disconnect().await {
+        tracing::debug!("Error disconnecting after the test run: {}", e);
     }
 
-    Ok(())
+    Ok(results)
+}
+
+/// Rerun the suite against a restarted server whenever a watched file changes
+async fn watch_and_rerun(
+    args: &TestArgs,
+    transport_config: &TransportConfig,
+    client_info: &Implementation,
+) -> Result<()> {
+    let mut watcher = FileWatcher::new(args.watch.clone()).await?;
+    let mut previous: Option<Vec<TestResult>> = None;
+
+    loop {
+        let results = run_suite(args, transport_config, client_info).await?;
+        if let Some(previous) = &previous {
+            print_run_changes(&RunChanges::between(previous, &results));
+        }
+        previous = Some(results);
+
+        println!(
+            "\n👀 Watching {} ({} files) for changes, Ctrl+C to stop",
+            watcher.describe(),
+            watcher.file_count()
+        );
+        let changed = tokio::select! {
+            changed = watcher.changed() => changed,
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        };
+        println!(
+            "\n🔄 {} changed, restarting the server and rerunning the tests\n",
+            watch::summarize(&changed)
+        );
+    }
+}
+
+/// What changed between two runs of the suite in watch mode
+#[derive(Debug, Default)]
+struct RunChanges {
+    /// Tests that did not run last time
+    new_tests: Vec<String>,
+    /// Tests that fail now but did not before
+    newly_failed: Vec<String>,
+    /// Tests that failed before and no longer do
+    fixed: Vec<String>,
+    /// Differences in the listed tools, resources and prompts
+    lists: Vec<ListDiff>,
+}
+
+impl RunChanges {
+    fn between(previous: &[TestResult], current: &[TestResult]) -> Self {
+        let mut changes = Self::default();
+        for result in current {
+            let failed = result.status == TestStatus::Fail;
+            match previous.iter().find(|before| before.name == result.name) {
+                None => changes.new_tests.push(result.name.clone()),
+                Some(before) => {
+                    let failed_before = before.status == TestStatus::Fail;
+                    if failed && !failed_before {
+                        changes.newly_failed.push(result.name.clone());
+                    } else if failed_before && !failed {
+                        changes.fixed.push(result.name.clone());
+                    }
+                }
+            }
+        }
+
+        // Listing tests keep the listed items as details
+        for (kind, name) in [
+            (ListKind::Tools, "Tools Listing"),
+            (ListKind::Resources, "Resources Listing"),
+            (ListKind::Prompts, "Prompts Listing"),
+        ] {
+            let items = |results: &[TestResult]| -> Vec<Value> {
+                results
+                    .iter()
+                    .find(|result| result.name == name)
+                    .and_then(|result| result.details.as_ref())
+                    .and_then(Value::as_array)
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let diff = ListDiff::between(kind, &items(previous), &items(current));
+            if !diff.is_empty() {
+                changes.lists.push(diff);
+            }
+        }
+        changes
+    }
+
+    fn is_empty(&self) -> bool {
+        self.new_tests.is_empty()
+            && self.newly_failed.is_empty()
+            && self.fixed.is_empty()
+            && self.lists.is_empty()
+    }
+}
+
+/// Print what changed since the previous run
+fn print_run_changes(changes: &RunChanges) {
+    println!("\n🔁 Since the last run");
+    println!("═════════════════════");
+    if changes.is_empty() {
+        println!("   No changes in test results or capabilities");
+        return;
+    }
+    for name in &changes.newly_failed {
+        println!("   ❌ Now failing: {}", name);
+    }
+    for name in &changes.fixed {
+        println!("   ✅ Fixed: {}", name);
+    }
+    for name in &changes.new_tests {
+        println!("   🆕 New test: {}", name);
+    }
+    for diff in &changes.lists {
+        println!("   🔔 {}", diff.summary());
+    }
 }
 
 /// Create a connection spinner with network-themed animation
@@ -390,15 +563,20 @@ fn update_spinner_message(spinner: &ProgressBar, phase: &str, detail: &str) {
 }
 
 /// Test connection and initialization
+///
+/// `process_log` receives the server's stderr capture as soon as the client
+/// exists, so it survives a failed initialization.
 async fn test_connection(
     transport_config: &TransportConfig,
     client_info: &Implementation,
     results: &mut Vec<TestResult>,
+    process_log: &mut Option<ProcessLog>,
 ) -> Result<McpClient> {
     let test_start = Instant::now();
 
     match McpClient::with_defaults(transport_config.clone()).await {
         Ok(mut client) => {
+            *process_log = client.process_log();
             results.push(TestResult {
                 name: "Connection".to_string(),
                 status: TestStatus::Pass,
@@ -741,11 +919,30 @@ fn print_results(results: &[TestResult], total_duration: Duration) {
     }
 }
 
+/// The server's exit and last stderr lines, if its process crashed
+fn server_crash(process_log: Option<&ProcessLog>) -> Option<ProcessCrash> {
+    process_log?.crash(CRASH_STDERR_LINES)
+}
+
+/// Print how the server process ended and what it last wrote to stderr
+fn print_server_crash(crash: &ProcessCrash) {
+    println!("\n💥 Server process exited unexpectedly ({})", crash.exit);
+    if crash.stderr.is_empty() {
+        println!("   (no stderr output)");
+        return;
+    }
+    println!("Last {} stderr lines:", crash.stderr.len());
+    for line in &crash.stderr {
+        println!("   {}", secrets::redact(line));
+    }
+}
+
 /// Generate test report
 fn generate_report(
     results: &[TestResult],
     duration: Duration,
     transport_config: &TransportConfig,
+    crash: Option<&ProcessCrash>,
     output_dir: Option<&std::path::PathBuf>,
 ) -> Result<()> {
     use crate::paths::get_mcp_probe_paths;
@@ -802,7 +999,8 @@ fn generate_report(
             "message": r.message,
             "duration_ms": r.duration.as_millis(),
             "details": r.details
-        })).collect::<Vec<_>>()
+        })).collect::<Vec<_>>(),
+        "server_crash": crash
     });
 
     if let Some(parent) = output_path.parent() {
@@ -810,7 +1008,7 @@ fn generate_report(
     }
 
     let mut file = fs::File::create(&output_path)?;
-    file.write_all(serde_json::to_string_pretty(&report)?.as_bytes())?;
+    file.write_all(secrets::redact(&serde_json::to_string_pretty(&report)?).as_bytes())?;
 
     println!("📄 Test report written to: {}", output_path.display());
 
@@ -1288,10 +1486,11 @@ async fn test_single_endpoint(
         metadata: std::collections::HashMap::new(),
     };
 
-    // Test connection with timeout
+    // Test connection with timeout; discovered endpoints are HTTP, so there is
+    // no server process to watch
     let connection_result = tokio::time::timeout(
         Duration::from_secs(args.timeout),
-        test_connection(transport_config, &client_info, results),
+        test_connection(transport_config, &client_info, results, &mut None),
     )
     .await;
 
@@ -1553,9 +1752,76 @@ fn generate_discovery_report(
     }
 
     let mut file = fs::File::create(&output_path)?;
-    file.write_all(json_content.as_bytes())?;
+    file.write_all(secrets::redact(&json_content).as_bytes())?;
 
     println!("📄 Discovery report written to: {}", output_path.display());
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, status: TestStatus, details: Option<Value>) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            status,
+            message: String::new(),
+            duration: Duration::ZERO,
+            details,
+        }
+    }
+
+    #[test]
+    fn test_run_changes_between_watch_runs() {
+        let previous = vec![
+            result("Initialization", TestStatus::Pass, None),
+            result(
+                "Tools Listing",
+                TestStatus::Pass,
+                Some(serde_json::json!([{"name": "echo"}, {"name": "add"}])),
+            ),
+            result("Error Handling", TestStatus::Fail, None),
+        ];
+        let current = vec![
+            result("Initialization", TestStatus::Fail, None),
+            result(
+                "Tools Listing",
+                TestStatus::Pass,
+                Some(serde_json::json!([{"name": "echo"}, {"name": "sum"}])),
+            ),
+            result("Error Handling", TestStatus::Pass, None),
+            result("Prompts Listing", TestStatus::Warning, None),
+        ];
+
+        let changes = RunChanges::between(&previous, &current);
+        assert_eq!(changes.newly_failed, ["Initialization"]);
+        assert_eq!(changes.fixed, ["Error Handling"]);
+        assert_eq!(changes.new_tests, ["Prompts Listing"]);
+        assert_eq!(changes.lists.len(), 1);
+        assert_eq!(changes.lists[0].added, ["sum"]);
+        assert_eq!(changes.lists[0].removed, ["add"]);
+
+        assert!(RunChanges::between(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_watch_conflicts_with_fail_fast() {
+        use clap::Parser;
+
+        // Fail-fast exits on the first failing run, which would end watch mode
+        let Err(error) = crate::cli::Cli::try_parse_from([
+            "mcp-probe",
+            "test",
+            "--stdio",
+            "python",
+            "--watch",
+            "server.py",
+            "--fail-fast",
+        ]) else {
+            panic!("--watch was accepted together with --fail-fast");
+        };
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+}
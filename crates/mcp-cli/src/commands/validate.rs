@@ -1,15 +1,21 @@
 //! Validation command implementation for MCP server compliance
 
-use super::validation::{ValidationConfig, ValidationEngine, ValidationStatus};
+use super::validation::{
+    NegotiationOutcome, ValidationConfig, ValidationEngine, ValidationStatus,
+    VersionNegotiationResult,
+};
 use crate::cli::{Severity, ValidateArgs};
+use crate::config::Config;
 use anyhow::Result;
+use mcp_probe_core::secrets;
+use mcp_probe_core::transport::ProcessCrash;
 use std::time::Duration;
 
 /// Execute the validate command
-pub async fn run(args: ValidateArgs) -> Result<()> {
+pub async fn run(args: ValidateArgs, config: &Config) -> Result<()> {
     tracing::info!("Starting MCP server validation");
 
-    let transport_config = args.transport.to_transport_config()?;
+    let transport_config = args.transport.resolve(config)?;
     tracing::info!("Using transport: {}", transport_config.transport_type());
 
     println!("🔍 MCP Server Validation");
@@ -23,7 +29,7 @@ pub async fn run(args: ValidateArgs) -> Result<()> {
     }
 
     // Configure validation engine based on command arguments
-    let mut config = ValidationConfig {
+    let mut validation_config = ValidationConfig {
         custom_rules: args.rules.clone(),
         ..Default::default()
     };
@@ -31,27 +37,27 @@ pub async fn run(args: ValidateArgs) -> Result<()> {
     // Adjust configuration based on severity level
     match args.severity {
         Severity::Info => {
-            config.strict_schema_validation = false;
-            config.test_error_conditions = false;
+            validation_config.strict_schema_validation = false;
+            validation_config.test_error_conditions = false;
         }
         Severity::Warning => {
-            config.strict_schema_validation = true;
-            config.test_error_conditions = false;
+            validation_config.strict_schema_validation = true;
+            validation_config.test_error_conditions = false;
         }
         Severity::Error => {
-            config.strict_schema_validation = true;
-            config.test_error_conditions = true;
+            validation_config.strict_schema_validation = true;
+            validation_config.test_error_conditions = true;
         }
         Severity::Critical => {
-            config.strict_schema_validation = true;
-            config.test_error_conditions = true;
-            config.test_timeout = Duration::from_secs(60);
-            config.total_timeout = Duration::from_secs(600);
+            validation_config.strict_schema_validation = true;
+            validation_config.test_error_conditions = true;
+            validation_config.test_timeout = Duration::from_secs(60);
+            validation_config.total_timeout = Duration::from_secs(600);
         }
     }
 
     // Create and run validation engine
-    let mut validator = ValidationEngine::new(transport_config).with_config(config);
+    let mut validator = ValidationEngine::new(transport_config).with_config(validation_config);
 
     println!("🚀 Starting validation engine...");
 
@@ -59,6 +65,10 @@ pub async fn run(args: ValidateArgs) -> Result<()> {
         Ok(report) => {
             // Display results
             display_validation_results(&report.results, &args.severity);
+            display_version_matrix(&report.version_negotiation);
+            if let Some(crash) = &report.server_crash {
+                display_server_crash(crash);
+            }
 
             // Generate report if requested
             if let Some(report_path) = &args.report {
@@ -149,16 +159,13 @@ fn display_validation_results(
             );
 
             // Show details for failures
-            if matches!(
-                result.status,
-                ValidationStatus::Error | ValidationStatus::Critical
-            ) && result.details.is_some()
+            if let (ValidationStatus::Error | ValidationStatus::Critical, Some(details)) =
+                (&result.status, &result.details)
             {
-                if let Ok(details_str) =
-                    serde_json::to_string_pretty(result.details.as_ref().unwrap())
-                {
-                    let truncated = if details_str.len() > 200 {
-                        format!("{}...", &details_str[..200])
+                if let Ok(details_str) = serde_json::to_string_pretty(details) {
+                    // Details may quote server output, so cut on a char boundary
+                    let truncated = if details_str.chars().count() > 200 {
+                        format!("{}...", details_str.chars().take(200).collect::<String>())
                     } else {
                         details_str
                     };
@@ -171,6 +178,61 @@ fn display_validation_results(
     println!("{:-<80}", "");
 }
 
+/// Display the protocol version negotiation matrix
+fn display_version_matrix(matrix: &[VersionNegotiationResult]) {
+    if matrix.is_empty() {
+        return;
+    }
+
+    println!("\n🔀 Protocol Version Matrix:");
+    println!("{:-<80}", "");
+    println!(
+        "{:<14} {:<14} {:<20} Issues",
+        "Requested", "Negotiated", "Outcome"
+    );
+    for entry in matrix {
+        println!(
+            "{:<14} {:<14} {:<20} {}",
+            entry.requested,
+            entry.negotiated.as_deref().unwrap_or("-"),
+            outcome_label(&entry.outcome),
+            entry.issues.len()
+        );
+        for issue in &entry.issues {
+            println!("   ⚠️  {}", issue);
+        }
+        if let Some(error) = &entry.error {
+            println!("   ❌ {}", error);
+        }
+    }
+    println!("{:-<80}", "");
+}
+
+/// Display how the server process ended and what it last wrote to stderr
+fn display_server_crash(crash: &ProcessCrash) {
+    println!("\n💥 Server process exited unexpectedly ({})", crash.exit);
+    println!("{:-<80}", "");
+    if crash.stderr.is_empty() {
+        println!("(no stderr output)");
+    }
+    for line in &crash.stderr {
+        println!("{}", secrets::redact(line));
+    }
+    println!("{:-<80}", "");
+}
+
+/// Human-readable label for a negotiation outcome
+fn outcome_label(outcome: &NegotiationOutcome) -> &'static str {
+    match outcome {
+        NegotiationOutcome::Accepted => "accepted",
+        NegotiationOutcome::Downgraded => "downgraded",
+        NegotiationOutcome::Upgraded => "upgraded",
+        NegotiationOutcome::EchoedUnknown => "echoed unknown",
+        NegotiationOutcome::UnrecognizedVersion => "unrecognized version",
+        NegotiationOutcome::Failed => "failed",
+    }
+}
+
 /// Determine if a result should be displayed based on severity filter
 fn should_display_result(
     result: &super::validation::ValidationResult,
@@ -201,20 +263,20 @@ fn generate_validation_report(
     match extension {
         "json" => {
             let json_content = serde_json::to_string_pretty(report)?;
-            std::fs::write(path, json_content)?;
+            std::fs::write(path, secrets::redact(&json_content))?;
         }
         "yaml" | "yml" => {
             let yaml_content = serde_yaml::to_string(report)?;
-            std::fs::write(path, yaml_content)?;
+            std::fs::write(path, secrets::redact(&yaml_content))?;
         }
         "md" | "markdown" => {
             let markdown_content = generate_markdown_report(report)?;
-            std::fs::write(path, markdown_content)?;
+            std::fs::write(path, secrets::redact(&markdown_content))?;
         }
         _ => {
             // Default to JSON
             let json_content = serde_json::to_string_pretty(report)?;
-            std::fs::write(path, json_content)?;
+            std::fs::write(path, secrets::redact(&json_content))?;
         }
     }
 
@@ -289,6 +351,54 @@ fn generate_markdown_report(report: &super::validation::ValidationReport) -> Res
         content.push('\n');
     }
 
+    // Protocol version matrix
+    if !report.version_negotiation.is_empty() {
+        content.push_str("## Protocol Version Matrix\n\n");
+        content.push_str("| Requested | Negotiated | Outcome | Issues |\n");
+        content.push_str("|-----------|------------|---------|--------|\n");
+        for entry in &report.version_negotiation {
+            let mut notes = entry.issues.join("; ");
+            if let Some(error) = &entry.error {
+                if !notes.is_empty() {
+                    notes.push_str("; ");
+                }
+                notes.push_str(error);
+            }
+            content.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                entry.requested,
+                entry.negotiated.as_deref().unwrap_or("-"),
+                outcome_label(&entry.outcome),
+                if notes.is_empty() {
+                    "-"
+                } else {
+                    notes.as_str()
+                }
+            ));
+        }
+        content.push('\n');
+    }
+
+    // Server crash
+    if let Some(crash) = &report.server_crash {
+        content.push_str("## Server Crash\n\n");
+        content.push_str(&format!(
+            "The server process exited unexpectedly ({}).\n\n",
+            crash.exit
+        ));
+        if !crash.stderr.is_empty() {
+            content.push_str(&format!(
+                "Last {} stderr lines:\n\n```\n",
+                crash.stderr.len()
+            ));
+            for line in &crash.stderr {
+                content.push_str(line);
+                content.push('\n');
+            }
+            content.push_str("```\n\n");
+        }
+    }
+
     // Performance metrics
     content.push_str("## Performance\n\n");
     content.push_str(&format!(
@@ -385,12 +495,27 @@ mod tests {
                 failed_requests: 0,
                 timeouts: 0,
             },
+            version_negotiation: vec![VersionNegotiationResult {
+                requested: "2099-01-01".to_string(),
+                negotiated: Some("2025-06-18".to_string()),
+                outcome: NegotiationOutcome::Downgraded,
+                server_capabilities: vec!["tools".to_string()],
+                issues: vec![],
+                error: None,
+            }],
+            server_crash: Some(ProcessCrash {
+                exit: mcp_probe_core::transport::ProcessExit::Code(1),
+                stderr: vec!["Traceback (most recent call last):".to_string()],
+            }),
         };
 
         let markdown = generate_markdown_report(&report)?;
         assert!(markdown.contains("# MCP Server Validation Report"));
         assert!(markdown.contains("**Total Tests:** 5"));
         assert!(markdown.contains("80.0%"));
+        assert!(markdown.contains("| 2099-01-01 | 2025-06-18 | downgraded | - |"));
+        assert!(markdown.contains("exited unexpectedly (exit code 1)"));
+        assert!(markdown.contains("```\nTraceback (most recent call last):\n```"));
 
         Ok(())
     }
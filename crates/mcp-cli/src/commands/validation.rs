@@ -20,11 +20,13 @@ use mcp_probe_core::{
         initialization::{InitializeRequest, InitializeResponse},
         prompts::{ListPromptsRequest, Prompt},
         resources::{ListResourcesRequest, Resource},
-        tools::{ListToolsRequest, Tool},
-        Capabilities, Implementation, ProtocolVersion,
+        tools::{CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, Tool},
+        Capabilities, Implementation, JsonRpcNotification, ProtocolVersion,
     },
-    transport::{Transport, TransportConfig, TransportFactory},
-    validation::ParameterValidator,
+    transport::{
+        ProcessCrash, ProcessLog, ShutdownStage, Transport, TransportConfig, TransportFactory,
+    },
+    validation::{validate_structured_content, ParameterValidator, ValidationError},
 };
 
 /// Comprehensive validation engine for MCP servers
@@ -32,9 +34,17 @@ pub struct ValidationEngine {
     transport_config: TransportConfig,
     config: ValidationConfig,
     results: Vec<ValidationResult>,
+    version_negotiation: Vec<VersionNegotiationResult>,
     start_time: Option<Instant>,
+    process_log: Option<ProcessLog>,
 }
 
+/// Protocol version sent to check how servers handle revisions they don't know
+const UNKNOWN_FUTURE_VERSION: &str = "2099-01-01";
+
+/// Stderr lines included in the report when the server process crashes
+const CRASH_STDERR_LINES: usize = 20;
+
 /// Configuration for validation engine behavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationConfig {
@@ -56,6 +66,15 @@ pub struct ValidationConfig {
     /// Whether to test capability discovery
     pub test_capability_discovery: bool,
 
+    /// Whether to initialize once per known protocol version (plus an unknown one)
+    #[serde(default = "default_true")]
+    pub test_version_negotiation: bool,
+
+    /// Whether to call read-only tools that declare an `outputSchema` and
+    /// check their `structuredContent` against it
+    #[serde(default = "default_true")]
+    pub test_structured_output: bool,
+
     /// Maximum number of tools to test individually
     pub max_tools_to_test: usize,
 
@@ -72,12 +91,18 @@ impl Default for ValidationConfig {
             test_error_conditions: true,
             validate_tool_schemas: true,
             test_capability_discovery: true,
+            test_version_negotiation: true,
+            test_structured_output: true,
             max_tools_to_test: 10,
             custom_rules: vec![],
         }
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
 /// Result of a single validation test
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -148,6 +173,48 @@ pub struct ValidationReport {
 
     /// Performance metrics
     pub performance: PerformanceMetrics,
+
+    /// Outcome of initializing the server with each protocol version
+    #[serde(default)]
+    pub version_negotiation: Vec<VersionNegotiationResult>,
+
+    /// Exit status and last stderr lines if the server process crashed
+    #[serde(default)]
+    pub server_crash: Option<ProcessCrash>,
+}
+
+/// How the server answered an `initialize` request for a specific version
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NegotiationOutcome {
+    /// Server answered with the requested version
+    Accepted,
+    /// Server answered with an older version it supports
+    Downgraded,
+    /// Server answered with a newer version than requested
+    Upgraded,
+    /// Server echoed back a version it cannot know about
+    EchoedUnknown,
+    /// Server answered with a version this validator does not recognise
+    UnrecognizedVersion,
+    /// Initialization failed (error response, transport failure or timeout)
+    Failed,
+}
+
+/// Result of initializing the server with one specific protocol version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionNegotiationResult {
+    /// Protocol version sent in the `initialize` request
+    pub requested: String,
+    /// Protocol version the server answered with
+    pub negotiated: Option<String>,
+    /// How the server negotiated
+    pub outcome: NegotiationOutcome,
+    /// Capability keys advertised by the server
+    pub server_capabilities: Vec<String>,
+    /// Methods or fields that don't match the negotiated version
+    pub issues: Vec<String>,
+    /// Error response or transport failure
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -227,7 +294,9 @@ impl ValidationEngine {
             transport_config,
             config: ValidationConfig::default(),
             results: Vec::new(),
+            version_negotiation: Vec::new(),
             start_time: None,
+            process_log: None,
         }
     }
 
@@ -247,6 +316,8 @@ impl ValidationEngine {
             timeout(self.config.total_timeout, self.run_validation_suite()).await;
 
         match validation_result {
+            // A crashed server is a finding, not a reason to lose the report
+            Ok(Err(_)) if self.server_crash().is_some() => self.generate_report(),
             Ok(result) => result,
             Err(_) => {
                 self.add_result(ValidationResult {
@@ -269,14 +340,25 @@ impl ValidationEngine {
     async fn run_validation_suite(&mut self) -> Result<ValidationReport> {
         // Step 1: Test basic connectivity and initialization
         let mut transport = self.create_transport().await?;
-        let server_info = self.test_initialization(&mut transport).await?;
+        let mut server_info = self.test_initialization(&mut transport).await?;
+
+        // Step 1.5: Test protocol version negotiation on fresh connections
+        if self.config.test_version_negotiation {
+            self.test_version_negotiation().await?;
+        }
 
         // Step 2: Test protocol compliance
         self.test_protocol_compliance(&mut transport).await?;
 
         // Step 3: Test capability discovery
         if self.config.test_capability_discovery {
-            self.test_capability_discovery(&mut transport).await?;
+            let discovered_tools = self.test_capability_discovery(&mut transport).await?;
+            if let Some(tools_cap) = server_info
+                .as_mut()
+                .and_then(|si| si.capabilities.tools.as_mut())
+            {
+                tools_cap.available_tools = discovered_tools;
+            }
         }
 
         // Step 3.5: Test transport-specific features
@@ -319,6 +401,9 @@ impl ValidationEngine {
             self.test_schema_validation().await?;
         }
 
+        // Step 9: Shut the server down the way MCP clients are expected to
+        self.test_shutdown(&mut transport).await?;
+
         info!("Validation suite completed successfully");
         self.generate_report()
     }
@@ -329,6 +414,7 @@ impl ValidationEngine {
 
         let result = async {
             let mut transport = TransportFactory::create(self.transport_config.clone()).await?;
+            self.process_log = transport.process_log();
             transport.connect().await?;
             Ok::<_, McpError>(transport)
         }
@@ -383,7 +469,7 @@ impl ValidationEngine {
         };
 
         let init_request = InitializeRequest {
-            protocol_version: ProtocolVersion::V2024_11_05,
+            protocol_version: ProtocolVersion::default(),
             capabilities: Capabilities::default(),
             client_info,
         };
@@ -531,6 +617,288 @@ impl ValidationEngine {
         }
     }
 
+    /// Initialize the server once per known protocol version plus an unknown
+    /// future version, checking negotiation and method availability
+    async fn test_version_negotiation(&mut self) -> Result<()> {
+        info!("Testing protocol version negotiation");
+
+        let mut requested_versions = ProtocolVersion::supported_versions();
+        requested_versions.push(ProtocolVersion::Custom(UNKNOWN_FUTURE_VERSION.to_string()));
+
+        for requested in requested_versions {
+            let test_start = Instant::now();
+            let negotiation = self.negotiate_version(&requested).await;
+
+            let (status, message) = match negotiation.outcome {
+                NegotiationOutcome::Accepted if negotiation.issues.is_empty() => (
+                    ValidationStatus::Pass,
+                    format!("Server accepted protocol version {}", requested),
+                ),
+                NegotiationOutcome::Accepted => (
+                    ValidationStatus::Warning,
+                    format!(
+                        "Server accepted {} but {} method/capability mismatch(es) found",
+                        requested,
+                        negotiation.issues.len()
+                    ),
+                ),
+                NegotiationOutcome::Downgraded => (
+                    if !negotiation.issues.is_empty() {
+                        ValidationStatus::Warning
+                    } else if requested.is_supported() {
+                        ValidationStatus::Info
+                    } else {
+                        ValidationStatus::Pass
+                    },
+                    format!(
+                        "Requested {}, server negotiated down to {}",
+                        requested,
+                        negotiation.negotiated.as_deref().unwrap_or("?")
+                    ),
+                ),
+                NegotiationOutcome::Upgraded => (
+                    ValidationStatus::Warning,
+                    format!(
+                        "Requested {}, server answered newer version {}",
+                        requested,
+                        negotiation.negotiated.as_deref().unwrap_or("?")
+                    ),
+                ),
+                NegotiationOutcome::EchoedUnknown => (
+                    ValidationStatus::Error,
+                    format!(
+                        "Server echoed unknown version {} instead of a version it supports",
+                        requested
+                    ),
+                ),
+                NegotiationOutcome::UnrecognizedVersion => (
+                    ValidationStatus::Warning,
+                    format!(
+                        "Requested {}, server answered unrecognized version {}",
+                        requested,
+                        negotiation.negotiated.as_deref().unwrap_or("?")
+                    ),
+                ),
+                NegotiationOutcome::Failed => (
+                    if requested.is_supported() {
+                        ValidationStatus::Warning
+                    } else {
+                        ValidationStatus::Error
+                    },
+                    format!(
+                        "Initialization with {} failed: {}",
+                        requested,
+                        negotiation.error.as_deref().unwrap_or("unknown error")
+                    ),
+                ),
+            };
+
+            self.add_result(ValidationResult {
+                test_id: format!("version_negotiation_{}", requested),
+                test_name: format!("Version Negotiation: {}", requested),
+                category: ValidationCategory::Initialization,
+                status,
+                message,
+                details: Some(serde_json::to_value(&negotiation)?),
+                duration: test_start.elapsed(),
+                timestamp: Utc::now(),
+            });
+            self.version_negotiation.push(negotiation);
+        }
+
+        Ok(())
+    }
+
+    /// Run a complete handshake with the given version on a fresh connection
+    async fn negotiate_version(&self, requested: &ProtocolVersion) -> VersionNegotiationResult {
+        let mut result = VersionNegotiationResult {
+            requested: requested.to_string(),
+            negotiated: None,
+            outcome: NegotiationOutcome::Failed,
+            server_capabilities: Vec::new(),
+            issues: Vec::new(),
+            error: None,
+        };
+
+        let mut transport = match TransportFactory::create(self.transport_config.clone()).await {
+            Ok(transport) => transport,
+            Err(e) => {
+                result.error = Some(e.to_string());
+                return result;
+            }
+        };
+        if let Err(e) = transport.connect().await {
+            result.error = Some(e.to_string());
+            return result;
+        }
+
+        let init_request = InitializeRequest {
+            protocol_version: requested.clone(),
+            capabilities: Capabilities::default(),
+            client_info: Implementation::new("mcp-probe-validator", "1.0.0"),
+        };
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: JsonRpcId::String(format!("init_{}", requested)),
+            method: "initialize".to_string(),
+            params: serde_json::to_value(init_request).ok(),
+        };
+
+        match self.send_probe_request(&mut transport, request).await {
+            Ok(response) => {
+                if let Some(error) = response.error {
+                    result.error = Some(format!("{} - {}", error.code, error.message));
+                } else if let Some(init) = response.result {
+                    let negotiated = init
+                        .get("protocolVersion")
+                        .and_then(|v| v.as_str())
+                        .map(ProtocolVersion::parse);
+                    result.server_capabilities = init
+                        .get("capabilities")
+                        .and_then(|c| c.as_object())
+                        .map(|c| c.keys().cloned().collect())
+                        .unwrap_or_default();
+
+                    match negotiated {
+                        Some(negotiated) => {
+                            result.outcome = classify_negotiation(requested, &negotiated);
+                            result.negotiated = Some(negotiated.to_string());
+
+                            if negotiated.is_supported() {
+                                let notification = JsonRpcNotification::new(
+                                    "notifications/initialized",
+                                    json!({}),
+                                );
+                                let _ = transport.send_notification(notification).await;
+                                result.issues = self
+                                    .check_method_availability(
+                                        &mut transport,
+                                        &negotiated,
+                                        &result.server_capabilities,
+                                    )
+                                    .await;
+                            }
+                        }
+                        None => {
+                            result.error =
+                                Some("Initialize result is missing protocolVersion".to_string());
+                        }
+                    }
+                } else {
+                    result.error = Some("Response missing both result and error".to_string());
+                }
+            }
+            Err(e) => result.error = Some(e.to_string()),
+        }
+
+        let _ = transport.disconnect().await;
+        result
+    }
+
+    /// Check that the methods and fields the server exposes match the
+    /// negotiated protocol version
+    async fn check_method_availability(
+        &self,
+        transport: &mut Box<dyn Transport>,
+        negotiated: &ProtocolVersion,
+        server_capabilities: &[String],
+    ) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        for capability in server_capabilities {
+            if !negotiated
+                .server_capability_keys()
+                .contains(&capability.as_str())
+            {
+                issues.push(format!(
+                    "Capability '{}' is not defined in protocol {}",
+                    capability, negotiated
+                ));
+            }
+        }
+
+        let mut probes = vec![("ping", json!({}))];
+        for (capability, method) in [
+            ("tools", "tools/list"),
+            ("resources", "resources/list"),
+            ("prompts", "prompts/list"),
+        ] {
+            if server_capabilities.iter().any(|c| c == capability) {
+                probes.push((method, json!({})));
+            }
+        }
+
+        for (method, params) in probes {
+            let request = JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: JsonRpcId::String(format!("{}_{}", method, negotiated)),
+                method: method.to_string(),
+                params: Some(params),
+            };
+
+            match self.send_probe_request(transport, request).await {
+                Ok(response) => {
+                    if let Some(error) = response.error {
+                        issues.push(format!(
+                            "'{}' failed under {}: {} - {}",
+                            method, negotiated, error.code, error.message
+                        ));
+                    } else if method == "tools/list" {
+                        let tools = response
+                            .result
+                            .as_ref()
+                            .and_then(|r| r.get("tools"))
+                            .and_then(|t| t.as_array())
+                            .cloned()
+                            .unwrap_or_default();
+                        let mut undefined_fields: Vec<String> = tools
+                            .iter()
+                            .filter_map(|tool| tool.as_object())
+                            .flat_map(|tool| tool.keys())
+                            .filter(|field| {
+                                !negotiated
+                                    .tool_definition_fields()
+                                    .contains(&field.as_str())
+                            })
+                            .cloned()
+                            .collect();
+                        undefined_fields.sort();
+                        undefined_fields.dedup();
+                        for field in undefined_fields {
+                            issues.push(format!(
+                                "Tool field '{}' is not defined in protocol {}",
+                                field, negotiated
+                            ));
+                        }
+                    }
+                }
+                Err(e) => issues.push(format!("'{}' failed under {}: {}", method, negotiated, e)),
+            }
+        }
+
+        issues
+    }
+
+    /// Send a request on a probe connection with the per-test timeout
+    async fn send_probe_request(
+        &self,
+        transport: &mut Box<dyn Transport>,
+        request: JsonRpcRequest,
+    ) -> Result<mcp_probe_core::messages::JsonRpcResponse> {
+        match timeout(
+            self.config.test_timeout,
+            transport.send_request(request, Some(self.config.test_timeout)),
+        )
+        .await
+        {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(anyhow::anyhow!(
+                "timed out after {:?}",
+                self.config.test_timeout
+            )),
+        }
+    }
+
     /// Test protocol compliance
     async fn test_protocol_compliance(
         &mut self,
@@ -557,11 +925,11 @@ impl ValidationEngine {
     async fn test_capability_discovery(
         &mut self,
         transport: &mut Box<dyn Transport>,
-    ) -> Result<()> {
+    ) -> Result<Vec<Tool>> {
         info!("Testing capability discovery");
 
         // Test tools listing
-        self.test_tools_listing(transport).await?;
+        let tools = self.test_tools_listing(transport).await?;
 
         // Test resources listing
         self.test_resources_listing(transport).await?;
@@ -569,7 +937,7 @@ impl ValidationEngine {
         // Test prompts listing
         self.test_prompts_listing(transport).await?;
 
-        Ok(())
+        Ok(tools)
     }
 
     /// Test transport-specific features like resumability and security
@@ -755,8 +1123,12 @@ impl ValidationEngine {
     }
 
     /// Test tools listing
-    async fn test_tools_listing(&mut self, transport: &mut Box<dyn Transport>) -> Result<()> {
+    async fn test_tools_listing(
+        &mut self,
+        transport: &mut Box<dyn Transport>,
+    ) -> Result<Vec<Tool>> {
         let test_start = Instant::now();
+        let mut tools = Vec::new();
 
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
@@ -772,7 +1144,12 @@ impl ValidationEngine {
         .await
         {
             Ok(Ok(response)) => {
-                if response.result.is_some() {
+                if let Some(result) = &response.result {
+                    if let Ok(list_response) =
+                        serde_json::from_value::<ListToolsResponse>(result.clone())
+                    {
+                        tools = list_response.tools;
+                    }
                     self.add_result(ValidationResult {
                         test_id: "tools_listing".to_string(),
                         test_name: "Tools Listing".to_string(),
@@ -822,7 +1199,7 @@ impl ValidationEngine {
             }
         }
 
-        Ok(())
+        Ok(tools)
     }
 
     /// Test resources listing
@@ -970,7 +1347,7 @@ impl ValidationEngine {
     /// Test individual tools
     async fn test_tools(
         &mut self,
-        _transport: &mut Box<dyn Transport>,
+        transport: &mut Box<dyn Transport>,
         tools: &[Tool],
     ) -> Result<()> {
         info!("Testing {} tools", tools.len());
@@ -983,6 +1360,12 @@ impl ValidationEngine {
                 self.validate_tool_schema(&tool.name, schema).await?;
             }
 
+            // Test structured output against the declared output schema
+            if let Some(ref output_schema) = tool.output_schema {
+                self.test_structured_output(transport, tool, output_schema)
+                    .await?;
+            }
+
             // Additional tool testing would go here
             self.add_result(ValidationResult {
                 test_id: format!("tool_{}", tool.name),
@@ -999,6 +1382,169 @@ impl ValidationEngine {
         Ok(())
     }
 
+    /// Check a tool's `outputSchema` and, for safe tools, that the
+    /// `structuredContent` it returns conforms to it
+    async fn test_structured_output(
+        &mut self,
+        transport: &mut Box<dyn Transport>,
+        tool: &Tool,
+        output_schema: &Value,
+    ) -> Result<()> {
+        let test_start = Instant::now();
+        let test_id = format!("tool_structured_output_{}", tool.name);
+        let test_name = format!("Structured Output: {}", tool.name);
+
+        // The spec requires output schemas to describe an object
+        if output_schema.get("type").and_then(|t| t.as_str()) != Some("object") {
+            self.add_result(ValidationResult {
+                test_id,
+                test_name,
+                category: ValidationCategory::Schema,
+                status: ValidationStatus::Error,
+                message: format!(
+                    "Tool '{}' declares an outputSchema whose type is not \"object\"",
+                    tool.name
+                ),
+                details: Some(output_schema.clone()),
+                duration: test_start.elapsed(),
+                timestamp: Utc::now(),
+            });
+            return Ok(());
+        }
+
+        // Only call tools that are declared read-only and need no arguments
+        let has_required_args = tool
+            .input_schema
+            .as_ref()
+            .and_then(|s| s.get("required"))
+            .and_then(|r| r.as_array())
+            .is_some_and(|r| !r.is_empty());
+        if !self.config.test_structured_output || !tool.is_read_only() || has_required_args {
+            self.add_result(ValidationResult {
+                test_id,
+                test_name,
+                category: ValidationCategory::Schema,
+                status: ValidationStatus::Skipped,
+                message: format!(
+                    "Tool '{}' not called: only read-only tools without required arguments are invoked",
+                    tool.name
+                ),
+                details: None,
+                duration: test_start.elapsed(),
+                timestamp: Utc::now(),
+            });
+            return Ok(());
+        }
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: JsonRpcId::String(format!("call_{}", tool.name)),
+            method: "tools/call".to_string(),
+            params: Some(serde_json::to_value(CallToolRequest {
+                name: tool.name.clone(),
+                arguments: Some(json!({})),
+            })?),
+        };
+
+        let (status, message, details) = match self.send_probe_request(transport, request).await {
+            Ok(response) => match (response.error, response.result) {
+                (Some(error), _) => (
+                    ValidationStatus::Warning,
+                    format!(
+                        "Tool '{}' call failed: {} - {}",
+                        tool.name, error.code, error.message
+                    ),
+                    Some(serde_json::to_value(error)?),
+                ),
+                (None, Some(result)) => {
+                    match serde_json::from_value::<CallToolResponse>(result.clone()) {
+                        Ok(call) if call.is_error == Some(true) => (
+                            ValidationStatus::Info,
+                            format!(
+                                "Tool '{}' reported an error; structured output not checked",
+                                tool.name
+                            ),
+                            Some(result),
+                        ),
+                        Ok(CallToolResponse {
+                            structured_content: None,
+                            ..
+                        }) => (
+                            ValidationStatus::Error,
+                            format!(
+                                "Tool '{}' declares an outputSchema but returned no structuredContent",
+                                tool.name
+                            ),
+                            Some(result),
+                        ),
+                        Ok(CallToolResponse {
+                            structured_content: Some(structured),
+                            ..
+                        }) => {
+                            let errors: Vec<String> =
+                                validate_structured_content(output_schema, &structured)
+                                    .iter()
+                                    .map(|e| e.to_string())
+                                    .collect();
+                            if errors.is_empty() {
+                                (
+                                    ValidationStatus::Pass,
+                                    format!(
+                                        "Tool '{}' structuredContent conforms to its outputSchema",
+                                        tool.name
+                                    ),
+                                    Some(structured),
+                                )
+                            } else {
+                                (
+                                    ValidationStatus::Error,
+                                    format!(
+                                        "Tool '{}' structuredContent does not match outputSchema: {}",
+                                        tool.name,
+                                        errors.join(", ")
+                                    ),
+                                    Some(json!({
+                                        "structuredContent": structured,
+                                        "outputSchema": output_schema,
+                                        "errors": errors,
+                                    })),
+                                )
+                            }
+                        }
+                        Err(e) => (
+                            ValidationStatus::Error,
+                            format!("Tool '{}' returned an invalid result: {}", tool.name, e),
+                            Some(result),
+                        ),
+                    }
+                }
+                (None, None) => (
+                    ValidationStatus::Error,
+                    "Response missing both result and error".to_string(),
+                    None,
+                ),
+            },
+            Err(e) => (
+                ValidationStatus::Warning,
+                format!("Tool '{}' call failed: {}", tool.name, e),
+                None,
+            ),
+        };
+
+        self.add_result(ValidationResult {
+            test_id,
+            test_name,
+            category: ValidationCategory::Schema,
+            status,
+            message,
+            details,
+            duration: test_start.elapsed(),
+            timestamp: Utc::now(),
+        });
+
+        Ok(())
+    }
+
     /// Validate tool schema
     async fn validate_tool_schema(&mut self, tool_name: &str, schema: &Value) -> Result<()> {
         let test_start = Instant::now();
@@ -1006,7 +1552,11 @@ impl ValidationEngine {
         // Use our simplified validation to check schema structure
         let validator = ParameterValidator::new();
         let dummy_params = json!({}); // Empty params for schema validation
-        let result = validator.validate(schema, &dummy_params);
+        let mut result = validator.validate(schema, &dummy_params);
+        // Empty params naturally miss required fields; that's not a schema defect
+        result
+            .errors
+            .retain(|e| !matches!(e, ValidationError::MissingRequired { .. }));
 
         if result.errors.is_empty() {
             self.add_result(ValidationResult {
@@ -1350,7 +1900,23 @@ impl ValidationEngine {
     }
 
     /// Generate comprehensive validation report
-    fn generate_report(&self) -> Result<ValidationReport> {
+    fn generate_report(&mut self) -> Result<ValidationReport> {
+        self.check_stdout_hygiene();
+
+        let server_crash = self.server_crash();
+        if let Some(crash) = &server_crash {
+            self.add_result(ValidationResult {
+                test_id: "server_process".to_string(),
+                test_name: "Server Process".to_string(),
+                category: ValidationCategory::Protocol,
+                status: ValidationStatus::Critical,
+                message: format!("Server process exited unexpectedly ({})", crash.exit),
+                details: Some(json!({"exit": crash.exit, "stderr": crash.stderr})),
+                duration: Duration::ZERO,
+                timestamp: Utc::now(),
+            });
+        }
+
         let total_duration = self
             .start_time
             .map(|start| start.elapsed())
@@ -1389,11 +1955,94 @@ impl ValidationEngine {
                     .filter(|r| r.message.contains("timeout") || r.message.contains("timed out"))
                     .count(),
             },
+            version_negotiation: self.version_negotiation.clone(),
+            server_crash,
         };
 
         Ok(report)
     }
 
+    /// Check that a stdio server exits once its stdin is closed
+    async fn test_shutdown(&mut self, transport: &mut Box<dyn Transport>) -> Result<()> {
+        let Some(log) = self.process_log.clone() else {
+            return Ok(());
+        };
+
+        let test_start = Instant::now();
+        transport.disconnect().await?;
+        let (Some(stage), Some(exit)) = (log.shutdown(), log.exit()) else {
+            // The process had already exited; the crash check reports that
+            return Ok(());
+        };
+
+        let status = match stage {
+            ShutdownStage::StdinClosed if exit.success() => ValidationStatus::Pass,
+            ShutdownStage::StdinClosed | ShutdownStage::Terminated => ValidationStatus::Warning,
+            ShutdownStage::Killed => ValidationStatus::Error,
+        };
+        let message = match stage {
+            ShutdownStage::StdinClosed => format!("Server exited on stdin EOF ({})", exit),
+            _ => format!("Server did not exit on stdin EOF and {} ({})", stage, exit),
+        };
+
+        self.add_result(ValidationResult {
+            test_id: "stdin_eof_shutdown".to_string(),
+            test_name: "Shutdown on Stdin EOF".to_string(),
+            category: ValidationCategory::Protocol,
+            status,
+            message,
+            details: Some(json!({"stage": stage, "exit": exit})),
+            duration: test_start.elapsed(),
+            timestamp: Utc::now(),
+        });
+
+        Ok(())
+    }
+
+    /// Report stdout lines of a stdio server that were not JSON-RPC messages
+    fn check_stdout_hygiene(&mut self) {
+        let Some(pollution) = self.process_log.as_ref().map(ProcessLog::stdout_pollution) else {
+            return;
+        };
+
+        let (status, message) = if pollution.is_clean() {
+            (
+                ValidationStatus::Pass,
+                "Server wrote only JSON-RPC messages to stdout".to_string(),
+            )
+        } else {
+            let kinds = pollution
+                .counts
+                .iter()
+                .map(|(kind, count)| format!("{} {}", count, kind))
+                .collect::<Vec<_>>()
+                .join(", ");
+            (
+                ValidationStatus::Error,
+                format!(
+                    "Server wrote {} non-JSON-RPC line(s) to stdout ({}); logs belong on stderr",
+                    pollution.total, kinds
+                ),
+            )
+        };
+
+        self.add_result(ValidationResult {
+            test_id: "stdout_hygiene".to_string(),
+            test_name: "Stdout Hygiene".to_string(),
+            category: ValidationCategory::Protocol,
+            status,
+            message,
+            details: (!pollution.is_clean()).then(|| json!(pollution)),
+            duration: Duration::ZERO,
+            timestamp: Utc::now(),
+        });
+    }
+
+    /// The server's exit and last stderr lines, if its process crashed
+    fn server_crash(&self) -> Option<ProcessCrash> {
+        self.process_log.as_ref()?.crash(CRASH_STDERR_LINES)
+    }
+
     /// Calculate initialization time from test results
     fn calculate_initialization_time(&self) -> Duration {
         self.results
@@ -1474,6 +2123,26 @@ impl ValidationEngine {
     }
 }
 
+/// Classify how the server negotiated relative to the requested version
+fn classify_negotiation(
+    requested: &ProtocolVersion,
+    negotiated: &ProtocolVersion,
+) -> NegotiationOutcome {
+    if !negotiated.is_supported() {
+        if negotiated == requested {
+            NegotiationOutcome::EchoedUnknown
+        } else {
+            NegotiationOutcome::UnrecognizedVersion
+        }
+    } else if negotiated == requested {
+        NegotiationOutcome::Accepted
+    } else if negotiated < requested {
+        NegotiationOutcome::Downgraded
+    } else {
+        NegotiationOutcome::Upgraded
+    }
+}
+
 impl ValidationStatus {
     /// Get a human-readable name for this status
     pub fn name(&self) -> &'static str {
@@ -1499,3 +2168,89 @@ impl ValidationStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_negotiation() {
+        let v2024 = ProtocolVersion::V2024_11_05;
+        let v2025 = ProtocolVersion::V2025_06_18;
+        let future = ProtocolVersion::Custom(UNKNOWN_FUTURE_VERSION.to_string());
+
+        assert_eq!(
+            classify_negotiation(&v2025, &v2025),
+            NegotiationOutcome::Accepted
+        );
+        assert_eq!(
+            classify_negotiation(&v2025, &v2024),
+            NegotiationOutcome::Downgraded
+        );
+        assert_eq!(
+            classify_negotiation(&v2024, &v2025),
+            NegotiationOutcome::Upgraded
+        );
+        assert_eq!(
+            classify_negotiation(&future, &v2025),
+            NegotiationOutcome::Downgraded
+        );
+        assert_eq!(
+            classify_negotiation(&future, &future),
+            NegotiationOutcome::EchoedUnknown
+        );
+        assert_eq!(
+            classify_negotiation(&v2025, &ProtocolVersion::Custom("1.0".to_string())),
+            NegotiationOutcome::UnrecognizedVersion
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_shutdown_finding() {
+        use mcp_probe_core::transport::StdioConfig;
+
+        // Ignores stdin EOF, so it takes SIGTERM to stop it
+        let config = TransportConfig::Stdio(
+            StdioConfig::new("sh")
+                .args(["-c", "sleep 30"])
+                .shutdown_timeouts(Duration::from_millis(100), Duration::from_secs(2)),
+        );
+        let mut engine = ValidationEngine::new(config);
+        let mut transport = engine.create_transport().await.unwrap();
+        engine.test_shutdown(&mut transport).await.unwrap();
+
+        let finding = engine.results.last().unwrap();
+        assert_eq!(finding.test_id, "stdin_eof_shutdown");
+        assert_eq!(finding.status, ValidationStatus::Warning);
+        assert!(finding.message.contains("exited after SIGTERM"));
+        assert_eq!(finding.details.as_ref().unwrap()["stage"], "terminated");
+    }
+
+    #[test]
+    fn test_stdout_hygiene_finding() {
+        use mcp_probe_core::transport::StdoutIssueKind;
+
+        let mut engine = ValidationEngine::new(TransportConfig::stdio("server", &[] as &[String]));
+        let log = ProcessLog::new(10);
+        engine.process_log = Some(log.clone());
+        engine.check_stdout_hygiene();
+        assert_eq!(engine.results[0].status, ValidationStatus::Pass);
+
+        log.record_stdout_issue(StdoutIssueKind::NotJson, "Listening...");
+        log.record_stdout_issue(StdoutIssueKind::NotJson, "Ready");
+        log.record_stdout_issue(StdoutIssueKind::PartialJson, "{\"id\":");
+        let report = engine.generate_report().unwrap();
+        let finding = report
+            .results
+            .iter()
+            .rev()
+            .find(|r| r.test_id == "stdout_hygiene")
+            .unwrap();
+        assert_eq!(finding.status, ValidationStatus::Error);
+        assert!(finding.message.contains("3 non-JSON-RPC line(s)"));
+        assert!(finding.message.contains("2 not JSON, 1 partial JSON"));
+        let details = finding.details.as_ref().unwrap();
+        assert_eq!(details["examples"][0]["line"], "Listening...");
+    }
+}
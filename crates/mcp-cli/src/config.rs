@@ -2,16 +2,76 @@
 //!
 //! This module handles loading and managing configuration settings from various
 //! sources including files, environment variables, and command-line arguments.
-
-use anyhow::Result;
+//!
+//! Settings are resolved in layers, each overriding the previous one per key:
+//!
+//! 1. Built-in defaults
+//! 2. User file (`~/.mcp-probe/config/mcp-probe.toml`)
+//! 3. Project-local file (`./mcp-probe.toml` or `./.mcp-probe.toml`)
+//! 4. File passed with `--config`
+//! 5. `MCP_PROBE_*` environment variables (e.g. `MCP_PROBE_LOGGING_LEVEL`)
+//! 6. Command-line flags
+
+use anyhow::{Context, Result};
+use mcp_probe_core::secrets;
+use mcp_probe_core::transport::{AuthConfig, TransportConfig};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// File names searched for in the current directory for project-local settings
+pub const PROJECT_CONFIG_FILES: &[&str] = &["mcp-probe.toml", ".mcp-probe.toml"];
+
+/// Prefix of environment variables that override configuration values
+pub const ENV_PREFIX: &str = "MCP_PROBE_";
+
+/// Configuration keys that can be overridden from the environment.
+///
+/// The variable name is the key upper-cased with dots replaced by underscores,
+/// prefixed with [`ENV_PREFIX`] (`logging.level` -> `MCP_PROBE_LOGGING_LEVEL`).
+pub const ENV_KEYS: &[&str] = &[
+    "client.name",
+    "client.version",
+    "client.reconnect",
+    "debug.show_raw_messages",
+    "debug.auto_save_sessions",
+    "debug.session_directory",
+    "debug.max_saved_sessions",
+    "logging.level",
+    "logging.format",
+    "logging.file",
+    "logging.stderr",
+    "telemetry.otlp_endpoint",
+    "telemetry.file",
+    "telemetry.service_name",
+    "tui.color_scheme",
+    "tui.keymap",
+    "tui.refresh_rate_ms",
+    "tui.show_help",
+];
+
+/// Whether a key's table is replaced as a whole instead of merged field by field.
+///
+/// This holds for the default transport and for each server profile, so a
+/// later layer redefining `servers.<name>` replaces the profile entirely.
+fn is_atomic(key: &str) -> bool {
+    key == "transport"
+        || key
+            .strip_prefix("servers.")
+            .is_some_and(|name| !name.contains('.'))
+}
 
 /// CLI configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct Config {
     /// Default transport configuration
-    pub transport: Option<mcp_probe_core::transport::TransportConfig>,
+    pub transport: Option<TransportConfig>,
+
+    /// Named server profiles, selected with `@name`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub servers: BTreeMap<String, ServerProfile>,
 
     /// Client information
     pub client: ClientConfig,
@@ -24,10 +84,164 @@ pub struct Config {
 
     /// TUI settings
     pub tui: TuiConfig,
+
+    /// OpenTelemetry export
+    pub telemetry: TelemetryConfig,
+}
+
+/// Named server profile, selected on the command line with `@name`
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerProfile {
+    /// Short description shown by `servers list`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// How to reach the server
+    pub transport: TransportConfig,
+
+    /// Authentication for HTTP transports (overrides `transport.auth`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthConfig>,
+
+    /// Extra environment variables for stdio servers
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// Default transport timeout (overrides `transport.timeout`)
+    #[serde(
+        default,
+        with = "humantime_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for ServerProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerProfile")
+            .field("description", &self.description)
+            .field("transport", &self.transport)
+            .field("auth", &self.auth)
+            .field("env", &secrets::RedactedMap(&self.env))
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl ServerProfile {
+    /// Create a profile for a transport with no overrides
+    pub fn new(transport: TransportConfig) -> Self {
+        Self {
+            description: None,
+            transport,
+            auth: None,
+            env: BTreeMap::new(),
+            timeout: None,
+        }
+    }
+
+    /// Transport configuration with the profile's auth, env and timeout applied
+    pub fn transport_config(&self) -> TransportConfig {
+        let mut transport = self.transport.clone();
+        match &mut transport {
+            TransportConfig::Stdio(config) => {
+                config.environment.extend(self.env.clone());
+                if let Some(timeout) = self.timeout {
+                    config.timeout = timeout;
+                }
+            }
+            TransportConfig::HttpSse(config) => {
+                if self.auth.is_some() {
+                    config.auth = self.auth.clone();
+                }
+                if let Some(timeout) = self.timeout {
+                    config.timeout = timeout;
+                }
+            }
+            TransportConfig::HttpStream(config) => {
+                if self.auth.is_some() {
+                    config.auth = self.auth.clone();
+                }
+                if let Some(timeout) = self.timeout {
+                    config.timeout = timeout;
+                }
+            }
+        }
+        transport
+    }
+
+    /// Command line or URL the profile connects to
+    pub fn target(&self) -> String {
+        match &self.transport {
+            TransportConfig::Stdio(config) => std::iter::once(config.command.as_str())
+                .chain(config.args.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" "),
+            TransportConfig::HttpSse(config) => config.base_url.to_string(),
+            TransportConfig::HttpStream(config) => config.base_url.to_string(),
+        }
+    }
+}
+
+/// Check that a profile name can be used as `@name` and as a config key
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!(
+            "Invalid server profile name '{}': use letters, digits, '-' and '_'",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// Server profiles managed by `mcp-probe servers`.
+///
+/// Kept in its own file so rewriting it never touches comments in the
+/// hand-edited configuration file. It is loaded as part of the user layer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServerRegistry {
+    /// Profiles by name
+    #[serde(default)]
+    pub servers: BTreeMap<String, ServerProfile>,
+}
+
+impl ServerRegistry {
+    /// Default registry location (`~/.mcp-probe/config/servers.toml`)
+    pub fn default_path() -> PathBuf {
+        Config::default_path().with_file_name("servers.toml")
+    }
+
+    /// Load the registry, or an empty one if the file does not exist
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse server registry {}", path.display()))
+    }
+
+    /// Write the registry back to disk
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = format!(
+            "# Server profiles managed by `mcp-probe servers`\n\n{}",
+            toml::to_string_pretty(self)?
+        );
+        std::fs::write(path, content)?;
+        Ok(())
+    }
 }
 
 /// Client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ClientConfig {
     /// Client name
     pub name: String,
@@ -37,10 +251,14 @@ pub struct ClientConfig {
 
     /// Additional metadata
     pub metadata: std::collections::HashMap<String, String>,
+
+    /// Restart or reconnect to the server when the connection is lost
+    pub reconnect: bool,
 }
 
 /// Debug configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DebugConfig {
     /// Show raw protocol messages
     pub show_raw_messages: bool,
@@ -57,27 +275,34 @@ pub struct DebugConfig {
 
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LoggingConfig {
-    /// Log level
+    /// Log level or filter directives (e.g. `debug`, `mcp_probe=trace,info`)
     pub level: String,
 
     /// Log format (pretty, json, compact)
     pub format: String,
 
-    /// Optional log file path
+    /// Log file path (default: a timestamped file in `~/.mcp-probe/logs`)
     pub file: Option<PathBuf>,
 
-    /// Whether to log to stderr
+    /// Whether to also log warnings and errors to stderr (off by default,
+    /// ignored in the TUI)
     pub stderr: bool,
 }
 
 /// TUI configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TuiConfig {
-    /// Color scheme
+    /// Theme: `default`, `monochrome`, `high-contrast`, `light`, the name of a
+    /// file in `~/.mcp-probe/themes` or the path of a theme file
     pub color_scheme: String,
 
-    /// Key bindings
+    /// Keymap preset (`default`, `vim` or `emacs`)
+    pub keymap: String,
+
+    /// Key binding overrides (action name -> keys, e.g. `quit = "ctrl+q"`)
     pub key_bindings: std::collections::HashMap<String, String>,
 
     /// UI refresh rate in milliseconds
@@ -87,6 +312,21 @@ pub struct TuiConfig {
     pub show_help: bool,
 }
 
+/// OpenTelemetry export of request spans and client metrics (needs a build
+/// with the `otel` feature)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// OTLP/HTTP collector endpoint, e.g. `http://localhost:4318`
+    pub otlp_endpoint: Option<String>,
+
+    /// File to append OTLP/JSON spans and metrics to, instead of a collector
+    pub file: Option<PathBuf>,
+
+    /// `service.name` of the exported telemetry
+    pub service_name: String,
+}
+
 // Default implementation is now derived
 
 impl Default for ClientConfig {
@@ -95,6 +335,22 @@ impl Default for ClientConfig {
             name: "mcp-probe".to_string(),
             version: env!("CARGO_PKG_VERSION").to_string(),
             metadata: std::collections::HashMap::new(),
+            reconnect: false,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Client implementation info sent during initialization
+    pub fn implementation(&self) -> mcp_probe_core::messages::Implementation {
+        mcp_probe_core::messages::Implementation {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            metadata: self
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.clone(), serde_json::Value::String(value.clone())))
+                .collect(),
         }
     }
 }
@@ -119,27 +375,33 @@ impl Default for LoggingConfig {
             level: "info".to_string(),
             format: "pretty".to_string(),
             file: None,
-            stderr: true,
+            stderr: false,
         }
     }
 }
 
 impl Default for TuiConfig {
     fn default() -> Self {
-        let mut key_bindings = std::collections::HashMap::new();
-        key_bindings.insert("quit".to_string(), "q".to_string());
-        key_bindings.insert("help".to_string(), "h".to_string());
-        key_bindings.insert("refresh".to_string(), "r".to_string());
-
         Self {
             color_scheme: "default".to_string(),
-            key_bindings,
+            keymap: "default".to_string(),
+            key_bindings: std::collections::HashMap::new(),
             refresh_rate_ms: 100,
             show_help: true,
         }
     }
 }
 
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            otlp_endpoint: None,
+            file: None,
+            service_name: "mcp-probe".to_string(),
+        }
+    }
+}
+
 impl Config {
     /// Load configuration from file
     #[allow(dead_code)]
@@ -193,8 +455,30 @@ impl Config {
         }
     }
 
+    /// Look up a server profile by name (with or without the leading `@`)
+    pub fn server_profile(&self, name: &str) -> Result<&ServerProfile> {
+        let name = name.strip_prefix('@').unwrap_or(name);
+        self.servers.get(name).ok_or_else(|| {
+            if self.servers.is_empty() {
+                anyhow::anyhow!(
+                    "Unknown server profile '@{}'. No profiles configured; add one with `mcp-probe servers add`",
+                    name
+                )
+            } else {
+                anyhow::anyhow!(
+                    "Unknown server profile '@{}'. Available: {}",
+                    name,
+                    self.servers
+                        .keys()
+                        .map(|name| format!("@{}", name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        })
+    }
+
     /// Get default configuration file path
-    #[allow(dead_code)]
     pub fn default_path() -> PathBuf {
         use crate::paths::get_mcp_probe_paths;
 
@@ -212,6 +496,329 @@ impl Config {
     }
 }
 
+/// Where an effective configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default
+    Default,
+    /// User-wide configuration file
+    UserFile(PathBuf),
+    /// Project-local configuration file
+    ProjectFile(PathBuf),
+    /// File passed with `--config`
+    ExplicitFile(PathBuf),
+    /// `MCP_PROBE_*` environment variable
+    Env(String),
+    /// Command-line flag
+    Flag(String),
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::UserFile(path) => write!(f, "user file {}", path.display()),
+            ConfigSource::ProjectFile(path) => write!(f, "project file {}", path.display()),
+            ConfigSource::ExplicitFile(path) => write!(f, "--config {}", path.display()),
+            ConfigSource::Env(name) => write!(f, "env {}", name),
+            ConfigSource::Flag(flag) => write!(f, "flag {}", flag),
+        }
+    }
+}
+
+/// Configuration resolved from all layers, with the source of every value
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig {
+    /// Effective configuration
+    pub config: Config,
+
+    /// Merged raw values, used to render `config show --effective`
+    values: toml::Value,
+
+    /// Source of each dotted key
+    sources: BTreeMap<String, ConfigSource>,
+}
+
+impl ResolvedConfig {
+    /// Get the layer that supplied a dotted key (e.g. `logging.level`)
+    pub fn source_of(&self, key: &str) -> Option<&ConfigSource> {
+        self.sources.get(key)
+    }
+
+    /// All effective values as `(key, rendered value, source)`, sorted by key
+    ///
+    /// Credentials are rendered as `***`; secret references are shown as written.
+    pub fn entries(&self) -> Vec<(String, String, ConfigSource)> {
+        leaf_entries(&self.values, "")
+            .into_iter()
+            .map(|(key, value)| {
+                let mut value = serde_json::to_value(&value).unwrap_or_default();
+                match &mut value {
+                    serde_json::Value::String(text) => {
+                        let leaf = key.rsplit('.').next().unwrap_or(&key);
+                        *text = secrets::redact_for_key(leaf, text);
+                    }
+                    other => secrets::redact_json(other),
+                }
+                let rendered = value.to_string();
+                let source = self
+                    .sources
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or(ConfigSource::Default);
+                (key, rendered, source)
+            })
+            .collect()
+    }
+}
+
+/// Builds a [`ResolvedConfig`] from the configuration layers
+#[derive(Debug, Default)]
+pub struct ConfigLoader {
+    registry_file: Option<PathBuf>,
+    user_file: Option<PathBuf>,
+    project_file: Option<PathBuf>,
+    explicit_file: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    flags: Vec<(String, toml::Value, String)>,
+}
+
+impl ConfigLoader {
+    /// Create a loader with no layers besides the built-in defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a loader for the standard locations and the process environment
+    pub fn discover() -> Self {
+        let project_file = std::env::current_dir().ok().and_then(|dir| {
+            PROJECT_CONFIG_FILES
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|path| path.is_file())
+        });
+
+        Self::new()
+            .registry_file(ServerRegistry::default_path())
+            .user_file(Config::default_path())
+            .project_file(project_file)
+            .env_vars(std::env::vars())
+    }
+
+    /// Set the server registry file, loaded before the user file (skipped if missing)
+    pub fn registry_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.registry_file = Some(path.into());
+        self
+    }
+
+    /// Set the user-wide configuration file (skipped if it does not exist)
+    pub fn user_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.user_file = Some(path.into());
+        self
+    }
+
+    /// Set the project-local configuration file (skipped if it does not exist)
+    pub fn project_file(mut self, path: Option<PathBuf>) -> Self {
+        self.project_file = path;
+        self
+    }
+
+    /// Set the file passed with `--config` (must exist)
+    pub fn explicit_file(mut self, path: Option<PathBuf>) -> Self {
+        self.explicit_file = path;
+        self
+    }
+
+    /// Add environment variables; only `MCP_PROBE_*` names from [`ENV_KEYS`] are used
+    pub fn env_vars(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.env.extend(
+            vars.into_iter()
+                .filter(|(name, _)| name.starts_with(ENV_PREFIX)),
+        );
+        self
+    }
+
+    /// Override a dotted key from a command-line flag
+    pub fn flag(mut self, key: &str, value: impl Into<toml::Value>, flag: &str) -> Self {
+        self.flags
+            .push((key.to_string(), value.into(), flag.to_string()));
+        self
+    }
+
+    /// Merge all layers into the effective configuration
+    pub fn load(&self) -> Result<ResolvedConfig> {
+        let mut values = toml::Value::try_from(Config::default())?;
+        let mut sources: BTreeMap<String, ConfigSource> = leaf_entries(&values, "")
+            .into_iter()
+            .map(|(key, _)| (key, ConfigSource::Default))
+            .collect();
+
+        let files = [
+            (
+                self.registry_file.as_ref(),
+                ConfigSource::UserFile as fn(PathBuf) -> _,
+            ),
+            (self.user_file.as_ref(), ConfigSource::UserFile),
+            (self.project_file.as_ref(), ConfigSource::ProjectFile),
+        ];
+        for (path, source) in files {
+            if let Some(path) = path.filter(|path| path.is_file()) {
+                let layer = read_layer(path)?;
+                overlay(&mut values, layer, "", &source(path.clone()), &mut sources);
+            }
+        }
+
+        if let Some(path) = &self.explicit_file {
+            if !path.is_file() {
+                anyhow::bail!("Configuration file not found: {}", path.display());
+            }
+            let layer = read_layer(path)?;
+            let source = ConfigSource::ExplicitFile(path.clone());
+            overlay(&mut values, layer, "", &source, &mut sources);
+        }
+
+        for key in ENV_KEYS {
+            let name = env_var_name(key);
+            if let Some((_, raw)) = self.env.iter().rev().find(|(n, _)| *n == name) {
+                let value = parse_env_value(&values, key, raw)
+                    .with_context(|| format!("Invalid value for {}", name))?;
+                set_key(&mut values, key, value.clone());
+                overlay_leaf(key, &value, &ConfigSource::Env(name), &mut sources);
+            }
+        }
+
+        for (key, value, flag) in &self.flags {
+            set_key(&mut values, key, value.clone());
+            overlay_leaf(key, value, &ConfigSource::Flag(flag.clone()), &mut sources);
+        }
+
+        let config: Config = values
+            .clone()
+            .try_into()
+            .context("Invalid effective configuration")?;
+
+        Ok(ResolvedConfig {
+            config,
+            values,
+            sources,
+        })
+    }
+}
+
+/// Environment variable name for a dotted configuration key
+pub fn env_var_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
+
+fn read_layer(path: &Path) -> Result<toml::Value> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read configuration file {}", path.display()))?;
+    toml::from_str(&content)
+        .with_context(|| format!("Failed to parse configuration file {}", path.display()))
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// Flatten a value into dotted leaf keys, keeping atomic tables whole
+fn leaf_entries(value: &toml::Value, prefix: &str) -> Vec<(String, toml::Value)> {
+    match value {
+        toml::Value::Table(table) if !is_atomic(prefix) => table
+            .iter()
+            .flat_map(|(key, value)| leaf_entries(value, &join_key(prefix, key)))
+            .collect(),
+        _ if prefix.is_empty() => Vec::new(),
+        _ => vec![(prefix.to_string(), value.clone())],
+    }
+}
+
+/// Merge `layer` into `base`, recording `source` for every key it sets
+fn overlay(
+    base: &mut toml::Value,
+    layer: toml::Value,
+    prefix: &str,
+    source: &ConfigSource,
+    sources: &mut BTreeMap<String, ConfigSource>,
+) {
+    let (toml::Value::Table(base_table), toml::Value::Table(layer_table)) = (base, layer) else {
+        return;
+    };
+
+    for (key, value) in layer_table {
+        let full_key = join_key(prefix, &key);
+        match base_table.get_mut(&key) {
+            Some(existing) if existing.is_table() && value.is_table() && !is_atomic(&full_key) => {
+                overlay(existing, value, &full_key, source, sources);
+            }
+            _ => {
+                overlay_leaf(&full_key, &value, source, sources);
+                base_table.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Record `source` for `key` (and any nested keys it replaces)
+fn overlay_leaf(
+    key: &str,
+    value: &toml::Value,
+    source: &ConfigSource,
+    sources: &mut BTreeMap<String, ConfigSource>,
+) {
+    let nested = format!("{}.", key);
+    sources.retain(|existing, _| existing != key && !existing.starts_with(&nested));
+    let leaves = leaf_entries(value, key);
+    if leaves.is_empty() {
+        sources.insert(key.to_string(), source.clone());
+    }
+    for (leaf, _) in leaves {
+        sources.insert(leaf, source.clone());
+    }
+}
+
+/// Set a dotted key, creating intermediate tables as needed
+fn set_key(values: &mut toml::Value, key: &str, value: toml::Value) {
+    let mut current = values;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        let toml::Value::Table(table) = current else {
+            return;
+        };
+        if parts.peek().is_none() {
+            table.insert(part.to_string(), value);
+            return;
+        }
+        current = table
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::map::Map::new()));
+    }
+}
+
+/// Parse an environment value using the type of the value it replaces
+fn parse_env_value(values: &toml::Value, key: &str, raw: &str) -> Result<toml::Value> {
+    let existing = key
+        .split('.')
+        .try_fold(values, |value, part| value.get(part));
+
+    Ok(match existing {
+        Some(toml::Value::Boolean(_)) => toml::Value::Boolean(match raw.to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "0" | "false" | "no" | "off" => false,
+            _ => anyhow::bail!("expected a boolean, got '{}'", raw),
+        }),
+        Some(toml::Value::Integer(_)) => toml::Value::Integer(
+            raw.parse()
+                .with_context(|| format!("expected an integer, got '{}'", raw))?,
+        ),
+        _ => toml::Value::String(raw.to_string()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +871,147 @@ mod tests {
         assert!(base.debug.show_raw_messages);
         assert_eq!(base.logging.level, "debug");
     }
+
+    #[test]
+    fn test_layered_precedence() -> Result<()> {
+        let user = NamedTempFile::new()?;
+        std::fs::write(
+            user.path(),
+            "[logging]\nlevel = \"warn\"\nformat = \"compact\"\n\n[tui]\nrefresh_rate_ms = 250\n\n[tui.key_bindings]\nhelp = \"?\"\n",
+        )?;
+        let project = NamedTempFile::new()?;
+        std::fs::write(
+            project.path(),
+            "[logging]\nlevel = \"debug\"\n\n[tui.key_bindings]\nquit = \"x\"\n",
+        )?;
+        let explicit = NamedTempFile::new()?;
+        std::fs::write(
+            explicit.path(),
+            "[transport]\ntype = \"stdio\"\ncommand = \"python\"\nargs = [\"server.py\"]\n",
+        )?;
+
+        let resolved = ConfigLoader::new()
+            .user_file(user.path())
+            .project_file(Some(project.path().to_path_buf()))
+            .explicit_file(Some(explicit.path().to_path_buf()))
+            .env_vars([
+                (
+                    "MCP_PROBE_TUI_REFRESH_RATE_MS".to_string(),
+                    "50".to_string(),
+                ),
+                (
+                    "MCP_PROBE_DEBUG_AUTO_SAVE_SESSIONS".to_string(),
+                    "false".to_string(),
+                ),
+                ("UNRELATED".to_string(), "1".to_string()),
+            ])
+            .flag("logging.level", "trace", "-vv")
+            .load()?;
+
+        let config = &resolved.config;
+        assert_eq!(config.logging.level, "trace");
+        assert_eq!(config.logging.format, "compact");
+        assert_eq!(config.tui.refresh_rate_ms, 50);
+        assert!(!config.debug.auto_save_sessions);
+        assert_eq!(config.tui.key_bindings["quit"], "x");
+        assert_eq!(config.tui.key_bindings["help"], "?");
+        assert!(matches!(config.transport, Some(TransportConfig::Stdio(_))));
+
+        assert_eq!(
+            resolved.source_of("logging.level"),
+            Some(&ConfigSource::Flag("-vv".to_string()))
+        );
+        assert_eq!(
+            resolved.source_of("logging.format"),
+            Some(&ConfigSource::UserFile(user.path().to_path_buf()))
+        );
+        assert_eq!(
+            resolved.source_of("tui.key_bindings.quit"),
+            Some(&ConfigSource::ProjectFile(project.path().to_path_buf()))
+        );
+        assert_eq!(
+            resolved.source_of("tui.refresh_rate_ms"),
+            Some(&ConfigSource::Env(
+                "MCP_PROBE_TUI_REFRESH_RATE_MS".to_string()
+            ))
+        );
+        assert_eq!(
+            resolved.source_of("transport"),
+            Some(&ConfigSource::ExplicitFile(explicit.path().to_path_buf()))
+        );
+        assert_eq!(
+            resolved.source_of("client.name"),
+            Some(&ConfigSource::Default)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_server_profiles_layering() -> Result<()> {
+        let registry = NamedTempFile::new()?;
+        std::fs::write(
+            registry.path(),
+            "[servers.search]\ndescription = \"registry\"\ntimeout = \"90s\"\n\n[servers.search.transport]\ntype = \"stdio\"\ncommand = \"node\"\nargs = [\"search.js\"]\n\n[servers.search.env]\nDEBUG = \"1\"\n",
+        )?;
+        let project = NamedTempFile::new()?;
+        std::fs::write(
+            project.path(),
+            "[servers.search.transport]\ntype = \"http_stream\"\nbase_url = \"https://example.com/mcp\"\n",
+        )?;
+
+        let resolved = ConfigLoader::new().registry_file(registry.path()).load()?;
+        let profile = resolved.config.server_profile("@search")?;
+        assert_eq!(profile.description.as_deref(), Some("registry"));
+        match profile.transport_config() {
+            TransportConfig::Stdio(config) => {
+                assert_eq!(config.timeout, Duration::from_secs(90));
+                assert_eq!(config.environment["DEBUG"], "1");
+            }
+            other => panic!("expected stdio transport, got {:?}", other),
+        }
+
+        // A later layer replaces the whole profile rather than merging fields
+        let resolved = ConfigLoader::new()
+            .registry_file(registry.path())
+            .project_file(Some(project.path().to_path_buf()))
+            .load()?;
+        let profile = resolved.config.server_profile("search")?;
+        assert!(profile.description.is_none());
+        assert!(matches!(profile.transport, TransportConfig::HttpStream(_)));
+        assert_eq!(
+            resolved.source_of("servers.search"),
+            Some(&ConfigSource::ProjectFile(project.path().to_path_buf()))
+        );
+
+        assert!(resolved.config.server_profile("missing").is_err());
+        assert!(validate_profile_name("github-server_2").is_ok());
+        assert!(validate_profile_name("bad.name").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_layered_errors() {
+        let missing = ConfigLoader::new()
+            .explicit_file(Some(PathBuf::from("/nonexistent/mcp-probe.toml")))
+            .load();
+        assert!(missing.is_err());
+
+        let bad_env = ConfigLoader::new()
+            .env_vars([("MCP_PROBE_TUI_SHOW_HELP".to_string(), "maybe".to_string())])
+            .load();
+        assert!(bad_env.is_err());
+
+        // Missing optional layers are skipped
+        let resolved = ConfigLoader::new()
+            .user_file("/nonexistent/user.toml")
+            .load()
+            .unwrap();
+        assert_eq!(resolved.config.client.name, "mcp-probe");
+        assert!(resolved
+            .entries()
+            .iter()
+            .all(|(_, _, source)| *source == ConfigSource::Default));
+    }
 }
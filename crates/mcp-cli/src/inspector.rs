@@ -0,0 +1,429 @@
+//! Message inspector filters and payload search
+//!
+//! The TUI message history can be narrowed with a filter line made of
+//! space-separated terms, all of which must match:
+//!
+//! ```text
+//! method:tools/call      method name contains the text
+//! dir:out                direction: out (client requests), in (server messages), local
+//! type:"call tool"       message type, ignoring case and spaces
+//! is:error               failed messages only (also is:pinned, is:bookmarked)
+//! timeout                any other word is searched for in summaries and payloads
+//! $..name == "echo"      JSONPath over the payloads; the rest of the line is the path
+//! ```
+//!
+//! JSONPath supports `$`, `.key`, `['key']`, `[n]`, `[*]`, `.*` and recursive
+//! `..key`, optionally followed by `== value` or `!= value` where the value is
+//! JSON (`true`, `3`, `"text"`) or bare text.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+
+/// Which side a message came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Sent by the client, usually paired with the server's response
+    Outbound,
+    /// Sent by the server on its own: notifications and server requests
+    Inbound,
+    /// Recorded by the debugger itself (sessions, errors, discovery notes)
+    Local,
+}
+
+impl Direction {
+    fn parse(text: &str) -> Option<Self> {
+        match text.to_lowercase().as_str() {
+            "out" | "outbound" | "sent" => Some(Self::Outbound),
+            "in" | "inbound" | "received" => Some(Self::Inbound),
+            "local" => Some(Self::Local),
+            _ => None,
+        }
+    }
+
+    /// Arrow shown in the history list
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Outbound => "→",
+            Self::Inbound => "←",
+            Self::Local => "·",
+        }
+    }
+}
+
+/// Outcome badge of a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Error,
+    Info,
+}
+
+impl Status {
+    /// Badge shown in the history list
+    pub fn badge(self) -> &'static str {
+        match self {
+            Self::Ok => "OK ",
+            Self::Error => "ERR",
+            Self::Info => "···",
+        }
+    }
+}
+
+/// What the filter sees of a history entry
+#[derive(Debug)]
+pub struct Record<'a> {
+    /// Message type as displayed, e.g. `Call Tool`
+    pub kind: String,
+    pub method: Option<String>,
+    pub direction: Direction,
+    pub status: Status,
+    pub pinned: bool,
+    pub bookmarked: bool,
+    /// Summary and error lines
+    pub text: Vec<&'a str>,
+    /// Request, response and result payloads
+    pub payloads: Vec<Value>,
+}
+
+/// A parsed filter line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    method: Option<String>,
+    direction: Option<Direction>,
+    kind: Option<String>,
+    errors_only: bool,
+    pinned_only: bool,
+    bookmarked_only: bool,
+    terms: Vec<String>,
+    path: Option<JsonPath>,
+}
+
+impl Filter {
+    /// Parse a filter line; an empty line matches everything
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut filter = Self::default();
+        let mut rest = line.trim();
+
+        while !rest.is_empty() {
+            if rest.starts_with('$') {
+                filter.path = Some(JsonPath::parse(rest)?);
+                break;
+            }
+            let (token, remaining) = next_token(rest);
+            rest = remaining.trim_start();
+
+            match token.split_once(':') {
+                Some(("method", method)) if !method.is_empty() => {
+                    filter.method = Some(method.to_lowercase())
+                }
+                Some(("dir", direction)) => {
+                    filter.direction = Some(Direction::parse(direction).ok_or_else(|| {
+                        anyhow!("Unknown direction '{}' (use in, out or local)", direction)
+                    })?)
+                }
+                Some(("type", kind)) if !kind.is_empty() => filter.kind = Some(normalize(kind)),
+                Some(("is", "error" | "errors" | "failed")) => filter.errors_only = true,
+                Some(("is", "pinned")) => filter.pinned_only = true,
+                Some(("is", "bookmarked")) => filter.bookmarked_only = true,
+                Some(("is", other)) => bail!(
+                    "Unknown flag 'is:{}' (use is:error, is:pinned or is:bookmarked)",
+                    other
+                ),
+                _ => filter.terms.push(token.to_lowercase()),
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Whether the filter lets every message through
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether a message passes every part of the filter
+    pub fn matches(&self, record: &Record) -> bool {
+        if let Some(method) = &self.method {
+            let matched = record
+                .method
+                .as_ref()
+                .is_some_and(|m| m.to_lowercase().contains(method));
+            if !matched {
+                return false;
+            }
+        }
+        if self.direction.is_some_and(|d| d != record.direction)
+            || self
+                .kind
+                .as_ref()
+                .is_some_and(|k| *k != normalize(&record.kind))
+            || (self.errors_only && record.status != Status::Error)
+            || (self.pinned_only && !record.pinned)
+            || (self.bookmarked_only && !record.bookmarked)
+        {
+            return false;
+        }
+
+        if !self.terms.is_empty() {
+            let mut haystack = record.kind.to_lowercase();
+            for text in &record.text {
+                haystack.push('\n');
+                haystack.push_str(&text.to_lowercase());
+            }
+            for payload in &record.payloads {
+                haystack.push('\n');
+                haystack.push_str(&payload.to_string().to_lowercase());
+            }
+            if !self.terms.iter().all(|term| haystack.contains(term)) {
+                return false;
+            }
+        }
+
+        match &self.path {
+            Some(path) => record.payloads.iter().any(|payload| path.matches(payload)),
+            None => true,
+        }
+    }
+}
+
+/// Split off the first token, keeping `key:"quoted value"` together
+fn next_token(text: &str) -> (String, &str) {
+    let mut token = String::new();
+    let mut quoted = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => return (token, &text[index..]),
+            c => token.push(c),
+        }
+    }
+    (token, "")
+}
+
+/// Lowercase without spaces, dashes or underscores, so `call_tool` finds `Call Tool`
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// One step of a JSONPath
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+    Descendant(String),
+}
+
+/// A JSONPath expression with an optional comparison
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+    comparison: Option<(bool, Value)>,
+}
+
+impl JsonPath {
+    /// Parse `$.path.to[0].field`, optionally followed by `== value` or `!= value`
+    pub fn parse(expression: &str) -> Result<Self> {
+        let (path, comparison) = match expression.find("==").or_else(|| expression.find("!=")) {
+            Some(at) => {
+                let equal = &expression[at..at + 2] == "==";
+                let literal = expression[at + 2..].trim();
+                let value = serde_json::from_str(literal)
+                    .unwrap_or_else(|_| Value::String(literal.to_string()));
+                (expression[..at].trim(), Some((equal, value)))
+            }
+            None => (expression.trim(), None),
+        };
+
+        let mut rest = path
+            .strip_prefix('$')
+            .ok_or_else(|| anyhow!("JSONPath must start with '$'"))?;
+        let mut segments = Vec::new();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("..") {
+                let (key, remaining) = split_key(after);
+                if key.is_empty() {
+                    bail!("Expected a key after '..' in {}", path);
+                }
+                segments.push(Segment::Descendant(key.to_string()));
+                rest = remaining;
+            } else if let Some(after) = rest.strip_prefix('.') {
+                let (key, remaining) = split_key(after);
+                segments.push(match key {
+                    "" => bail!("Expected a key after '.' in {}", path),
+                    "*" => Segment::Wildcard,
+                    key => Segment::Key(key.to_string()),
+                });
+                rest = remaining;
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let end = after
+                    .find(']')
+                    .ok_or_else(|| anyhow!("Unclosed '[' in {}", path))?;
+                let inner = after[..end].trim();
+                segments.push(if inner == "*" {
+                    Segment::Wildcard
+                } else if let Ok(index) = inner.parse() {
+                    Segment::Index(index)
+                } else {
+                    let key = inner.trim_matches(|c| c == '\'' || c == '"');
+                    Segment::Key(key.to_string())
+                });
+                rest = &after[end + 1..];
+            } else {
+                bail!("Unexpected '{}' in {}", rest, path);
+            }
+        }
+
+        Ok(Self {
+            segments,
+            comparison,
+        })
+    }
+
+    /// Values the path selects in `root`
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![root];
+        for segment in &self.segments {
+            let mut next = Vec::new();
+            for value in current {
+                match segment {
+                    Segment::Key(key) => next.extend(value.get(key.as_str())),
+                    Segment::Index(index) => next.extend(value.get(*index)),
+                    Segment::Wildcard => match value {
+                        Value::Array(items) => next.extend(items.iter()),
+                        Value::Object(fields) => next.extend(fields.values()),
+                        _ => {}
+                    },
+                    Segment::Descendant(key) => collect_descendants(value, key, &mut next),
+                }
+            }
+            current = next;
+        }
+        current
+    }
+
+    /// Whether the path selects anything (that passes the comparison)
+    pub fn matches(&self, root: &Value) -> bool {
+        let selected = self.select(root);
+        match &self.comparison {
+            None => !selected.is_empty(),
+            Some((true, expected)) => selected.contains(&expected),
+            Some((false, expected)) => selected.iter().any(|value| *value != expected),
+        }
+    }
+}
+
+/// Split a dotted key from the rest of the path
+fn split_key(text: &str) -> (&str, &str) {
+    let end = text.find(['.', '[']).unwrap_or(text.len());
+    (&text[..end], &text[end..])
+}
+
+fn collect_descendants<'a>(value: &'a Value, key: &str, found: &mut Vec<&'a Value>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, child) in fields {
+                if name == key {
+                    found.push(child);
+                }
+                collect_descendants(child, key, found);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_descendants(item, key, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn record(kind: &str, method: &str, status: Status, payload: Value) -> Record<'static> {
+        Record {
+            kind: kind.to_string(),
+            method: Some(method.to_string()),
+            direction: Direction::Outbound,
+            status,
+            pinned: false,
+            bookmarked: false,
+            text: vec!["Tool executed"],
+            payloads: vec![payload],
+        }
+    }
+
+    #[test]
+    fn test_filter_terms() {
+        let call = record(
+            "Call Tool",
+            "tools/call",
+            Status::Ok,
+            json!({"content": [{"type": "text", "text": "Sunny in Paris"}]}),
+        );
+        let failed = record(
+            "Get Resource",
+            "resources/read",
+            Status::Error,
+            json!({"error": "missing"}),
+        );
+
+        assert!(Filter::parse("").unwrap().is_empty());
+        assert!(Filter::parse("method:tools").unwrap().matches(&call));
+        assert!(!Filter::parse("method:tools").unwrap().matches(&failed));
+        assert!(Filter::parse("type:call_tool dir:out")
+            .unwrap()
+            .matches(&call));
+        assert!(!Filter::parse("dir:in").unwrap().matches(&call));
+        assert!(Filter::parse("is:error").unwrap().matches(&failed));
+        assert!(!Filter::parse("is:error").unwrap().matches(&call));
+        assert!(Filter::parse("paris EXECUTED").unwrap().matches(&call));
+        assert!(!Filter::parse("paris london").unwrap().matches(&call));
+        assert!(Filter::parse(r#"type:"get resource" missing"#)
+            .unwrap()
+            .matches(&failed));
+        assert!(Filter::parse("is:pinned").unwrap().matches(&Record {
+            pinned: true,
+            ..record("Call Tool", "tools/call", Status::Ok, json!({}))
+        }));
+
+        assert!(Filter::parse("dir:sideways").is_err());
+        assert!(Filter::parse("is:odd").is_err());
+    }
+
+    #[test]
+    fn test_json_path() {
+        let payload = json!({
+            "content": [
+                {"type": "text", "text": "a"},
+                {"type": "image", "data": "…", "meta": {"name": "chart"}}
+            ],
+            "isError": false
+        });
+        let path = |expression: &str| JsonPath::parse(expression).unwrap();
+
+        assert_eq!(path("$.content[0].text").select(&payload), vec!["a"]);
+        assert_eq!(path("$.content[*].type").select(&payload).len(), 2);
+        assert_eq!(path("$['isError']").select(&payload), vec![false]);
+        assert_eq!(path("$..name").select(&payload), vec!["chart"]);
+        assert!(path("$.content[1].meta").matches(&payload));
+        assert!(!path("$.structuredContent").matches(&payload));
+        assert!(path("$.content[*].type == image").matches(&payload));
+        assert!(path(r#"$.content[*].type == "text""#).matches(&payload));
+        assert!(path("$.isError == false").matches(&payload));
+        assert!(!path("$.isError != false").matches(&payload));
+
+        // The rest of a filter line after `$` is the path
+        let filter = Filter::parse("method:tools $..name == chart").unwrap();
+        assert!(filter.matches(&record("Call Tool", "tools/call", Status::Ok, payload)));
+
+        assert!(JsonPath::parse("content").is_err());
+        assert!(JsonPath::parse("$.content[0").is_err());
+        assert!(JsonPath::parse("$..").is_err());
+    }
+}
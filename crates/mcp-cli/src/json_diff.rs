@@ -0,0 +1,472 @@
+//! Structural JSON diff for comparing two responses side by side
+//!
+//! Objects are compared key by key and arrays item by item, aligning items
+//! with a longest common subsequence so an inserted element does not mark
+//! everything after it as changed. Two results of the same tool might read:
+//!
+//! ```text
+//!  {                                 {
+//!    "content": [                      "content": [
+//!      {                                 {
+//!        "type": "text"                    "type": "text"
+//! ~      "text": "21.5"              ~      "text": "23.0"
+//!      }                                 }
+//!    ]                                 ]
+//!    "structuredContent": {…} 2 same   "structuredContent": {…} 2 same
+//! -  "cached": true
+//!                                    +  "isError": false
+//!  }                                 }
+//! ```
+//!
+//! Unchanged subtrees can be collapsed to a single line, and the changes
+//! exported as a list of JSONPath locations with their before/after values.
+
+use serde_json::{json, Value};
+
+/// Arrays longer than this are compared index by index instead of aligned
+const MAX_ALIGNED_ITEMS: usize = 500;
+
+/// Difference between two JSON values
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffNode {
+    /// Equal on both sides
+    Same(Value),
+    /// Only on the right
+    Added(Value),
+    /// Only on the left
+    Removed(Value),
+    /// Replaced by a value of another type or a different scalar
+    Changed { before: Value, after: Value },
+    /// Objects on both sides, compared key by key in left-then-right key order
+    Object(Vec<(String, DiffNode)>),
+    /// Arrays on both sides, compared item by item
+    Array(Vec<DiffNode>),
+}
+
+/// How a rendered line differs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Same,
+    Added,
+    Removed,
+    Changed,
+    /// An unchanged subtree folded to one line
+    Collapsed,
+}
+
+/// One row of the side-by-side view
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    pub left: String,
+    pub right: String,
+}
+
+/// Counts of differing leaves
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+}
+
+impl DiffStats {
+    /// Whether both sides are equal
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl std::fmt::Display for DiffStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("identical");
+        }
+        write!(
+            f,
+            "+{} added, -{} removed, ~{} changed",
+            self.added, self.removed, self.changed
+        )
+    }
+}
+
+impl DiffNode {
+    /// Compare two values
+    pub fn between(before: &Value, after: &Value) -> Self {
+        match (before, after) {
+            _ if before == after => DiffNode::Same(before.clone()),
+            (Value::Object(left), Value::Object(right)) => {
+                let mut entries: Vec<(String, DiffNode)> = left
+                    .iter()
+                    .map(|(key, old)| {
+                        let node = match right.get(key) {
+                            Some(new) => DiffNode::between(old, new),
+                            None => DiffNode::Removed(old.clone()),
+                        };
+                        (key.clone(), node)
+                    })
+                    .collect();
+                entries.extend(
+                    right
+                        .iter()
+                        .filter(|(key, _)| !left.contains_key(*key))
+                        .map(|(key, new)| (key.clone(), DiffNode::Added(new.clone()))),
+                );
+                DiffNode::Object(entries)
+            }
+            (Value::Array(left), Value::Array(right)) => DiffNode::Array(diff_arrays(left, right)),
+            _ => DiffNode::Changed {
+                before: before.clone(),
+                after: after.clone(),
+            },
+        }
+    }
+
+    /// Count added, removed and changed leaves
+    pub fn stats(&self) -> DiffStats {
+        let mut stats = DiffStats::default();
+        self.count(&mut stats);
+        stats
+    }
+
+    fn count(&self, stats: &mut DiffStats) {
+        match self {
+            DiffNode::Same(_) => {}
+            DiffNode::Added(_) => stats.added += 1,
+            DiffNode::Removed(_) => stats.removed += 1,
+            DiffNode::Changed { .. } => stats.changed += 1,
+            DiffNode::Object(entries) => entries.iter().for_each(|(_, node)| node.count(stats)),
+            DiffNode::Array(items) => items.iter().for_each(|node| node.count(stats)),
+        }
+    }
+
+    /// Render the side-by-side rows, optionally folding unchanged subtrees
+    pub fn lines(&self, collapse_unchanged: bool) -> Vec<DiffLine> {
+        let mut lines = Vec::new();
+        self.render("", 0, collapse_unchanged, &mut lines);
+        lines
+    }
+
+    fn render(&self, label: &str, depth: usize, collapse: bool, lines: &mut Vec<DiffLine>) {
+        let indent = "  ".repeat(depth);
+        match self {
+            DiffNode::Same(value) => match value {
+                Value::Object(map) if collapse && !map.is_empty() => {
+                    let text = format!("{}{}{{…}} {} same", indent, label, map.len());
+                    lines.push(DiffLine::both(LineKind::Collapsed, text));
+                }
+                Value::Array(items) if collapse && !items.is_empty() => {
+                    let text = format!("{}{}[…] {} same", indent, label, items.len());
+                    lines.push(DiffLine::both(LineKind::Collapsed, text));
+                }
+                _ => lines.extend(
+                    pretty_lines(label, depth, value)
+                        .into_iter()
+                        .map(|text| DiffLine::both(LineKind::Same, text)),
+                ),
+            },
+            DiffNode::Added(value) => lines.extend(
+                pretty_lines(label, depth, value)
+                    .into_iter()
+                    .map(|text| DiffLine {
+                        kind: LineKind::Added,
+                        left: String::new(),
+                        right: text,
+                    }),
+            ),
+            DiffNode::Removed(value) => lines.extend(
+                pretty_lines(label, depth, value)
+                    .into_iter()
+                    .map(|text| DiffLine {
+                        kind: LineKind::Removed,
+                        left: text,
+                        right: String::new(),
+                    }),
+            ),
+            DiffNode::Changed { before, after } => {
+                let left = pretty_lines(label, depth, before);
+                let right = pretty_lines(label, depth, after);
+                for row in 0..left.len().max(right.len()) {
+                    lines.push(DiffLine {
+                        kind: LineKind::Changed,
+                        left: left.get(row).cloned().unwrap_or_default(),
+                        right: right.get(row).cloned().unwrap_or_default(),
+                    });
+                }
+            }
+            DiffNode::Object(entries) => {
+                lines.push(DiffLine::both(
+                    LineKind::Same,
+                    format!("{}{}{{", indent, label),
+                ));
+                for (key, node) in entries {
+                    node.render(&format!("{:?}: ", key), depth + 1, collapse, lines);
+                }
+                lines.push(DiffLine::both(LineKind::Same, format!("{}}}", indent)));
+            }
+            DiffNode::Array(items) => {
+                lines.push(DiffLine::both(
+                    LineKind::Same,
+                    format!("{}{}[", indent, label),
+                ));
+                for node in items {
+                    node.render("", depth + 1, collapse, lines);
+                }
+                lines.push(DiffLine::both(LineKind::Same, format!("{}]", indent)));
+            }
+        }
+    }
+
+    /// Every difference as `{path, op, before, after}`, paths in JSONPath syntax
+    pub fn changes(&self) -> Vec<Value> {
+        let mut changes = Vec::new();
+        self.collect_changes("$".to_string(), &mut changes);
+        changes
+    }
+
+    fn collect_changes(&self, path: String, changes: &mut Vec<Value>) {
+        match self {
+            DiffNode::Same(_) => {}
+            DiffNode::Added(value) => {
+                changes.push(json!({"path": path, "op": "added", "after": value}))
+            }
+            DiffNode::Removed(value) => {
+                changes.push(json!({"path": path, "op": "removed", "before": value}))
+            }
+            DiffNode::Changed { before, after } => changes
+                .push(json!({"path": path, "op": "changed", "before": before, "after": after})),
+            DiffNode::Object(entries) => {
+                for (key, node) in entries {
+                    node.collect_changes(child_path(&path, key), changes);
+                }
+            }
+            DiffNode::Array(items) => {
+                // Index on the side the item exists on: the right, or the left when removed
+                let (mut left, mut right) = (0, 0);
+                for node in items {
+                    let index = if matches!(node, DiffNode::Removed(_)) {
+                        left
+                    } else {
+                        right
+                    };
+                    node.collect_changes(format!("{}[{}]", path, index), changes);
+                    if !matches!(node, DiffNode::Added(_)) {
+                        left += 1;
+                    }
+                    if !matches!(node, DiffNode::Removed(_)) {
+                        right += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl DiffLine {
+    fn both(kind: LineKind, text: String) -> Self {
+        Self {
+            kind,
+            left: text.clone(),
+            right: text,
+        }
+    }
+}
+
+/// Align array items on their longest common subsequence of equal items;
+/// unmatched items at the same position are compared structurally
+fn diff_arrays(left: &[Value], right: &[Value]) -> Vec<DiffNode> {
+    if left.len() > MAX_ALIGNED_ITEMS || right.len() > MAX_ALIGNED_ITEMS {
+        let mut nodes: Vec<DiffNode> = left
+            .iter()
+            .zip(right)
+            .map(|(old, new)| DiffNode::between(old, new))
+            .collect();
+        nodes.extend(
+            left.iter()
+                .skip(right.len())
+                .cloned()
+                .map(DiffNode::Removed),
+        );
+        nodes.extend(right.iter().skip(left.len()).cloned().map(DiffNode::Added));
+        return nodes;
+    }
+
+    // lengths[i][j]: LCS length of left[i..] and right[j..]
+    let mut lengths = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lengths[i][j] = if left[i] == right[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut nodes = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            pair_items(&mut nodes, &mut removed, &mut added);
+            nodes.push(DiffNode::Same(left[i].clone()));
+            i += 1;
+            j += 1;
+        } else if j < right.len() && (i == left.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            added.push(right[j].clone());
+            j += 1;
+        } else {
+            removed.push(left[i].clone());
+            i += 1;
+        }
+    }
+    pair_items(&mut nodes, &mut removed, &mut added);
+    nodes
+}
+
+/// Compare each removed item with the most similar added item still in order,
+/// so a run of edits between two equal items reads as changes, not churn
+fn pair_items(nodes: &mut Vec<DiffNode>, removed: &mut Vec<Value>, added: &mut Vec<Value>) {
+    let mut pairs: Vec<Option<usize>> = vec![None; added.len()];
+    let mut unpaired = Vec::new();
+    let mut next = 0;
+    for (index, old) in removed.iter().enumerate() {
+        let mut best: Option<(usize, usize)> = None;
+        for (candidate, new) in added.iter().enumerate().skip(next) {
+            let score = similarity(old, new);
+            if score > 0 && best.map_or(true, |(_, top)| score > top) {
+                best = Some((candidate, score));
+            }
+        }
+        match best {
+            Some((candidate, _)) => {
+                pairs[candidate] = Some(index);
+                next = candidate + 1;
+            }
+            None => unpaired.push(index),
+        }
+    }
+
+    nodes.extend(
+        unpaired
+            .into_iter()
+            .map(|index| DiffNode::Removed(removed[index].clone())),
+    );
+    for (new, pair) in added.drain(..).zip(pairs) {
+        nodes.push(match pair {
+            Some(index) => DiffNode::between(&removed[index], &new),
+            None => DiffNode::Added(new),
+        });
+    }
+    removed.clear();
+}
+
+/// 0 for values of different types, more for objects sharing more fields
+fn similarity(left: &Value, right: &Value) -> usize {
+    match (left, right) {
+        (Value::Object(old), Value::Object(new)) => {
+            1 + old
+                .iter()
+                .filter(|(key, value)| new.get(*key) == Some(*value))
+                .count()
+        }
+        _ if std::mem::discriminant(left) == std::mem::discriminant(right) => 1,
+        _ => 0,
+    }
+}
+
+/// Pretty-printed value, the first line prefixed with `label`, all indented
+fn pretty_lines(label: &str, depth: usize, value: &Value) -> Vec<String> {
+    let indent = "  ".repeat(depth);
+    serde_json::to_string_pretty(value)
+        .unwrap_or_default()
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            if row == 0 {
+                format!("{}{}{}", indent, label, line)
+            } else {
+                format!("{}{}", indent, line)
+            }
+        })
+        .collect()
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}['{}']", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_structural_diff() {
+        let before = json!({
+            "content": [{"type": "text", "text": "21.5"}, {"type": "text", "text": "sunny"}],
+            "meta": {"region": "eu", "units": "c"},
+            "stale": true
+        });
+        let after = json!({
+            "content": [
+                {"type": "image", "data": "AA=="},
+                {"type": "text", "text": "23.0"},
+                {"type": "text", "text": "sunny"}
+            ],
+            "meta": {"region": "eu", "units": "c"},
+            "cached": false
+        });
+
+        let diff = DiffNode::between(&before, &after);
+        assert_eq!(
+            diff.stats(),
+            DiffStats {
+                added: 2,
+                removed: 1,
+                changed: 1
+            }
+        );
+        assert_eq!(diff.stats().to_string(), "+2 added, -1 removed, ~1 changed");
+
+        let changes = diff.changes();
+        let paths: Vec<&str> = changes
+            .iter()
+            .map(|change| change["path"].as_str().unwrap())
+            .collect();
+        // The image is inserted, the first text item changed and "sunny" is aligned
+        assert_eq!(
+            paths,
+            vec!["$.content[0]", "$.content[1].text", "$.stale", "$.cached"]
+        );
+        assert_eq!(changes[0]["op"], "added");
+        assert_eq!(changes[1]["before"], "21.5");
+        assert_eq!(changes[1]["after"], "23.0");
+
+        let collapsed = diff.lines(true);
+        assert!(collapsed
+            .iter()
+            .any(|line| line.kind == LineKind::Collapsed && line.left == "  \"meta\": {…} 2 same"));
+        let stale = collapsed
+            .iter()
+            .find(|line| line.kind == LineKind::Removed)
+            .unwrap();
+        assert_eq!(
+            (stale.left.as_str(), stale.right.as_str()),
+            ("  \"stale\": true", "")
+        );
+        assert!(diff.lines(false).len() > collapsed.len());
+
+        assert!(DiffNode::between(&before, &before).stats().is_empty());
+        assert_eq!(
+            DiffNode::between(&json!(1), &json!("1")),
+            DiffNode::Changed {
+                before: json!(1),
+                after: json!("1")
+            }
+        );
+    }
+}
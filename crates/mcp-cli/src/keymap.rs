@@ -0,0 +1,755 @@
+//! Action-based key bindings for the TUI
+//!
+//! Key presses are resolved to [`Action`]s through a [`Keymap`]: a preset
+//! (`default`, `vim` or `emacs`, selected with `tui.keymap`) with the
+//! `tui.key_bindings` overrides applied on top. An override replaces every
+//! key of its action and may list several keys separated by commas:
+//!
+//! ```toml
+//! [tui.key_bindings]
+//! quit = "ctrl+q"
+//! down = "j, down"
+//! clear_history = ""    # unbound
+//! ```
+//!
+//! Keys are single characters (`q`, `G`, `?`), named keys (`esc`, `enter`,
+//! `tab`, `up`, `pgdn`, `f1`, ...) or either of those with `ctrl+`/`alt+`.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Keymap presets understood by `tui.keymap`
+pub const PRESETS: &[&str] = &["default", "vim", "emacs"];
+
+/// Something a key can do in the TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Help,
+    Search,
+    Terminal,
+    SaveSession,
+    ToggleRawJson,
+    ClearHistory,
+    EnvVariables,
+    Composer,
+    Confirm,
+    Cancel,
+    Execute,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    OpenResponse,
+    CycleView,
+    SavePayloads,
+    Complete,
+    Send,
+    ToggleCorrelation,
+    SaveSnippet,
+    LoadSnippet,
+    Decline,
+    Filter,
+    Pin,
+    Bookmark,
+    NextBookmark,
+    PreviousBookmark,
+    MarkDiff,
+    OpenDiff,
+    ToggleUnchanged,
+    ExportDiff,
+    ServerStderr,
+}
+
+impl Action {
+    /// Help dialog sections, in display order
+    pub const SECTIONS: &'static [(&'static str, &'static [Action])] = &[
+        (
+            "General",
+            &[
+                Action::Help,
+                Action::Search,
+                Action::Terminal,
+                Action::SaveSession,
+                Action::ToggleRawJson,
+                Action::ClearHistory,
+                Action::EnvVariables,
+                Action::Composer,
+                Action::ServerStderr,
+                Action::Confirm,
+                Action::Cancel,
+                Action::Execute,
+                Action::Quit,
+            ],
+        ),
+        (
+            "Navigation",
+            &[
+                Action::Up,
+                Action::Down,
+                Action::Left,
+                Action::Right,
+                Action::PageUp,
+                Action::PageDown,
+                Action::Home,
+                Action::End,
+            ],
+        ),
+        (
+            "Response viewer",
+            &[
+                Action::OpenResponse,
+                Action::CycleView,
+                Action::SavePayloads,
+                Action::ToggleUnchanged,
+                Action::ExportDiff,
+            ],
+        ),
+        (
+            "Message inspector",
+            &[
+                Action::Filter,
+                Action::Pin,
+                Action::Bookmark,
+                Action::NextBookmark,
+                Action::PreviousBookmark,
+                Action::MarkDiff,
+                Action::OpenDiff,
+            ],
+        ),
+        ("Terminal", &[Action::Complete]),
+        (
+            "Composer",
+            &[
+                Action::Send,
+                Action::ToggleCorrelation,
+                Action::SaveSnippet,
+                Action::LoadSnippet,
+            ],
+        ),
+        ("Elicitation", &[Action::Decline]),
+    ];
+
+    /// Actions of the main screen
+    pub const MAIN: &'static [Action] = &[
+        Action::Quit,
+        Action::Help,
+        Action::Search,
+        Action::Terminal,
+        Action::SaveSession,
+        Action::ToggleRawJson,
+        Action::ClearHistory,
+        Action::EnvVariables,
+        Action::Composer,
+        Action::Confirm,
+        Action::Cancel,
+        Action::Execute,
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Home,
+        Action::End,
+        Action::OpenResponse,
+        Action::CycleView,
+        Action::SavePayloads,
+        Action::Filter,
+        Action::Pin,
+        Action::Bookmark,
+        Action::NextBookmark,
+        Action::PreviousBookmark,
+        Action::MarkDiff,
+        Action::OpenDiff,
+        Action::ToggleUnchanged,
+        Action::ExportDiff,
+        Action::ServerStderr,
+    ];
+
+    /// Main screen actions that still work while the terminal has focus
+    pub const GLOBAL: &'static [Action] = &[
+        Action::Quit,
+        Action::Help,
+        Action::Search,
+        Action::SaveSession,
+        Action::ToggleRawJson,
+        Action::ClearHistory,
+        Action::EnvVariables,
+        Action::Composer,
+    ];
+
+    /// Actions of the search popup
+    pub const SEARCH: &'static [Action] =
+        &[Action::Confirm, Action::Cancel, Action::Up, Action::Down];
+
+    /// Actions of the message inspector filter line
+    pub const FILTER: &'static [Action] = &[Action::Confirm, Action::Cancel];
+
+    /// Actions of the interactive terminal
+    pub const TERMINAL: &'static [Action] = &[
+        Action::Confirm,
+        Action::Cancel,
+        Action::Complete,
+        Action::Up,
+        Action::Down,
+    ];
+
+    /// Actions of the raw JSON-RPC composer
+    pub const COMPOSER: &'static [Action] = &[
+        Action::Cancel,
+        Action::Send,
+        Action::ToggleCorrelation,
+        Action::SaveSnippet,
+        Action::LoadSnippet,
+    ];
+
+    /// Actions of the elicitation dialog
+    pub const ELICITATION: &'static [Action] = &[
+        Action::Confirm,
+        Action::Cancel,
+        Action::Execute,
+        Action::Decline,
+        Action::Up,
+        Action::Down,
+    ];
+
+    /// Every action
+    pub fn all() -> impl Iterator<Item = Action> {
+        Self::SECTIONS
+            .iter()
+            .flat_map(|(_, actions)| actions.iter().copied())
+    }
+
+    /// Name used in `tui.key_bindings`
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Help => "help",
+            Action::Search => "search",
+            Action::Terminal => "terminal",
+            Action::SaveSession => "save_session",
+            Action::ToggleRawJson => "toggle_raw_json",
+            Action::ClearHistory => "clear_history",
+            Action::EnvVariables => "env_variables",
+            Action::Composer => "composer",
+            Action::Confirm => "confirm",
+            Action::Cancel => "cancel",
+            Action::Execute => "execute",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Home => "home",
+            Action::End => "end",
+            Action::OpenResponse => "open_response",
+            Action::CycleView => "cycle_view",
+            Action::SavePayloads => "save_payloads",
+            Action::Complete => "complete",
+            Action::Send => "send",
+            Action::ToggleCorrelation => "toggle_correlation",
+            Action::SaveSnippet => "save_snippet",
+            Action::LoadSnippet => "load_snippet",
+            Action::Decline => "decline",
+            Action::Filter => "filter",
+            Action::Pin => "pin",
+            Action::Bookmark => "bookmark",
+            Action::NextBookmark => "next_bookmark",
+            Action::PreviousBookmark => "previous_bookmark",
+            Action::MarkDiff => "mark_diff",
+            Action::OpenDiff => "open_diff",
+            Action::ToggleUnchanged => "toggle_unchanged",
+            Action::ExportDiff => "export_diff",
+            Action::ServerStderr => "server_stderr",
+        }
+    }
+
+    /// Look up an action by its `tui.key_bindings` name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().find(|action| action.name() == name)
+    }
+
+    /// What the action does, for the help dialog
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit application",
+            Action::Help => "Toggle this help",
+            Action::Search => "Search capabilities (fuzzy matching)",
+            Action::Terminal => "Focus the interactive terminal",
+            Action::SaveSession => "Save session",
+            Action::ToggleRawJson => "Toggle raw JSON view",
+            Action::ClearHistory => "Clear message history",
+            Action::EnvVariables => "Environment variables",
+            Action::Composer => "Raw JSON-RPC composer",
+            Action::Confirm => "Select / execute / save field and move to next",
+            Action::Cancel => "Close dialog / go back",
+            Action::Execute => "Execute parameter form / open latest response",
+            Action::Up => "Move up / scroll up",
+            Action::Down => "Move down / scroll down",
+            Action::Left => "Previous page / scroll left",
+            Action::Right => "Next page / scroll right",
+            Action::PageUp => "Scroll a page up",
+            Action::PageDown => "Scroll a page down",
+            Action::Home => "Jump to top",
+            Action::End => "Jump to bottom",
+            Action::OpenResponse => "Open response viewer for selected message",
+            Action::CycleView => "Cycle view modes (Formatted/Raw/Tree/Summary)",
+            Action::SavePayloads => "Save image/audio/blob payloads to disk",
+            Action::Complete => "Complete names and argument keys",
+            Action::Send => "Send the message",
+            Action::ToggleCorrelation => "Toggle reply correlation",
+            Action::SaveSnippet => "Save as snippet",
+            Action::LoadSnippet => "Load next snippet",
+            Action::Decline => "Decline the request",
+            Action::Filter => "Filter messages (method:, dir:, type:, is:error, text, $.json.path)",
+            Action::Pin => "Pin / unpin the selected message at the top",
+            Action::Bookmark => "Bookmark / unbookmark the selected message",
+            Action::NextBookmark => "Jump to the next bookmark",
+            Action::PreviousBookmark => "Jump to the previous bookmark",
+            Action::MarkDiff => "Mark / unmark the selected message for diffing",
+            Action::OpenDiff => "Diff the two marked responses side by side",
+            Action::ToggleUnchanged => "Collapse / expand unchanged parts of a diff",
+            Action::ExportDiff => "Export the open diff to a JSON file",
+            Action::ServerStderr => "Show / hide the server stderr pane",
+        }
+    }
+
+    /// Keys of the `default` preset
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::Help => &["f1", "h"],
+            Action::Search => &["/"],
+            Action::Terminal => &[":"],
+            Action::SaveSession => &["f2"],
+            Action::ToggleRawJson => &["f3"],
+            Action::ClearHistory => &["f4"],
+            Action::EnvVariables => &["f5"],
+            Action::Composer => &["f6"],
+            Action::Confirm => &["enter"],
+            Action::Cancel => &["esc"],
+            Action::Execute => &["tab"],
+            Action::Up => &["up"],
+            Action::Down => &["down"],
+            Action::Left => &["left"],
+            Action::Right => &["right"],
+            Action::PageUp => &["pgup"],
+            Action::PageDown => &["pgdn"],
+            Action::Home => &["home"],
+            Action::End => &["end"],
+            Action::OpenResponse => &["r", "R"],
+            Action::CycleView => &["v", "V"],
+            Action::SavePayloads => &["s", "S"],
+            Action::Complete => &["tab"],
+            Action::Send => &["f7"],
+            Action::ToggleCorrelation => &["f8"],
+            Action::SaveSnippet => &["f9"],
+            Action::LoadSnippet => &["f10"],
+            Action::Decline => &["f9"],
+            Action::Filter => &["f"],
+            Action::Pin => &["p"],
+            Action::Bookmark => &["b"],
+            Action::NextBookmark => &["n"],
+            Action::PreviousBookmark => &["N"],
+            Action::MarkDiff => &["m"],
+            Action::OpenDiff => &["d"],
+            Action::ToggleUnchanged => &["u"],
+            Action::ExportDiff => &["e"],
+            Action::ServerStderr => &["o"],
+        }
+    }
+
+    /// Keys of the `vim` preset, where they differ from `default`
+    fn vim_keys(self) -> Option<&'static [&'static str]> {
+        Some(match self {
+            Action::Help => &["f1", "?"],
+            Action::Up => &["up", "k"],
+            Action::Down => &["down", "j"],
+            Action::Left => &["left", "h"],
+            Action::Right => &["right", "l"],
+            Action::PageUp => &["pgup", "ctrl+b", "ctrl+u"],
+            Action::PageDown => &["pgdn", "ctrl+f", "ctrl+d"],
+            Action::Home => &["home", "g"],
+            Action::End => &["end", "G"],
+            _ => return None,
+        })
+    }
+
+    /// Keys of the `emacs` preset, where they differ from `default`
+    fn emacs_keys(self) -> Option<&'static [&'static str]> {
+        Some(match self {
+            Action::Quit => &["q", "ctrl+c"],
+            Action::Search => &["/", "ctrl+s"],
+            Action::Terminal => &[":", "alt+x"],
+            Action::Cancel => &["esc", "ctrl+g"],
+            Action::Up => &["up", "ctrl+p"],
+            Action::Down => &["down", "ctrl+n"],
+            Action::Left => &["left", "ctrl+b"],
+            Action::Right => &["right", "ctrl+f"],
+            Action::PageUp => &["pgup", "alt+v"],
+            Action::PageDown => &["pgdn", "ctrl+v"],
+            Action::Home => &["home", "alt+<"],
+            Action::End => &["end", "alt+>"],
+            _ => return None,
+        })
+    }
+}
+
+/// A key with optional `ctrl`/`alt` modifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parse a key such as `q`, `F1`, `esc`, `ctrl+s` or `alt+<`
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut key = text.trim();
+        while let Some((modifier, rest)) = key.split_once('+') {
+            if rest.is_empty() {
+                break;
+            }
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                _ => return None,
+            };
+            key = rest;
+        }
+
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Some(Self {
+                code: KeyCode::Char(c),
+                modifiers,
+            });
+        }
+
+        let lower = key.to_lowercase();
+        let code = match lower.as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "comma" => KeyCode::Char(','),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            _ => lower
+                .strip_prefix('f')
+                .and_then(|n| n.parse::<u8>().ok())
+                .filter(|n| (1..=12).contains(n))
+                .map(KeyCode::F)?,
+        };
+        Some(Self { code, modifiers })
+    }
+
+    /// Whether a key press is this binding
+    ///
+    /// Shift is not compared: it is part of the character (`G`, `?`, `<`).
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code
+            && self.modifiers == key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => write!(f, "{}", c),
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Esc => f.write_str("Esc"),
+            KeyCode::Enter => f.write_str("Enter"),
+            KeyCode::Tab => f.write_str("Tab"),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::Backspace => f.write_str("Backspace"),
+            KeyCode::Delete => f.write_str("Del"),
+            KeyCode::Insert => f.write_str("Ins"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::PageUp => f.write_str("PgUp"),
+            KeyCode::PageDown => f.write_str("PgDn"),
+            KeyCode::Home => f.write_str("Home"),
+            KeyCode::End => f.write_str("End"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Whether a key press types a character rather than triggering a binding
+///
+/// Text fields keep these keys even when an action is bound to them.
+pub fn is_text_input(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char(_))
+        && !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+/// Keys bound to each action
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    preset: String,
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new("default", &HashMap::new()).0
+    }
+}
+
+impl Keymap {
+    /// Build a keymap from a preset and `tui.key_bindings` overrides
+    ///
+    /// Also returns a description of every problem found: unknown presets,
+    /// actions or keys (which are ignored) and keys bound to two actions
+    /// that are active at the same time (the first action listed wins).
+    pub fn new(preset: &str, overrides: &HashMap<String, String>) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        let preset = if PRESETS.contains(&preset) {
+            preset
+        } else {
+            problems.push(format!("Unknown keymap '{}', using 'default'", preset));
+            "default"
+        };
+
+        let mut bindings = HashMap::new();
+        for action in Action::all() {
+            let keys = match preset {
+                "vim" => action.vim_keys(),
+                "emacs" => action.emacs_keys(),
+                _ => None,
+            }
+            .unwrap_or_else(|| action.default_keys());
+            let keys = keys
+                .iter()
+                .filter_map(|key| KeyBinding::parse(key))
+                .collect();
+            bindings.insert(action, keys);
+        }
+
+        let mut overrides: Vec<_> = overrides.iter().collect();
+        overrides.sort();
+        for (name, keys) in overrides {
+            let Some(action) = Action::from_name(name) else {
+                problems.push(format!(
+                    "Ignoring key binding for unknown action '{}'",
+                    name
+                ));
+                continue;
+            };
+            let parsed: Option<Vec<KeyBinding>> = if keys.trim() == "," {
+                KeyBinding::parse(",").map(|key| vec![key])
+            } else {
+                keys.split(',')
+                    .filter(|key| !key.trim().is_empty())
+                    .map(KeyBinding::parse)
+                    .collect()
+            };
+            match parsed {
+                Some(keys) => {
+                    bindings.insert(action, keys);
+                }
+                None => problems.push(format!("Ignoring key binding {} = '{}'", name, keys)),
+            }
+        }
+
+        let keymap = Self {
+            preset: preset.to_string(),
+            bindings,
+        };
+        problems.extend(keymap.conflicts());
+        (keymap, problems)
+    }
+
+    /// Build the keymap for the TUI configuration, logging any problems
+    pub fn from_config(config: &crate::config::TuiConfig) -> Self {
+        let (keymap, problems) = Self::new(&config.keymap, &config.key_bindings);
+        for problem in problems {
+            tracing::warn!("{}", problem);
+        }
+        keymap
+    }
+
+    /// Name of the preset the keymap is based on
+    pub fn preset(&self) -> &str {
+        &self.preset
+    }
+
+    /// Whether `key` triggers `action`
+    pub fn is_bound(&self, action: Action, key: &KeyEvent) -> bool {
+        self.bindings
+            .get(&action)
+            .is_some_and(|keys| keys.iter().any(|binding| binding.matches(key)))
+    }
+
+    /// The first of `actions` that `key` triggers
+    pub fn resolve(&self, key: &KeyEvent, actions: &[Action]) -> Option<Action> {
+        actions
+            .iter()
+            .copied()
+            .find(|action| self.is_bound(*action, key))
+    }
+
+    /// Like [`Keymap::resolve`], but typed characters are left to a text field
+    pub fn resolve_while_typing(&self, key: &KeyEvent, actions: &[Action]) -> Option<Action> {
+        if is_text_input(key) {
+            None
+        } else {
+            self.resolve(key, actions)
+        }
+    }
+
+    /// Display label for the keys bound to `action`, e.g. `F1/h`
+    pub fn label(&self, action: Action) -> String {
+        match self.bindings.get(&action) {
+            Some(keys) if !keys.is_empty() => keys
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("/"),
+            _ => "-".to_string(),
+        }
+    }
+
+    /// Keys bound to two actions of the same screen
+    fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for context in [
+            Action::MAIN,
+            Action::SEARCH,
+            Action::FILTER,
+            Action::TERMINAL,
+            Action::COMPOSER,
+            Action::ELICITATION,
+        ] {
+            for (index, first) in context.iter().enumerate() {
+                for second in &context[index + 1..] {
+                    for key in &self.bindings[first] {
+                        if self.bindings[second].contains(key) {
+                            let conflict = format!(
+                                "Key {} is bound to both {} and {}",
+                                key,
+                                first.name(),
+                                second.name()
+                            );
+                            if !conflicts.contains(&conflict) {
+                                conflicts.push(conflict);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_key_bindings() {
+        let binding = KeyBinding::parse("ctrl+s").unwrap();
+        assert!(binding.matches(&press(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+        assert!(!binding.matches(&press(KeyCode::Char('s'), KeyModifiers::NONE)));
+        assert_eq!(binding.to_string(), "Ctrl+S");
+
+        // Shifted characters match however the terminal reports shift
+        let end = KeyBinding::parse("G").unwrap();
+        assert!(end.matches(&press(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+        assert!(KeyBinding::parse("alt+<").unwrap().matches(&press(
+            KeyCode::Char('<'),
+            KeyModifiers::ALT | KeyModifiers::SHIFT
+        )));
+
+        assert_eq!(KeyBinding::parse("F1").unwrap().to_string(), "F1");
+        assert_eq!(KeyBinding::parse("pgdn").unwrap().to_string(), "PgDn");
+        assert_eq!(KeyBinding::parse("+").unwrap().to_string(), "+");
+        assert!(KeyBinding::parse("f13").is_none());
+        assert!(KeyBinding::parse("hyper+x").is_none());
+        assert!(KeyBinding::parse("nope").is_none());
+    }
+
+    #[test]
+    fn test_presets_and_overrides() {
+        let (default, problems) = Keymap::new("default", &HashMap::new());
+        assert!(problems.is_empty(), "{:?}", problems);
+        let j = press(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(default.resolve(&j, Action::MAIN), None);
+        assert_eq!(default.label(Action::Help), "F1/h");
+
+        let (vim, problems) = Keymap::new("vim", &HashMap::new());
+        assert!(problems.is_empty(), "{:?}", problems);
+        assert_eq!(vim.resolve(&j, Action::MAIN), Some(Action::Down));
+        assert_eq!(vim.resolve_while_typing(&j, Action::MAIN), None);
+        let h = press(KeyCode::Char('h'), KeyModifiers::NONE);
+        assert_eq!(vim.resolve(&h, Action::MAIN), Some(Action::Left));
+
+        let (emacs, problems) = Keymap::new("emacs", &HashMap::new());
+        assert!(problems.is_empty(), "{:?}", problems);
+        let ctrl_g = press(KeyCode::Char('g'), KeyModifiers::CONTROL);
+        assert_eq!(
+            emacs.resolve_while_typing(&ctrl_g, Action::COMPOSER),
+            Some(Action::Cancel)
+        );
+
+        let overrides = HashMap::from([
+            ("quit".to_string(), "ctrl+q, x".to_string()),
+            ("clear_history".to_string(), String::new()),
+            ("teleport".to_string(), "t".to_string()),
+            ("search".to_string(), "ctrl+nope".to_string()),
+            ("open_response".to_string(), "j".to_string()),
+        ]);
+        let (keymap, problems) = Keymap::new("vim", &overrides);
+        assert_eq!(keymap.label(Action::Quit), "Ctrl+Q/x");
+        assert_eq!(keymap.label(Action::ClearHistory), "-");
+        assert_eq!(keymap.label(Action::Search), "/");
+        assert_eq!(
+            problems,
+            vec![
+                "Ignoring key binding search = 'ctrl+nope'",
+                "Ignoring key binding for unknown action 'teleport'",
+                "Key j is bound to both down and open_response",
+            ]
+        );
+
+        let (keymap, problems) = Keymap::new("nano", &HashMap::new());
+        assert_eq!(keymap.preset(), "default");
+        assert_eq!(problems, vec!["Unknown keymap 'nano', using 'default'"]);
+    }
+}
@@ -8,15 +8,22 @@
 
 use anyhow::Result;
 use clap::Parser;
+use std::io::IsTerminal;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod cli;
+mod command;
 mod commands;
 mod config;
 mod flows;
+mod inspector;
+mod json_diff;
+mod keymap;
 mod paths;
 mod search;
+mod theme;
 mod tui;
+mod watch;
 
 use cli::{Cli, Commands};
 
@@ -25,57 +32,145 @@ async fn main() -> Result<()> {
     // Parse command line arguments first to check if we're in TUI mode
     let cli = Cli::parse();
 
+    // Resolve layered configuration (defaults, files, env, flags). The config
+    // command still runs on a broken file so it can be inspected and fixed.
+    let resolved = match cli.config_loader().load() {
+        Ok(resolved) => resolved,
+        Err(e) if matches!(cli.command, Commands::Config(_)) => {
+            eprintln!("⚠️  {:#}", e);
+            config::ConfigLoader::new().load()?
+        }
+        Err(e) => return Err(e),
+    };
+
     // Initialize logging based on command type
     let tui_mode = matches!(cli.command, Commands::Debug(ref cmd) if !cmd.non_interactive);
-    init_logging(tui_mode)?;
+    init_logging(tui_mode, &resolved.config.logging)?;
 
     // Log the startup
     tracing::info!("MCP Probe starting up, TUI mode: {}", tui_mode);
     tracing::debug!("Command: {:?}", cli.command);
 
+    #[cfg(feature = "otel")]
+    let telemetry = init_telemetry(&resolved.config.telemetry)?;
+    #[cfg(not(feature = "otel"))]
+    warn_telemetry_unavailable(&resolved.config.telemetry);
+
     // Execute the appropriate command
-    match cli.command {
-        Commands::Debug(debug_cmd) => debug_cmd.execute().await,
-        Commands::Test(args) => commands::test::run(args).await,
-        Commands::Config(args) => commands::config::run(args).await,
-        Commands::Validate(args) => commands::validate::run(args).await,
+    let config = &resolved.config;
+    let result = match cli.command {
+        Commands::Debug(debug_cmd) => debug_cmd.execute(config).await,
+        Commands::Test(args) => commands::test::run(args, config).await,
+        Commands::Config(args) => commands::config::run(args, &resolved).await,
+        Commands::Validate(args) => commands::validate::run(args, config).await,
         Commands::Export(args) => commands::export::run(args).await,
         Commands::Paths(args) => commands::paths::run(args).await,
+        Commands::Bench(args) => commands::bench::run(args, config).await,
+        Commands::Servers(args) => commands::servers::run(args, &resolved).await,
+        Commands::Monitor(args) => commands::monitor::run(args, config).await,
+    };
+
+    #[cfg(feature = "otel")]
+    if let Some(telemetry) = telemetry {
+        if let Err(e) = telemetry.shutdown().await {
+            tracing::warn!("{}", e);
+        }
+    }
+
+    result
+}
+
+/// Start exporting request spans and client metrics if telemetry is configured.
+#[cfg(feature = "otel")]
+fn init_telemetry(
+    telemetry: &config::TelemetryConfig,
+) -> Result<Option<mcp_probe_core::otel::Telemetry>> {
+    use mcp_probe_core::otel::{self, Exporter};
+
+    let exporter = match (&telemetry.otlp_endpoint, &telemetry.file) {
+        (Some(_), Some(_)) => {
+            anyhow::bail!("Set only one of telemetry.otlp_endpoint and telemetry.file")
+        }
+        (Some(endpoint), None) => Exporter::Otlp {
+            endpoint: endpoint.clone(),
+        },
+        (None, Some(file)) => Exporter::File(file.clone()),
+        (None, None) => return Ok(None),
+    };
+    tracing::info!("Exporting telemetry to {:?}", exporter);
+    Ok(Some(otel::init(&telemetry.service_name, exporter)?))
+}
+
+/// Telemetry export needs the `otel` feature; say so if it is configured.
+#[cfg(not(feature = "otel"))]
+fn warn_telemetry_unavailable(telemetry: &config::TelemetryConfig) {
+    if telemetry.otlp_endpoint.is_some() || telemetry.file.is_some() {
+        eprintln!(
+            "⚠️  Telemetry is configured, but mcp-probe was built without the `otel` feature"
+        );
     }
 }
 
-/// Initialize structured logging based on environment variables and CLI options
-fn init_logging(tui_mode: bool) -> Result<()> {
+/// Initialize structured logging from the resolved logging configuration.
+///
+/// `RUST_LOG` still takes precedence over `logging.level` when set.
+fn init_logging(tui_mode: bool, logging: &config::LoggingConfig) -> Result<()> {
     use crate::paths::get_mcp_probe_paths;
+    use tracing_subscriber::{EnvFilter, Layer};
 
     // Use the centralized path management
     let paths = get_mcp_probe_paths()?;
 
-    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| "mcp_probe=debug,mcp_core=debug,info".into());
+    let env_filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&logging.level))
+        .unwrap_or_else(|_| "info".into());
+
+    // In TUI mode, use a fixed file name; otherwise one log file per run
+    let log_file_path = match &logging.file {
+        Some(path) => path.clone(),
+        None if tui_mode => paths.debug_log_file(),
+        None => paths.log_file("mcp-probe"),
+    };
+    if let Some(parent) = log_file_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let log_file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(&log_file_path)?;
+
+    let file_layer = tracing_subscriber::fmt::layer()
+        .with_writer(log_file)
+        .with_target(true)
+        .with_thread_ids(tui_mode)
+        .with_file(true)
+        .with_line_number(true)
+        .with_ansi(false); // No ANSI codes in log file
+
+    let file_layer = match logging.format.as_str() {
+        "json" => file_layer.json().boxed(),
+        "compact" => file_layer.compact().boxed(),
+        _ => file_layer.boxed(),
+    };
+
+    // With `logging.stderr`, warnings and errors also go to stderr, except in
+    // TUI mode where they would corrupt the screen
+    let stderr_layer = (logging.stderr && !tui_mode).then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_target(false)
+            .with_ansi(std::io::stderr().is_terminal())
+            .with_filter(tracing_subscriber::filter::LevelFilter::WARN)
+    });
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(file_layer)
+        .with(stderr_layer)
+        .init();
 
     if tui_mode {
-        // In TUI mode, use immediate file logging (synchronous to ensure writes)
-        let log_file_path = paths.debug_log_file();
-        let log_file = std::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(&log_file_path)?;
-
-        let file_layer = tracing_subscriber::fmt::layer()
-            .with_writer(log_file)
-            .with_target(true)
-            .with_thread_ids(true)
-            .with_file(true)
-            .with_line_number(true)
-            .with_ansi(false); // No ANSI codes in log file
-
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(file_layer)
-            .init();
-
         // Write an initial log to confirm logging is working
         tracing::info!("=== MCP Probe Debug Log Started ===");
         tracing::info!("Log file: {:?}", log_file_path);
@@ -84,29 +179,6 @@ fn init_logging(tui_mode: bool) -> Result<()> {
             paths.home_dir.display()
         );
     } else {
-        // Normal mode - redirect all logs to file to keep display clean
-        let log_file_path = paths.log_file("mcp-probe");
-
-        let log_file = std::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(&log_file_path)?;
-
-        let file_layer = tracing_subscriber::fmt::layer()
-            .with_writer(log_file)
-            .with_target(true)
-            .with_thread_ids(false)
-            .with_thread_names(false)
-            .with_file(true)
-            .with_line_number(true)
-            .with_ansi(false); // No ANSI codes in log file
-
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(file_layer)
-            .init();
-
         tracing::debug!("Logging initialized");
         tracing::info!("Log file: {:?}", log_file_path);
         tracing::info!(
@@ -19,6 +19,10 @@ pub struct McpProbePaths {
     pub sessions_dir: PathBuf,
     /// Config directory for configuration files
     pub config_dir: PathBuf,
+    /// Snippets directory for saved raw JSON-RPC messages
+    pub snippets_dir: PathBuf,
+    /// Themes directory for TUI theme files
+    pub themes_dir: PathBuf,
 }
 
 impl McpProbePaths {
@@ -31,6 +35,8 @@ impl McpProbePaths {
             reports_dir: home_dir.join("reports"),
             sessions_dir: home_dir.join("sessions"),
             config_dir: home_dir.join("config"),
+            snippets_dir: home_dir.join("snippets"),
+            themes_dir: home_dir.join("themes"),
             home_dir,
         };
 
@@ -57,6 +63,8 @@ impl McpProbePaths {
             &self.reports_dir,
             &self.sessions_dir,
             &self.config_dir,
+            &self.snippets_dir,
+            &self.themes_dir,
         ] {
             std::fs::create_dir_all(dir)?;
         }
@@ -109,7 +117,6 @@ impl McpProbePaths {
     }
 
     /// Create a custom output directory under reports
-    #[allow(dead_code)]
     pub fn custom_output_dir(&self, name: &str) -> Result<PathBuf> {
         let dir = self.reports_dir.join(name);
         std::fs::create_dir_all(&dir)?;
@@ -180,6 +187,8 @@ impl McpProbePaths {
         println!("   📊 Reports: {}", self.reports_dir.display());
         println!("   💾 Sessions: {}", self.sessions_dir.display());
         println!("   ⚙️  Config: {}", self.config_dir.display());
+        println!("   🧩 Snippets: {}", self.snippets_dir.display());
+        println!("   🎨 Themes: {}", self.themes_dir.display());
     }
 }
 
@@ -1,16 +1,61 @@
 //! Lightweight in-memory search for MCP tools
 //!
-//! This module provides fast, fuzzy search capabilities for tools, resources, and prompts
-//! using a combination of string similarity and keyword matching.
+//! This module provides ranked, typo-tolerant search over tools, resources and
+//! prompts. Names, descriptions, parameter names and parameter descriptions are
+//! indexed as separate fields and scored with BM25, weighting name matches
+//! highest. Query terms also match vocabulary terms they are a prefix of, a
+//! substring of, or a close misspelling of; those candidates come from a
+//! trigram index, so a query does not scan the vocabulary.
+//!
+//! Queries can mix free text with field filters:
+//!
+//! | Filter | Keeps items |
+//! |--------|-------------|
+//! | `type:tool`, `type:resource`, `type:prompt` | of that kind (prefixes such as `type:res` work) |
+//! | `param:path` | with a parameter or prompt argument whose name contains `path` |
+//! | `mime:image/*` | resources whose MIME type matches the pattern (`*` is a wildcard) |
+//! | `name:git` | whose name contains `git` |
+//! | `server:github` | offered by a server whose name contains `github` |
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Range;
+
+/// BM25 term frequency saturation
+const BM25_K1: f32 = 1.2;
+
+/// BM25 field length normalization
+const BM25_B: f32 = 0.75;
+
+/// Most vocabulary terms a query term expands to by prefix
+const MAX_PREFIX_EXPANSIONS: usize = 50;
+
+/// Most vocabulary terms a query term expands to by substring or misspelling
+const MAX_FUZZY_EXPANSIONS: usize = 20;
 
-use std::collections::HashMap;
+/// Lowest Jaro similarity accepted as a misspelling
+const FUZZY_THRESHOLD: f32 = 0.8;
 
 /// Search result with relevance scoring
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub index: usize,
+    /// Relevance from 0 to 100, relative to the best result
     pub score: f32,
     pub match_reason: String,
+    /// Where the query matched, for highlighting
+    pub highlights: Highlights,
+}
+
+/// Matched parts of a search result
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Highlights {
+    /// Byte ranges in the item name
+    pub name: Vec<Range<usize>>,
+    /// Byte ranges in the item description
+    pub description: Vec<Range<usize>>,
+    /// Names of matched parameters
+    pub params: Vec<String>,
 }
 
 /// Searchable item that can be indexed
@@ -19,6 +64,19 @@ pub struct SearchableItem {
     pub name: String,
     pub description: String,
     pub category: SearchCategory,
+    /// Tool parameters (from the input schema) or prompt arguments
+    pub params: Vec<ParamInfo>,
+    /// MIME type of a resource
+    pub mime_type: Option<String>,
+    /// Index of the server offering the item
+    pub server: usize,
+}
+
+/// Parameter of a tool or argument of a prompt
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamInfo {
+    pub name: String,
+    pub description: String,
 }
 
 /// Category of searchable items
@@ -29,11 +87,168 @@ pub enum SearchCategory {
     Prompt,
 }
 
+impl SearchCategory {
+    const ALL: [SearchCategory; 3] = [
+        SearchCategory::Tool,
+        SearchCategory::Resource,
+        SearchCategory::Prompt,
+    ];
+
+    /// Name used in `type:` filters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchCategory::Tool => "tool",
+            SearchCategory::Resource => "resource",
+            SearchCategory::Prompt => "prompt",
+        }
+    }
+}
+
+/// Indexed field of an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Field {
+    Name,
+    Description,
+    Param,
+    ParamDescription,
+}
+
+impl Field {
+    const COUNT: usize = 4;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Relative importance of a match in this field
+    fn weight(self) -> f32 {
+        match self {
+            Field::Name => 3.0,
+            Field::Param => 1.5,
+            Field::Description => 1.0,
+            Field::ParamDescription => 0.6,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Field::Name => "Name",
+            Field::Description => "Description",
+            Field::Param => "Parameter",
+            Field::ParamDescription => "Parameter description",
+        }
+    }
+}
+
+/// How a query term matched a vocabulary term
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MatchKind {
+    Exact,
+    Prefix,
+    Substring,
+    Fuzzy,
+}
+
+impl MatchKind {
+    fn label(self) -> &'static str {
+        match self {
+            MatchKind::Exact => "match",
+            MatchKind::Prefix => "prefix match",
+            MatchKind::Substring => "partial match",
+            MatchKind::Fuzzy => "fuzzy match",
+        }
+    }
+}
+
+/// Occurrences of a term in one field of an item
+#[derive(Debug, Clone, Copy)]
+struct Posting {
+    item: usize,
+    field: Field,
+    frequency: u32,
+}
+
+/// Field filter in a query
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryFilter {
+    /// `type:` — items of any of these categories
+    Category(Vec<SearchCategory>),
+    /// `param:` — a parameter name containing the value
+    Param(String),
+    /// `mime:` — a MIME type matching the pattern
+    Mime(String),
+    /// `name:` — a name containing the value
+    Name(String),
+    /// `server:` — offered by a server whose name contains the value
+    Server(String),
+}
+
+/// Parsed search query
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Free text words, lowercased
+    pub words: Vec<String>,
+    pub filters: Vec<QueryFilter>,
+}
+
+impl Query {
+    /// Split a query into free text and `field:value` filters. Words with an
+    /// unknown field prefix, like URIs, stay free text.
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Query::default();
+        for word in query.split_whitespace() {
+            let filter = word.split_once(':').and_then(|(field, value)| {
+                let value = value.to_lowercase();
+                match field.to_lowercase().as_str() {
+                    "type" | "kind" => Some(QueryFilter::Category(
+                        SearchCategory::ALL
+                            .into_iter()
+                            .filter(|category| {
+                                category.as_str().starts_with(value.trim_end_matches('s'))
+                            })
+                            .collect(),
+                    )),
+                    "param" | "arg" => Some(QueryFilter::Param(value)),
+                    "mime" => Some(QueryFilter::Mime(value)),
+                    "name" => Some(QueryFilter::Name(value)),
+                    "server" => Some(QueryFilter::Server(value)),
+                    _ => None,
+                }
+            });
+            match filter {
+                // An empty value is a filter still being typed
+                Some(
+                    QueryFilter::Param(value)
+                    | QueryFilter::Mime(value)
+                    | QueryFilter::Name(value)
+                    | QueryFilter::Server(value),
+                ) if value.is_empty() => {}
+                Some(filter) => parsed.filters.push(filter),
+                None => parsed.words.push(word.to_lowercase()),
+            }
+        }
+        parsed
+    }
+}
+
 /// Lightweight search engine for MCP capabilities
 pub struct SearchEngine {
     items: Vec<SearchableItem>,
-    name_index: HashMap<String, Vec<usize>>,
-    keyword_index: HashMap<String, Vec<usize>>,
+    /// Term text by term ID
+    terms: Vec<String>,
+    /// Term IDs, sorted for prefix lookups
+    term_ids: BTreeMap<String, usize>,
+    /// Postings by term ID
+    postings: Vec<Vec<Posting>>,
+    /// Number of items containing each term
+    document_frequency: Vec<usize>,
+    /// Term IDs by padded trigram
+    trigram_index: HashMap<String, Vec<usize>>,
+    /// Terms per field, by item
+    field_lengths: Vec<[u32; Field::COUNT]>,
+    total_field_lengths: [u64; Field::COUNT],
+    /// Server names by index, for `server:` filters
+    server_names: Vec<String>,
 }
 
 impl SearchEngine {
@@ -41,204 +256,435 @@ impl SearchEngine {
     pub fn new() -> Self {
         Self {
             items: Vec::new(),
-            name_index: HashMap::new(),
-            keyword_index: HashMap::new(),
+            terms: Vec::new(),
+            term_ids: BTreeMap::new(),
+            postings: Vec::new(),
+            document_frequency: Vec::new(),
+            trigram_index: HashMap::new(),
+            field_lengths: Vec::new(),
+            total_field_lengths: [0; Field::COUNT],
+            server_names: Vec::new(),
         }
     }
 
+    /// Name the servers items are indexed with, for `server:` filters
+    pub fn set_server_names(&mut self, names: Vec<String>) {
+        self.server_names = names;
+    }
+
     /// Index tools for searching
     pub fn index_tools(&mut self, tools: &[crate::tui::ToolInfo]) {
-        for tool in tools.iter() {
-            let keywords =
-                self.extract_keywords(&tool.name, &tool.description.clone().unwrap_or_default());
-            let item = SearchableItem {
-                name: tool.name.clone(),
-                description: tool.description.clone().unwrap_or_default(),
-                category: SearchCategory::Tool,
-            };
-
-            let item_index = self.items.len();
-            self.items.push(item);
-
-            // Index by name tokens
-            for token in self.tokenize(&tool.name) {
-                self.name_index
-                    .entry(token.to_lowercase())
-                    .or_default()
-                    .push(item_index);
-            }
-
-            // Index by keywords
-            for keyword in keywords {
-                self.keyword_index
-                    .entry(keyword.to_lowercase())
-                    .or_default()
-                    .push(item_index);
+        for tool in tools {
+            let mut params = Vec::new();
+            if let Some(schema) = &tool.parameters {
+                schema_params(schema, &mut params, 0);
             }
+            self.add_item(
+                SearchableItem {
+                    name: tool.name.clone(),
+                    description: tool.description.clone().unwrap_or_default(),
+                    category: SearchCategory::Tool,
+                    params,
+                    mime_type: None,
+                    server: tool.server,
+                },
+                tool.title.as_deref(),
+            );
         }
     }
 
     /// Index resources for searching
     pub fn index_resources(&mut self, resources: &[crate::tui::ResourceInfo]) {
-        let _tool_count = self
-            .items
-            .iter()
-            .filter(|i| i.category == SearchCategory::Tool)
-            .count();
-
-        for resource in resources.iter() {
-            let keywords = self.extract_keywords(
-                &resource.uri,
-                &resource.description.clone().unwrap_or_default(),
+        for resource in resources {
+            self.add_item(
+                SearchableItem {
+                    name: resource.uri.clone(),
+                    description: resource.description.clone().unwrap_or_default(),
+                    category: SearchCategory::Resource,
+                    params: Vec::new(),
+                    mime_type: resource.mime_type.clone(),
+                    server: resource.server,
+                },
+                resource.name.as_deref(),
             );
-            let item = SearchableItem {
-                name: resource.uri.clone(),
-                description: resource.description.clone().unwrap_or_default(),
-                category: SearchCategory::Resource,
-            };
-
-            let item_index = self.items.len();
-            self.items.push(item);
-
-            // Index by name tokens
-            for token in self.tokenize(&resource.uri) {
-                self.name_index
-                    .entry(token.to_lowercase())
-                    .or_default()
-                    .push(item_index);
-            }
-
-            // Index by keywords
-            for keyword in keywords {
-                self.keyword_index
-                    .entry(keyword.to_lowercase())
-                    .or_default()
-                    .push(item_index);
-            }
         }
     }
 
     /// Index prompts for searching
     pub fn index_prompts(&mut self, prompts: &[crate::tui::PromptInfo]) {
-        let _existing_count = self.items.len();
-
-        for prompt in prompts.iter() {
-            let keywords = self.extract_keywords(
-                &prompt.name,
-                &prompt.description.clone().unwrap_or_default(),
+        for prompt in prompts {
+            let params = prompt
+                .arguments
+                .as_ref()
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|argument| {
+                    Some(ParamInfo {
+                        name: argument.get("name")?.as_str()?.to_string(),
+                        description: argument
+                            .get("description")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default()
+                            .to_string(),
+                    })
+                })
+                .collect();
+            self.add_item(
+                SearchableItem {
+                    name: prompt.name.clone(),
+                    description: prompt.description.clone().unwrap_or_default(),
+                    category: SearchCategory::Prompt,
+                    params,
+                    mime_type: None,
+                    server: prompt.server,
+                },
+                None,
             );
-            let item = SearchableItem {
-                name: prompt.name.clone(),
-                description: prompt.description.clone().unwrap_or_default(),
-                category: SearchCategory::Prompt,
-            };
-
-            let item_index = self.items.len();
-            self.items.push(item);
-
-            // Index by name tokens
-            for token in self.tokenize(&prompt.name) {
-                self.name_index
-                    .entry(token.to_lowercase())
-                    .or_default()
-                    .push(item_index);
-            }
-
-            // Index by keywords
-            for keyword in keywords {
-                self.keyword_index
-                    .entry(keyword.to_lowercase())
-                    .or_default()
-                    .push(item_index);
-            }
         }
     }
 
-    /// Search for items matching the query
-    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
-        if query.trim().is_empty() {
-            return Vec::new();
+    /// Index an item; `alias` (a title or display name) counts as name text
+    fn add_item(&mut self, item: SearchableItem, alias: Option<&str>) {
+        let item_index = self.items.len();
+        let mut name = self.tokenize(&item.name);
+        if let Some(alias) = alias {
+            name.extend(self.tokenize(alias));
         }
+        let description = self.content_terms(&item.description);
+        let params = item
+            .params
+            .iter()
+            .flat_map(|param| self.tokenize(&param.name))
+            .collect();
+        let param_descriptions = item
+            .params
+            .iter()
+            .flat_map(|param| self.content_terms(&param.description))
+            .collect();
 
-        let query_lower = query.to_lowercase();
-        let query_tokens = self.tokenize(&query_lower);
-
-        let mut scores: HashMap<usize, (f32, String)> = HashMap::new();
+        let mut lengths = [0; Field::COUNT];
+        let mut seen = HashSet::new();
+        for (field, terms) in [
+            (Field::Name, name),
+            (Field::Description, description),
+            (Field::Param, params),
+            (Field::ParamDescription, param_descriptions),
+        ] {
+            lengths[field.index()] = terms.len() as u32;
+            self.total_field_lengths[field.index()] += terms.len() as u64;
 
-        // Exact name matches (highest priority)
-        for (index, item) in self.items.iter().enumerate() {
-            if item.name.to_lowercase().contains(&query_lower) {
-                let score = if item.name.to_lowercase() == query_lower {
-                    100.0 // Perfect match
-                } else if item.name.to_lowercase().starts_with(&query_lower) {
-                    90.0 // Prefix match
-                } else {
-                    80.0 // Contains match
-                };
-                scores.insert(index, (score, "Name match".to_string()));
+            let mut frequencies: BTreeMap<String, u32> = BTreeMap::new();
+            for term in terms {
+                *frequencies.entry(term).or_default() += 1;
+            }
+            for (term, frequency) in frequencies {
+                let term_id = self.term_id(term);
+                self.postings[term_id].push(Posting {
+                    item: item_index,
+                    field,
+                    frequency,
+                });
+                if seen.insert(term_id) {
+                    self.document_frequency[term_id] += 1;
+                }
             }
         }
+        self.field_lengths.push(lengths);
+        self.items.push(item);
+    }
 
-        // Description matches
-        for (index, item) in self.items.iter().enumerate() {
-            if !scores.contains_key(&index)
-                && item.description.to_lowercase().contains(&query_lower)
-            {
-                scores.insert(index, (70.0, "Description match".to_string()));
-            }
+    /// ID of a term, adding it to the vocabulary if new
+    fn term_id(&mut self, term: String) -> usize {
+        if let Some(&id) = self.term_ids.get(&term) {
+            return id;
         }
+        let id = self.terms.len();
+        for trigram in trigrams(&term) {
+            self.trigram_index.entry(trigram).or_default().push(id);
+        }
+        self.term_ids.insert(term.clone(), id);
+        self.terms.push(term);
+        self.postings.push(Vec::new());
+        self.document_frequency.push(0);
+        id
+    }
 
-        // Token-based fuzzy matching
-        for token in &query_tokens {
-            // Direct token matches in name index
-            if let Some(indices) = self.name_index.get(token) {
-                for &index in indices {
-                    scores
-                        .entry(index)
-                        .or_insert_with(|| (60.0, "Token match".to_string()));
-                }
-            }
+    /// Search for items matching the query
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let query = Query::parse(query);
+        let query_terms: Vec<String> = query
+            .words
+            .iter()
+            .flat_map(|word| self.tokenize(word))
+            .collect();
+        if query_terms.is_empty() && query.filters.is_empty() {
+            return Vec::new();
+        }
 
-            // Keyword matches
-            if let Some(indices) = self.keyword_index.get(token) {
-                for &index in indices {
-                    scores
-                        .entry(index)
-                        .or_insert_with(|| (50.0, "Keyword match".to_string()));
+        let mut filter_cache: HashMap<usize, bool> = HashMap::new();
+        let mut passes = |index: usize| {
+            *filter_cache
+                .entry(index)
+                .or_insert_with(|| self.matches_filters(&self.items[index], &query.filters))
+        };
+
+        let mut candidates: HashMap<usize, Candidate> = HashMap::new();
+        if query_terms.is_empty() {
+            for index in 0..self.items.len() {
+                if passes(index) {
+                    candidates.insert(index, Candidate::default());
                 }
             }
+        }
 
-            // Fuzzy token matching
-            for (key, indices) in &self.name_index {
-                if self.fuzzy_match(token, key) > 0.7 {
-                    for &index in indices {
-                        scores.entry(index).or_insert_with(|| {
-                            let similarity = self.fuzzy_match(token, key);
-                            (similarity * 40.0, "Fuzzy match".to_string())
-                        });
+        for (position, query_term) in query_terms.iter().enumerate() {
+            for (term_id, weight, kind) in self.expand(query_term) {
+                let idf = self.idf(term_id);
+                // Sum the fields of each item, then keep the best expansion
+                let mut per_item: HashMap<usize, (f32, Field)> = HashMap::new();
+                for posting in &self.postings[term_id] {
+                    if !passes(posting.item) {
+                        continue;
+                    }
+                    let contribution = weight * posting.field.weight() * idf * self.bm25(posting);
+                    let entry = per_item.entry(posting.item).or_insert((0.0, posting.field));
+                    if contribution > entry.0 {
+                        entry.1 = posting.field;
+                    }
+                    entry.0 += contribution;
+                }
+                for (item, (score, field)) in per_item {
+                    let candidate = candidates.entry(item).or_default();
+                    candidate.matched_terms.insert(term_id);
+                    let best = candidate
+                        .term_scores
+                        .entry(position)
+                        .or_insert((0.0, field, kind, term_id));
+                    if score > best.0 {
+                        *best = (score, field, kind, term_id);
                     }
                 }
             }
         }
 
-        // Convert to results and sort by score
-        let mut results: Vec<SearchResult> = scores
+        let phrase = query.words.join(" ");
+        let mut ranked: Vec<(f32, Option<&str>, usize, Candidate)> = candidates
             .into_iter()
-            .map(|(index, (score, reason))| SearchResult {
-                index,
-                score,
-                match_reason: reason,
+            .map(|(index, candidate)| {
+                let mut score = if query_terms.is_empty() {
+                    1.0
+                } else {
+                    // Prefer items matching more of the query
+                    let matched = candidate.term_scores.len() as f32;
+                    let score: f32 = candidate.term_scores.values().map(|best| best.0).sum();
+                    score * matched / query_terms.len() as f32
+                };
+                let name = self.items[index].name.to_lowercase();
+                let mut name_match = None;
+                if !phrase.is_empty() {
+                    if name == phrase {
+                        score *= 3.0;
+                        name_match = Some("Exact name match");
+                    } else if name.starts_with(&phrase) {
+                        score *= 1.5;
+                        name_match = Some("Name prefix match");
+                    }
+                }
+                (score, name_match, index, candidate)
             })
             .collect();
 
-        results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
+        ranked.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| self.items[a.2].name.cmp(&self.items[b.2].name))
         });
-        results.truncate(limit);
+        ranked.truncate(limit);
 
-        results
+        // Reasons and highlights only for the results shown
+        let best = ranked.first().map_or(1.0, |ranked| ranked.0);
+        ranked
+            .into_iter()
+            .map(|(score, name_match, index, candidate)| SearchResult {
+                index,
+                score: score / best * 100.0,
+                match_reason: match name_match {
+                    Some(reason) => reason.to_string(),
+                    None if query_terms.is_empty() => "Filter match".to_string(),
+                    None => self.match_reason(&candidate),
+                },
+                highlights: self.highlights(
+                    &self.items[index],
+                    &candidate.matched_terms,
+                    &query.filters,
+                ),
+            })
+            .collect()
+    }
+
+    /// Vocabulary terms a query term matches, with a weight for how well
+    fn expand(&self, query_term: &str) -> Vec<(usize, f32, MatchKind)> {
+        let mut expansions = Vec::new();
+        if let Some(&id) = self.term_ids.get(query_term) {
+            expansions.push((id, 1.0, MatchKind::Exact));
+        }
+
+        let query_len = query_term.chars().count();
+        if query_len >= 2 {
+            expansions.extend(
+                self.term_ids
+                    .range::<str, _>((
+                        std::ops::Bound::Excluded(query_term),
+                        std::ops::Bound::Unbounded,
+                    ))
+                    .take_while(|(term, _)| term.starts_with(query_term))
+                    .take(MAX_PREFIX_EXPANSIONS)
+                    .map(|(term, &id)| {
+                        let coverage = query_len as f32 / term.chars().count() as f32;
+                        (id, 0.5 + 0.3 * coverage, MatchKind::Prefix)
+                    }),
+            );
+        }
+
+        if query_len >= 3 {
+            let query_trigrams = trigrams(query_term);
+            let mut shared: HashMap<usize, usize> = HashMap::new();
+            for trigram in &query_trigrams {
+                for &id in self.trigram_index.get(trigram).into_iter().flatten() {
+                    *shared.entry(id).or_default() += 1;
+                }
+            }
+
+            let mut fuzzy: Vec<(usize, f32, MatchKind)> = shared
+                .into_iter()
+                .filter(|&(_, count)| count * 2 >= query_trigrams.len())
+                .filter_map(|(id, _)| {
+                    let term = &self.terms[id];
+                    if term == query_term || term.starts_with(query_term) {
+                        None
+                    } else if term.contains(query_term) {
+                        let coverage = query_len as f32 / term.chars().count() as f32;
+                        Some((id, 0.3 + 0.3 * coverage, MatchKind::Substring))
+                    } else {
+                        let similarity = self.fuzzy_match(query_term, term);
+                        (similarity >= FUZZY_THRESHOLD).then_some((
+                            id,
+                            0.5 * similarity,
+                            MatchKind::Fuzzy,
+                        ))
+                    }
+                })
+                .collect();
+            fuzzy.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            fuzzy.truncate(MAX_FUZZY_EXPANSIONS);
+            expansions.extend(fuzzy);
+        }
+        expansions
+    }
+
+    /// Inverse document frequency of a term
+    fn idf(&self, term_id: usize) -> f32 {
+        let items = self.items.len() as f32;
+        let frequency = self.document_frequency[term_id] as f32;
+        (1.0 + (items - frequency + 0.5) / (frequency + 0.5)).ln()
+    }
+
+    /// BM25 term frequency component of a posting
+    fn bm25(&self, posting: &Posting) -> f32 {
+        let field = posting.field.index();
+        let length = self.field_lengths[posting.item][field] as f32;
+        let average = self.total_field_lengths[field] as f32 / self.items.len().max(1) as f32;
+        let frequency = posting.frequency as f32;
+        frequency * (BM25_K1 + 1.0)
+            / (frequency + BM25_K1 * (1.0 - BM25_B + BM25_B * length / average.max(1.0)))
+    }
+
+    /// Describe the strongest match of a candidate
+    fn match_reason(&self, candidate: &Candidate) -> String {
+        let Some(&(_, field, kind, term_id)) = candidate
+            .term_scores
+            .values()
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        else {
+            return String::new();
+        };
+        match kind {
+            MatchKind::Fuzzy => format!(
+                "{} {} ({})",
+                field.label(),
+                kind.label(),
+                self.terms[term_id]
+            ),
+            _ => format!("{} {}", field.label(), kind.label()),
+        }
+    }
+
+    fn matches_filters(&self, item: &SearchableItem, filters: &[QueryFilter]) -> bool {
+        filters.iter().all(|filter| match filter {
+            QueryFilter::Category(categories) => categories.contains(&item.category),
+            QueryFilter::Param(value) => item
+                .params
+                .iter()
+                .any(|param| param.name.to_lowercase().contains(value.as_str())),
+            QueryFilter::Mime(pattern) => item
+                .mime_type
+                .as_deref()
+                .is_some_and(|mime_type| wildcard_match(pattern, &mime_type.to_lowercase())),
+            QueryFilter::Name(value) => item.name.to_lowercase().contains(value.as_str()),
+            QueryFilter::Server(value) => self
+                .server_names
+                .get(item.server)
+                .is_some_and(|name| name.to_lowercase().contains(value.as_str())),
+        })
+    }
+
+    /// Spans of an item matched by query terms and filters
+    fn highlights(
+        &self,
+        item: &SearchableItem,
+        matched_terms: &HashSet<usize>,
+        filters: &[QueryFilter],
+    ) -> Highlights {
+        let matched = |text: &str| -> Vec<Range<usize>> {
+            term_spans(text)
+                .into_iter()
+                .filter(|(_, term)| {
+                    self.term_ids
+                        .get(term)
+                        .is_some_and(|id| matched_terms.contains(id))
+                })
+                .map(|(span, _)| span)
+                .collect()
+        };
+
+        let mut name = matched(&item.name);
+        let mut params: Vec<String> = item
+            .params
+            .iter()
+            .filter(|param| !matched(&param.name).is_empty())
+            .map(|param| param.name.clone())
+            .collect();
+        for filter in filters {
+            match filter {
+                QueryFilter::Name(value) => name.extend(find_ignore_case(&item.name, value)),
+                QueryFilter::Param(value) => params.extend(
+                    item.params
+                        .iter()
+                        .filter(|param| param.name.to_lowercase().contains(value.as_str()))
+                        .map(|param| param.name.clone()),
+                ),
+                _ => {}
+            }
+        }
+        params.sort();
+        params.dedup();
+
+        Highlights {
+            name: merge_ranges(name),
+            description: matched(&item.description),
+            params,
+        }
     }
 
     /// Get item by index
@@ -251,40 +697,17 @@ impl SearchEngine {
         self.items.len()
     }
 
-    /// Extract keywords from name and description
-    fn extract_keywords(&self, name: &str, description: &str) -> Vec<String> {
-        let mut keywords = Vec::new();
-
-        // Extract from name (split by common separators)
-        let name_tokens = self.tokenize(name);
-        keywords.extend(name_tokens.into_iter().map(|s| s.to_lowercase()));
-
-        // Extract important words from description
-        let desc_words: Vec<String> = description
-            .split_whitespace()
-            .filter(|word| word.len() > 3) // Only meaningful words
-            .filter(|word| !self.is_stopword(word))
-            .take(10) // Limit to first 10 meaningful words
-            .map(|word| {
-                word.to_lowercase()
-                    .trim_matches(|c: char| !c.is_alphanumeric())
-                    .to_string()
-            })
-            .filter(|word| !word.is_empty())
-            .collect();
-
-        keywords.extend(desc_words);
-        keywords.sort();
-        keywords.dedup();
-        keywords
+    /// Terms of free text, without stopwords
+    fn content_terms(&self, text: &str) -> Vec<String> {
+        self.tokenize(text)
+            .into_iter()
+            .filter(|term| !self.is_stopword(term))
+            .collect()
     }
 
     /// Tokenize text into searchable tokens
     fn tokenize(&self, text: &str) -> Vec<String> {
-        text.split(|c: char| !c.is_alphanumeric())
-            .filter(|token| !token.is_empty() && token.len() > 1)
-            .map(|token| token.to_string())
-            .collect()
+        term_spans(text).into_iter().map(|(_, term)| term).collect()
     }
 
     /// Simple fuzzy string matching using Jaro similarity
@@ -400,9 +823,243 @@ impl Default for SearchEngine {
     }
 }
 
+/// Scores of an item collected during a search
+#[derive(Debug, Default)]
+struct Candidate {
+    /// Best score per query term position: score, field, match kind, term ID
+    term_scores: HashMap<usize, (f32, Field, MatchKind, usize)>,
+    /// Vocabulary terms that matched, for highlighting
+    matched_terms: HashSet<usize>,
+}
+
+/// Lowercased terms of a text with their byte ranges.
+///
+/// Text splits at non-alphanumeric characters and at camelCase boundaries;
+/// a camelCase word is also kept whole, so `listFiles` yields `list`,
+/// `files` and `listfiles`. Single characters are dropped.
+fn term_spans(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut spans = Vec::new();
+    let push = |spans: &mut Vec<(Range<usize>, String)>, range: Range<usize>| {
+        let word = &text[range.clone()];
+        if word.chars().count() > 1 {
+            spans.push((range, word.to_lowercase()));
+        }
+    };
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].1.is_alphanumeric() {
+            i += 1;
+            continue;
+        }
+        let word_start = i;
+        while i < chars.len() && chars[i].1.is_alphanumeric() {
+            i += 1;
+        }
+        let end_of = |index: usize| chars.get(index).map_or(text.len(), |&(offset, _)| offset);
+
+        // camelCase parts: a break before an uppercase letter that follows a
+        // lowercase one, or that starts a word after an acronym (`HTTPServer`)
+        let mut part_start = word_start;
+        let mut parts = 0;
+        for j in word_start + 1..i {
+            let (previous, current) = (chars[j - 1].1, chars[j].1);
+            let next_lower = chars.get(j + 1).is_some_and(|&(_, c)| c.is_lowercase()) && j + 1 < i;
+            if current.is_uppercase()
+                && (previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_lower))
+            {
+                push(&mut spans, chars[part_start].0..chars[j].0);
+                part_start = j;
+                parts += 1;
+            }
+        }
+        push(&mut spans, chars[part_start].0..end_of(i));
+        if parts > 0 {
+            push(&mut spans, chars[word_start].0..end_of(i));
+        }
+    }
+    spans
+}
+
+/// Trigrams of a term padded with `$` at both ends
+fn trigrams(term: &str) -> Vec<String> {
+    let padded: Vec<char> = std::iter::once('$')
+        .chain(term.chars())
+        .chain(std::iter::once('$'))
+        .collect();
+    let mut trigrams: Vec<String> = padded.windows(3).map(|w| w.iter().collect()).collect();
+    trigrams.sort();
+    trigrams.dedup();
+    trigrams
+}
+
+/// Parameters of a JSON schema: object properties, including those of
+/// nested objects and array items
+fn schema_params(schema: &Value, params: &mut Vec<ParamInfo>, depth: usize) {
+    const MAX_DEPTH: usize = 4;
+    if depth > MAX_DEPTH {
+        return;
+    }
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (name, property) in properties {
+            params.push(ParamInfo {
+                name: name.clone(),
+                description: property
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+            schema_params(property, params, depth + 1);
+        }
+    }
+    if let Some(items) = schema.get("items") {
+        schema_params(items, params, depth + 1);
+    }
+}
+
+/// Match text against a pattern where `*` matches any run of characters
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(offset) => rest = &rest[offset + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Byte ranges of case-insensitive occurrences of a lowercase needle
+fn find_ignore_case(text: &str, needle: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    for (start, _) in text.char_indices() {
+        let mut matched = text[start..].char_indices().flat_map(|(offset, c)| {
+            c.to_lowercase()
+                .map(move |lower| (offset + c.len_utf8(), lower))
+        });
+        let mut end = start;
+        let found = needle.chars().all(|expected| match matched.next() {
+            Some((offset, lower)) if lower == expected => {
+                end = start + offset;
+                true
+            }
+            _ => false,
+        });
+        if found && !needle.is_empty() {
+            ranges.push(start..end);
+        }
+    }
+    ranges
+}
+
+/// Sort ranges and merge overlapping ones
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| (range.start, range.end));
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tui::{PromptInfo, ResourceInfo, ToolInfo};
+    use serde_json::json;
+
+    fn tool(name: &str, description: &str, schema: Value) -> ToolInfo {
+        ToolInfo {
+            name: name.to_string(),
+            full_name: name.to_string(),
+            description: Some(description.to_string()),
+            parameters: Some(schema),
+            title: None,
+            output_schema: None,
+            annotations: None,
+            server: 0,
+        }
+    }
+
+    fn engine() -> SearchEngine {
+        let mut engine = SearchEngine::new();
+        engine.set_server_names(vec!["local".to_string(), "remote".to_string()]);
+        engine.index_tools(&[
+            tool(
+                "read_file",
+                "Read the contents of a file",
+                json!({"type": "object", "properties": {
+                    "path": {"type": "string", "description": "File to read"}
+                }}),
+            ),
+            ToolInfo {
+                server: 1,
+                ..tool(
+                    "github_create_issue",
+                    "Open an issue in a GitHub repository",
+                    json!({"type": "object", "properties": {
+                        "repo": {"type": "string"},
+                        "labels": {"type": "array", "items": {"type": "object", "properties": {
+                            "labelName": {"type": "string", "description": "Existing label"}
+                        }}}
+                    }}),
+                )
+            },
+            tool(
+                "search",
+                "Search documents",
+                json!({"type": "object", "properties": {
+                    "query": {"type": "string", "description": "Text to look for in the file path"}
+                }}),
+            ),
+        ]);
+        engine.index_resources(&[
+            ResourceInfo {
+                uri: "file:///logo.png".to_string(),
+                name: Some("Logo".to_string()),
+                description: Some("Company logo".to_string()),
+                mime_type: Some("image/png".to_string()),
+                server: 1,
+            },
+            ResourceInfo {
+                uri: "file:///readme.md".to_string(),
+                name: Some("Readme".to_string()),
+                description: None,
+                mime_type: Some("text/markdown".to_string()),
+                server: 0,
+            },
+        ]);
+        engine.index_prompts(&[PromptInfo {
+            name: "summarize".to_string(),
+            description: Some("Summarize a file".to_string()),
+            arguments: Some(json!([{"name": "path", "description": "File to summarize"}])),
+            server: 0,
+        }]);
+        engine
+    }
+
+    fn names(engine: &SearchEngine, query: &str) -> Vec<String> {
+        engine
+            .search(query, 10)
+            .into_iter()
+            .map(|result| engine.get_item(result.index).unwrap().name.clone())
+            .collect()
+    }
 
     #[test]
     fn test_fuzzy_match() {
@@ -429,5 +1086,104 @@ mod tests {
         assert!(tokens.contains(&"create".to_string()));
         assert!(tokens.contains(&"commit".to_string()));
         assert!(tokens.contains(&"status".to_string()));
+
+        assert_eq!(
+            engine.tokenize("getHTTPResponse v2"),
+            ["get", "http", "response", "gethttpresponse", "v2"]
+        );
+    }
+
+    #[test]
+    fn test_ranking_prefers_names_and_tolerates_typos() {
+        let engine = engine();
+
+        // A name match outranks description and parameter matches
+        assert_eq!(names(&engine, "file")[0], "read_file");
+        assert_eq!(names(&engine, "read_file")[0], "read_file");
+        // Prefixes, substrings and misspellings
+        assert_eq!(names(&engine, "git")[0], "github_create_issue");
+        assert_eq!(names(&engine, "hub")[0], "github_create_issue");
+        assert_eq!(names(&engine, "gitub isue")[0], "github_create_issue");
+        // Parameter names and descriptions, nested ones included
+        assert_eq!(names(&engine, "labelname"), ["github_create_issue"]);
+        assert_eq!(names(&engine, "query"), ["search"]);
+
+        let results = engine.search("read file", 10);
+        assert_eq!(results[0].score, 100.0);
+        assert!(results
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+        assert!(engine.search("zzzz", 10).is_empty());
+    }
+
+    #[test]
+    fn test_field_filters() {
+        let engine = engine();
+
+        assert_eq!(
+            Query::parse("TYPE:res mime:image/* logo file:///x"),
+            Query {
+                words: vec!["logo".to_string(), "file:///x".to_string()],
+                filters: vec![
+                    QueryFilter::Category(vec![SearchCategory::Resource]),
+                    QueryFilter::Mime("image/*".to_string()),
+                ],
+            }
+        );
+
+        assert_eq!(names(&engine, "param:path"), ["read_file", "summarize"]);
+        assert_eq!(names(&engine, "param:path type:prompt"), ["summarize"]);
+        assert_eq!(names(&engine, "file type:tools"), ["read_file", "search"]);
+        assert_eq!(names(&engine, "mime:image/*"), ["file:///logo.png"]);
+        assert_eq!(names(&engine, "mime:*/markdown"), ["file:///readme.md"]);
+        assert_eq!(names(&engine, "name:issue"), ["github_create_issue"]);
+        assert!(names(&engine, "type:nothing").is_empty());
+        let mut remote = names(&engine, "server:REM");
+        remote.sort();
+        assert_eq!(remote, ["file:///logo.png", "github_create_issue"]);
+        assert_eq!(
+            names(&engine, "file server:local type:res"),
+            ["file:///readme.md"]
+        );
+        // A filter still being typed does not hide everything
+        assert_eq!(names(&engine, "summarize param:")[0], "summarize");
+    }
+
+    #[test]
+    fn test_highlights() {
+        let engine = engine();
+
+        let result = &engine.search("github issue", 10)[0];
+        let item = engine.get_item(result.index).unwrap();
+        let highlighted: Vec<&str> = result
+            .highlights
+            .name
+            .iter()
+            .map(|range| &item.name[range.clone()])
+            .collect();
+        assert_eq!(highlighted, ["github", "issue"]);
+        let highlighted: Vec<&str> = result
+            .highlights
+            .description
+            .iter()
+            .map(|range| &item.description[range.clone()])
+            .collect();
+        assert_eq!(highlighted, ["issue", "GitHub"]);
+
+        let result = &engine.search("name:FILE param:pa", 10)[0];
+        let item = engine.get_item(result.index).unwrap();
+        assert_eq!(&item.name[result.highlights.name[0].clone()], "file");
+        assert_eq!(result.highlights.params, ["path"]);
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("image/*", "image/png"));
+        assert!(wildcard_match("*/json", "application/json"));
+        assert!(wildcard_match("text/plain", "text/plain"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("a*b*c", "abc"));
+        assert!(!wildcard_match("image/*", "text/png"));
+        assert!(!wildcard_match("a*ab", "ab"));
     }
 }
@@ -0,0 +1,186 @@
+//! Color themes for the TUI
+//!
+//! A theme maps the roles the TUI draws with (text, accent, errors, ...) to
+//! terminal colors. `tui.color_scheme` names a built-in theme (`default`,
+//! `monochrome`, `high-contrast`, `light`), a theme file in
+//! `~/.mcp-probe/themes/<name>.toml`, or the path of a theme file.
+//!
+//! Theme files are flat TOML tables of role = color; roles left out keep the
+//! color of the `default` theme:
+//!
+//! ```toml
+//! accent = "light-blue"
+//! error = "#d70000"
+//! muted = "242"
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use ratatui::style::{Color, Modifier, Style};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Built-in themes, by name
+const BUILTIN_THEMES: &[(&str, &str)] = &[
+    ("default", include_str!("../themes/default.toml")),
+    ("monochrome", include_str!("../themes/monochrome.toml")),
+    (
+        "high-contrast",
+        include_str!("../themes/high-contrast.toml"),
+    ),
+    ("light", include_str!("../themes/light.toml")),
+];
+
+/// Colors the TUI draws with, by role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Regular text
+    pub text: Color,
+    /// Secondary text such as counts and empty-list notes
+    pub muted: Color,
+    /// Focused borders, titles and highlights
+    pub accent: Color,
+    /// Headings, tools and scroll indicators
+    pub info: Color,
+    /// Successful results, parameters and resources
+    pub success: Color,
+    /// Errors and failures
+    pub error: Color,
+    /// Prompts and capability badges
+    pub special: Color,
+    /// Resource URIs and notifications
+    pub link: Color,
+    /// Text on highlighted rows and the status bar background
+    pub background: Color,
+    /// Header bar text
+    pub header_fg: Color,
+    /// Header bar background
+    pub header_bg: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::DarkGray,
+            accent: Color::Yellow,
+            info: Color::Cyan,
+            success: Color::Green,
+            error: Color::Red,
+            special: Color::Magenta,
+            link: Color::Blue,
+            background: Color::Black,
+            header_fg: Color::White,
+            header_bg: Color::Blue,
+        }
+    }
+}
+
+impl Theme {
+    /// Names of the built-in themes
+    pub fn builtin_names() -> impl Iterator<Item = &'static str> {
+        BUILTIN_THEMES.iter().map(|(name, _)| *name)
+    }
+
+    /// Load a theme by built-in name, name of a file in `themes_dir`, or path
+    pub fn load(name: &str, themes_dir: &Path) -> Result<Self> {
+        if let Some((_, source)) = BUILTIN_THEMES.iter().find(|(builtin, _)| *builtin == name) {
+            return Self::parse(source);
+        }
+
+        let path = if name.ends_with(".toml") || name.contains(std::path::MAIN_SEPARATOR) {
+            Path::new(name).to_path_buf()
+        } else {
+            themes_dir.join(format!("{}.toml", name))
+        };
+        if !path.exists() {
+            return Err(anyhow!(
+                "Unknown theme '{}' (built-in themes: {}; no file {})",
+                name,
+                Self::builtin_names().collect::<Vec<_>>().join(", "),
+                path.display()
+            ));
+        }
+        let source = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read theme {}", path.display()))?;
+        Self::parse(&source).with_context(|| format!("Invalid theme {}", path.display()))
+    }
+
+    /// Parse a theme file, starting from the default colors
+    pub fn parse(source: &str) -> Result<Self> {
+        let roles: BTreeMap<String, String> = toml::from_str(source)?;
+        let mut theme = Self::default();
+        for (role, value) in roles {
+            let color = value
+                .parse::<Color>()
+                .map_err(|_| anyhow!("Invalid color '{}' for {}", value, role))?;
+            let slot = match role.as_str() {
+                "text" => &mut theme.text,
+                "muted" => &mut theme.muted,
+                "accent" => &mut theme.accent,
+                "info" => &mut theme.info,
+                "success" => &mut theme.success,
+                "error" => &mut theme.error,
+                "special" => &mut theme.special,
+                "link" => &mut theme.link,
+                "background" => &mut theme.background,
+                "header_fg" => &mut theme.header_fg,
+                "header_bg" => &mut theme.header_bg,
+                _ => return Err(anyhow!("Unknown theme color '{}'", role)),
+            };
+            *slot = color;
+        }
+        Ok(theme)
+    }
+
+    /// Style of a selected row highlighted with `color`
+    ///
+    /// Without colors (`reset`) the row is shown in reverse video instead.
+    pub fn selected(&self, color: Color) -> Style {
+        if color == Color::Reset {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.background).bg(color)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_and_file_themes() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in Theme::builtin_names() {
+            assert!(Theme::load(name, dir.path()).is_ok(), "{}", name);
+        }
+        assert_eq!(
+            Theme::load("default", dir.path()).unwrap(),
+            Theme::default()
+        );
+        let light = Theme::load("light", dir.path()).unwrap();
+        assert_eq!(light.text, Color::Black);
+        assert_eq!(light.accent, Color::Indexed(130));
+        assert_eq!(
+            Theme::load("monochrome", dir.path())
+                .unwrap()
+                .selected(Color::Reset),
+            Style::default().add_modifier(Modifier::REVERSED)
+        );
+
+        // Partial theme files keep the default colors for missing roles
+        std::fs::write(
+            dir.path().join("mine.toml"),
+            "accent = \"light-blue\"\nerror = \"#d70000\"\n",
+        )
+        .unwrap();
+        let mine = Theme::load("mine", dir.path()).unwrap();
+        assert_eq!(mine.accent, Color::LightBlue);
+        assert_eq!(mine.error, Color::Rgb(0xd7, 0, 0));
+        assert_eq!(mine.text, Color::White);
+
+        assert!(Theme::load("missing", dir.path()).is_err());
+        assert!(Theme::parse("accent = \"not-a-color\"").is_err());
+        assert!(Theme::parse("border = \"red\"").is_err());
+    }
+}
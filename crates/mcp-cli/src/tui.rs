@@ -5,7 +5,9 @@
 
 use anyhow::Result;
 use mcp_probe_core::{
-    client::McpClient,
+    changes::{CapabilityChange, CapabilityChangeReceiver, ListDiff, ListKind},
+    client::{ClientConfig, ClientState, DefaultNotificationHandler, McpClient, RequestHandle},
+    events::{ClientEventKind, ClientEventReceiver},
     messages::{
         prompts::{
             GetPromptRequest, GetPromptResponse, ListPromptsRequest, ListPromptsResponse, Prompt,
@@ -14,21 +16,32 @@ use mcp_probe_core::{
             ListResourcesRequest, ListResourcesResponse, ReadResourceRequest, ReadResourceResponse,
             Resource,
         },
-        tools::{CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse},
-        Implementation, JsonRpcRequest, JsonRpcResponse,
+        tools::{
+            CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse, Tool,
+            ToolAnnotations,
+        },
+        ElicitRequest, ElicitResult, Implementation, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
+        RequestId, ELICITATION_METHOD,
+    },
+    secrets,
+    supervision::SupervisionPolicy,
+    transport::{
+        ProcessLog, RawTrafficReceiver, ServerRequest, ServerRequestReceiver, SharedRawSender,
+        TransportConfig,
     },
-    transport::TransportConfig,
     McpResult,
 };
 use ratatui::crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{
         Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
@@ -38,25 +51,27 @@ use ratatui::{
 };
 use serde_json::Value;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     io,
+    path::PathBuf,
     time::{Duration, Instant},
 };
-use tui_textarea::{Input, TextArea};
-
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tui_textarea::{CursorMove, Input, TextArea};
+
+use crate::command::{self, Catalog, Command, CommandHistory};
+use crate::config::Config;
+use crate::inspector::{self, Filter, Record, Status};
+use crate::json_diff::{DiffNode, LineKind};
+use crate::keymap::{self, Action, Keymap};
 use crate::search::{SearchCategory, SearchEngine, SearchResult};
+use crate::theme::Theme;
+use crate::watch::{self, FileWatcher};
 
 /// Extension trait to add higher-level methods to McpClient
 trait McpClientExt {
     async fn list_resources(&mut self) -> McpResult<Vec<Resource>>;
     async fn list_prompts(&mut self) -> McpResult<Vec<Prompt>>;
-    async fn call_tool(&mut self, name: &str, arguments: Value) -> McpResult<CallToolResponse>;
-    async fn read_resource(&mut self, uri: &str) -> McpResult<ReadResourceResponse>;
-    async fn get_prompt(
-        &mut self,
-        name: &str,
-        arguments: Option<Value>,
-    ) -> McpResult<GetPromptResponse>;
 }
 
 impl McpClientExt for McpClient {
@@ -83,67 +98,43 @@ impl McpClientExt for McpClient {
             Ok(Vec::new())
         }
     }
+}
 
-    async fn call_tool(&mut self, name: &str, arguments: Value) -> McpResult<CallToolResponse> {
-        let request = CallToolRequest {
-            name: name.to_string(),
-            arguments: Some(arguments),
-        };
-        let response = self.send_request("tools/call", request).await?;
-
-        // Log the raw response for debugging
-        tracing::info!("=== RAW TOOL CALL RESPONSE ===");
+/// Parse a `tools/call` response, wrapping non-MCP results as text content
+fn parse_call_tool_response(response: JsonRpcResponse) -> McpResult<CallToolResponse> {
+    // Log the raw response for debugging
+    tracing::info!("=== RAW TOOL CALL RESPONSE ===");
+    tracing::info!(
+        "Full response: {}",
+        serde_json::to_string_pretty(&response)
+            .unwrap_or_else(|_| "Failed to serialize response".to_string())
+    );
+
+    if let Some(result) = response.result {
+        tracing::info!("=== RESULT FIELD ANALYSIS ===");
         tracing::info!(
-            "Full response: {}",
-            serde_json::to_string_pretty(&response)
-                .unwrap_or_else(|_| "Failed to serialize response".to_string())
+            "Result field: {}",
+            serde_json::to_string_pretty(&result)
+                .unwrap_or_else(|_| "Failed to serialize result".to_string())
         );
 
-        if let Some(result) = response.result {
-            tracing::info!("=== RESULT FIELD ANALYSIS ===");
-            tracing::info!(
-                "Result field: {}",
-                serde_json::to_string_pretty(&result)
-                    .unwrap_or_else(|_| "Failed to serialize result".to_string())
-            );
+        // Try to parse as standard MCP CallToolResponse first
+        if let Ok(call_response) = serde_json::from_value::<CallToolResponse>(result.clone()) {
+            tracing::info!("✅ Successfully parsed as standard MCP CallToolResponse");
+            tracing::info!("Content items: {}", call_response.content.len());
 
-            // Try to parse as standard MCP CallToolResponse first
-            if let Ok(call_response) = serde_json::from_value::<CallToolResponse>(result.clone()) {
-                tracing::info!("✅ Successfully parsed as standard MCP CallToolResponse");
-                tracing::info!("Content items: {}", call_response.content.len());
-
-                // Check if we got a "successful" parse but with empty content
-                // This happens when the tool returns non-MCP format like {"a": 30, "b": 40}
-                if call_response.content.is_empty() && result.is_object() {
-                    tracing::warn!("⚠️  Parsed as CallToolResponse but content is empty - tool likely returned non-MCP format");
-                    tracing::warn!("🔄 Falling back to custom parsing...");
-
-                    // Force custom parsing
-                    let result_text = serde_json::to_string_pretty(&result)
-                        .unwrap_or_else(|_| result.to_string());
-                    tracing::info!("📝 Custom parsed result text: {}", result_text);
-
-                    Ok(CallToolResponse {
-                        content: vec![mcp_probe_core::messages::tools::ToolResult::Text {
-                            text: result_text,
-                        }],
-                        is_error: Some(false),
-                    })
-                } else {
-                    Ok(call_response)
-                }
-            } else {
-                tracing::info!(
-                    "❌ Failed to parse as standard MCP CallToolResponse, using custom parsing"
-                );
-                // Handle non-standard tool responses (like add_numbers)
-                // Convert the raw result into a text-based ToolResult
-                let result_text = if result.is_object() || result.is_array() {
-                    serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string())
-                } else {
-                    result.to_string()
-                };
+            // Check if we got a "successful" parse but with empty content
+            // This happens when the tool returns non-MCP format like {"a": 30, "b": 40}
+            if call_response.content.is_empty()
+                && call_response.structured_content.is_none()
+                && result.is_object()
+            {
+                tracing::warn!("⚠️  Parsed as CallToolResponse but content is empty - tool likely returned non-MCP format");
+                tracing::warn!("🔄 Falling back to custom parsing...");
 
+                // Force custom parsing
+                let result_text =
+                    serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string());
                 tracing::info!("📝 Custom parsed result text: {}", result_text);
 
                 Ok(CallToolResponse {
@@ -151,56 +142,67 @@ impl McpClientExt for McpClient {
                         text: result_text,
                     }],
                     is_error: Some(false),
+                    ..Default::default()
                 })
+            } else {
+                Ok(call_response)
             }
         } else {
-            Err(mcp_probe_core::McpError::Protocol(
-                mcp_probe_core::error::ProtocolError::RequestFailed {
-                    reason: "No result in tool call response".to_string(),
-                },
-            ))
-        }
-    }
+            tracing::info!(
+                "❌ Failed to parse as standard MCP CallToolResponse, using custom parsing"
+            );
+            // Handle non-standard tool responses (like add_numbers)
+            // Convert the raw result into a text-based ToolResult
+            let result_text = if result.is_object() || result.is_array() {
+                serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string())
+            } else {
+                result.to_string()
+            };
 
-    async fn read_resource(&mut self, uri: &str) -> McpResult<ReadResourceResponse> {
-        let request = ReadResourceRequest {
-            uri: uri.to_string(),
-        };
-        let response = self.send_request("resources/read", request).await?;
+            tracing::info!("📝 Custom parsed result text: {}", result_text);
 
-        if let Some(result) = response.result {
-            let read_response: ReadResourceResponse = serde_json::from_value(result)?;
-            Ok(read_response)
-        } else {
-            Err(mcp_probe_core::McpError::Protocol(
-                mcp_probe_core::error::ProtocolError::RequestFailed {
-                    reason: "No result in resource read response".to_string(),
-                },
-            ))
+            Ok(CallToolResponse {
+                content: vec![mcp_probe_core::messages::tools::ToolResult::Text {
+                    text: result_text,
+                }],
+                is_error: Some(false),
+                ..Default::default()
+            })
         }
+    } else {
+        Err(mcp_probe_core::McpError::Protocol(
+            mcp_probe_core::error::ProtocolError::RequestFailed {
+                reason: "No result in tool call response".to_string(),
+            },
+        ))
     }
+}
 
-    async fn get_prompt(
-        &mut self,
-        name: &str,
-        arguments: Option<Value>,
-    ) -> McpResult<GetPromptResponse> {
-        let request = GetPromptRequest {
-            name: name.to_string(),
-            arguments,
-        };
-        let response = self.send_request("prompts/get", request).await?;
+/// Parse a `resources/read` response
+fn parse_read_resource_response(response: JsonRpcResponse) -> McpResult<ReadResourceResponse> {
+    if let Some(result) = response.result {
+        let read_response: ReadResourceResponse = serde_json::from_value(result)?;
+        Ok(read_response)
+    } else {
+        Err(mcp_probe_core::McpError::Protocol(
+            mcp_probe_core::error::ProtocolError::RequestFailed {
+                reason: "No result in resource read response".to_string(),
+            },
+        ))
+    }
+}
 
-        if let Some(result) = response.result {
-            let prompt_response: GetPromptResponse = serde_json::from_value(result)?;
-            Ok(prompt_response)
-        } else {
-            Err(mcp_probe_core::McpError::Protocol(
-                mcp_probe_core::error::ProtocolError::RequestFailed {
-                    reason: "No result in prompt get response".to_string(),
-                },
-            ))
-        }
+/// Parse a `prompts/get` response
+fn parse_get_prompt_response(response: JsonRpcResponse) -> McpResult<GetPromptResponse> {
+    if let Some(result) = response.result {
+        let prompt_response: GetPromptResponse = serde_json::from_value(result)?;
+        Ok(prompt_response)
+    } else {
+        Err(mcp_probe_core::McpError::Protocol(
+            mcp_probe_core::error::ProtocolError::RequestFailed {
+                reason: "No result in prompt get response".to_string(),
+            },
+        ))
     }
 }
 
@@ -221,12 +223,34 @@ pub struct DebuggerApp {
     /// Message history
     message_history: Vec<MessageEntry>,
 
+    /// Entries dropped from the front of the history; history index plus
+    /// this offset numbers a message for the whole session
+    history_offset: usize,
+
+    /// Session numbers of pinned messages, listed above the others
+    pinned_messages: BTreeSet<usize>,
+
+    /// Session numbers of bookmarked messages
+    bookmarked_messages: BTreeSet<usize>,
+
+    /// Filter applied to the message history
+    message_filter: Filter,
+
+    /// History indices shown in the message list: pinned, then filtered
+    inspector_rows: Vec<usize>,
+
+    /// Session numbers of the messages marked for diffing, in marking order
+    diff_marks: Vec<usize>,
+
     /// Current server capabilities
     capabilities: ServerCapabilities,
 
-    /// Environment variables for capability invocation
+    /// Variables substituted into terminal commands as `${NAME}`
     env_variables: HashMap<String, String>,
 
+    /// Commands entered in the interactive terminal
+    command_history: CommandHistory,
+
     /// MCP client instance
     client: Option<McpClient>,
 
@@ -247,6 +271,365 @@ pub struct DebuggerApp {
 
     /// Search engine for capabilities
     search_engine: SearchEngine,
+
+    /// Requests running on background tasks, oldest first
+    pending_requests: Vec<PendingRequest>,
+
+    /// Identifier for the next background request
+    next_request_id: u64,
+
+    /// Background tasks report finished requests here
+    completion_sender: mpsc::UnboundedSender<RequestCompletion>,
+
+    /// Finished background requests awaiting processing by the UI loop
+    completion_receiver: mpsc::UnboundedReceiver<RequestCompletion>,
+
+    /// Server-to-client requests (e.g. elicitation) awaiting handling
+    server_requests: Option<ServerRequestReceiver>,
+
+    /// Inbound messages no request claimed, shown in the message history
+    raw_traffic: Option<RawTrafficReceiver>,
+
+    /// Tool, resource and prompt lists re-fetched after `list_changed`
+    capability_changes: Option<CapabilityChangeReceiver>,
+
+    /// Summary of the latest capability change, shown in the status bar
+    capability_notice: Option<(String, Instant)>,
+
+    /// Captured stderr and exit status of a stdio server process
+    process_log: Option<ProcessLog>,
+
+    /// Delivers the process log once the client has spawned the server
+    process_log_receiver: Option<oneshot::Receiver<ProcessLog>>,
+
+    /// Whether the server process exit has already been reported
+    process_exit_reported: bool,
+
+    /// Non-JSON-RPC lines the server has written to stdout so far
+    stdout_pollution: u64,
+
+    /// Client lifecycle events; state transitions are shown in the message history
+    client_events: Option<ClientEventReceiver>,
+
+    /// Latency of the latest response, shown in the status bar
+    last_latency: Option<Duration>,
+
+    /// Returns the client from a background reconnect or restart
+    reconnect_receiver: Option<oneshot::Receiver<Reconnection>>,
+
+    /// Whether automatic reconnection gave up on the lost server
+    reconnect_failed: bool,
+
+    /// Files and directories whose changes restart the server (`--watch`)
+    watch_paths: Vec<PathBuf>,
+
+    /// Changed files reported by the watcher task
+    file_changes: Option<mpsc::UnboundedReceiver<Vec<PathBuf>>>,
+
+    /// Changed files waiting for the client to be free for a restart
+    pending_restart: Vec<PathBuf>,
+
+    /// Capability lists fetched by the last restart, compared with the next
+    watch_lists: CapabilityLists,
+
+    /// Name of the first server, shown when the session has several
+    server_name: String,
+
+    /// Further servers of a multi-server session (`--with`), in order
+    peers: Vec<PeerServer>,
+
+    /// Tool names offered by more than one server, with the servers offering each
+    tool_collisions: BTreeMap<String, Vec<String>>,
+
+    /// Resolved CLI configuration (TUI and session settings)
+    config: Config,
+
+    /// Key bindings from `tui.keymap` and `tui.key_bindings`
+    keymap: Keymap,
+
+    /// Colors from `tui.color_scheme`
+    theme: Theme,
+
+    /// Explicit session file from `--save-session`
+    session_file: Option<PathBuf>,
+
+    /// Where the session was last saved
+    saved_session: Option<PathBuf>,
+}
+
+/// A request issued from the UI and run on a background task
+#[derive(Debug, Clone)]
+enum BackgroundCall {
+    Tool {
+        name: String,
+        params: Value,
+    },
+    Resource {
+        uri: String,
+    },
+    Prompt {
+        name: String,
+        arguments: Value,
+    },
+    Raw {
+        method: String,
+        params: Value,
+    },
+    RawMessage {
+        payload: String,
+        correlate: bool,
+        sender: RawChannel,
+    },
+}
+
+impl BackgroundCall {
+    /// Short description for the pending-requests indicator
+    fn label(&self) -> String {
+        match self {
+            Self::Tool { name, .. } => format!("tools/call {}", name),
+            Self::Resource { uri } => format!("resources/read {}", uri),
+            Self::Prompt { name, .. } => format!("prompts/get {}", name),
+            Self::Raw { method, .. } => method.clone(),
+            Self::RawMessage { .. } => "raw message".to_string(),
+        }
+    }
+
+    /// Method and params sent for this call, `None` for raw messages
+    fn request(&self) -> Option<(&str, Value)> {
+        match self {
+            Self::Tool { name, params } => Some((
+                "tools/call",
+                serde_json::json!({"name": name, "arguments": params}),
+            )),
+            Self::Resource { uri } => Some(("resources/read", serde_json::json!({"uri": uri}))),
+            Self::Prompt { name, arguments } => Some((
+                "prompts/get",
+                serde_json::json!({"name": name, "arguments": arguments}),
+            )),
+            Self::Raw { method, params } => Some((method, params.clone())),
+            Self::RawMessage { .. } => None,
+        }
+    }
+
+    /// Send the request and parse the response, keeping the raw response
+    async fn run(&self, requester: &mut Requester) -> (BackgroundResult, Option<JsonRpcResponse>) {
+        match self {
+            Self::Tool { name, params } => {
+                let request = CallToolRequest {
+                    name: name.clone(),
+                    arguments: Some(params.clone()),
+                };
+                let response = requester.send_request("tools/call", request).await;
+                let raw = response.as_ref().ok().cloned();
+                (
+                    BackgroundResult::Tool(response.and_then(parse_call_tool_response)),
+                    raw,
+                )
+            }
+            Self::Resource { uri } => {
+                let request = ReadResourceRequest { uri: uri.clone() };
+                let response = requester.send_request("resources/read", request).await;
+                let raw = response.as_ref().ok().cloned();
+                (
+                    BackgroundResult::Resource(response.and_then(parse_read_resource_response)),
+                    raw,
+                )
+            }
+            Self::Prompt { name, arguments } => {
+                let request = GetPromptRequest {
+                    name: name.clone(),
+                    arguments: Some(arguments.clone()),
+                };
+                let response = requester.send_request("prompts/get", request).await;
+                let raw = response.as_ref().ok().cloned();
+                (
+                    BackgroundResult::Prompt(response.and_then(parse_get_prompt_response)),
+                    raw,
+                )
+            }
+            Self::Raw { method, params } => {
+                let response = requester.send_request(method, params).await;
+                let raw = response.as_ref().ok().cloned();
+                (BackgroundResult::Raw(response), raw)
+            }
+            Self::RawMessage {
+                payload,
+                correlate,
+                sender,
+            } => (
+                BackgroundResult::RawMessage(sender.0.send_raw(payload, *correlate, None).await),
+                None,
+            ),
+        }
+    }
+}
+
+/// Raw sender carried by [`BackgroundCall::RawMessage`]
+#[derive(Clone)]
+struct RawChannel(SharedRawSender);
+
+impl std::fmt::Debug for RawChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RawChannel")
+    }
+}
+
+/// Parsed result of a [`BackgroundCall`]
+enum BackgroundResult {
+    Tool(McpResult<CallToolResponse>),
+    Resource(McpResult<ReadResourceResponse>),
+    Prompt(McpResult<GetPromptResponse>),
+    Raw(McpResult<JsonRpcResponse>),
+    RawMessage(McpResult<Vec<String>>),
+}
+
+/// Raw tool, resource and prompt lists as returned by the server
+type CapabilityLists = HashMap<ListKind, Vec<Value>>;
+
+/// A client handed back by a background reconnect or watch-mode restart
+struct Reconnection {
+    client: McpClient,
+    result: McpResult<()>,
+    /// Capability lists before and after a restart
+    lists: Option<(CapabilityLists, CapabilityLists)>,
+}
+
+/// Another server of a multi-server session.
+///
+/// The first server keeps the connection fields of [`DebuggerApp`], so
+/// `--watch` restarts, the stderr pane, elicitation and the raw composer
+/// apply to it. Further servers get a client of their own; their
+/// capabilities are merged into the shared lists, tagged with the index of
+/// the server (its position after the first).
+struct PeerServer {
+    name: String,
+    transport_config: TransportConfig,
+    status: ServerStatus,
+    /// `None` while connecting, or while a request owns the client
+    client: Option<McpClient>,
+    /// Name and version the server reported
+    implementation: Option<Implementation>,
+    events: Option<ClientEventReceiver>,
+    capability_changes: Option<CapabilityChangeReceiver>,
+    /// Returns the client from a background connect or reconnect
+    connection: Option<oneshot::Receiver<PeerConnection>>,
+}
+
+impl PeerServer {
+    fn new(name: String, transport_config: TransportConfig) -> Self {
+        Self {
+            name,
+            transport_config,
+            status: ServerStatus::Connecting,
+            client: None,
+            implementation: None,
+            events: None,
+            capability_changes: None,
+            connection: None,
+        }
+    }
+
+    /// State shown for the server; a ready client may have lost its server since
+    fn current_status(&self) -> ServerStatus {
+        match (&self.status, &self.client) {
+            (ServerStatus::Ready, Some(client)) if client.connection_lost() => ServerStatus::Lost,
+            (status, _) => status.clone(),
+        }
+    }
+}
+
+/// A peer client handed back by a background connect or reconnect
+struct PeerConnection {
+    /// `None` if the client could not be created
+    client: Option<McpClient>,
+    result: Result<(Implementation, CapabilityLists), String>,
+}
+
+/// Connection state of one server, as shown in the header and status bar
+#[derive(Debug, Clone, PartialEq)]
+enum ServerStatus {
+    Connecting,
+    Ready,
+    Reconnecting,
+    Lost,
+    Failed(String),
+}
+
+impl ServerStatus {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Connecting => "◌",
+            Self::Ready => "●",
+            Self::Reconnecting => "⟳",
+            Self::Lost | Self::Failed(_) => "✗",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Connecting => "connecting",
+            Self::Ready => "ready",
+            Self::Reconnecting => "reconnecting",
+            Self::Lost => "lost",
+            Self::Failed(_) => "failed",
+        }
+    }
+}
+
+/// How a background task reaches the server.
+///
+/// Transports that correlate responses hand out a shared [`RequestHandle`], so
+/// any number of requests can run at once. Otherwise the client itself is moved
+/// into the task and returned with the result, one request at a time.
+enum Requester {
+    Shared(RequestHandle),
+    Owned(Box<McpClient>),
+}
+
+impl Requester {
+    async fn send_request(
+        &mut self,
+        method: &str,
+        params: impl serde::Serialize,
+    ) -> McpResult<JsonRpcResponse> {
+        match self {
+            Self::Shared(handle) => handle.send_request(method, params).await,
+            Self::Owned(client) => client.send_request(method, params).await,
+        }
+    }
+}
+
+/// A background request that has not completed yet
+struct PendingRequest {
+    id: u64,
+    /// Index of the server the request went to
+    server: usize,
+    label: String,
+    started: Instant,
+    task: tokio::task::JoinHandle<()>,
+}
+
+/// A finished background request, delivered over the completion channel
+struct RequestCompletion {
+    id: u64,
+    server: usize,
+    call: BackgroundCall,
+    result: BackgroundResult,
+    /// Raw response, paired with the request on the history entry
+    response: Option<JsonRpcResponse>,
+    elapsed: Duration,
+    /// The client, when it was moved into the task
+    client: Option<McpClient>,
+}
+
+/// An `elicitation/create` request shown in the parameter form dialog
+#[derive(Debug)]
+pub struct PendingElicitation {
+    /// Request handle used to send the user's answer
+    request: ServerRequest,
+
+    /// Parsed elicitation parameters
+    elicit: ElicitRequest,
 }
 
 /// Application state
@@ -280,6 +663,9 @@ pub struct UiState {
     /// Input state
     input_area: TextArea<'static>,
 
+    /// Candidates from the last tab-completion in the terminal
+    completion_candidates: Vec<String>,
+
     /// Environment variables input area
     env_input_area: TextArea<'static>,
 
@@ -307,8 +693,10 @@ pub struct UiState {
     /// Whether to show raw JSON
     show_raw_json: bool,
 
-    /// Current selected message index for inspection
-    selected_message_index: Option<usize>,
+    /// Message inspector filter line
+    filter_input: TextArea<'static>,
+    filter_editing: bool,
+    filter_error: Option<String>,
 
     /// Capability selection tracking
     capability_indices: Vec<Option<CapabilityRef>>,
@@ -334,10 +722,26 @@ pub struct UiState {
     search_results: Vec<SearchResult>,
     search_results_state: ListState,
 
+    /// Server stderr pane
+    stderr_pane_open: bool,
+
+    /// Raw JSON-RPC composer
+    composer_open: bool,
+    composer_area: TextArea<'static>,
+    composer_correlate: bool,
+    composer_snippet: Option<PathBuf>,
+
     /// Response viewer
     response_viewer_open: bool,
     response_viewer_mode: ResponseViewMode,
     selected_response: Option<Value>,
+
+    /// Diff shown in the response viewer instead of a single response
+    response_diff: Option<ResponseDiff>,
+    diff_collapse_unchanged: bool,
+
+    /// Elicitation request currently shown in the parameter form dialog
+    elicitation: Option<PendingElicitation>,
 }
 
 #[derive(Debug, Clone)]
@@ -381,6 +785,17 @@ pub enum CapabilityView {
     DetailedList(CapabilityCategory),
 }
 
+/// Two marked responses compared in the response viewer
+#[derive(Debug, Clone)]
+pub struct ResponseDiff {
+    /// Labels of the compared messages, e.g. `#3 tools/call`
+    before_label: String,
+    after_label: String,
+    before: Value,
+    after: Value,
+    tree: DiffNode,
+}
+
 /// Response viewer display modes
 #[derive(Debug, Clone, PartialEq)]
 pub enum ResponseViewMode {
@@ -413,6 +828,114 @@ pub struct MessageEntry {
 
     /// Success information
     success: Option<String>,
+
+    /// Time between sending the request and receiving its response
+    latency: Option<Duration>,
+}
+
+impl MessageEntry {
+    /// Method of the request or inbound message, if known
+    fn method(&self) -> Option<String> {
+        if let Some(request) = &self.request {
+            return Some(request.method.clone());
+        }
+        let method = match &self.message_type {
+            MessageType::Initialize => "initialize",
+            MessageType::ListTools => "tools/list",
+            MessageType::ListResources => "resources/list",
+            MessageType::ListPrompts => "prompts/list",
+            MessageType::CallTool => "tools/call",
+            MessageType::GetResource => "resources/read",
+            MessageType::GetPrompt => "prompts/get",
+            MessageType::Inbound => {
+                return self
+                    .raw_response
+                    .as_ref()
+                    .and_then(|raw| raw.get("method"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            }
+            _ => return None,
+        };
+        Some(method.to_string())
+    }
+
+    /// Whether the client sent the message, the server did, or neither
+    fn direction(&self) -> inspector::Direction {
+        match &self.message_type {
+            MessageType::Inbound | MessageType::CapabilitiesChanged => {
+                inspector::Direction::Inbound
+            }
+            MessageType::Other(kind) if kind == "Server Request" || kind == "Elicitation" => {
+                inspector::Direction::Inbound
+            }
+            _ if self.request.is_some() || self.latency.is_some() => inspector::Direction::Outbound,
+            MessageType::Other(_) => inspector::Direction::Local,
+            _ => inspector::Direction::Outbound,
+        }
+    }
+
+    /// Badge for the history list
+    fn status(&self) -> Status {
+        let failed = self.error.is_some()
+            || self
+                .response
+                .as_ref()
+                .is_some_and(|response| response.error.is_some());
+        if failed {
+            Status::Error
+        } else if self.success.is_some() && self.direction() == inspector::Direction::Outbound {
+            Status::Ok
+        } else {
+            Status::Info
+        }
+    }
+
+    /// Everything the inspector filter can match on
+    fn record(&self, pinned: bool, bookmarked: bool) -> Record<'_> {
+        let mut payloads = Vec::new();
+        if let Some(request) = &self.request {
+            payloads.extend(serde_json::to_value(request).ok());
+        }
+        if let Some(response) = &self.response {
+            payloads.extend(serde_json::to_value(response).ok());
+        }
+        payloads.extend(self.raw_response.clone());
+
+        Record {
+            kind: self.message_type.to_string(),
+            method: self.method(),
+            direction: self.direction(),
+            status: self.status(),
+            pinned,
+            bookmarked,
+            text: self
+                .success
+                .iter()
+                .chain(self.error.iter())
+                .map(String::as_str)
+                .collect(),
+            payloads,
+        }
+    }
+
+    /// What a diff compares: the result, the error, or at least the summary
+    fn diff_payload(&self) -> Option<Value> {
+        if let Some(raw) = &self.raw_response {
+            return Some(raw.clone());
+        }
+        if let Some(response) = &self.response {
+            return match (&response.result, &response.error) {
+                (Some(result), _) => Some(result.clone()),
+                (None, Some(error)) => serde_json::to_value(error).ok(),
+                (None, None) => None,
+            };
+        }
+        self.error
+            .clone()
+            .or(self.success.clone())
+            .map(Value::String)
+    }
 }
 
 /// Message type classification
@@ -425,6 +948,9 @@ pub enum MessageType {
     CallTool,
     GetResource,
     GetPrompt,
+    RawMessage,
+    Inbound,
+    CapabilitiesChanged,
     Other(String),
 }
 
@@ -438,6 +964,9 @@ impl std::fmt::Display for MessageType {
             MessageType::CallTool => write!(f, "Call Tool"),
             MessageType::GetResource => write!(f, "Get Resource"),
             MessageType::GetPrompt => write!(f, "Get Prompt"),
+            MessageType::RawMessage => write!(f, "Raw Message"),
+            MessageType::Inbound => write!(f, "Inbound"),
+            MessageType::CapabilitiesChanged => write!(f, "Capabilities Changed"),
             MessageType::Other(s) => write!(f, "{}", s),
         }
     }
@@ -463,6 +992,11 @@ pub struct ToolInfo {
     pub full_name: String, // Original full name with prefix (for API calls)
     pub description: Option<String>,
     pub parameters: Option<Value>,
+    pub title: Option<String>,
+    pub output_schema: Option<Value>,
+    pub annotations: Option<ToolAnnotations>,
+    /// Index of the server offering the tool, 0 for the first
+    pub server: usize,
 }
 
 /// Resource information
@@ -472,6 +1006,8 @@ pub struct ResourceInfo {
     pub name: Option<String>,
     pub description: Option<String>,
     pub mime_type: Option<String>,
+    /// Index of the server offering the resource, 0 for the first
+    pub server: usize,
 }
 
 /// Prompt information
@@ -480,6 +1016,8 @@ pub struct PromptInfo {
     pub name: String,
     pub description: Option<String>,
     pub arguments: Option<Value>,
+    /// Index of the server offering the prompt, 0 for the first
+    pub server: usize,
 }
 
 impl DebuggerApp {
@@ -491,10 +1029,7 @@ impl DebuggerApp {
         );
         tracing::info!("Creating DebuggerApp");
         tracing::debug!("Transport config: {:?}", transport_config);
-        let mut input_area = TextArea::default();
-        input_area.set_placeholder_text(
-            "Enter MCP command (e.g., tools.calculator {\"a\": 5, \"b\": 3})",
-        );
+        let input_area = terminal_input("");
 
         let mut env_input_area = TextArea::default();
         env_input_area
@@ -511,6 +1046,7 @@ impl DebuggerApp {
             capabilities_state: ListState::default().with_selected(Some(0)),
             message_history_state: ListState::default(),
             input_area,
+            completion_candidates: Vec::new(),
             env_input_area,
             env_dialog_open: false,
             message_scroll: ScrollbarState::new(0),
@@ -524,7 +1060,9 @@ impl DebuggerApp {
             param_field_names: Vec::new(),
             help_dialog_open: false,
             show_raw_json: false,
-            selected_message_index: None,
+            filter_input: TextArea::default(),
+            filter_editing: false,
+            filter_error: None,
             capability_indices: Vec::new(),
             capability_view: CapabilityView::Categories,
             capability_detail_state: ListState::default(),
@@ -539,19 +1077,38 @@ impl DebuggerApp {
             search_active: false,
             search_results: Vec::new(),
             search_results_state: ListState::default(),
+            stderr_pane_open: false,
+            composer_open: false,
+            composer_area: composer_input(COMPOSER_TEMPLATE),
+            composer_correlate: true,
+            composer_snippet: None,
             response_viewer_open: false,
             response_viewer_mode: ResponseViewMode::Formatted,
             selected_response: None,
+            response_diff: None,
+            diff_collapse_unchanged: true,
+            elicitation: None,
         };
 
+        let (completion_sender, completion_receiver) = mpsc::unbounded_channel();
+
         Ok(Self {
             transport_config,
             client_info,
             state: AppState::Initializing,
             ui_state,
             message_history: Vec::new(),
+            history_offset: 0,
+            pinned_messages: BTreeSet::new(),
+            bookmarked_messages: BTreeSet::new(),
+            message_filter: Filter::default(),
+            inspector_rows: Vec::new(),
+            diff_marks: Vec::new(),
             capabilities: ServerCapabilities::default(),
             env_variables: HashMap::new(),
+            command_history: crate::paths::get_mcp_probe_paths()
+                .map(|paths| CommandHistory::load(paths.home_dir.join("history")))
+                .unwrap_or_default(),
             client: None,
             session_id: None,
             session_start: Instant::now(),
@@ -559,9 +1116,75 @@ impl DebuggerApp {
             error_count: 0,
             discovery_step: String::new(),
             search_engine: SearchEngine::new(),
+            pending_requests: Vec::new(),
+            next_request_id: 1,
+            completion_sender,
+            completion_receiver,
+            server_requests: None,
+            raw_traffic: None,
+            capability_changes: None,
+            capability_notice: None,
+            process_log: None,
+            process_log_receiver: None,
+            process_exit_reported: false,
+            stdout_pollution: 0,
+            client_events: None,
+            last_latency: None,
+            reconnect_receiver: None,
+            reconnect_failed: false,
+            watch_paths: Vec::new(),
+            file_changes: None,
+            pending_restart: Vec::new(),
+            watch_lists: HashMap::new(),
+            server_name: "server".to_string(),
+            peers: Vec::new(),
+            tool_collisions: BTreeMap::new(),
+            config: Config::default(),
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            session_file: None,
+            saved_session: None,
         })
     }
 
+    /// Apply TUI and session settings from the resolved configuration
+    pub fn with_config(mut self, config: Config) -> Self {
+        let themes_dir = crate::paths::get_mcp_probe_paths()
+            .map(|paths| paths.themes_dir)
+            .unwrap_or_default();
+        self.theme = Theme::load(&config.tui.color_scheme, &themes_dir).unwrap_or_else(|e| {
+            tracing::warn!("{:#}, using the default theme", e);
+            Theme::default()
+        });
+        self.keymap = Keymap::from_config(&config.tui);
+        self.ui_state.show_raw_json = config.debug.show_raw_messages;
+        self.config = config;
+        self
+    }
+
+    /// Save the session to this file on exit (and on F2) instead of the session directory
+    pub fn with_session_file(mut self, path: Option<PathBuf>) -> Self {
+        self.session_file = path;
+        self
+    }
+
+    /// Restart the server and refresh capabilities when these paths change
+    pub fn with_watch(mut self, paths: Vec<PathBuf>) -> Self {
+        self.watch_paths = paths;
+        self
+    }
+
+    /// Connect to further servers alongside the first one, which is called
+    /// `name`. Their capabilities are browsed and searched together.
+    pub fn with_servers(mut self, name: String, servers: Vec<(String, TransportConfig)>) -> Self {
+        self.server_name = name;
+        self.peers = servers
+            .into_iter()
+            .map(|(name, transport_config)| PeerServer::new(name, transport_config))
+            .collect();
+        self
+    }
+
     /// Run the TUI application
     pub async fn run(&mut self) -> Result<()> {
         // Setup terminal
@@ -583,6 +1206,13 @@ impl DebuggerApp {
         )?;
         terminal.show_cursor()?;
 
+        if self.session_file.is_some() || self.config.debug.auto_save_sessions {
+            match self.save_session() {
+                Ok(path) => eprintln!("💾 Session saved to: {}", path.display()),
+                Err(e) => eprintln!("⚠️  Failed to save session: {}", e),
+            }
+        }
+
         result
     }
 
@@ -593,6 +1223,21 @@ impl DebuggerApp {
         let mut initialization_task: Option<tokio::task::JoinHandle<anyhow::Result<McpClient>>> =
             None;
 
+        if !self.watch_paths.is_empty() {
+            match FileWatcher::new(self.watch_paths.clone()).await {
+                Ok(watcher) => {
+                    self.add_connection_message(format!(
+                        "Watching {} ({} files) for changes",
+                        watcher.describe(),
+                        watcher.file_count()
+                    ));
+                    self.file_changes = Some(watcher.spawn());
+                }
+                Err(e) => self.add_error(format!("Failed to watch files: {}", e)),
+            }
+        }
+        self.connect_peers();
+
         loop {
             // Start client initialization if not already started
             if !client_initialized && initialization_task.is_none() {
@@ -603,15 +1248,38 @@ impl DebuggerApp {
                 tracing::debug!("Transport config: {:?}", transport_config);
                 tracing::debug!("Client info: {:?}", client_info);
 
+                let (log_sender, log_receiver) = oneshot::channel();
+                self.process_log_receiver = Some(log_receiver);
+                let supervision = self
+                    .config
+                    .client
+                    .reconnect
+                    .then(SupervisionPolicy::default);
+
                 initialization_task = Some(tokio::spawn(async move {
-                    tracing::debug!("Creating MCP client with defaults");
-                    let mut client =
-                        McpClient::with_defaults(transport_config)
-                            .await
-                            .map_err(|e| {
-                                tracing::error!("Failed to create MCP client: {}", e);
-                                anyhow::anyhow!("Failed to create MCP client: {}", e)
-                            })?;
+                    tracing::debug!("Creating MCP client with elicitation enabled");
+                    let client_config = ClientConfig {
+                        enable_elicitation: true,
+                        refresh_on_list_changed: true,
+                        supervision,
+                        ..Default::default()
+                    };
+                    let mut client = McpClient::new(
+                        transport_config,
+                        client_config,
+                        Box::new(DefaultNotificationHandler),
+                    )
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Failed to create MCP client: {}", e);
+                        anyhow::anyhow!("Failed to create MCP client: {}", e)
+                    })?;
+
+                    // Hand over stderr capture before connecting, so a server
+                    // that dies during the handshake still shows its output
+                    if let Some(log) = client.process_log() {
+                        let _ = log_sender.send(log);
+                    }
 
                     tracing::debug!("Attempting to connect to MCP server");
                     let _server_info = client.connect(client_info).await.map_err(|e| {
@@ -649,8 +1317,15 @@ impl DebuggerApp {
                                 raw_response: None,
                                 error: None,
                                 success: None,
+                                latency: None,
                             });
 
+                            // Take over server-to-client requests (elicitation)
+                            self.server_requests = client.take_server_requests();
+                            self.raw_traffic = client.take_raw_traffic();
+                            self.capability_changes = client.take_capability_changes();
+                            self.client_events = Some(client.subscribe());
+
                             // Extract session ID from the transport
                             let transport_info = client.transport_info();
                             tracing::debug!("Transport info: {:?}", transport_info);
@@ -690,6 +1365,7 @@ impl DebuggerApp {
                                 raw_response: None,
                                 error: None,
                                 success: Some(format!("Session ID extracted: {}", session_debug)),
+                                latency: None,
                             });
 
                             // Discover capabilities step by step
@@ -738,20 +1414,17 @@ impl DebuggerApp {
                                                     }
                                                 }
 
-                                                self.capabilities.tools = tools
+                                                let tools: Vec<ToolInfo> = tools
                                                     .into_iter()
-                                                    .map(|tool| {
-                                                        ToolInfo {
-                                                            name: Self::strip_tool_prefix(
-                                                                &tool.name,
-                                                            )
-                                                            .to_string(),
-                                                            full_name: tool.name, // Use the exact name from server
-                                                            description: Some(tool.description),
-                                                            parameters: tool.input_schema,
-                                                        }
-                                                    })
+                                                    .map(|tool| tool_info(tool, 0))
                                                     .collect();
+                                                let count = tools.len();
+                                                replace_server_items(
+                                                    &mut self.capabilities.tools,
+                                                    0,
+                                                    tools,
+                                                    |tool| tool.server,
+                                                );
 
                                                 // Add success message to history
                                                 self.add_message(MessageEntry {
@@ -762,9 +1435,11 @@ impl DebuggerApp {
                                                     raw_response: None,
                                                     error: None,
                                                     success: Some(format!(
-                                                        "Found {} tools",
-                                                        self.capabilities.tools.len()
+                                                        "Found {} tools{}",
+                                                        count,
+                                                        self.server_suffix(0)
                                                     )),
+                                                    latency: None,
                                                 });
                                             }
                                             Err(e) => {
@@ -787,6 +1462,7 @@ impl DebuggerApp {
                                                         e
                                                     )),
                                                     success: None,
+                                                    latency: None,
                                                 });
                                             }
                                         }
@@ -801,6 +1477,7 @@ impl DebuggerApp {
                                             raw_response: None,
                                             error: Some("No result in tools response".to_string()),
                                             success: None,
+                                            latency: None,
                                         });
                                     }
                                 }
@@ -815,6 +1492,7 @@ impl DebuggerApp {
                                         raw_response: None,
                                         error: Some(format!("Tools error: {}", e)),
                                         success: None,
+                                        latency: None,
                                     });
                                 }
                             }
@@ -822,15 +1500,16 @@ impl DebuggerApp {
                             self.discovery_step = "Listing resources...".to_string();
                             match client.list_resources().await {
                                 Ok(resources) => {
-                                    self.capabilities.resources = resources
-                                        .into_iter()
-                                        .map(|resource| ResourceInfo {
-                                            uri: resource.uri,
-                                            name: Some(resource.name),
-                                            description: resource.description,
-                                            mime_type: resource.mime_type,
-                                        })
-                                        .collect();
+                                    let count = resources.len();
+                                    replace_server_items(
+                                        &mut self.capabilities.resources,
+                                        0,
+                                        resources
+                                            .into_iter()
+                                            .map(|resource| resource_info(resource, 0))
+                                            .collect(),
+                                        |resource| resource.server,
+                                    );
 
                                     // Add success message to history
                                     self.add_message(MessageEntry {
@@ -841,9 +1520,11 @@ impl DebuggerApp {
                                         raw_response: None,
                                         error: None,
                                         success: Some(format!(
-                                            "Found {} resources",
-                                            self.capabilities.resources.len()
+                                            "Found {} resources{}",
+                                            count,
+                                            self.server_suffix(0)
                                         )),
+                                        latency: None,
                                     });
                                 }
                                 Err(e) => {
@@ -856,6 +1537,7 @@ impl DebuggerApp {
                                         raw_response: None,
                                         error: Some(format!("Resources error: {}", e)),
                                         success: None,
+                                        latency: None,
                                     });
                                 }
                             }
@@ -863,14 +1545,16 @@ impl DebuggerApp {
                             self.discovery_step = "Listing prompts...".to_string();
                             match client.list_prompts().await {
                                 Ok(prompts) => {
-                                    self.capabilities.prompts = prompts
-                                        .into_iter()
-                                        .map(|prompt| PromptInfo {
-                                            name: prompt.name,
-                                            description: Some(prompt.description),
-                                            arguments: prompt.arguments,
-                                        })
-                                        .collect();
+                                    let count = prompts.len();
+                                    replace_server_items(
+                                        &mut self.capabilities.prompts,
+                                        0,
+                                        prompts
+                                            .into_iter()
+                                            .map(|prompt| prompt_info(prompt, 0))
+                                            .collect(),
+                                        |prompt| prompt.server,
+                                    );
 
                                     // Add success message to history
                                     self.add_message(MessageEntry {
@@ -881,9 +1565,11 @@ impl DebuggerApp {
                                         raw_response: None,
                                         error: None,
                                         success: Some(format!(
-                                            "Found {} prompts",
-                                            self.capabilities.prompts.len()
+                                            "Found {} prompts{}",
+                                            count,
+                                            self.server_suffix(0)
                                         )),
+                                        latency: None,
                                     });
                                 }
                                 Err(e) => {
@@ -896,6 +1582,7 @@ impl DebuggerApp {
                                         raw_response: None,
                                         error: Some(format!("Prompts error: {}", e)),
                                         success: None,
+                                        latency: None,
                                     });
                                 }
                             }
@@ -904,10 +1591,7 @@ impl DebuggerApp {
                             tracing::info!("All discovery completed successfully, client ready");
 
                             // Index capabilities for search
-                            self.search_engine.index_tools(&self.capabilities.tools);
-                            self.search_engine
-                                .index_resources(&self.capabilities.resources);
-                            self.search_engine.index_prompts(&self.capabilities.prompts);
+                            self.reindex_capabilities();
                             tracing::info!(
                                 "Indexed {} items for search",
                                 self.search_engine.total_items()
@@ -936,6 +1620,7 @@ impl DebuggerApp {
                                 raw_response: None,
                                 error: Some(format!("Connection error: {}", e)),
                                 success: None,
+                                latency: None,
                             });
 
                             client_initialized = true; // Don't retry automatically
@@ -956,6 +1641,7 @@ impl DebuggerApp {
                                 raw_response: None,
                                 error: Some(format!("Task panic: {}", e)),
                                 success: None,
+                                latency: None,
                             });
 
                             client_initialized = true;
@@ -965,13 +1651,24 @@ impl DebuggerApp {
                 }
             }
 
+            // Pick up finished background requests and incoming server requests
+            self.poll_completed_requests();
+            self.poll_server_requests();
+            self.poll_raw_traffic();
+            self.poll_capability_changes();
+            self.poll_process_log();
+            self.poll_connection();
+            self.poll_file_changes();
+            self.poll_peers();
+
             // Draw the UI
             terminal.draw(|f| self.draw_ui(f))?;
 
             // Handle events
-            if event::poll(Duration::from_millis(100))? {
+            let refresh_rate = Duration::from_millis(self.config.tui.refresh_rate_ms.max(10));
+            if event::poll(refresh_rate)? {
                 if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press && self.handle_key_event(key.code).await? {
+                    if key.kind == KeyEventKind::Press && self.handle_key_event(key).await? {
                         break; // Exit requested
                     }
                 }
@@ -983,30 +1680,34 @@ impl DebuggerApp {
             }
         }
 
+        // Don't leave requests running against a server we are leaving
+        for pending in self.pending_requests.drain(..) {
+            tracing::debug!("Cancelling background request: {}", pending.label);
+            pending.task.abort();
+        }
+
         Ok(())
     }
 
     /// Handle keyboard events (with enhanced safety checks)
-    async fn handle_key_event(&mut self, key: KeyCode) -> Result<bool> {
+    async fn handle_key_event(&mut self, key: KeyEvent) -> Result<bool> {
         // Handle search mode first
         if self.ui_state.search_active {
-            match key {
-                KeyCode::Esc => {
+            match self.keymap.resolve_while_typing(&key, Action::SEARCH) {
+                Some(Action::Cancel) => {
                     self.ui_state.search_active = false;
                     self.ui_state.search_results.clear();
                     self.ui_state.current_focus = FocusedPanel::Capabilities;
-                    return Ok(false);
                 }
-                KeyCode::Enter => {
+                Some(Action::Confirm) => {
                     // Select from search results
                     if let Some(selected) = self.ui_state.search_results_state.selected() {
                         if let Some(result) = self.ui_state.search_results.get(selected) {
                             self.select_search_result(result.clone());
                         }
                     }
-                    return Ok(false);
                 }
-                KeyCode::Up => {
+                Some(Action::Up) => {
                     if !self.ui_state.search_results.is_empty() {
                         let current = self.ui_state.search_results_state.selected().unwrap_or(0);
                         if current > 0 {
@@ -1017,9 +1718,8 @@ impl DebuggerApp {
                                 .select(Some(self.ui_state.search_results.len() - 1));
                         }
                     }
-                    return Ok(false);
                 }
-                KeyCode::Down => {
+                Some(Action::Down) => {
                     if !self.ui_state.search_results.is_empty() {
                         let current = self.ui_state.search_results_state.selected().unwrap_or(0);
                         if current + 1 < self.ui_state.search_results.len() {
@@ -1028,52 +1728,111 @@ impl DebuggerApp {
                             self.ui_state.search_results_state.select(Some(0));
                         }
                     }
-                    return Ok(false);
                 }
                 _ => {
                     // Handle search input
-                    let key_event = crossterm::event::KeyEvent::new(
-                        key,
-                        crossterm::event::KeyModifiers::empty(),
-                    );
-                    self.ui_state.search_input.input(Input::from(key_event));
+                    self.ui_state.search_input.input(Input::from(key));
                     self.perform_search();
-                    return Ok(false);
                 }
             }
+            return Ok(false);
         }
 
-        match key {
-            KeyCode::Char('q') if !self.ui_state.env_dialog_open => {
-                self.state = AppState::ShuttingDown;
-                return Ok(true);
-            }
-            KeyCode::Char('/') => {
-                // Activate search mode (available from any panel)
-                self.ui_state.search_active = true;
-                self.ui_state.current_focus = FocusedPanel::Capabilities;
+        // The elicitation dialog is modal: the server is waiting for an answer
+        if self.ui_state.elicitation.is_some() {
+            self.handle_elicitation_key(key);
+            return Ok(false);
+        }
+
+        // The composer edits text until closed; the response viewer opens on top
+        if self.ui_state.composer_open && !self.ui_state.response_viewer_open {
+            self.handle_composer_key(key);
+            return Ok(false);
+        }
+
+        // The inspector filter line edits text until confirmed or cleared
+        if self.ui_state.filter_editing {
+            self.handle_filter_key(key);
+            return Ok(false);
+        }
+
+        // The terminal takes every key but those bound to global actions
+        if self.terminal_focused()
+            && (self
+                .keymap
+                .resolve_while_typing(&key, Action::TERMINAL)
+                .is_some()
+                || self
+                    .keymap
+                    .resolve_while_typing(&key, Action::GLOBAL)
+                    .is_none())
+        {
+            self.handle_terminal_key(key).await?;
+            return Ok(false);
+        }
+
+        // Text fields keep typed characters even when they are bound
+        let typing = (self.ui_state.env_dialog_open
+            && self.ui_state.current_focus == FocusedPanel::EnvVariables)
+            || (self.ui_state.parameter_dialog_open && self.ui_state.param_edit_mode);
+        let action = if typing {
+            self.keymap.resolve_while_typing(&key, Action::MAIN)
+        } else {
+            self.keymap.resolve(&key, Action::MAIN)
+        };
+
+        match action {
+            Some(Action::Quit) if !self.ui_state.env_dialog_open => {
+                self.state = AppState::ShuttingDown;
+                return Ok(true);
+            }
+            Some(Action::Help)
+                if !(keymap::is_text_input(&key)
+                    && (self.ui_state.env_dialog_open || self.ui_state.parameter_dialog_open)) =>
+            {
+                self.ui_state.help_dialog_open = !self.ui_state.help_dialog_open;
+            }
+            Some(Action::Search) => {
+                // Activate search mode (available from any panel)
+                self.ui_state.search_active = true;
+                self.ui_state.current_focus = FocusedPanel::Capabilities;
                 // Clear any previous search
                 self.ui_state.search_input.select_all();
                 self.ui_state.search_input.cut();
-                return Ok(false);
             }
-            KeyCode::Char('r') | KeyCode::Char('R') => {
+            Some(Action::Terminal) if !self.overlay_open() => {
+                // Focus the terminal to type a command, vi style
+                self.ui_state.current_focus = FocusedPanel::Input;
+                self.ui_state.input_area = terminal_input(":");
+            }
+            Some(Action::Filter) if !self.overlay_open() => {
+                self.ui_state.current_focus = FocusedPanel::MessageInspector;
+                self.ui_state.filter_editing = true;
+            }
+            Some(Action::Pin) if !self.overlay_open() => self.toggle_pin(),
+            Some(Action::Bookmark) if !self.overlay_open() => self.toggle_bookmark(),
+            Some(Action::NextBookmark) if !self.overlay_open() => self.jump_to_bookmark(true),
+            Some(Action::PreviousBookmark) if !self.overlay_open() => self.jump_to_bookmark(false),
+            Some(Action::MarkDiff) if !self.overlay_open() => self.toggle_diff_mark(),
+            Some(Action::OpenDiff) if !self.overlay_open() => self.open_diff(),
+            Some(Action::ToggleUnchanged) if self.ui_state.response_diff.is_some() => {
+                self.ui_state.diff_collapse_unchanged = !self.ui_state.diff_collapse_unchanged;
+            }
+            Some(Action::ExportDiff) if self.ui_state.response_diff.is_some() => self.export_diff(),
+            Some(Action::OpenResponse) => {
                 // Open response viewer for selected message
-                if let Some(selected_idx) = self.ui_state.message_history_state.selected() {
+                if let Some(selected_idx) = self.selected_message() {
                     if let Some(message) = self.message_history.get(selected_idx) {
-                        if let Some(ref response) = message.raw_response {
-                            self.ui_state.selected_response = Some(response.clone());
-                            self.ui_state.response_viewer_open = true;
-                            // Reset scroll positions to start from top
-                            self.ui_state.response_viewer_vertical_pos = 0;
-                            self.ui_state.response_viewer_horizontal_pos = 0;
-                            return Ok(false);
+                        if let Some(response) = message.raw_response.clone() {
+                            self.open_response_viewer(response);
                         }
                     }
                 }
-                return Ok(false);
             }
-            KeyCode::Char('v') | KeyCode::Char('V') => {
+            Some(Action::SavePayloads) if self.ui_state.response_viewer_open => {
+                self.save_response_payloads();
+            }
+            Some(Action::CycleView) => {
                 // Cycle response view modes
                 if self.ui_state.response_viewer_open {
                     self.ui_state.response_viewer_mode = match self.ui_state.response_viewer_mode {
@@ -1083,26 +1842,45 @@ impl DebuggerApp {
                         ResponseViewMode::Summary => ResponseViewMode::Formatted,
                     };
                 }
-                return Ok(false);
-            }
-            KeyCode::F(1) => {
-                self.ui_state.help_dialog_open = !self.ui_state.help_dialog_open;
             }
-            KeyCode::F(2) => {
+            Some(Action::SaveSession) => {
                 // Save session
-                self.save_session().await?;
+                match self.save_session() {
+                    Ok(path) => self.add_message(MessageEntry {
+                        timestamp: Instant::now(),
+                        message_type: MessageType::Other("Session".to_string()),
+                        request: None,
+                        response: None,
+                        raw_response: None,
+                        error: None,
+                        success: Some(format!("Session saved to {}", path.display())),
+                        latency: None,
+                    }),
+                    Err(e) => self.add_error(format!("Failed to save session: {}", e)),
+                }
             }
-            KeyCode::F(3) => {
+            Some(Action::ToggleRawJson) => {
                 // Toggle raw JSON view
                 self.ui_state.show_raw_json = !self.ui_state.show_raw_json;
             }
-            KeyCode::F(4) => {
+            Some(Action::ClearHistory) => {
                 // Clear messages
+                self.history_offset += self.message_history.len();
                 self.message_history.clear();
+                self.pinned_messages.clear();
+                self.bookmarked_messages.clear();
+                self.diff_marks.clear();
+                self.refresh_inspector();
                 self.message_count = 0;
                 self.error_count = 0;
             }
-            KeyCode::F(5) => {
+            Some(Action::Composer) if !self.overlay_open() => {
+                self.ui_state.composer_open = true;
+            }
+            Some(Action::ServerStderr) if !self.overlay_open() => {
+                self.ui_state.stderr_pane_open = !self.ui_state.stderr_pane_open;
+            }
+            Some(Action::EnvVariables) => {
                 // Open environment variables dialog
                 self.ui_state.env_dialog_open = !self.ui_state.env_dialog_open;
                 if self.ui_state.env_dialog_open {
@@ -1111,7 +1889,7 @@ impl DebuggerApp {
                     self.ui_state.current_focus = FocusedPanel::Input;
                 }
             }
-            KeyCode::Enter => {
+            Some(Action::Confirm) => {
                 if self.ui_state.parameter_dialog_open {
                     if self.ui_state.param_field_names.is_empty() {
                         // No parameters - execute directly
@@ -1136,10 +1914,11 @@ impl DebuggerApp {
                     self.execute_command().await?;
                 }
             }
-            KeyCode::Esc => {
+            Some(Action::Cancel) => {
                 if self.ui_state.response_viewer_open {
                     self.ui_state.response_viewer_open = false;
                     self.ui_state.selected_response = None;
+                    self.ui_state.response_diff = None;
                 } else if self.ui_state.parameter_dialog_open {
                     if self.ui_state.param_edit_mode {
                         // Exit edit mode
@@ -1162,9 +1941,11 @@ impl DebuggerApp {
                     // Go back from detailed list to categories
                     self.ui_state.capability_view = CapabilityView::Categories;
                     self.ui_state.current_focus = FocusedPanel::Capabilities;
+                } else if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+                    self.ui_state.current_focus = FocusedPanel::Capabilities;
                 }
             }
-            KeyCode::Tab => {
+            Some(Action::Execute) => {
                 if self.ui_state.parameter_dialog_open
                     && !self.ui_state.param_field_names.is_empty()
                 {
@@ -1175,25 +1956,29 @@ impl DebuggerApp {
                     self.open_latest_response_viewer();
                 }
             }
-            KeyCode::Up => {
+            Some(Action::Up) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_up();
                 } else if self.ui_state.parameter_dialog_open && !self.ui_state.param_edit_mode {
                     self.navigate_parameter_form_up();
                 } else if self.ui_state.current_focus == FocusedPanel::Capabilities {
                     self.navigate_capabilities_up();
+                } else if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+                    self.navigate_messages(-1);
                 }
             }
-            KeyCode::Down => {
+            Some(Action::Down) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_down();
                 } else if self.ui_state.parameter_dialog_open && !self.ui_state.param_edit_mode {
                     self.navigate_parameter_form_down();
                 } else if self.ui_state.current_focus == FocusedPanel::Capabilities {
                     self.navigate_capabilities_down();
+                } else if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+                    self.navigate_messages(1);
                 }
             }
-            KeyCode::Left => {
+            Some(Action::Left) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_left();
                 } else if matches!(
@@ -1203,7 +1988,7 @@ impl DebuggerApp {
                     self.navigate_page_left();
                 }
             }
-            KeyCode::Right => {
+            Some(Action::Right) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_right();
                 } else if matches!(
@@ -1213,135 +1998,300 @@ impl DebuggerApp {
                     self.navigate_page_right();
                 }
             }
-            KeyCode::PageUp => {
+            Some(Action::PageUp) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_page_up();
                 }
             }
-            KeyCode::PageDown => {
+            Some(Action::PageDown) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_page_down();
                 }
             }
-            KeyCode::Home => {
+            Some(Action::Home) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_home();
+                } else if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+                    self.navigate_messages(isize::MIN);
                 }
             }
-            KeyCode::End => {
+            Some(Action::End) => {
                 if self.ui_state.response_viewer_open {
                     self.navigate_response_viewer_end();
+                } else if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+                    self.navigate_messages(isize::MAX);
                 }
             }
             _ => {
                 // Handle input for focused text areas
                 match self.ui_state.current_focus {
                     FocusedPanel::Input => {
-                        self.ui_state.input_area.input(Input::from(
-                            crossterm::event::KeyEvent::new(key, event::KeyModifiers::empty()),
-                        ));
+                        self.ui_state.input_area.input(Input::from(key));
                     }
                     FocusedPanel::EnvVariables if self.ui_state.env_dialog_open => {
-                        self.ui_state.env_input_area.input(Input::from(
-                            crossterm::event::KeyEvent::new(key, event::KeyModifiers::empty()),
-                        ));
+                        self.ui_state.env_input_area.input(Input::from(key));
                     }
                     _ => {
                         // Handle parameter form text input when in edit mode
                         if self.ui_state.parameter_dialog_open && self.ui_state.param_edit_mode {
-                            // Enhanced safety checks with proper error handling
-                            if self.ui_state.param_field_names.is_empty() {
-                                tracing::debug!("No parameter fields available for input");
-                                return Ok(false);
-                            }
+                            self.handle_parameter_field_input(key.code);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
 
-                            if self.ui_state.param_selected_field
-                                >= self.ui_state.param_field_names.len()
-                            {
-                                tracing::warn!(
-                                    "param_selected_field {} is out of bounds (len: {}), resetting to 0",
-                                    self.ui_state.param_selected_field,
-                                    self.ui_state.param_field_names.len()
-                                );
-                                self.ui_state.param_selected_field = 0;
-                                return Ok(false);
-                            }
+    /// Whether a dialog, the response viewer or search covers the panels
+    fn overlay_open(&self) -> bool {
+        self.ui_state.help_dialog_open
+            || self.ui_state.env_dialog_open
+            || self.ui_state.parameter_dialog_open
+            || self.ui_state.response_viewer_open
+            || self.ui_state.search_active
+            || self.ui_state.composer_open
+    }
 
-                            // Safe indexing with bounds check
-                            let field_name = match self
-                                .ui_state
-                                .param_field_names
-                                .get(self.ui_state.param_selected_field)
-                            {
-                                Some(name) => name.clone(),
-                                None => {
-                                    tracing::error!(
-                                        "No field name at index {} despite bounds check",
-                                        self.ui_state.param_selected_field
-                                    );
-                                    return Ok(false);
-                                }
-                            };
+    /// Whether keys go to the interactive terminal
+    fn terminal_focused(&self) -> bool {
+        self.ui_state.current_focus == FocusedPanel::Input && !self.overlay_open()
+    }
 
-                            // Safe field mutation with error handling
-                            if let Some(field) = self.ui_state.param_fields.get_mut(&field_name) {
-                                match key {
-                                    KeyCode::Char(c) => {
-                                        // Validate character input to prevent crashes
-                                        if c.is_control() && c != '\t' && c != '\n' {
-                                            tracing::debug!("Ignoring control character: {:?}", c);
-                                            return Ok(false);
-                                        }
+    /// Handle a key press in the interactive terminal
+    async fn handle_terminal_key(&mut self, key: KeyEvent) -> Result<()> {
+        let action = self.keymap.resolve_while_typing(&key, Action::TERMINAL);
+        if action != Some(Action::Complete) {
+            self.ui_state.completion_candidates.clear();
+        }
 
-                                        // Limit field length to prevent memory issues
-                                        if field.value.len() >= 1000 {
-                                            tracing::warn!(
-                                                "Field '{}' has reached maximum length",
-                                                field_name
-                                            );
-                                            return Ok(false);
-                                        }
+        match action {
+            Some(Action::Confirm) => self.execute_command().await?,
+            Some(Action::Complete) => self.complete_command(),
+            Some(Action::Up) => {
+                let current = self.ui_state.input_area.lines().join(" ");
+                if let Some(previous) = self.command_history.previous(&current) {
+                    self.ui_state.input_area = terminal_input(previous);
+                }
+            }
+            Some(Action::Down) => {
+                if let Some(next) = self.command_history.next() {
+                    self.ui_state.input_area = terminal_input(next);
+                }
+            }
+            Some(Action::Cancel) => self.ui_state.current_focus = FocusedPanel::Capabilities,
+            _ => {
+                self.ui_state.input_area.input(Input::from(key));
+            }
+        }
+        Ok(())
+    }
 
-                                        field.value.push(c);
-                                        tracing::debug!(
-                                            "Added char '{}' to field '{}', value now has {} chars",
-                                            c,
-                                            field_name,
-                                            field.value.len()
-                                        );
-                                    }
-                                    KeyCode::Backspace => {
-                                        if !field.value.is_empty() {
-                                            field.value.pop();
-                                            tracing::debug!(
-                                                "Removed char from field '{}', value now has {} chars",
-                                                field_name,
-                                                field.value.len()
-                                            );
-                                        }
-                                    }
-                                    _ => {
-                                        // Ignore other keys safely
-                                        tracing::debug!(
-                                            "Ignoring key in parameter form: {:?}",
-                                            key
-                                        );
-                                    }
-                                }
-                            } else {
-                                tracing::error!(
-                                    "Field '{}' not found in param_fields HashMap",
-                                    field_name
-                                );
-                                // Don't crash, just log the error
-                                return Ok(false);
-                            }
-                        }
+    /// Tab-complete the end of the terminal command
+    fn complete_command(&mut self) {
+        let input = self.ui_state.input_area.lines().join(" ");
+        let catalog = Catalog {
+            tools: &self.capabilities.tools,
+            resources: &self.capabilities.resources,
+            prompts: &self.capabilities.prompts,
+        };
+        match command::complete(&input, &catalog) {
+            Some(completion) => {
+                self.ui_state.input_area = terminal_input(&completion.line);
+                self.ui_state.completion_candidates = if completion.candidates.len() > 1 {
+                    completion.candidates
+                } else {
+                    Vec::new()
+                };
+            }
+            None => self.ui_state.completion_candidates.clear(),
+        }
+    }
+
+    /// Handle a key press in the raw JSON-RPC composer
+    fn handle_composer_key(&mut self, key: KeyEvent) {
+        match self.keymap.resolve_while_typing(&key, Action::COMPOSER) {
+            Some(Action::Cancel) => self.ui_state.composer_open = false,
+            Some(Action::Send) => self.send_composed_message(),
+            Some(Action::ToggleCorrelation) => {
+                self.ui_state.composer_correlate = !self.ui_state.composer_correlate
+            }
+            Some(Action::SaveSnippet) => self.save_snippet(),
+            Some(Action::LoadSnippet) => self.load_next_snippet(),
+            _ => {
+                self.ui_state.composer_area.input(Input::from(key));
+            }
+        }
+    }
+
+    /// Send the composer text exactly as typed
+    fn send_composed_message(&mut self) {
+        let payload = self.ui_state.composer_area.lines().join("\n");
+        if payload.trim().is_empty() {
+            return;
+        }
+        if !self.client_available(0, "send a raw message") {
+            return;
+        }
+        let Some(sender) = self.client.as_ref().and_then(McpClient::raw_sender) else {
+            self.add_error(format!(
+                "The {} transport cannot send raw messages",
+                self.transport_config.transport_type()
+            ));
+            return;
+        };
+
+        self.spawn_request(
+            0,
+            BackgroundCall::RawMessage {
+                payload,
+                correlate: self.ui_state.composer_correlate,
+                sender: RawChannel(sender),
+            },
+        );
+    }
+
+    /// Save the composer text as a snippet
+    fn save_snippet(&mut self) {
+        let payload = self.ui_state.composer_area.lines().join("\n");
+        let saved = crate::paths::get_mcp_probe_paths().and_then(|paths| {
+            let path = paths.snippets_dir.join(snippet_file_name(&payload));
+            std::fs::write(&path, format!("{}\n", payload.trim_end()))?;
+            Ok(path)
+        });
+        match saved {
+            Ok(path) => {
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::Other("Snippet".to_string()),
+                    request: None,
+                    response: None,
+                    raw_response: None,
+                    error: None,
+                    success: Some(format!("Snippet saved to {}", path.display())),
+                    latency: None,
+                });
+                self.ui_state.composer_snippet = Some(path);
+            }
+            Err(e) => self.add_error(format!("Failed to save snippet: {}", e)),
+        }
+    }
+
+    /// Load the next saved snippet (by name) into the composer
+    fn load_next_snippet(&mut self) {
+        let snippets = crate::paths::get_mcp_probe_paths()
+            .and_then(|paths| Ok(std::fs::read_dir(paths.snippets_dir)?))
+            .map(|entries| {
+                let mut snippets: Vec<PathBuf> = entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                    .collect();
+                snippets.sort();
+                snippets
+            })
+            .unwrap_or_default();
+
+        let next = match &self.ui_state.composer_snippet {
+            Some(current) => snippets
+                .iter()
+                .position(|path| path == current)
+                .map_or(0, |index| (index + 1) % snippets.len()),
+            None => 0,
+        };
+        let Some(path) = snippets.get(next) else {
+            self.add_error(format!(
+                "No snippets saved yet ({} saves the composer text)",
+                self.keymap.label(Action::SaveSnippet)
+            ));
+            return;
+        };
+
+        match std::fs::read_to_string(path) {
+            Ok(content) => {
+                self.ui_state.composer_area = composer_input(content.trim_end());
+                self.ui_state.composer_snippet = Some(path.clone());
+            }
+            Err(e) => self.add_error(format!("Failed to load snippet {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Apply a key press to the parameter form field being edited
+    fn handle_parameter_field_input(&mut self, key: KeyCode) {
+        // Enhanced safety checks with proper error handling
+        if self.ui_state.param_field_names.is_empty() {
+            tracing::debug!("No parameter fields available for input");
+            return;
+        }
+
+        if self.ui_state.param_selected_field >= self.ui_state.param_field_names.len() {
+            tracing::warn!(
+                "param_selected_field {} is out of bounds (len: {}), resetting to 0",
+                self.ui_state.param_selected_field,
+                self.ui_state.param_field_names.len()
+            );
+            self.ui_state.param_selected_field = 0;
+            return;
+        }
+
+        // Safe indexing with bounds check
+        let field_name = match self
+            .ui_state
+            .param_field_names
+            .get(self.ui_state.param_selected_field)
+        {
+            Some(name) => name.clone(),
+            None => {
+                tracing::error!(
+                    "No field name at index {} despite bounds check",
+                    self.ui_state.param_selected_field
+                );
+                return;
+            }
+        };
+
+        // Safe field mutation with error handling
+        if let Some(field) = self.ui_state.param_fields.get_mut(&field_name) {
+            match key {
+                KeyCode::Char(c) => {
+                    // Validate character input to prevent crashes
+                    if c.is_control() && c != '\t' && c != '\n' {
+                        tracing::debug!("Ignoring control character: {:?}", c);
+                        return;
+                    }
+
+                    // Limit field length to prevent memory issues
+                    if field.value.len() >= 1000 {
+                        tracing::warn!("Field '{}' has reached maximum length", field_name);
+                        return;
+                    }
+
+                    field.value.push(c);
+                    tracing::debug!(
+                        "Added char '{}' to field '{}', value now has {} chars",
+                        c,
+                        field_name,
+                        field.value.len()
+                    );
+                }
+                KeyCode::Backspace => {
+                    if !field.value.is_empty() {
+                        field.value.pop();
+                        tracing::debug!(
+                            "Removed char from field '{}', value now has {} chars",
+                            field_name,
+                            field.value.len()
+                        );
                     }
                 }
+                _ => {
+                    // Ignore other keys safely
+                    tracing::debug!("Ignoring key in parameter form: {:?}", key);
+                }
             }
+        } else {
+            tracing::error!("Field '{}' not found in param_fields HashMap", field_name);
+            // Don't crash, just log the error
         }
-        Ok(false)
     }
 
     /// Cycle through focusable panels
@@ -1394,10 +2344,12 @@ impl DebuggerApp {
                     .capability_detail_state
                     .selected()
                     .unwrap_or(0);
-                if current > 0 {
-                    self.ui_state
-                        .capability_detail_state
-                        .select(Some(current - 1));
+                // Skip server group headers
+                let previous = (0..current).rev().find(|row| {
+                    matches!(self.ui_state.capability_indices.get(*row), Some(Some(_)))
+                });
+                if let Some(previous) = previous {
+                    self.ui_state.capability_detail_state.select(Some(previous));
                 }
             }
         }
@@ -1419,11 +2371,10 @@ impl DebuggerApp {
                     .capability_detail_state
                     .selected()
                     .unwrap_or(0);
-                let max_items = self.ui_state.capability_indices.len();
-                if current + 1 < max_items {
-                    self.ui_state
-                        .capability_detail_state
-                        .select(Some(current + 1));
+                let next = (current + 1..self.ui_state.capability_indices.len())
+                    .find(|row| self.ui_state.capability_indices[*row].is_some());
+                if let Some(next) = next {
+                    self.ui_state.capability_detail_state.select(Some(next));
                 }
             }
         }
@@ -1536,7 +2487,7 @@ impl DebuggerApp {
                         .capabilities
                         .tools
                         .iter()
-                        .position(|t| t.name == item.name)
+                        .position(|t| t.name == item.name && t.server == item.server)
                     {
                         self.ui_state.selected_capability = Some(CapabilityRef::Tool(tool_index));
                         self.ui_state.capability_view =
@@ -1550,7 +2501,7 @@ impl DebuggerApp {
                         .capabilities
                         .resources
                         .iter()
-                        .position(|r| r.uri == item.name)
+                        .position(|r| r.uri == item.name && r.server == item.server)
                     {
                         self.ui_state.selected_capability =
                             Some(CapabilityRef::Resource(resource_index));
@@ -1565,7 +2516,7 @@ impl DebuggerApp {
                         .capabilities
                         .prompts
                         .iter()
-                        .position(|p| p.name == item.name)
+                        .position(|p| p.name == item.name && p.server == item.server)
                     {
                         self.ui_state.selected_capability =
                             Some(CapabilityRef::Prompt(prompt_index));
@@ -1586,7 +2537,9 @@ impl DebuggerApp {
     fn navigate_page_left(&mut self) {
         if self.ui_state.capability_page > 0 {
             self.ui_state.capability_page -= 1;
-            self.ui_state.capability_detail_state.select(Some(0));
+            self.ui_state
+                .capability_detail_state
+                .select(Some(self.first_capability_row()));
         }
     }
 
@@ -1602,11 +2555,19 @@ impl DebuggerApp {
             let max_pages = total_count.div_ceil(self.ui_state.capability_page_size);
             if self.ui_state.capability_page + 1 < max_pages {
                 self.ui_state.capability_page += 1;
-                self.ui_state.capability_detail_state.select(Some(0));
+                self.ui_state
+                    .capability_detail_state
+                    .select(Some(self.first_capability_row()));
             }
         }
     }
 
+    /// First selectable row of a capability list page, below the server
+    /// group header in a multi-server session
+    fn first_capability_row(&self) -> usize {
+        usize::from(self.multi_server())
+    }
+
     /// Select a capability category or individual capability
     fn select_capability(&mut self) {
         match self.ui_state.capability_view {
@@ -1621,7 +2582,9 @@ impl DebuggerApp {
 
                     self.ui_state.capability_view = CapabilityView::DetailedList(category);
                     self.ui_state.capability_page = 0;
-                    self.ui_state.capability_detail_state.select(Some(0));
+                    self.ui_state
+                        .capability_detail_state
+                        .select(Some(self.first_capability_row()));
                 }
             }
             CapabilityView::DetailedList(_) => {
@@ -1801,140 +2764,1158 @@ impl DebuggerApp {
         );
     }
 
-    /// Execute selected capability with form parameters
-    async fn execute_selected_capability(&mut self) -> Result<()> {
-        if let Some(ref capability_ref) = self.ui_state.selected_capability.clone() {
-            // Build parameters object from form fields
-            let mut params = serde_json::Map::new();
-
-            for (param_name, field) in &self.ui_state.param_fields {
-                if !field.value.is_empty() {
-                    // Try to parse as JSON first, then fall back to string
-                    let param_value =
-                        if let Ok(json_value) = serde_json::from_str::<Value>(&field.value) {
-                            json_value
-                        } else {
-                            Value::String(field.value.clone())
-                        };
-                    params.insert(param_name.clone(), param_value);
-                }
+    /// Build a parameters object from the non-empty form fields
+    fn collect_parameter_values(&self) -> Value {
+        let mut params = serde_json::Map::new();
+
+        for (param_name, field) in &self.ui_state.param_fields {
+            if !field.value.is_empty() {
+                // Try to parse as JSON first, then fall back to string
+                let param_value =
+                    if let Ok(json_value) = serde_json::from_str::<Value>(&field.value) {
+                        json_value
+                    } else {
+                        Value::String(field.value.clone())
+                    };
+                params.insert(param_name.clone(), param_value);
             }
+        }
 
-            let params_value = Value::Object(params);
+        Value::Object(params)
+    }
 
-            match capability_ref {
-                CapabilityRef::Tool(index) => {
-                    if let Some(tool) = self.capabilities.tools.get(*index) {
-                        let tool_name = tool.full_name.clone(); // Use full name with prefix for API call
-                        let params_str = params_value.to_string();
-                        self.execute_tool(&tool_name, &params_str).await?;
-                    }
-                }
-                CapabilityRef::Resource(index) => {
-                    if let Some(resource) = self.capabilities.resources.get(*index) {
-                        let resource_uri = resource.uri.clone();
-                        self.get_resource(&resource_uri).await?;
-                    }
+    /// Show inbound messages no request claimed since the last tick
+    fn poll_raw_traffic(&mut self) {
+        let Some(receiver) = self.raw_traffic.as_mut() else {
+            return;
+        };
+        let mut lines = Vec::new();
+        while let Ok(line) = receiver.try_recv() {
+            lines.push(line);
+        }
+
+        for line in lines {
+            match serde_json::from_str::<Value>(&line) {
+                Ok(message) => {
+                    let summary = match (message.get("method"), message.get("id")) {
+                        (Some(method), _) => format!("← {}", method.as_str().unwrap_or("?")),
+                        (None, Some(id)) if message.get("error").is_some() => {
+                            format!("← error response (id {})", id)
+                        }
+                        (None, Some(id)) => format!("← unmatched response (id {})", id),
+                        (None, None) => format!("← {}", truncate_summary(&line)),
+                    };
+                    self.add_message(MessageEntry {
+                        timestamp: Instant::now(),
+                        message_type: MessageType::Inbound,
+                        request: None,
+                        response: None,
+                        raw_response: Some(message),
+                        error: None,
+                        success: Some(summary),
+                        latency: None,
+                    });
                 }
-                CapabilityRef::Prompt(index) => {
-                    if let Some(prompt) = self.capabilities.prompts.get(*index) {
-                        let prompt_name = prompt.name.clone();
-                        let params_str = params_value.to_string();
-                        self.get_prompt(&prompt_name, &params_str).await?;
-                    }
+                Err(e) => {
+                    self.error_count += 1;
+                    self.add_message(MessageEntry {
+                        timestamp: Instant::now(),
+                        message_type: MessageType::Inbound,
+                        request: None,
+                        response: None,
+                        raw_response: Some(Value::String(line.clone())),
+                        error: Some(format!(
+                            "Server sent invalid JSON ({}): {}",
+                            e,
+                            truncate_summary(&line)
+                        )),
+                        success: None,
+                        latency: None,
+                    });
                 }
             }
+        }
+    }
 
-            // Close the parameter dialog
-            self.ui_state.parameter_dialog_open = false;
-            self.ui_state.selected_capability = None;
+    /// Apply tool, resource and prompt lists re-fetched after `list_changed`
+    fn poll_capability_changes(&mut self) {
+        let Some(receiver) = self.capability_changes.as_mut() else {
+            return;
+        };
+        let mut changes = Vec::new();
+        while let Ok(change) = receiver.try_recv() {
+            changes.push(change);
         }
 
-        Ok(())
+        for change in changes {
+            match change {
+                Ok(change) => self.apply_capability_change(0, change),
+                Err(e) => self.add_error(format!("Failed to refresh capabilities: {}", e)),
+            }
+        }
     }
 
-    /// Execute a command from the input area
-    async fn execute_command(&mut self) -> Result<()> {
-        let input = self.ui_state.input_area.lines().join(" ");
-        if input.trim().is_empty() {
-            return Ok(());
+    /// Pick up the server process log; report stdout pollution and an
+    /// unexpected exit once
+    fn poll_process_log(&mut self) {
+        if let Some(receiver) = self.process_log_receiver.as_mut() {
+            match receiver.try_recv() {
+                Ok(log) => {
+                    self.process_log = Some(log);
+                    self.process_exit_reported = false;
+                    self.process_log_receiver = None;
+                }
+                Err(oneshot::error::TryRecvError::Empty) => {}
+                Err(oneshot::error::TryRecvError::Closed) => self.process_log_receiver = None,
+            }
         }
 
-        // Parse command (simplified parser for demo)
-        // Format: tools.toolname {"param": "value"}
-        // Or: resources.resourcename
-        // Or: prompts.promptname {"param": "value"}
+        let Some(log) = self.process_log.clone() else {
+            return;
+        };
 
-        let parts: Vec<&str> = input.trim().splitn(2, ' ').collect();
-        if parts.is_empty() {
-            return Ok(());
+        // Anything but JSON-RPC on stdout corrupts the protocol stream
+        let pollution = log.stdout_pollution();
+        if pollution.total > self.stdout_pollution {
+            if self.stdout_pollution == 0 {
+                let example = pollution
+                    .examples
+                    .first()
+                    .map(|issue| format!(" ({}): {}", issue.kind, secrets::redact(&issue.line)))
+                    .unwrap_or_default();
+                self.add_error(format!(
+                    "Stdout pollution: server wrote a non-JSON-RPC line to stdout{}",
+                    example
+                ));
+            }
+            self.stdout_pollution = pollution.total;
         }
 
-        let command_parts: Vec<&str> = parts[0].split('.').collect();
-        if command_parts.len() != 2 {
-            self.add_error("Invalid command format. Use: tools.toolname {params}".to_string());
-            return Ok(());
+        if self.process_exit_reported {
+            return;
         }
+        let Some(crash) = log.crash(0) else {
+            return;
+        };
+        self.process_exit_reported = true;
+        self.ui_state.stderr_pane_open = true;
+        self.add_error(format!(
+            "Server process exited unexpectedly ({})",
+            crash.exit
+        ));
+    }
 
-        let (category, name) = (command_parts[0], command_parts[1]);
-        let params = if parts.len() > 1 { parts[1] } else { "{}" };
-
-        match category {
-            "tools" => {
-                // Find the tool by clean name and use full name for execution
-                if let Some(tool) = self.capabilities.tools.iter().find(|t| t.name == name) {
-                    let full_name = tool.full_name.clone();
-                    self.execute_tool(&full_name, params).await?;
-                } else {
-                    self.add_error(format!("Tool '{}' not found", name));
+    /// Show client state transitions and reconnect a supervised client whose
+    /// server has gone away
+    fn poll_connection(&mut self) {
+        let mut events = Vec::new();
+        if let Some(receiver) = self.client_events.as_mut() {
+            loop {
+                match receiver.try_recv() {
+                    Ok(event) => events.push(event.kind),
+                    // Events dropped while the UI was busy are not worth stopping for
+                    Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                    Err(_) => break,
                 }
             }
-            "resources" => {
-                self.get_resource(name).await?;
-            }
-            "prompts" => {
-                self.get_prompt(name, params).await?;
-            }
-            _ => {
-                self.add_error(format!("Unknown category: {}", category));
+        }
+        for event in events {
+            match event {
+                ClientEventKind::StateChanged {
+                    state: ClientState::Reconnecting { attempt },
+                    reason,
+                    ..
+                } => {
+                    let reason = reason
+                        .map(|reason| format!(": {}", secrets::redact(&reason)))
+                        .unwrap_or_default();
+                    self.add_connection_message(format!(
+                        "Reconnecting to the server (attempt {}){}",
+                        attempt, reason
+                    ))
+                }
+                ClientEventKind::Reconnected { .. } => {
+                    self.add_connection_message("Reconnected to the server".to_string())
+                }
+                ClientEventKind::ResponseReceived { latency, .. } => {
+                    self.last_latency = Some(latency)
+                }
+                _ => {}
             }
         }
 
-        // Clear input
-        self.ui_state.input_area = TextArea::default();
-        self.ui_state.input_area.set_placeholder_text(
-            "Enter MCP command (e.g., tools.calculator {\"a\": 5, \"b\": 3})",
-        );
-        self.ui_state.input_area.set_block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Interactive Terminal"),
-        );
-
-        Ok(())
-    }
-
-    /// Execute a tool with parameters
-    async fn execute_tool(&mut self, tool_name: &str, params_str: &str) -> Result<()> {
-        if let Some(client) = &mut self.client {
-            // Parse parameters
-            let mut params: Value = serde_json::from_str(params_str)
-                .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
-
-            // Inject environment variables
-            if let Value::Object(ref mut map) = params {
-                for (key, value) in &self.env_variables {
-                    if !map.contains_key(key) {
-                        map.insert(key.clone(), Value::String(value.clone()));
+        if let Some(receiver) = self.reconnect_receiver.as_mut() {
+            match receiver.try_recv() {
+                Ok(reconnection) => {
+                    self.reconnect_receiver = None;
+                    self.client = Some(reconnection.client);
+                    match reconnection.result {
+                        Ok(()) => {
+                            // A restarted server process gets its own exit report
+                            self.process_exit_reported = false;
+                            self.reconnect_failed = false;
+                            self.state = AppState::Ready;
+                            if let Some((before, after)) = reconnection.lists {
+                                self.apply_restarted_lists(before, after);
+                            }
+                        }
+                        Err(e) => {
+                            self.reconnect_failed = true;
+                            self.add_error(format!("Failed to reconnect to the server: {}", e));
+                        }
                     }
                 }
+                Err(oneshot::error::TryRecvError::Empty) => {}
+                Err(oneshot::error::TryRecvError::Closed) => {
+                    self.reconnect_receiver = None;
+                    self.reconnect_failed = true;
+                    self.add_error("Reconnect task failed".to_string());
+                }
             }
+            return;
+        }
+
+        // Background requests hold a handle to the connection being replaced
+        if !self.config.client.reconnect
+            || self.reconnect_failed
+            || self.has_pending_requests(0)
+            || !self.client.as_ref().is_some_and(McpClient::connection_lost)
+        {
+            return;
+        }
+        let Some(mut client) = self.client.take() else {
+            return;
+        };
+        let (sender, receiver) = oneshot::channel();
+        self.reconnect_receiver = Some(receiver);
+        tokio::spawn(async move {
+            let result = client.reconnect().await.map(|_| ());
+            let _ = sender.send(Reconnection {
+                client,
+                result,
+                lists: None,
+            });
+        });
+    }
+
+    /// Connect to the further servers of the session in the background
+    fn connect_peers(&mut self) {
+        for peer in 0..self.peers.len() {
+            self.spawn_peer_connection(peer, None);
+        }
+    }
+
+    /// Connect a further server on a background task, or reconnect its client
+    fn spawn_peer_connection(&mut self, peer: usize, client: Option<McpClient>) {
+        let transport_config = self.peers[peer].transport_config.clone();
+        let client_info = self.client_info.clone();
+        let supervision = self
+            .config
+            .client
+            .reconnect
+            .then(SupervisionPolicy::default);
+        let (sender, receiver) = oneshot::channel();
+        self.peers[peer].connection = Some(receiver);
+        self.peers[peer].status = if client.is_some() {
+            ServerStatus::Reconnecting
+        } else {
+            ServerStatus::Connecting
+        };
+
+        tokio::spawn(async move {
+            let reconnect = client.is_some();
+            let mut client = match client {
+                Some(client) => client,
+                None => {
+                    // Elicitation and the raw composer stay with the first server
+                    let client_config = ClientConfig {
+                        refresh_on_list_changed: true,
+                        supervision,
+                        ..Default::default()
+                    };
+                    match McpClient::new(
+                        transport_config,
+                        client_config,
+                        Box::new(DefaultNotificationHandler),
+                    )
+                    .await
+                    {
+                        Ok(client) => client,
+                        Err(e) => {
+                            let _ = sender.send(PeerConnection {
+                                client: None,
+                                result: Err(e.to_string()),
+                            });
+                            return;
+                        }
+                    }
+                }
+            };
+            let connected = if reconnect {
+                client.reconnect().await
+            } else {
+                client.connect(client_info).await
+            };
+            let result = match connected {
+                Ok(server_info) => Ok((
+                    server_info.implementation,
+                    fetch_capability_lists(&mut client).await,
+                )),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = sender.send(PeerConnection {
+                client: Some(client),
+                result,
+            });
+        });
+    }
+
+    /// Pick up connected servers, state changes and refreshed capability
+    /// lists of the further servers, and reconnect lost ones
+    fn poll_peers(&mut self) {
+        for peer in 0..self.peers.len() {
+            let server = peer + 1;
+            let connection = match self.peers[peer].connection.as_mut().map(|r| r.try_recv()) {
+                Some(Ok(connection)) => Some(connection),
+                Some(Err(oneshot::error::TryRecvError::Closed)) => Some(PeerConnection {
+                    client: None,
+                    result: Err("connect task failed".to_string()),
+                }),
+                Some(Err(oneshot::error::TryRecvError::Empty)) | None => None,
+            };
+            if let Some(connection) = connection {
+                self.peers[peer].connection = None;
+                self.apply_peer_connection(peer, connection);
+            }
+
+            let mut events = Vec::new();
+            if let Some(receiver) = self.peers[peer].events.as_mut() {
+                loop {
+                    match receiver.try_recv() {
+                        Ok(event) => events.push(event.kind),
+                        Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                        Err(_) => break,
+                    }
+                }
+            }
+            for event in events {
+                match event {
+                    ClientEventKind::StateChanged {
+                        state: ClientState::Reconnecting { attempt },
+                        ..
+                    } => self.add_connection_message(format!(
+                        "Reconnecting to {} (attempt {})",
+                        self.peers[peer].name, attempt
+                    )),
+                    ClientEventKind::Reconnected { .. } => self.add_connection_message(format!(
+                        "Reconnected to {}",
+                        self.peers[peer].name
+                    )),
+                    _ => {}
+                }
+            }
+
+            let mut changes = Vec::new();
+            if let Some(receiver) = self.peers[peer].capability_changes.as_mut() {
+                while let Ok(change) = receiver.try_recv() {
+                    changes.push(change);
+                }
+            }
+            for change in changes {
+                match change {
+                    Ok(change) => self.apply_capability_change(server, change),
+                    Err(e) => self.add_error(format!(
+                        "Failed to refresh capabilities of {}: {}",
+                        self.peers[peer].name, e
+                    )),
+                }
+            }
+
+            // Background requests hold a handle to the connection being replaced
+            if self.config.client.reconnect
+                && self.peers[peer].current_status() == ServerStatus::Lost
+                && !self.has_pending_requests(server)
+            {
+                let client = self.peers[peer].client.take();
+                self.spawn_peer_connection(peer, client);
+            }
+        }
+    }
+
+    /// Take over a connected further server and merge its capability lists
+    fn apply_peer_connection(&mut self, peer: usize, connection: PeerConnection) {
+        let name = self.peers[peer].name.clone();
+        if let Some(mut client) = connection.client {
+            if self.peers[peer].events.is_none() {
+                self.peers[peer].events = Some(client.subscribe());
+                self.peers[peer].capability_changes = client.take_capability_changes();
+            }
+            self.peers[peer].client = Some(client);
+        }
+        match connection.result {
+            Ok((implementation, mut lists)) => {
+                let count = |kind| lists.get(&kind).map_or(0, Vec::len);
+                self.add_connection_message(format!(
+                    "Connected to {} ({} v{}): {} tools, {} resources, {} prompts",
+                    name,
+                    implementation.name,
+                    implementation.version,
+                    count(ListKind::Tools),
+                    count(ListKind::Resources),
+                    count(ListKind::Prompts)
+                ));
+                self.peers[peer].status = ServerStatus::Ready;
+                self.peers[peer].implementation = Some(implementation);
+                for kind in ListKind::ALL {
+                    let items = lists.remove(&kind).unwrap_or_default();
+                    self.replace_capability_list(peer + 1, kind, items);
+                }
+            }
+            Err(e) => {
+                let e = secrets::redact(&e);
+                self.add_error(format!("Failed to connect to {}: {}", name, e));
+                self.peers[peer].status = ServerStatus::Failed(e);
+            }
+        }
+    }
+
+    /// Connection state of every server, the first one first
+    fn server_statuses(&self) -> Vec<(&str, ServerStatus)> {
+        let first = match &self.state {
+            AppState::Initializing | AppState::Connecting | AppState::Discovering => {
+                ServerStatus::Connecting
+            }
+            AppState::Error(e) => ServerStatus::Failed(e.clone()),
+            AppState::Ready | AppState::ShuttingDown if self.client_reconnecting() => {
+                ServerStatus::Reconnecting
+            }
+            AppState::Ready | AppState::ShuttingDown
+                if self.client.as_ref().is_some_and(McpClient::connection_lost) =>
+            {
+                ServerStatus::Lost
+            }
+            AppState::Ready | AppState::ShuttingDown => ServerStatus::Ready,
+        };
+        std::iter::once((self.server_name.as_str(), first))
+            .chain(
+                self.peers
+                    .iter()
+                    .map(|peer| (peer.name.as_str(), peer.current_status())),
+            )
+            .collect()
+    }
+
+    /// Restart the server once watched files changed and the client is free
+    fn poll_file_changes(&mut self) {
+        if let Some(receiver) = self.file_changes.as_mut() {
+            while let Ok(changed) = receiver.try_recv() {
+                self.pending_restart.extend(changed);
+            }
+        }
+        if self.pending_restart.is_empty()
+            || self.client_reconnecting()
+            || self.has_pending_requests(0)
+        {
+            return;
+        }
+        let Some(mut client) = self.client.take() else {
+            return;
+        };
+
+        let mut changed = std::mem::take(&mut self.pending_restart);
+        changed.sort();
+        changed.dedup();
+        self.add_connection_message(format!(
+            "{} changed, restarting the server",
+            watch::summarize(&changed)
+        ));
+
+        let mut before = self.watch_lists.clone();
+        let (sender, receiver) = oneshot::channel();
+        self.reconnect_receiver = Some(receiver);
+        tokio::spawn(async move {
+            // The first restart compares with what the old server still lists
+            if before.is_empty() && !client.connection_lost() {
+                before = fetch_capability_lists(&mut client).await;
+            }
+            let result = client.reconnect().await.map(|_| ());
+            let after = match result {
+                Ok(()) => fetch_capability_lists(&mut client).await,
+                Err(_) => HashMap::new(),
+            };
+            let _ = sender.send(Reconnection {
+                client,
+                result,
+                lists: Some((before, after)),
+            });
+        });
+    }
+
+    /// Replace the capability lists re-fetched after a restart and record
+    /// what changed
+    fn apply_restarted_lists(&mut self, before: CapabilityLists, mut after: CapabilityLists) {
+        for kind in ListKind::ALL {
+            let Some(items) = after.remove(&kind) else {
+                continue;
+            };
+            if !self.replace_capability_list(0, kind, items.clone()) {
+                continue;
+            }
+            if let Some(previous) = before.get(&kind) {
+                let diff = ListDiff::between(kind, previous, &items);
+                if !diff.is_empty() {
+                    self.record_capability_diff(0, &diff);
+                }
+            }
+            self.watch_lists.insert(kind, items);
+        }
+    }
+
+    /// Whether the client is away on a background reconnect
+    fn client_reconnecting(&self) -> bool {
+        self.reconnect_receiver.is_some()
+    }
+
+    /// Record a connection event in the message history
+    fn add_connection_message(&mut self, message: String) {
+        self.add_message(MessageEntry {
+            timestamp: Instant::now(),
+            message_type: MessageType::Other("Connection".to_string()),
+            request: None,
+            response: None,
+            raw_response: None,
+            error: None,
+            success: Some(message),
+            latency: None,
+        });
+    }
+
+    /// Replace a server's capability list and record what changed in the timeline
+    fn apply_capability_change(&mut self, server: usize, change: CapabilityChange) {
+        if self.replace_capability_list(server, change.diff.kind, change.items) {
+            self.record_capability_diff(server, &change.diff);
+        }
+    }
+
+    /// Replace a server's capability list, returning whether it parsed
+    fn replace_capability_list(
+        &mut self,
+        server: usize,
+        kind: ListKind,
+        items: Vec<Value>,
+    ) -> bool {
+        let items = Value::Array(items);
+        let capabilities = &mut self.capabilities;
+        let replaced = match kind {
+            ListKind::Tools => serde_json::from_value::<Vec<Tool>>(items).map(|tools| {
+                let tools = tools.into_iter().map(|tool| tool_info(tool, server));
+                replace_server_items(&mut capabilities.tools, server, tools.collect(), |tool| {
+                    tool.server
+                })
+            }),
+            ListKind::Resources => {
+                serde_json::from_value::<Vec<Resource>>(items).map(|resources| {
+                    let resources = resources
+                        .into_iter()
+                        .map(|resource| resource_info(resource, server));
+                    replace_server_items(
+                        &mut capabilities.resources,
+                        server,
+                        resources.collect(),
+                        |resource| resource.server,
+                    )
+                })
+            }
+            ListKind::Prompts => serde_json::from_value::<Vec<Prompt>>(items).map(|prompts| {
+                let prompts = prompts
+                    .into_iter()
+                    .map(|prompt| prompt_info(prompt, server));
+                replace_server_items(
+                    &mut capabilities.prompts,
+                    server,
+                    prompts.collect(),
+                    |prompt| prompt.server,
+                )
+            }),
+        };
+        if let Err(e) = replaced {
+            self.add_error(format!(
+                "Failed to parse refreshed {}{}: {}",
+                kind,
+                self.server_suffix(server),
+                e
+            ));
+            return false;
+        }
+
+        // Indices into the replaced list no longer point at the same items
+        let stale = matches!(
+            (&self.ui_state.selected_capability, kind),
+            (Some(CapabilityRef::Tool(_)), ListKind::Tools)
+                | (Some(CapabilityRef::Resource(_)), ListKind::Resources)
+                | (Some(CapabilityRef::Prompt(_)), ListKind::Prompts)
+        );
+        if stale {
+            self.ui_state.selected_capability = None;
+        }
+        self.reindex_capabilities();
+        true
+    }
+
+    /// Show a capability list difference in the status bar and the timeline
+    fn record_capability_diff(&mut self, server: usize, diff: &ListDiff) {
+        let summary = format!("{}{}", diff.summary(), self.server_suffix(server));
+        self.capability_notice = Some((summary.clone(), Instant::now()));
+        self.add_message(MessageEntry {
+            timestamp: Instant::now(),
+            message_type: MessageType::CapabilitiesChanged,
+            request: None,
+            response: None,
+            raw_response: serde_json::to_value(diff).ok(),
+            error: None,
+            success: Some(summary),
+            latency: None,
+        });
+    }
+
+    /// Rebuild the search index from the current capability lists and
+    /// report tool names that newly collide across servers
+    fn reindex_capabilities(&mut self) {
+        self.search_engine = SearchEngine::new();
+        self.search_engine.set_server_names(self.server_names());
+        self.search_engine.index_tools(&self.capabilities.tools);
+        self.search_engine
+            .index_resources(&self.capabilities.resources);
+        self.search_engine.index_prompts(&self.capabilities.prompts);
+        self.ui_state.search_results.clear();
+
+        let collisions = name_collisions(
+            self.capabilities
+                .tools
+                .iter()
+                .map(|tool| (self.server_label(tool.server), tool.full_name.as_str())),
+        );
+        let new: Vec<String> = collisions
+            .iter()
+            .filter(|(tool, servers)| self.tool_collisions.get(*tool) != Some(*servers))
+            .map(|(tool, servers)| format!("'{}' on {}", tool, servers.join(", ")))
+            .collect();
+        self.tool_collisions = collisions;
+        if !new.is_empty() {
+            self.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::Other("Name Collision".to_string()),
+                request: None,
+                response: None,
+                raw_response: None,
+                error: Some(format!(
+                    "Tool names offered by several servers: {}",
+                    new.join("; ")
+                )),
+                success: None,
+                latency: None,
+            });
+        }
+    }
+
+    /// Name of a server by index, 0 being the first
+    fn server_label(&self, server: usize) -> &str {
+        match server.checked_sub(1) {
+            None => &self.server_name,
+            Some(peer) => self.peers.get(peer).map_or("?", |peer| peer.name.as_str()),
+        }
+    }
+
+    /// Names of all servers, the first one first
+    fn server_names(&self) -> Vec<String> {
+        std::iter::once(self.server_name.clone())
+            .chain(self.peers.iter().map(|peer| peer.name.clone()))
+            .collect()
+    }
+
+    /// Whether the session has more than one server
+    fn multi_server(&self) -> bool {
+        !self.peers.is_empty()
+    }
+
+    /// ` on <server>` in a multi-server session, for messages about a server
+    fn server_suffix(&self, server: usize) -> String {
+        if self.multi_server() {
+            format!(" on {}", self.server_label(server))
+        } else {
+            String::new()
+        }
+    }
+
+    /// Dispatch server-to-client requests received since the last tick
+    fn poll_server_requests(&mut self) {
+        // Show one elicitation at a time; later requests wait in the channel
+        while self.ui_state.elicitation.is_none() {
+            let Some(receiver) = self.server_requests.as_mut() else {
+                return;
+            };
+            let Ok(server_request) = receiver.try_recv() else {
+                return;
+            };
+
+            if server_request.request.method == ELICITATION_METHOD {
+                self.open_elicitation(server_request);
+                continue;
+            }
+
+            let method = server_request.request.method.clone();
+            tracing::warn!("Rejecting unsupported server request: {}", method);
+            let response = JsonRpcResponse::error(
+                server_request.request.id.clone(),
+                JsonRpcError::method_not_found(&method),
+            );
+            if let Err(e) = server_request.respond(response) {
+                tracing::error!("Failed to reject server request '{}': {}", method, e);
+            }
+            self.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::Other("Server Request".to_string()),
+                request: None,
+                response: None,
+                raw_response: None,
+                error: Some(format!("Unsupported server request: {}", method)),
+                success: None,
+                latency: None,
+            });
+        }
+    }
+
+    /// Open the parameter form dialog for an elicitation request
+    fn open_elicitation(&mut self, server_request: ServerRequest) {
+        let elicit = match server_request.request.params_as::<ElicitRequest>() {
+            Ok(elicit) => elicit,
+            Err(e) => {
+                let response = JsonRpcResponse::error(
+                    server_request.request.id.clone(),
+                    JsonRpcError::invalid_params(e.to_string()),
+                );
+                let _ = server_request.respond(response);
+                self.add_error(format!("Invalid elicitation request: {}", e));
+                return;
+            }
+        };
+
+        tracing::info!("Server requested user input: {}", elicit.message);
+
+        // Reuse the tool parameter form for the requested schema
+        self.ui_state.param_fields.clear();
+        self.ui_state.param_field_names.clear();
+        self.ui_state.param_selected_field = 0;
+        self.ui_state.param_edit_mode = false;
+        self.build_parameter_form_from_schema(&elicit.requested_schema);
+        if !self.ui_state.param_field_names.is_empty() {
+            self.ui_state.param_edit_mode = true;
+        }
+
+        self.ui_state.selected_capability = None;
+        self.ui_state.parameter_dialog_open = true;
+        self.ui_state.response_viewer_open = false;
+        self.ui_state.help_dialog_open = false;
+        self.ui_state.search_active = false;
+
+        self.add_message(MessageEntry {
+            timestamp: Instant::now(),
+            message_type: MessageType::Other("Elicitation".to_string()),
+            request: Some(server_request.request.clone()),
+            response: None,
+            raw_response: None,
+            error: None,
+            success: Some(format!("Server asks: {}", elicit.message)),
+            latency: None,
+        });
+
+        self.ui_state.elicitation = Some(PendingElicitation {
+            request: server_request,
+            elicit,
+        });
+    }
+
+    /// Accept the open elicitation with the current form values
+    fn accept_elicitation(&mut self) {
+        let Some(pending) = &self.ui_state.elicitation else {
+            return;
+        };
+
+        use mcp_probe_core::validation::ParameterValidator;
+        let content = self.collect_parameter_values();
+        let validation =
+            ParameterValidator::new().validate(&pending.elicit.requested_schema, &content);
+
+        if !validation.is_valid {
+            self.add_error(format!(
+                "Elicitation answer does not match the requested schema: {}",
+                validation
+                    .errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            return;
+        }
+
+        self.respond_to_elicitation(ElicitResult::accept(validation.validated_params));
+    }
+
+    /// Send the answer for the open elicitation and close the dialog
+    fn respond_to_elicitation(&mut self, result: ElicitResult) {
+        let Some(pending) = self.ui_state.elicitation.take() else {
+            return;
+        };
+
+        self.ui_state.parameter_dialog_open = false;
+        self.ui_state.param_edit_mode = false;
+        self.ui_state.param_fields.clear();
+        self.ui_state.param_field_names.clear();
+        self.ui_state.current_focus = FocusedPanel::Capabilities;
+
+        let result_json = serde_json::to_value(&result).unwrap_or(Value::Null);
+        let response =
+            JsonRpcResponse::success(pending.request.request.id.clone(), result_json.clone());
+
+        match pending.request.respond(response.clone()) {
+            Ok(()) => {
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::Other("Elicitation".to_string()),
+                    request: None,
+                    response: Some(response),
+                    raw_response: Some(result_json),
+                    error: None,
+                    success: Some(format!("Answered elicitation: {}", result.action)),
+                    latency: None,
+                });
+            }
+            Err(e) => {
+                self.add_error(format!("Failed to answer elicitation: {}", e));
+            }
+        }
+    }
+
+    /// Handle keys while an elicitation dialog is open
+    fn handle_elicitation_key(&mut self, key: KeyEvent) {
+        let action = if self.ui_state.param_edit_mode {
+            self.keymap.resolve_while_typing(&key, Action::ELICITATION)
+        } else {
+            self.keymap.resolve(&key, Action::ELICITATION)
+        };
+        match action {
+            Some(Action::Execute) => self.accept_elicitation(),
+            Some(Action::Confirm) if self.ui_state.param_field_names.is_empty() => {
+                self.accept_elicitation()
+            }
+            Some(Action::Confirm) if self.ui_state.param_edit_mode => {
+                self.ui_state.param_edit_mode = false;
+                self.navigate_parameter_form_down();
+            }
+            Some(Action::Confirm) => self.ui_state.param_edit_mode = true,
+            Some(Action::Decline) => self.respond_to_elicitation(ElicitResult::decline()),
+            Some(Action::Cancel) if self.ui_state.param_edit_mode => {
+                self.ui_state.param_edit_mode = false;
+            }
+            Some(Action::Cancel) => self.respond_to_elicitation(ElicitResult::cancel()),
+            Some(Action::Up) if !self.ui_state.param_edit_mode => self.navigate_parameter_form_up(),
+            Some(Action::Down) if !self.ui_state.param_edit_mode => {
+                self.navigate_parameter_form_down()
+            }
+            _ if self.ui_state.param_edit_mode => self.handle_parameter_field_input(key.code),
+            _ => {}
+        }
+    }
+
+    /// Execute selected capability with form parameters
+    async fn execute_selected_capability(&mut self) -> Result<()> {
+        if let Some(ref capability_ref) = self.ui_state.selected_capability.clone() {
+            let params_value = self.collect_parameter_values();
+
+            match capability_ref {
+                CapabilityRef::Tool(index) => {
+                    if let Some(tool) = self.capabilities.tools.get(*index) {
+                        let tool_name = tool.full_name.clone(); // Use full name with prefix for API call
+                        let server = tool.server;
+                        let params_str = params_value.to_string();
+                        self.execute_tool(server, &tool_name, &params_str).await?;
+                    }
+                }
+                CapabilityRef::Resource(index) => {
+                    if let Some(resource) = self.capabilities.resources.get(*index) {
+                        let resource_uri = resource.uri.clone();
+                        let server = resource.server;
+                        self.get_resource(server, &resource_uri).await?;
+                    }
+                }
+                CapabilityRef::Prompt(index) => {
+                    if let Some(prompt) = self.capabilities.prompts.get(*index) {
+                        let prompt_name = prompt.name.clone();
+                        let server = prompt.server;
+                        let params_str = params_value.to_string();
+                        self.get_prompt(server, &prompt_name, &params_str).await?;
+                    }
+                }
+            }
+
+            // Close the parameter dialog
+            self.ui_state.parameter_dialog_open = false;
+            self.ui_state.selected_capability = None;
+        }
+
+        Ok(())
+    }
+
+    /// Execute a command from the input area
+    async fn execute_command(&mut self) -> Result<()> {
+        let input = self.ui_state.input_area.lines().join(" ");
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(());
+        }
+        self.command_history.push(input);
+
+        let last = self.last_response();
+        let context = command::Context {
+            variables: &self.env_variables,
+            last: last.as_ref(),
+        };
+        match command::parse(input, &context) {
+            Ok(command) => {
+                self.ui_state.input_area = terminal_input("");
+                self.run_command(command).await?;
+            }
+            // Keep the input so the command can be corrected
+            Err(e) => self.add_error(e),
+        }
+
+        Ok(())
+    }
+
+    /// The latest response payload, as `$last` sees it
+    fn last_response(&self) -> Option<Value> {
+        self.message_history
+            .iter()
+            .rev()
+            .find_map(|message| message.raw_response.clone())
+            .map(|result| serde_json::json!({ "result": result }))
+    }
+
+    /// Send a parsed terminal command
+    ///
+    /// In a multi-server session, `tools.<name>@<server>` (and likewise for
+    /// resources and prompts) picks the server; otherwise the one offering
+    /// the capability is used. Meta-commands go to the first server.
+    async fn run_command(&mut self, command: Command) -> Result<()> {
+        match command {
+            Command::Tool { name, arguments } => {
+                // Find the tool by clean name and use full name for execution
+                let (name, server) = self.split_server(&name);
+                let matches: Vec<&ToolInfo> = self
+                    .capabilities
+                    .tools
+                    .iter()
+                    .filter(|t| t.name == name || t.full_name == name)
+                    .filter(|t| server.map_or(true, |server| t.server == server))
+                    .collect();
+                match matches.as_slice() {
+                    [] => self.add_error(format!("Tool '{}' not found", name)),
+                    [tool, others @ ..] if others.iter().all(|t| t.server == tool.server) => {
+                        let (full_name, server) = (tool.full_name.clone(), tool.server);
+                        self.execute_tool(server, &full_name, &arguments.to_string())
+                            .await?;
+                    }
+                    tools => {
+                        let error =
+                            self.ambiguous_error("Tool", name, tools.iter().map(|t| t.server));
+                        self.add_error(error);
+                    }
+                }
+            }
+            Command::Resource { uri } => {
+                let (uri, server) = self.split_server(&uri);
+                // Resources the server does not list, such as templated ones,
+                // go to the first server
+                let server = server.unwrap_or_else(|| {
+                    self.capabilities
+                        .resources
+                        .iter()
+                        .find(|r| r.uri == uri)
+                        .map_or(0, |r| r.server)
+                });
+                self.get_resource(server, uri).await?
+            }
+            Command::Prompt { name, arguments } => {
+                let (name, server) = self.split_server(&name);
+                let offering: BTreeSet<usize> = self
+                    .capabilities
+                    .prompts
+                    .iter()
+                    .filter(|p| p.name == name)
+                    .map(|p| p.server)
+                    .collect();
+                match server {
+                    Some(server) => {
+                        self.get_prompt(server, name, &arguments.to_string())
+                            .await?
+                    }
+                    None if offering.len() > 1 => {
+                        let error = self.ambiguous_error("Prompt", name, offering);
+                        self.add_error(error);
+                    }
+                    None => {
+                        let server = offering.first().copied().unwrap_or(0);
+                        self.get_prompt(server, name, &arguments.to_string())
+                            .await?
+                    }
+                }
+            }
+            Command::Ping => self.send_raw("ping", serde_json::json!({})),
+            Command::Raw { method, params } => self.send_raw(&method, params),
+            Command::SetLevel(level) => {
+                self.send_raw("logging/setLevel", serde_json::json!({ "level": level }))
+            }
+            Command::Subscribe(uri) => {
+                self.send_raw("resources/subscribe", serde_json::json!({ "uri": uri }))
+            }
+        }
+        Ok(())
+    }
+
+    /// Split `name@server` into the name and the named server's index; a
+    /// suffix that names no server stays part of the name
+    fn split_server<'a>(&self, reference: &'a str) -> (&'a str, Option<usize>) {
+        if let Some((name, server)) = reference.rsplit_once('@') {
+            if let Some(index) = self.server_names().iter().position(|known| known == server) {
+                return (name, Some(index));
+            }
+        }
+        (reference, None)
+    }
+
+    /// Error for a name offered by several servers, suggesting `name@server`
+    fn ambiguous_error(
+        &self,
+        kind: &str,
+        name: &str,
+        servers: impl IntoIterator<Item = usize>,
+    ) -> String {
+        let mut servers: Vec<&str> = servers
+            .into_iter()
+            .map(|server| self.server_label(server))
+            .collect();
+        servers.dedup();
+        format!(
+            "{} '{}' is offered by {}; pick one with {}@{}",
+            kind,
+            name,
+            servers.join(", "),
+            name,
+            servers[0]
+        )
+    }
+
+    /// Send an arbitrary request to the first server on a background task
+    fn send_raw(&mut self, method: &str, params: Value) {
+        if self.client_available(0, &format!("send '{}'", method)) {
+            self.spawn_request(
+                0,
+                BackgroundCall::Raw {
+                    method: method.to_string(),
+                    params,
+                },
+            );
+        }
+    }
+
+    /// Record the replies to a message sent from the composer
+    fn handle_raw_message_result(&mut self, payload: &str, result: McpResult<Vec<String>>) {
+        let parse = |text: &str| {
+            serde_json::from_str::<Value>(text).unwrap_or_else(|_| Value::String(text.to_string()))
+        };
+        match result {
+            Ok(replies) => {
+                let summary = match replies.len() {
+                    0 => format!("Sent {} bytes, replies appear as Inbound", payload.len()),
+                    1 => format!(
+                        "Sent {} bytes → {}",
+                        payload.len(),
+                        truncate_summary(&replies[0])
+                    ),
+                    n => format!("Sent {} bytes → {} replies", payload.len(), n),
+                };
+                let exchange = serde_json::json!({
+                    "sent": parse(payload),
+                    "replies": replies.iter().map(|reply| parse(reply)).collect::<Vec<_>>(),
+                });
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::RawMessage,
+                    request: serde_json::from_str(payload).ok(),
+                    response: None,
+                    raw_response: Some(exchange.clone()),
+                    error: None,
+                    success: Some(summary),
+                    latency: None,
+                });
+                if !replies.is_empty() {
+                    self.open_response_viewer(exchange);
+                }
+            }
+            Err(e) => self.add_error(format!("Raw message failed: {}", e)),
+        }
+    }
+
+    /// Record the response to a request sent with [`Self::send_raw`]
+    fn handle_raw_result(&mut self, method: &str, result: McpResult<JsonRpcResponse>) {
+        match result {
+            Ok(response) => match response.error {
+                Some(error) => self.add_error(format!(
+                    "{} failed: {} (code {})",
+                    method, error.message, error.code
+                )),
+                None => {
+                    let result = response.result.unwrap_or(Value::Null);
+                    let summary = truncate_summary(&result.to_string());
+                    self.add_message(MessageEntry {
+                        timestamp: Instant::now(),
+                        message_type: MessageType::Other(method.to_string()),
+                        request: None,
+                        response: None,
+                        raw_response: Some(result),
+                        error: None,
+                        success: Some(format!("{} → {}", method, summary)),
+                        latency: None,
+                    });
+                }
+            },
+            Err(e) => self.add_error(format!("{} failed: {}", method, e)),
+        }
+    }
+
+    /// Execute a server's tool with parameters
+    async fn execute_tool(
+        &mut self,
+        server: usize,
+        tool_name: &str,
+        params_str: &str,
+    ) -> Result<()> {
+        if !self.client_available(server, &format!("call tool '{}'", tool_name)) {
+            return Ok(());
+        }
+
+        if self.server_client(server).is_some() {
+            // Parse parameters
+            let mut params: Value = serde_json::from_str(params_str)
+                .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
 
             // Find tool schema for validation
             let tool_schema = self
                 .capabilities
                 .tools
                 .iter()
+                .filter(|t| t.server == server)
                 .find(|t| t.full_name == tool_name || t.name == tool_name)
                 .and_then(|t| t.parameters.as_ref());
 
@@ -1977,178 +3958,410 @@ impl DebuggerApp {
                     .unwrap_or_else(|_| "Failed to serialize params".to_string())
             );
 
-            match client.call_tool(tool_name, params.clone()).await {
-                Ok(result) => {
-                    tracing::info!("=== TOOL EXECUTION RESULT DEBUG ===");
-                    tracing::info!("Tool: '{}'", tool_name);
-                    tracing::info!("Content items: {}", result.content.len());
-                    tracing::info!("is_error: {:?}", result.is_error);
+            self.spawn_request(
+                server,
+                BackgroundCall::Tool {
+                    name: tool_name.to_string(),
+                    params,
+                },
+            );
+        }
+        Ok(())
+    }
 
-                    // Log the raw result structure for debugging
-                    tracing::debug!("Raw result structure: {:#?}", result);
+    /// Whether a new request can be sent to a server, reporting why not otherwise
+    fn client_available(&mut self, server: usize, action: &str) -> bool {
+        let status = match server.checked_sub(1) {
+            None if self.client_reconnecting() => Some(ServerStatus::Reconnecting),
+            None => None,
+            Some(peer) => self.peers.get(peer).map(|peer| peer.status.clone()),
+        };
+        match status {
+            Some(ServerStatus::Reconnecting) => {
+                self.add_error(format!(
+                    "Cannot {} while reconnecting to the server{}",
+                    action,
+                    self.server_suffix(server)
+                ));
+                return false;
+            }
+            Some(ServerStatus::Connecting) => {
+                self.add_error(format!(
+                    "Cannot {} before connecting to {}",
+                    action,
+                    self.server_label(server)
+                ));
+                return false;
+            }
+            Some(ServerStatus::Failed(_)) if self.server_client(server).is_none() => {
+                self.add_error(format!(
+                    "Cannot {}: not connected to {}",
+                    action,
+                    self.server_label(server)
+                ));
+                return false;
+            }
+            _ => {}
+        }
+        let available = self.server_client(server).is_some();
+        if !available && self.has_pending_requests(server) {
+            self.add_error(format!(
+                "Cannot {} until the running request finishes (this transport handles one request at a time)",
+                action
+            ));
+            return false;
+        }
+        available
+    }
 
-                    // Log the actual content for debugging
-                    if result.content.is_empty() {
-                        tracing::warn!(
-                            "⚠️  Tool returned EMPTY content array - this might indicate:"
-                        );
-                        tracing::warn!("   - Tool executed but produced no output");
-                        tracing::warn!("   - Response parsing issue");
-                        tracing::warn!("   - Server-side tool implementation issue");
-                    } else {
-                        tracing::info!("📄 Tool returned {} content items:", result.content.len());
-                        for (i, content_item) in result.content.iter().enumerate() {
-                            match content_item {
-                                mcp_probe_core::messages::tools::ToolResult::Text { text } => {
-                                    tracing::info!(
-                                        "Content[{}]: Text with {} chars: '{}'",
-                                        i,
-                                        text.len(),
-                                        if text.len() > 200 { &text[..200] } else { text }
-                                    );
-                                }
-                                mcp_probe_core::messages::tools::ToolResult::Image {
+    /// Client of a server, unless it is away on a request or reconnect
+    fn server_client(&self, server: usize) -> Option<&McpClient> {
+        match server.checked_sub(1) {
+            None => self.client.as_ref(),
+            Some(peer) => self.peers.get(peer)?.client.as_ref(),
+        }
+    }
+
+    /// Slot holding a server's client
+    fn server_client_slot(&mut self, server: usize) -> Option<&mut Option<McpClient>> {
+        match server.checked_sub(1) {
+            None => Some(&mut self.client),
+            Some(peer) => self.peers.get_mut(peer).map(|peer| &mut peer.client),
+        }
+    }
+
+    /// Whether requests to a server are still running
+    fn has_pending_requests(&self, server: usize) -> bool {
+        self.pending_requests
+            .iter()
+            .any(|pending| pending.server == server)
+    }
+
+    /// Run a request on a background task; the result arrives on the completion channel
+    fn spawn_request(&mut self, server: usize, call: BackgroundCall) {
+        let Some(slot) = self.server_client_slot(server) else {
+            return;
+        };
+        let mut requester = match slot.as_ref().and_then(McpClient::request_handle) {
+            Some(handle) => Requester::Shared(handle),
+            None => match slot.take() {
+                Some(client) => Requester::Owned(Box::new(client)),
+                None => return,
+            },
+        };
+
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let label = if self.multi_server() {
+            format!("{}: {}", self.server_label(server), call.label())
+        } else {
+            call.label()
+        };
+        tracing::debug!("Dispatching background request #{}: {}", id, label);
+
+        let sender = self.completion_sender.clone();
+        let started = Instant::now();
+        let task = tokio::spawn(async move {
+            let (result, response) = call.run(&mut requester).await;
+            let client = match requester {
+                Requester::Shared(_) => None,
+                Requester::Owned(client) => Some(*client),
+            };
+            let _ = sender.send(RequestCompletion {
+                id,
+                server,
+                call,
+                result,
+                response,
+                elapsed: started.elapsed(),
+                client,
+            });
+        });
+
+        self.pending_requests.push(PendingRequest {
+            id,
+            server,
+            label,
+            started,
+            task,
+        });
+    }
+
+    /// Process background requests that finished since the last frame
+    fn poll_completed_requests(&mut self) {
+        // Tasks send their completion before finishing, so any task finished
+        // now has its completion queued unless it panicked
+        let finished: Vec<u64> = self
+            .pending_requests
+            .iter()
+            .filter(|pending| pending.task.is_finished())
+            .map(|pending| pending.id)
+            .collect();
+
+        while let Ok(completion) = self.completion_receiver.try_recv() {
+            self.pending_requests
+                .retain(|pending| pending.id != completion.id);
+            if let Some(client) = completion.client {
+                if let Some(slot) = self.server_client_slot(completion.server) {
+                    *slot = Some(client);
+                }
+            }
+            tracing::debug!(
+                "Background request #{} finished after {:?}",
+                completion.id,
+                completion.elapsed
+            );
+
+            let messages_before = self.message_count;
+            let request = completion.call.request().map(|(method, params)| {
+                let id = completion
+                    .response
+                    .as_ref()
+                    .map_or(RequestId::Null, |response| response.id.clone());
+                JsonRpcRequest::new(id, method, params)
+            });
+            match (completion.call, completion.result) {
+                (BackgroundCall::Tool { name, params }, BackgroundResult::Tool(result)) => {
+                    self.handle_tool_result(&name, &params, result)
+                }
+                (BackgroundCall::Resource { uri }, BackgroundResult::Resource(result)) => {
+                    self.handle_resource_result(&uri, result)
+                }
+                (BackgroundCall::Prompt { name, .. }, BackgroundResult::Prompt(result)) => {
+                    self.handle_prompt_result(&name, result)
+                }
+                (BackgroundCall::Raw { method, .. }, BackgroundResult::Raw(result)) => {
+                    self.handle_raw_result(&method, result)
+                }
+                (
+                    BackgroundCall::RawMessage { payload, .. },
+                    BackgroundResult::RawMessage(result),
+                ) => self.handle_raw_message_result(&payload, result),
+                (call, _) => tracing::error!("Mismatched result for {}", call.label()),
+            }
+
+            // Say which server answered
+            if self.multi_server() {
+                let name = self.server_label(completion.server).to_string();
+                let added = (self.message_count - messages_before).min(self.message_history.len());
+                let start = self.message_history.len() - added;
+                for entry in &mut self.message_history[start..] {
+                    for text in [&mut entry.success, &mut entry.error].into_iter().flatten() {
+                        *text = format!("[{}] {}", name, text);
+                    }
+                }
+            }
+
+            // Pair the history entry recorded for this request with what was
+            // sent and received, and how long it took
+            if self.message_count == messages_before + 1 {
+                if let Some(entry) = self.message_history.last_mut() {
+                    entry.latency = Some(completion.elapsed);
+                    entry.request = entry.request.take().or(request);
+                    entry.response = entry.response.take().or(completion.response);
+                }
+                self.refresh_inspector();
+            }
+        }
+
+        let (lost, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_requests)
+            .into_iter()
+            .partition(|pending| finished.contains(&pending.id));
+        self.pending_requests = pending;
+        for request in lost {
+            self.add_error(format!("Background request '{}' failed", request.label));
+        }
+    }
+
+    /// Record a tool call result in the history and open the response viewer
+    fn handle_tool_result(
+        &mut self,
+        tool_name: &str,
+        params: &Value,
+        result: McpResult<CallToolResponse>,
+    ) {
+        match result {
+            Ok(result) => {
+                tracing::info!("=== TOOL EXECUTION RESULT DEBUG ===");
+                tracing::info!("Tool: '{}'", tool_name);
+                tracing::info!("Content items: {}", result.content.len());
+                tracing::info!("is_error: {:?}", result.is_error);
+
+                // Log the raw result structure for debugging
+                tracing::debug!("Raw result structure: {:#?}", result);
+
+                // Log the actual content for debugging
+                if result.content.is_empty() {
+                    tracing::warn!("⚠️  Tool returned EMPTY content array - this might indicate:");
+                    tracing::warn!("   - Tool executed but produced no output");
+                    tracing::warn!("   - Response parsing issue");
+                    tracing::warn!("   - Server-side tool implementation issue");
+                } else {
+                    tracing::info!("📄 Tool returned {} content items:", result.content.len());
+                    for (i, content_item) in result.content.iter().enumerate() {
+                        match content_item {
+                            mcp_probe_core::messages::tools::ToolResult::Text { text } => {
+                                tracing::info!(
+                                    "Content[{}]: Text with {} chars: '{}'",
+                                    i,
+                                    text.len(),
+                                    if text.len() > 200 { &text[..200] } else { text }
+                                );
+                            }
+                            mcp_probe_core::messages::tools::ToolResult::Image {
+                                mime_type,
+                                data,
+                            } => {
+                                tracing::info!(
+                                    "Content[{}]: Image {} with {} bytes",
+                                    i,
                                     mime_type,
-                                    data,
-                                } => {
-                                    tracing::info!(
-                                        "Content[{}]: Image {} with {} bytes",
-                                        i,
-                                        mime_type,
-                                        data.len()
-                                    );
-                                }
-                                mcp_probe_core::messages::tools::ToolResult::Resource {
-                                    resource,
-                                } => {
-                                    tracing::info!("Content[{}]: Resource {}", i, resource.uri);
-                                }
+                                    data.len()
+                                );
+                            }
+                            mcp_probe_core::messages::tools::ToolResult::Audio {
+                                mime_type,
+                                data,
+                            } => {
+                                tracing::info!(
+                                    "Content[{}]: Audio {} with {} bytes",
+                                    i,
+                                    mime_type,
+                                    data.len()
+                                );
+                            }
+                            mcp_probe_core::messages::tools::ToolResult::ResourceLink {
+                                uri,
+                                ..
+                            } => {
+                                tracing::info!("Content[{}]: Resource link {}", i, uri);
+                            }
+                            mcp_probe_core::messages::tools::ToolResult::Resource { resource } => {
+                                tracing::info!("Content[{}]: Resource {}", i, resource.uri);
+                            }
+                            other => {
+                                tracing::info!(
+                                    "Content[{}]: Unknown content type '{}'",
+                                    i,
+                                    other.content_type()
+                                );
                             }
                         }
                     }
+                }
 
-                    // Convert result to JSON value for better handling
-                    let result_json = match serde_json::to_value(&result) {
-                        Ok(json) => {
-                            tracing::info!("=== SERIALIZED RESULT JSON ===");
-                            tracing::info!(
-                                "{}",
-                                serde_json::to_string_pretty(&json)
-                                    .unwrap_or_else(|_| "Failed to pretty print".to_string())
-                            );
+                // Convert result to JSON value for better handling
+                let result_json = match serde_json::to_value(&result) {
+                    Ok(json) => {
+                        tracing::info!("=== SERIALIZED RESULT JSON ===");
+                        tracing::info!(
+                            "{}",
+                            serde_json::to_string_pretty(&json)
+                                .unwrap_or_else(|_| "Failed to pretty print".to_string())
+                        );
 
-                            // Check if there are any unexpected fields
-                            if let Some(obj) = json.as_object() {
+                        // Check if there are any unexpected fields
+                        if let Some(obj) = json.as_object() {
+                            tracing::debug!("Response contains {} top-level fields:", obj.len());
+                            for (key, value) in obj {
                                 tracing::debug!(
-                                    "Response contains {} top-level fields:",
-                                    obj.len()
+                                    "  {}: {}",
+                                    key,
+                                    if value.is_string() || value.is_number() || value.is_boolean()
+                                    {
+                                        value.to_string()
+                                    } else {
+                                        format!(
+                                            "{} (type: {})",
+                                            if value.is_array() {
+                                                "array"
+                                            } else if value.is_object() {
+                                                "object"
+                                            } else {
+                                                "other"
+                                            },
+                                            if value.is_array() {
+                                                format!(
+                                                    "length {}",
+                                                    value.as_array().map_or(0, |arr| arr.len())
+                                                )
+                                            } else {
+                                                "".to_string()
+                                            }
+                                        )
+                                    }
                                 );
-                                for (key, value) in obj {
-                                    tracing::debug!(
-                                        "  {}: {}",
-                                        key,
-                                        if value.is_string()
-                                            || value.is_number()
-                                            || value.is_boolean()
-                                        {
-                                            value.to_string()
-                                        } else {
-                                            format!(
-                                                "{} (type: {})",
-                                                if value.is_array() {
-                                                    "array"
-                                                } else if value.is_object() {
-                                                    "object"
-                                                } else {
-                                                    "other"
-                                                },
-                                                if value.is_array() {
-                                                    format!(
-                                                        "length {}",
-                                                        value.as_array().map_or(0, |arr| arr.len())
-                                                    )
-                                                } else {
-                                                    "".to_string()
-                                                }
-                                            )
-                                        }
-                                    );
-                                }
                             }
-
-                            json
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to serialize tool result: {}", e);
-                            self.add_error(format!("Failed to serialize tool result: {}", e));
-                            return Ok(());
                         }
-                    };
 
-                    let success_summary = self.format_tool_response_summary(&result);
-                    tracing::info!("=== SUCCESS SUMMARY ===");
-                    tracing::info!("{}", success_summary);
-
-                    // Additional analysis for empty responses
-                    if result.content.is_empty() {
-                        tracing::warn!("🔍 INVESTIGATING EMPTY RESPONSE:");
-                        tracing::warn!("  - Tool name used: '{}'", tool_name);
-                        tracing::warn!(
-                            "  - Parameters sent: {}",
-                            serde_json::to_string(&params)
-                                .unwrap_or_else(|_| "Failed to serialize".to_string())
-                        );
-                        tracing::warn!("  - is_error flag: {:?}", result.is_error);
-                        tracing::warn!("  - This suggests the tool executed successfully but returned no content");
-                        tracing::warn!("  - Check if the tool implementation on the server actually returns content");
+                        json
                     }
-
-                    self.add_message(MessageEntry {
-                        timestamp: Instant::now(),
-                        message_type: MessageType::CallTool,
-                        request: None,
-                        response: None,
-                        raw_response: Some(result_json.clone()),
-                        error: None,
-                        success: Some(success_summary),
-                    });
-
-                    // Auto-open response viewer for successful tool execution
-                    self.ui_state.selected_response = Some(result_json);
-                    self.ui_state.response_viewer_open = true;
-                    // Reset scroll positions to start from top
-                    self.ui_state.response_viewer_vertical_pos = 0;
-                    self.ui_state.response_viewer_horizontal_pos = 0;
-                    // Select the latest message in history
-                    if !self.message_history.is_empty() {
-                        self.ui_state
-                            .message_history_state
-                            .select(Some(self.message_history.len() - 1));
+                    Err(e) => {
+                        tracing::error!("Failed to serialize tool result: {}", e);
+                        self.add_error(format!("Failed to serialize tool result: {}", e));
+                        return;
                     }
+                };
 
-                    tracing::info!(
-                        "Tool '{}' executed successfully - response viewer opened",
-                        tool_name
+                let success_summary = self.format_tool_response_summary(&result);
+                tracing::info!("=== SUCCESS SUMMARY ===");
+                tracing::info!("{}", success_summary);
+
+                // Additional analysis for empty responses
+                if result.content.is_empty() {
+                    tracing::warn!("🔍 INVESTIGATING EMPTY RESPONSE:");
+                    tracing::warn!("  - Tool name used: '{}'", tool_name);
+                    tracing::warn!(
+                        "  - Parameters sent: {}",
+                        serde_json::to_string(&params)
+                            .unwrap_or_else(|_| "Failed to serialize".to_string())
+                    );
+                    tracing::warn!("  - is_error flag: {:?}", result.is_error);
+                    tracing::warn!(
+                        "  - This suggests the tool executed successfully but returned no content"
                     );
+                    tracing::warn!("  - Check if the tool implementation on the server actually returns content");
                 }
-                Err(e) => {
-                    tracing::error!("=== TOOL EXECUTION FAILED ===");
-                    tracing::error!("Tool: '{}'", tool_name);
-                    tracing::error!("Error: {}", e);
-                    tracing::error!("Error debug: {:?}", e);
-
-                    // Try to get more specific error information
-                    let error_msg = if e.to_string().contains("Serialization error") {
-                        format!("🔧 Tool '{}' execution failed with SERIALIZATION ERROR: {}\n💡 This usually means:\n  - Parameter format is incorrect\n  - Tool name is malformed\n  - Server rejected the request format", tool_name, e)
-                    } else {
-                        format!("Tool '{}' execution failed: {}", tool_name, e)
-                    };
 
-                    self.add_error(error_msg);
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::CallTool,
+                    request: None,
+                    response: None,
+                    raw_response: Some(result_json.clone()),
+                    error: None,
+                    success: Some(success_summary),
+                    latency: None,
+                });
+
+                // Auto-open response viewer for successful tool execution
+                self.open_response_viewer(result_json);
+                // Select the latest message in history
+                if let Some(latest) = self.message_history.len().checked_sub(1) {
+                    self.select_message(latest);
                 }
+
+                tracing::info!(
+                    "Tool '{}' executed successfully - response viewer opened",
+                    tool_name
+                );
+            }
+            Err(e) => {
+                tracing::error!("=== TOOL EXECUTION FAILED ===");
+                tracing::error!("Tool: '{}'", tool_name);
+                tracing::error!("Error: {}", e);
+                tracing::error!("Error debug: {:?}", e);
+
+                // Try to get more specific error information
+                let error_msg = if e.to_string().contains("Serialization error") {
+                    format!("🔧 Tool '{}' execution failed with SERIALIZATION ERROR: {}\n💡 This usually means:\n  - Parameter format is incorrect\n  - Tool name is malformed\n  - Server rejected the request format", tool_name, e)
+                } else {
+                    format!("Tool '{}' execution failed: {}", tool_name, e)
+                };
+
+                self.add_error(error_msg);
             }
         }
-        Ok(())
     }
 
     /// Format a tool response summary for display
@@ -2176,9 +4389,16 @@ impl DebuggerApp {
                     ToolResult::Image { data, mime_type } => {
                         format!("[{}] Image {} ({} bytes)", i, mime_type, data.len())
                     }
+                    ToolResult::Audio { data, mime_type } => {
+                        format!("[{}] Audio {} ({} bytes)", i, mime_type, data.len())
+                    }
+                    ToolResult::ResourceLink { uri, .. } => {
+                        format!("[{}] Resource link: {}", i, uri)
+                    }
                     ToolResult::Resource { resource } => {
                         format!("[{}] Resource: {}", i, resource.uri)
                     }
+                    other => format!("[{}] Unknown content ({})", i, other.content_type()),
                 };
                 summaries.push(item_summary);
             }
@@ -2254,9 +4474,19 @@ impl DebuggerApp {
                 mcp_probe_core::messages::prompts::PromptContent::Image { .. } => {
                     "✅ Prompt retrieved: Image message".to_string()
                 }
+                mcp_probe_core::messages::prompts::PromptContent::Audio { .. } => {
+                    "✅ Prompt retrieved: Audio message".to_string()
+                }
+                mcp_probe_core::messages::prompts::PromptContent::ResourceLink { .. } => {
+                    "✅ Prompt retrieved: Resource link message".to_string()
+                }
                 mcp_probe_core::messages::prompts::PromptContent::Resource { .. } => {
                     "✅ Prompt retrieved: Resource message".to_string()
                 }
+                other => format!(
+                    "✅ Prompt retrieved: {} message (unknown type)",
+                    other.content_type()
+                ),
             }
         } else {
             format!("✅ Prompt retrieved: {} messages", msg_count)
@@ -2264,111 +4494,130 @@ impl DebuggerApp {
     }
 
     /// Get a resource
-    async fn get_resource(&mut self, resource_uri: &str) -> Result<()> {
-        if let Some(client) = &mut self.client {
-            match client.read_resource(resource_uri).await {
-                Ok(result) => {
-                    let result_json = match serde_json::to_value(&result) {
-                        Ok(json) => json,
-                        Err(e) => {
-                            self.add_error(format!("Failed to serialize resource result: {}", e));
-                            return Ok(());
-                        }
-                    };
-
-                    let summary = self.format_resource_response_summary(&result);
-
-                    self.add_message(MessageEntry {
-                        timestamp: Instant::now(),
-                        message_type: MessageType::GetResource,
-                        request: None,
-                        response: None,
-                        raw_response: Some(result_json.clone()),
-                        error: None,
-                        success: Some(summary),
-                    });
+    async fn get_resource(&mut self, server: usize, resource_uri: &str) -> Result<()> {
+        if self.client_available(server, &format!("read resource '{}'", resource_uri)) {
+            self.spawn_request(
+                server,
+                BackgroundCall::Resource {
+                    uri: resource_uri.to_string(),
+                },
+            );
+        }
+        Ok(())
+    }
 
-                    // Auto-open response viewer for successful resource retrieval
-                    self.ui_state.selected_response = Some(result_json);
-                    self.ui_state.response_viewer_open = true;
-                    // Reset scroll positions to start from top
-                    self.ui_state.response_viewer_vertical_pos = 0;
-                    self.ui_state.response_viewer_horizontal_pos = 0;
-                    // Select the latest message in history
-                    if !self.message_history.is_empty() {
-                        self.ui_state
-                            .message_history_state
-                            .select(Some(self.message_history.len() - 1));
+    /// Record a resource read result in the history and open the response viewer
+    fn handle_resource_result(
+        &mut self,
+        resource_uri: &str,
+        result: McpResult<ReadResourceResponse>,
+    ) {
+        match result {
+            Ok(result) => {
+                let result_json = match serde_json::to_value(&result) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        self.add_error(format!("Failed to serialize resource result: {}", e));
+                        return;
                     }
+                };
 
-                    tracing::info!(
-                        "Resource '{}' retrieved successfully - response viewer opened",
-                        resource_uri
-                    );
-                }
-                Err(e) => {
-                    self.add_error(format!(
-                        "Resource '{}' retrieval failed: {}",
-                        resource_uri, e
-                    ));
+                let summary = self.format_resource_response_summary(&result);
+
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::GetResource,
+                    request: None,
+                    response: None,
+                    raw_response: Some(result_json.clone()),
+                    error: None,
+                    success: Some(summary),
+                    latency: None,
+                });
+
+                // Auto-open response viewer for successful resource retrieval
+                self.open_response_viewer(result_json);
+                // Select the latest message in history
+                if let Some(latest) = self.message_history.len().checked_sub(1) {
+                    self.select_message(latest);
                 }
+
+                tracing::info!(
+                    "Resource '{}' retrieved successfully - response viewer opened",
+                    resource_uri
+                );
+            }
+            Err(e) => {
+                self.add_error(format!(
+                    "Resource '{}' retrieval failed: {}",
+                    resource_uri, e
+                ));
             }
         }
-        Ok(())
     }
 
     /// Get a prompt
-    async fn get_prompt(&mut self, prompt_name: &str, params_str: &str) -> Result<()> {
-        if let Some(client) = &mut self.client {
-            let params: Value = serde_json::from_str(params_str)
+    async fn get_prompt(
+        &mut self,
+        server: usize,
+        prompt_name: &str,
+        params_str: &str,
+    ) -> Result<()> {
+        if self.client_available(server, &format!("get prompt '{}'", prompt_name)) {
+            let arguments: Value = serde_json::from_str(params_str)
                 .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
+            self.spawn_request(
+                server,
+                BackgroundCall::Prompt {
+                    name: prompt_name.to_string(),
+                    arguments,
+                },
+            );
+        }
+        Ok(())
+    }
 
-            match client.get_prompt(prompt_name, Some(params)).await {
-                Ok(result) => {
-                    let result_json = match serde_json::to_value(&result) {
-                        Ok(json) => json,
-                        Err(e) => {
-                            self.add_error(format!("Failed to serialize prompt result: {}", e));
-                            return Ok(());
-                        }
-                    };
-
-                    let summary = self.format_prompt_response_summary(&result);
-
-                    self.add_message(MessageEntry {
-                        timestamp: Instant::now(),
-                        message_type: MessageType::GetPrompt,
-                        request: None,
-                        response: None,
-                        raw_response: Some(result_json.clone()),
-                        error: None,
-                        success: Some(summary),
-                    });
-
-                    // Auto-open response viewer for successful prompt retrieval
-                    self.ui_state.selected_response = Some(result_json);
-                    self.ui_state.response_viewer_open = true;
-                    // Reset scroll positions to start from top
-                    self.ui_state.response_viewer_vertical_pos = 0;
-                    self.ui_state.response_viewer_horizontal_pos = 0;
-                    // Select the latest message in history
-                    if !self.message_history.is_empty() {
-                        self.ui_state
-                            .message_history_state
-                            .select(Some(self.message_history.len() - 1));
+    /// Record a prompt result in the history and open the response viewer
+    fn handle_prompt_result(&mut self, prompt_name: &str, result: McpResult<GetPromptResponse>) {
+        match result {
+            Ok(result) => {
+                let result_json = match serde_json::to_value(&result) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        self.add_error(format!("Failed to serialize prompt result: {}", e));
+                        return;
                     }
+                };
 
-                    tracing::info!(
-                        "Prompt '{}' retrieved successfully - response viewer opened",
-                        prompt_name
-                    );
-                }
-                Err(e) => {
-                    self.add_error(format!("Prompt '{}' retrieval failed: {}", prompt_name, e));
+                let summary = self.format_prompt_response_summary(&result);
+
+                self.add_message(MessageEntry {
+                    timestamp: Instant::now(),
+                    message_type: MessageType::GetPrompt,
+                    request: None,
+                    response: None,
+                    raw_response: Some(result_json.clone()),
+                    error: None,
+                    success: Some(summary),
+                    latency: None,
+                });
+
+                // Auto-open response viewer for successful prompt retrieval
+                self.open_response_viewer(result_json);
+                // Select the latest message in history
+                if let Some(latest) = self.message_history.len().checked_sub(1) {
+                    self.select_message(latest);
                 }
+
+                tracing::info!(
+                    "Prompt '{}' retrieved successfully - response viewer opened",
+                    prompt_name
+                );
+            }
+            Err(e) => {
+                self.add_error(format!("Prompt '{}' retrieval failed: {}", prompt_name, e));
             }
         }
-        Ok(())
     }
 
     /// Add a message to history
@@ -2379,6 +4628,157 @@ impl DebuggerApp {
         // Limit history size
         if self.message_history.len() > 1000 {
             self.message_history.remove(0);
+            self.history_offset += 1;
+        }
+        self.refresh_inspector();
+    }
+
+    /// Rebuild the message list rows after the history, pins or filter changed
+    fn refresh_inspector(&mut self) {
+        // Keep the selected message, or follow new messages from the bottom
+        let following = self
+            .ui_state
+            .message_history_state
+            .selected()
+            .map_or(true, |row| row + 1 >= self.inspector_rows.len());
+        let selected = self.selected_message().filter(|_| !following);
+        let offset = self.history_offset;
+        let pinned: Vec<usize> = self
+            .pinned_messages
+            .iter()
+            .filter_map(|number| number.checked_sub(offset))
+            .filter(|index| *index < self.message_history.len())
+            .collect();
+        let filtered = self
+            .message_history
+            .iter()
+            .enumerate()
+            .filter(|(index, message)| {
+                let number = offset + index;
+                !self.pinned_messages.contains(&number)
+                    && (self.message_filter.is_empty()
+                        || self.message_filter.matches(
+                            &message.record(false, self.bookmarked_messages.contains(&number)),
+                        ))
+            })
+            .map(|(index, _)| index);
+        self.inspector_rows = pinned.into_iter().chain(filtered).collect();
+
+        let row = selected
+            .and_then(|index| self.inspector_rows.iter().position(|row| *row == index))
+            .or_else(|| self.inspector_rows.len().checked_sub(1));
+        self.ui_state.message_history_state.select(row);
+    }
+
+    /// History index of the message selected in the message list
+    fn selected_message(&self) -> Option<usize> {
+        self.ui_state
+            .message_history_state
+            .selected()
+            .and_then(|row| self.inspector_rows.get(row).copied())
+    }
+
+    /// Select a history entry in the message list, if the filter shows it
+    fn select_message(&mut self, index: usize) {
+        if let Some(row) = self.inspector_rows.iter().position(|row| *row == index) {
+            self.ui_state.message_history_state.select(Some(row));
+        }
+    }
+
+    /// Move the message list selection by `delta` rows
+    fn navigate_messages(&mut self, delta: isize) {
+        let Some(last) = self.inspector_rows.len().checked_sub(1) else {
+            return;
+        };
+        let current = self
+            .ui_state
+            .message_history_state
+            .selected()
+            .unwrap_or(last);
+        let row = current.saturating_add_signed(delta).min(last);
+        self.ui_state.message_history_state.select(Some(row));
+    }
+
+    /// Pin or unpin the selected message
+    fn toggle_pin(&mut self) {
+        if let Some(index) = self.selected_message() {
+            let number = self.history_offset + index;
+            if !self.pinned_messages.remove(&number) {
+                self.pinned_messages.insert(number);
+            }
+            self.refresh_inspector();
+            self.select_message(index);
+        }
+    }
+
+    /// Bookmark or unbookmark the selected message
+    fn toggle_bookmark(&mut self) {
+        if let Some(index) = self.selected_message() {
+            let number = self.history_offset + index;
+            if !self.bookmarked_messages.remove(&number) {
+                self.bookmarked_messages.insert(number);
+            }
+        }
+    }
+
+    /// Select the next (or previous) bookmarked message shown in the list
+    fn jump_to_bookmark(&mut self, forward: bool) {
+        let bookmarked: Vec<usize> = self
+            .inspector_rows
+            .iter()
+            .enumerate()
+            .filter(|(_, index)| {
+                self.bookmarked_messages
+                    .contains(&(self.history_offset + **index))
+            })
+            .map(|(row, _)| row)
+            .collect();
+        let current = self.ui_state.message_history_state.selected();
+        let target = if forward {
+            bookmarked
+                .iter()
+                .find(|row| current.map_or(true, |current| **row > current))
+                .or(bookmarked.first())
+        } else {
+            bookmarked
+                .iter()
+                .rev()
+                .find(|row| current.map_or(true, |current| **row < current))
+                .or(bookmarked.last())
+        };
+        if let Some(row) = target {
+            self.ui_state.message_history_state.select(Some(*row));
+            self.ui_state.current_focus = FocusedPanel::MessageInspector;
+        }
+    }
+
+    /// Handle a key press in the message inspector filter line
+    fn handle_filter_key(&mut self, key: KeyEvent) {
+        match self.keymap.resolve_while_typing(&key, Action::FILTER) {
+            Some(Action::Confirm) => {
+                if self.ui_state.filter_error.is_none() {
+                    self.ui_state.filter_editing = false;
+                }
+            }
+            Some(Action::Cancel) => {
+                self.ui_state.filter_input = TextArea::default();
+                self.ui_state.filter_editing = false;
+                self.ui_state.filter_error = None;
+                self.message_filter = Filter::default();
+                self.refresh_inspector();
+            }
+            _ => {
+                self.ui_state.filter_input.input(Input::from(key));
+                // Filter as you type; an incomplete line keeps the last valid filter
+                match Filter::parse(&self.ui_state.filter_input.lines().join(" ")) {
+                    Ok(filter) => {
+                        self.message_filter = filter;
+                        self.ui_state.filter_error = None;
+                        self.refresh_inspector();
+                    }
+                    Err(e) => self.ui_state.filter_error = Some(e.to_string()),
+                }
+            }
         }
     }
 
@@ -2393,14 +4793,87 @@ impl DebuggerApp {
             raw_response: None,
             error: Some(error),
             success: None,
+            latency: None,
         });
     }
 
-    /// Save current session
-    async fn save_session(&self) -> Result<()> {
-        // TODO: Implement session saving
-        tracing::info!("Session saved (not implemented yet)");
-        Ok(())
+    /// Save current session as JSON and prune old auto-saved sessions.
+    ///
+    /// Saves to `--save-session` when given, otherwise to a new file in the
+    /// configured session directory (reused for later saves of this session).
+    fn save_session(&mut self) -> Result<PathBuf> {
+        let path = match (&self.session_file, &self.saved_session) {
+            (Some(path), _) | (None, Some(path)) => path.clone(),
+            (None, None) => self.config.debug.session_directory.join(format!(
+                "session-{}.json",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            )),
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let messages: Vec<Value> = self
+            .message_history
+            .iter()
+            .enumerate()
+            .map(|(index, message)| {
+                let number = self.history_offset + index;
+                // Secrets resolved for this session never reach saved files
+                let mut message = serde_json::json!({
+                    "elapsed_ms": message
+                        .timestamp
+                        .saturating_duration_since(self.session_start)
+                        .as_millis() as u64,
+                    "type": message.message_type.to_string(),
+                    "request": message.request,
+                    "response": message.response,
+                    "raw_response": message.raw_response,
+                    "error": message.error,
+                    "success": message.success,
+                    "latency_ms": message.latency.map(|latency| latency.as_millis() as u64),
+                    "pinned": self.pinned_messages.contains(&number),
+                    "bookmarked": self.bookmarked_messages.contains(&number),
+                });
+                secrets::redact_payload(&mut message);
+                message
+            })
+            .collect();
+
+        let mut session = serde_json::json!({
+            "format": "mcp-probe-session",
+            "version": "1.0",
+            "saved_at": chrono::Utc::now().to_rfc3339(),
+            "client": self.client_info,
+            "transport": self.transport_config.redacted(),
+            "session_id": self.session_id,
+            "duration_ms": self.session_start.elapsed().as_millis() as u64,
+            "message_count": self.message_count,
+            "error_count": self.error_count,
+            "messages": messages,
+        });
+        if self.multi_server() {
+            session["servers"] = (0..=self.peers.len())
+                .map(|server| {
+                    serde_json::json!({
+                        "name": self.server_label(server),
+                        "transport": self.server_transport(server).redacted(),
+                    })
+                })
+                .collect();
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(&session)?)?;
+        tracing::info!("Session saved to {}", path.display());
+
+        if self.session_file.is_none() {
+            self.saved_session = Some(path.clone());
+            prune_sessions(
+                &self.config.debug.session_directory,
+                self.config.debug.max_saved_sessions as usize,
+            );
+        }
+
+        Ok(path)
     }
 
     /// Draw the main UI
@@ -2443,6 +4916,10 @@ impl DebuggerApp {
             self.draw_search_popup(f, size);
         }
 
+        if self.ui_state.composer_open {
+            self.draw_composer_dialog(f, size);
+        }
+
         if self.ui_state.response_viewer_open {
             self.draw_response_viewer_dialog(f, size);
         }
@@ -2484,16 +4961,44 @@ impl DebuggerApp {
             String::new()
         };
 
-        let header_text = format!(
-            "Server: {} | Transport: {} | Status: {} | Protocol: MCP-2024-11-05{}",
-            server_info,
-            self.transport_config.transport_type(),
-            status,
-            session_info
-        );
+        let header_line = if self.multi_server() {
+            let mut spans = vec![Span::raw("Servers: ")];
+            for (server, (name, status)) in self.server_statuses().into_iter().enumerate() {
+                if server > 0 {
+                    spans.push(Span::raw("  "));
+                }
+                spans.push(Span::styled(
+                    status.symbol(),
+                    Style::default().fg(self.server_status_color(&status)),
+                ));
+                spans.push(Span::raw(format!(
+                    " {} ({}, {})",
+                    name,
+                    self.server_transport(server).transport_type(),
+                    status.label()
+                )));
+            }
+            spans.push(Span::raw(format!(
+                " | Protocol: MCP-2024-11-05{}",
+                session_info
+            )));
+            Line::from(spans)
+        } else {
+            Line::from(format!(
+                "Server: {} | Transport: {} | Status: {} | Protocol: MCP-2024-11-05{}",
+                server_info,
+                self.transport_config.transport_type(),
+                status,
+                session_info
+            ))
+        };
 
-        let header = Paragraph::new(header_text)
-            .style(Style::default().fg(Color::White).bg(Color::Blue))
+        let header = Paragraph::new(header_line)
+            .style(
+                Style::default()
+                    .fg(self.theme.header_fg)
+                    .bg(self.theme.header_bg),
+            )
             .alignment(Alignment::Center)
             .block(
                 Block::default()
@@ -2504,6 +5009,49 @@ impl DebuggerApp {
         f.render_widget(header, area);
     }
 
+    /// Transport of a server by index, 0 being the first
+    fn server_transport(&self, server: usize) -> &TransportConfig {
+        match server.checked_sub(1).and_then(|peer| self.peers.get(peer)) {
+            Some(peer) => &peer.transport_config,
+            None => &self.transport_config,
+        }
+    }
+
+    /// Color of a server's state symbol
+    fn server_status_color(&self, status: &ServerStatus) -> Color {
+        match status {
+            ServerStatus::Ready => self.theme.success,
+            ServerStatus::Connecting | ServerStatus::Reconnecting => self.theme.accent,
+            ServerStatus::Lost | ServerStatus::Failed(_) => self.theme.error,
+        }
+    }
+
+    /// Row starting a server's group in a capability list
+    fn server_group_header(&self, server: usize, count: usize, noun: &str) -> ListItem<'static> {
+        let status = self
+            .server_statuses()
+            .into_iter()
+            .nth(server)
+            .map_or(ServerStatus::Connecting, |(_, status)| status);
+        ListItem::new(Line::from(vec![
+            Span::styled("━━ ", Style::default().fg(self.theme.muted)),
+            Span::styled(
+                status.symbol(),
+                Style::default().fg(self.server_status_color(&status)),
+            ),
+            Span::styled(
+                format!(" {} ", self.server_label(server)),
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(ratatui::style::Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("({} {}) ━━", count, noun),
+                Style::default().fg(self.theme.muted),
+            ),
+        ]))
+    }
+
     /// Draw the main content area
     fn draw_main_content(&mut self, f: &mut Frame, area: Rect) {
         let chunks = Layout::default()
@@ -2514,15 +5062,25 @@ impl DebuggerApp {
             ])
             .split(area);
 
-        // Top row layout
-        let top_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(25), // Protocol Flow
-                Constraint::Percentage(50), // Message Inspector
-                Constraint::Percentage(25), // Controls
-            ])
-            .split(chunks[0]);
+        // Top row layout; the stderr pane takes the place of the side panels
+        let top_chunks = if self.ui_state.stderr_pane_open {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(50), // Message Inspector
+                    Constraint::Percentage(50), // Server stderr
+                ])
+                .split(chunks[0])
+        } else {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(25), // Protocol Flow
+                    Constraint::Percentage(50), // Message Inspector
+                    Constraint::Percentage(25), // Controls
+                ])
+                .split(chunks[0])
+        };
 
         // Bottom row layout
         let bottom_chunks = Layout::default()
@@ -2534,9 +5092,14 @@ impl DebuggerApp {
             .split(chunks[1]);
 
         // Draw panels
-        self.draw_protocol_flow(f, top_chunks[0]);
-        self.draw_message_inspector(f, top_chunks[1]);
-        self.draw_controls(f, top_chunks[2]);
+        if self.ui_state.stderr_pane_open {
+            self.draw_message_inspector(f, top_chunks[0]);
+            self.draw_server_stderr(f, top_chunks[1]);
+        } else {
+            self.draw_protocol_flow(f, top_chunks[0]);
+            self.draw_message_inspector(f, top_chunks[1]);
+            self.draw_controls(f, top_chunks[2]);
+        }
         self.draw_capabilities(f, bottom_chunks[0]);
         self.draw_interactive_terminal(f, bottom_chunks[1]);
     }
@@ -2559,7 +5122,7 @@ impl DebuggerApp {
                     .borders(Borders::ALL)
                     .title("Protocol Flow"),
             )
-            .highlight_style(Style::default().fg(Color::Yellow));
+            .highlight_style(Style::default().fg(self.theme.accent));
 
         f.render_stateful_widget(list, area, &mut self.ui_state.protocol_flow_state);
     }
@@ -2579,7 +5142,9 @@ impl DebuggerApp {
     /// Draw detailed capability list for selected category
     fn draw_capability_details(&mut self, f: &mut Frame, area: Rect, category: CapabilityCategory) {
         let mut items = Vec::new();
+        // Server group headers have no capability
         let mut capability_refs = Vec::new();
+        let mut group = None;
 
         let selected_detail_index = self
             .ui_state
@@ -2591,15 +5156,23 @@ impl DebuggerApp {
             CapabilityCategory::Tools => {
                 let start = self.ui_state.capability_page * self.ui_state.capability_page_size;
 
-                for (display_idx, (i, tool)) in self
+                for (i, tool) in self
                     .capabilities
                     .tools
                     .iter()
                     .enumerate()
                     .skip(start)
                     .take(self.ui_state.capability_page_size)
-                    .enumerate()
                 {
+                    if self.multi_server() && group != Some(tool.server) {
+                        group = Some(tool.server);
+                        let count = self.capabilities.tools.iter();
+                        let count = count.filter(|t| t.server == tool.server).count();
+                        items.push(self.server_group_header(tool.server, count, "tools"));
+                        capability_refs.push(None);
+                    }
+                    let display_idx = items.len();
+
                     // Format description with truncation
                     let desc = tool
                         .description
@@ -2656,32 +5229,65 @@ impl DebuggerApp {
                     };
                     let tool_name_style = if display_idx == selected_detail_index {
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.accent)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     } else {
                         Style::default()
-                            .fg(Color::Cyan)
+                            .fg(self.theme.info)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     };
 
+                    let tool_label = match tool.title {
+                        Some(ref title) if title != &tool.name => {
+                            format!("🔧 {} ({})", title, tool.name)
+                        }
+                        _ => format!("🔧 {}", tool.name),
+                    };
+                    let badges = Self::tool_badges(tool);
+
+                    // The same name on another server is ambiguous to a host
+                    let mut name_line = vec![Span::raw(selection_indicator)];
+                    match self.tool_collisions.get(&tool.full_name) {
+                        Some(servers) => {
+                            let own = self.server_label(tool.server);
+                            let others: Vec<&str> = servers
+                                .iter()
+                                .map(String::as_str)
+                                .filter(|server| *server != own)
+                                .collect();
+                            let collision_style = Style::default()
+                                .fg(self.theme.error)
+                                .add_modifier(ratatui::style::Modifier::BOLD);
+                            let name_style = if display_idx == selected_detail_index {
+                                tool_name_style
+                            } else {
+                                collision_style
+                            };
+                            name_line.push(Span::styled(tool_label, name_style));
+                            name_line.push(Span::styled(
+                                format!("  ⚠ also on {}", others.join(", ")),
+                                collision_style,
+                            ));
+                        }
+                        None => name_line.push(Span::styled(tool_label, tool_name_style)),
+                    }
+
                     let item_text = vec![
-                        Line::from(vec![
-                            Span::raw(selection_indicator),
-                            Span::styled(format!("🔧 {}", tool.name), tool_name_style),
-                        ]),
+                        Line::from(name_line),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(params_info, Style::default().fg(Color::Green)),
+                            Span::styled(params_info, Style::default().fg(self.theme.success)),
+                            Span::styled(badges, Style::default().fg(self.theme.special)),
                         ]),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(desc, Style::default().fg(Color::White)),
+                            Span::styled(desc, Style::default().fg(self.theme.text)),
                         ]),
                         Line::from(""), // Empty line for spacing
                     ];
 
                     items.push(ListItem::new(item_text));
-                    capability_refs.push(CapabilityRef::Tool(i));
+                    capability_refs.push(Some(CapabilityRef::Tool(i)));
                 }
 
                 (format!("🔧 Tools - Page {}/{} [↑/↓ Navigate, Enter Select & Configure, ←/→ Page, Esc Back]", 
@@ -2692,15 +5298,23 @@ impl DebuggerApp {
             CapabilityCategory::Resources => {
                 let start = self.ui_state.capability_page * self.ui_state.capability_page_size;
 
-                for (display_idx, (i, resource)) in self
+                for (i, resource) in self
                     .capabilities
                     .resources
                     .iter()
                     .enumerate()
                     .skip(start)
                     .take(self.ui_state.capability_page_size)
-                    .enumerate()
                 {
+                    if self.multi_server() && group != Some(resource.server) {
+                        group = Some(resource.server);
+                        let count = self.capabilities.resources.iter();
+                        let count = count.filter(|r| r.server == resource.server).count();
+                        items.push(self.server_group_header(resource.server, count, "resources"));
+                        capability_refs.push(None);
+                    }
+                    let display_idx = items.len();
+
                     let name = resource.name.as_ref().unwrap_or(&resource.uri);
                     let desc = resource
                         .description
@@ -2727,11 +5341,11 @@ impl DebuggerApp {
                     };
                     let resource_name_style = if display_idx == selected_detail_index {
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.accent)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     } else {
                         Style::default()
-                            .fg(Color::Green)
+                            .fg(self.theme.success)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     };
 
@@ -2744,22 +5358,22 @@ impl DebuggerApp {
                             Span::raw("   "),
                             Span::styled(
                                 format!("🔗 URI: {}", resource.uri),
-                                Style::default().fg(Color::Blue),
+                                Style::default().fg(self.theme.link),
                             ),
                         ]),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(mime_info, Style::default().fg(Color::Green)),
+                            Span::styled(mime_info, Style::default().fg(self.theme.success)),
                         ]),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(desc, Style::default().fg(Color::White)),
+                            Span::styled(desc, Style::default().fg(self.theme.text)),
                         ]),
                         Line::from(""), // Empty line for spacing
                     ];
 
                     items.push(ListItem::new(item_text));
-                    capability_refs.push(CapabilityRef::Resource(i));
+                    capability_refs.push(Some(CapabilityRef::Resource(i)));
                 }
 
                 (
@@ -2777,15 +5391,23 @@ impl DebuggerApp {
             CapabilityCategory::Prompts => {
                 let start = self.ui_state.capability_page * self.ui_state.capability_page_size;
 
-                for (display_idx, (i, prompt)) in self
+                for (i, prompt) in self
                     .capabilities
                     .prompts
                     .iter()
                     .enumerate()
                     .skip(start)
                     .take(self.ui_state.capability_page_size)
-                    .enumerate()
                 {
+                    if self.multi_server() && group != Some(prompt.server) {
+                        group = Some(prompt.server);
+                        let count = self.capabilities.prompts.iter();
+                        let count = count.filter(|p| p.server == prompt.server).count();
+                        items.push(self.server_group_header(prompt.server, count, "prompts"));
+                        capability_refs.push(None);
+                    }
+                    let display_idx = items.len();
+
                     let desc = prompt
                         .description
                         .as_ref()
@@ -2839,11 +5461,11 @@ impl DebuggerApp {
                     };
                     let prompt_name_style = if display_idx == selected_detail_index {
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.accent)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     } else {
                         Style::default()
-                            .fg(Color::Magenta)
+                            .fg(self.theme.special)
                             .add_modifier(ratatui::style::Modifier::BOLD)
                     };
 
@@ -2854,17 +5476,17 @@ impl DebuggerApp {
                         ]),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(args_info, Style::default().fg(Color::Green)),
+                            Span::styled(args_info, Style::default().fg(self.theme.success)),
                         ]),
                         Line::from(vec![
                             Span::raw("   "),
-                            Span::styled(desc, Style::default().fg(Color::White)),
+                            Span::styled(desc, Style::default().fg(self.theme.text)),
                         ]),
                         Line::from(""), // Empty line for spacing
                     ];
 
                     items.push(ListItem::new(item_text));
-                    capability_refs.push(CapabilityRef::Prompt(i));
+                    capability_refs.push(Some(CapabilityRef::Prompt(i)));
                 }
 
                 (format!("💬 Prompts - Page {}/{} [↑/↓ Navigate, Enter Configure & Run, ←/→ Page, Esc Back]", 
@@ -2875,10 +5497,10 @@ impl DebuggerApp {
         };
 
         // Store capability references for selection
-        self.ui_state.capability_indices = capability_refs.into_iter().map(Some).collect();
+        self.ui_state.capability_indices = capability_refs;
 
         let border_style = if self.ui_state.current_focus == FocusedPanel::Capabilities {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(self.theme.accent)
         } else {
             Style::default()
         };
@@ -2890,7 +5512,7 @@ impl DebuggerApp {
                     .title(title)
                     .border_style(border_style),
             )
-            .highlight_style(Style::default().fg(Color::Black).bg(Color::Green));
+            .highlight_style(self.theme.selected(self.theme.success));
 
         // Update scrollbar state for capability details
         let item_count = items.len().saturating_sub(1);
@@ -2937,59 +5559,75 @@ impl DebuggerApp {
                 total_count
             );
 
-            let paragraph = Paragraph::new(page_info).style(Style::default().fg(Color::Cyan));
+            let paragraph = Paragraph::new(page_info).style(Style::default().fg(self.theme.info));
             f.render_widget(paragraph, info_area);
         }
     }
 
-    /// Draw message details (original message inspector functionality)
+    /// Draw message details: the selected message with its request/response pair
     fn draw_message_details(&mut self, f: &mut Frame, area: Rect) {
-        let _inner = area.inner(Margin {
-            vertical: 1,
-            horizontal: 1,
-        });
-
-        let content = if let Some(index) = self.ui_state.selected_message_index {
+        let content = if let Some(index) = self.selected_message() {
             if let Some(message) = self.message_history.get(index) {
-                let mut text = Vec::new();
+                let number = self.history_offset + index;
+                let status = message.status();
+                let mut header = vec![Span::styled(
+                    format!("{} {} ", message.direction().symbol(), status.badge()),
+                    Style::default().fg(self.status_color(status)),
+                )];
+                header.push(Span::styled(
+                    message
+                        .method()
+                        .unwrap_or_else(|| message.message_type.to_string()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                if let Some(latency) = message.latency {
+                    header.push(Span::raw(format!("  {}", format_latency(latency))));
+                }
+                if self.pinned_messages.contains(&number) {
+                    header.push(Span::raw("  📌"));
+                }
+                if self.bookmarked_messages.contains(&number) {
+                    header.push(Span::raw("  🔖"));
+                }
+                let mut text = vec![Line::from(header)];
 
+                let mut section = |title: &str, color: Color, body: String| {
+                    text.push(Line::from(""));
+                    text.push(Line::from(Span::styled(
+                        title.to_string(),
+                        Style::default().fg(color),
+                    )));
+                    text.extend(
+                        secrets::redact(&body)
+                            .lines()
+                            .map(|line| Line::from(line.to_string())),
+                    );
+                };
                 if let Some(request) = &message.request {
-                    text.push(Line::from(vec![Span::styled(
+                    section(
                         "Request:",
-                        Style::default().fg(Color::Green),
-                    )]));
-                    text.push(Line::from(
+                        self.theme.success,
                         serde_json::to_string_pretty(request).unwrap_or_default(),
-                    ));
+                    );
                 }
-
                 if let Some(response) = &message.response {
-                    text.push(Line::from(""));
-                    text.push(Line::from(vec![Span::styled(
+                    section(
                         "Response:",
-                        Style::default().fg(Color::Blue),
-                    )]));
-                    text.push(Line::from(
+                        self.theme.link,
                         serde_json::to_string_pretty(response).unwrap_or_default(),
-                    ));
+                    );
+                } else if let Some(result) = &message.raw_response {
+                    section(
+                        "Result:",
+                        self.theme.link,
+                        serde_json::to_string_pretty(result).unwrap_or_default(),
+                    );
                 }
-
                 if let Some(error) = &message.error {
-                    text.push(Line::from(""));
-                    text.push(Line::from(vec![Span::styled(
-                        "Error:",
-                        Style::default().fg(Color::Red),
-                    )]));
-                    text.push(Line::from(error.clone()));
+                    section("Error:", self.theme.error, error.clone());
                 }
-
                 if let Some(success) = &message.success {
-                    text.push(Line::from(""));
-                    text.push(Line::from(vec![Span::styled(
-                        "Success:",
-                        Style::default().fg(Color::Green),
-                    )]));
-                    text.push(Line::from(success.clone()));
+                    section("Success:", self.theme.success, success.clone());
                 }
 
                 Text::from(text)
@@ -3006,25 +5644,37 @@ impl DebuggerApp {
                     .borders(Borders::ALL)
                     .title("Message Inspector"),
             )
-            .wrap(Wrap { trim: true });
+            .wrap(Wrap { trim: false });
 
         f.render_widget(paragraph, area);
     }
 
+    /// Color for a message status badge
+    fn status_color(&self, status: Status) -> Color {
+        match status {
+            Status::Ok => self.theme.success,
+            Status::Error => self.theme.error,
+            Status::Info => self.theme.info,
+        }
+    }
+
     /// Draw controls panel
     fn draw_controls(&self, f: &mut Frame, area: Rect) {
         let controls = [
-            "[F1] Help",
-            "[F2] Save",
-            "[F3] Raw JSON",
-            "[F4] Clear",
-            "[F5] Env Vars",
-            "[Q] Quit",
+            (Action::Help, "Help"),
+            (Action::SaveSession, "Save"),
+            (Action::ToggleRawJson, "Raw JSON"),
+            (Action::ClearHistory, "Clear"),
+            (Action::EnvVariables, "Env Vars"),
+            (Action::ServerStderr, "Stderr"),
+            (Action::Quit, "Quit"),
         ];
 
         let items: Vec<ListItem> = controls
             .iter()
-            .map(|control| ListItem::new(*control))
+            .map(|(action, label)| {
+                ListItem::new(format!("[{}] {}", self.keymap.label(*action), label))
+            })
             .collect();
 
         let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Controls"));
@@ -3032,6 +5682,54 @@ impl DebuggerApp {
         f.render_widget(list, area);
     }
 
+    /// Draw the server stderr pane - newest lines at the bottom
+    fn draw_server_stderr(&self, f: &mut Frame, area: Rect) {
+        let Some(log) = &self.process_log else {
+            let text = Paragraph::new("No stderr captured: the server is not a local process.")
+                .style(Style::default().fg(self.theme.muted))
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Server stderr"),
+                );
+            f.render_widget(text, area);
+            return;
+        };
+
+        let mut title = vec![Span::raw(format!(
+            "Server stderr ({} lines) ",
+            log.total_lines()
+        ))];
+        if let Some(exit) = log.exit() {
+            let color = if exit.success() {
+                self.theme.muted
+            } else {
+                self.theme.error
+            };
+            title.push(Span::styled(
+                format!("exited: {} ", exit),
+                Style::default().fg(color),
+            ));
+        }
+
+        let height = area.height.saturating_sub(2) as usize;
+        let lines: Vec<Line> = log
+            .tail(height)
+            .into_iter()
+            .map(|line| Line::from(secrets::redact(&line)))
+            .collect();
+
+        let text = Paragraph::new(lines)
+            .style(Style::default().fg(self.theme.text))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Line::from(title)),
+            );
+        f.render_widget(text, area);
+    }
+
     /// Draw capabilities panel - shows categories
     fn draw_capabilities(&mut self, f: &mut Frame, area: Rect) {
         let mut items = Vec::new();
@@ -3049,20 +5747,23 @@ impl DebuggerApp {
             Span::styled(
                 "🔧 Tools",
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(self.theme.info)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             ),
             Span::styled(
                 format!(" ({}) ", tools_count),
-                Style::default().fg(Color::White),
+                Style::default().fg(self.theme.text),
             ),
             if tools_count > 0 {
                 Span::styled(
                     "- GitHub API, calculations, etc.",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(self.theme.muted),
                 )
             } else {
-                Span::styled("- No tools available", Style::default().fg(Color::Red))
+                Span::styled(
+                    "- No tools available",
+                    Style::default().fg(self.theme.error),
+                )
             },
         ])));
 
@@ -3071,20 +5772,23 @@ impl DebuggerApp {
             Span::styled(
                 "📁 Resources",
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(self.theme.success)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             ),
             Span::styled(
                 format!(" ({}) ", resources_count),
-                Style::default().fg(Color::White),
+                Style::default().fg(self.theme.text),
             ),
             if resources_count > 0 {
                 Span::styled(
                     "- Files, documents, data sources",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(self.theme.muted),
                 )
             } else {
-                Span::styled("- No resources available", Style::default().fg(Color::Red))
+                Span::styled(
+                    "- No resources available",
+                    Style::default().fg(self.theme.error),
+                )
             },
         ])));
 
@@ -3093,36 +5797,94 @@ impl DebuggerApp {
             Span::styled(
                 "💬 Prompts",
                 Style::default()
-                    .fg(Color::Magenta)
+                    .fg(self.theme.special)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             ),
             Span::styled(
                 format!(" ({}) ", prompts_count),
-                Style::default().fg(Color::White),
+                Style::default().fg(self.theme.text),
             ),
             if prompts_count > 0 {
                 Span::styled(
                     "- Templates, conversations, queries",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(self.theme.muted),
                 )
             } else {
-                Span::styled("- No prompts available", Style::default().fg(Color::Red))
+                Span::styled(
+                    "- No prompts available",
+                    Style::default().fg(self.theme.error),
+                )
             },
         ])));
 
+        // What each server offers, in a multi-server session
+        if self.multi_server() {
+            items.push(ListItem::new(Line::from("")));
+            for (server, (name, status)) in self.server_statuses().into_iter().enumerate() {
+                let tools = self
+                    .capabilities
+                    .tools
+                    .iter()
+                    .filter(|t| t.server == server);
+                let resources = self
+                    .capabilities
+                    .resources
+                    .iter()
+                    .filter(|r| r.server == server);
+                let prompts = self
+                    .capabilities
+                    .prompts
+                    .iter()
+                    .filter(|p| p.server == server);
+                items.push(ListItem::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(
+                        status.symbol(),
+                        Style::default().fg(self.server_status_color(&status)),
+                    ),
+                    Span::styled(
+                        format!(" {} ", name),
+                        Style::default().add_modifier(ratatui::style::Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{} tools · {} resources · {} prompts",
+                            tools.count(),
+                            resources.count(),
+                            prompts.count()
+                        ),
+                        Style::default().fg(self.theme.muted),
+                    ),
+                ])));
+            }
+            if !self.tool_collisions.is_empty() {
+                items.push(ListItem::new(Line::from(Span::styled(
+                    format!(
+                        "  ⚠ Same tool name on several servers: {}",
+                        self.tool_collisions
+                            .keys()
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    Style::default().fg(self.theme.error),
+                ))));
+            }
+        }
+
         // Add a search prompt
         items.push(ListItem::new(Line::from(""))); // Empty line
         items.push(ListItem::new(Line::from(vec![
-            Span::styled("🔍 Press ", Style::default().fg(Color::Yellow)),
+            Span::styled("🔍 Press ", Style::default().fg(self.theme.accent)),
             Span::styled(
-                "/",
+                self.keymap.label(Action::Search),
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(self.theme.accent)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             ),
             Span::styled(
                 " to search all capabilities instantly!",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(self.theme.accent),
             ),
         ])));
 
@@ -3140,7 +5902,7 @@ impl DebuggerApp {
         };
 
         let border_style = if self.ui_state.current_focus == FocusedPanel::Capabilities {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(self.theme.accent)
         } else {
             Style::default()
         };
@@ -3152,7 +5914,7 @@ impl DebuggerApp {
                     .title(title)
                     .border_style(border_style),
             )
-            .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow));
+            .highlight_style(self.theme.selected(self.theme.accent));
 
         f.render_stateful_widget(list, area, &mut self.ui_state.capabilities_state);
     }
@@ -3174,8 +5936,8 @@ impl DebuggerApp {
         // Draw search input
         let search_block = Block::default()
             .borders(Borders::ALL)
-            .title("🔍 Search MCP Capabilities - Instant Fuzzy Search")
-            .border_style(Style::default().fg(Color::Yellow));
+            .title("🔍 Search MCP Capabilities - type:tool param:path mime:image/* name:git server:main")
+            .border_style(Style::default().fg(self.theme.accent));
 
         self.ui_state.search_input.set_block(search_block);
         f.render_widget(&self.ui_state.search_input, chunks[0]);
@@ -3190,7 +5952,7 @@ impl DebuggerApp {
                 .trim()
                 .is_empty()
             {
-                format!("💡 Start typing to search across {} capabilities...\n\n🎯 Search Features:\n• Exact name matches (highest priority)\n• Prefix matches (e.g., 'git' finds 'github_*')\n• Description keywords\n• Fuzzy matching (handles typos)\n• Token-based search\n\n📊 Available:\n• 🔧 {} Tools\n• 📁 {} Resources\n• 💬 {} Prompts", 
+                format!("💡 Start typing to search across {} capabilities...\n\n🎯 Search Features:\n• Ranked matches in names, descriptions and parameters (names first)\n• Prefix and partial matches (e.g., 'git' or 'hub' finds 'github_*')\n• Fuzzy matching (handles typos)\n• Filters: type:tool, param:path, mime:image/*, name:git, server:main\n\n📊 Available:\n• 🔧 {} Tools\n• 📁 {} Resources\n• 💬 {} Prompts", 
                     self.search_engine.total_items(),
                     self.capabilities.tools.len(),
                     self.capabilities.resources.len(),
@@ -3200,13 +5962,13 @@ impl DebuggerApp {
             };
 
             let no_results = Paragraph::new(no_results_text)
-                .style(Style::default().fg(Color::Cyan))
+                .style(Style::default().fg(self.theme.info))
                 .alignment(ratatui::layout::Alignment::Center)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
                         .title("Search Results")
-                        .border_style(Style::default().fg(Color::Yellow)),
+                        .border_style(Style::default().fg(self.theme.accent)),
                 )
                 .wrap(ratatui::widgets::Wrap { trim: true });
 
@@ -3226,48 +5988,69 @@ impl DebuggerApp {
                         };
 
                         let category_color = match item.category {
-                            SearchCategory::Tool => Color::Cyan,
-                            SearchCategory::Resource => Color::Green,
-                            SearchCategory::Prompt => Color::Magenta,
+                            SearchCategory::Tool => self.theme.info,
+                            SearchCategory::Resource => self.theme.success,
+                            SearchCategory::Prompt => self.theme.special,
                         };
 
                         let score_display = format!("{:.0}%", result.score);
-                        let description_preview = if item.description.len() > 120 {
-                            format!("{}...", &item.description[..117])
-                        } else {
-                            item.description.clone()
-                        };
+                        let name_style = Style::default()
+                            .fg(category_color)
+                            .add_modifier(Modifier::BOLD);
+                        let match_style = Style::default()
+                            .fg(self.theme.accent)
+                            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+                        let mut title = vec![
+                            Span::styled(category_icon, Style::default().fg(category_color)),
+                            Span::styled(" ", Style::default()),
+                        ];
+                        title.extend(highlighted_spans(
+                            &item.name,
+                            &result.highlights.name,
+                            usize::MAX,
+                            name_style,
+                            match_style,
+                        ));
+                        if self.multi_server() {
+                            title.push(Span::styled(
+                                format!(" @{}", self.server_label(item.server)),
+                                Style::default().fg(self.theme.muted),
+                            ));
+                        }
+                        title.extend([
+                            Span::styled(" ", Style::default()),
+                            Span::styled(
+                                format!("(Match: {})", score_display),
+                                Style::default().fg(self.theme.accent),
+                            ),
+                            Span::styled(
+                                format!(" - {}", result.match_reason),
+                                Style::default().fg(self.theme.info),
+                            ),
+                        ]);
+
+                        let mut description = vec![Span::styled("   ", Style::default())];
+                        description.extend(highlighted_spans(
+                            &item.description,
+                            &result.highlights.description,
+                            120,
+                            Style::default().fg(self.theme.text),
+                            match_style,
+                        ));
 
                         // Create multi-line item for better readability
-                        let content = vec![
-                            Line::from(vec![
-                                Span::styled(category_icon, Style::default().fg(category_color)),
-                                Span::styled(" ", Style::default()),
+                        let mut content = vec![Line::from(title), Line::from(description)];
+                        if !result.highlights.params.is_empty() {
+                            content.push(Line::from(vec![
                                 Span::styled(
-                                    &item.name,
-                                    Style::default()
-                                        .fg(category_color)
-                                        .add_modifier(ratatui::style::Modifier::BOLD),
+                                    "   Parameters: ",
+                                    Style::default().fg(self.theme.muted),
                                 ),
-                                Span::styled(" ", Style::default()),
-                                Span::styled(
-                                    format!("(Match: {})", score_display),
-                                    Style::default().fg(Color::Yellow),
-                                ),
-                                Span::styled(
-                                    format!(" - {}", result.match_reason),
-                                    Style::default().fg(Color::Cyan),
-                                ),
-                            ]),
-                            Line::from(vec![
-                                Span::styled("   ", Style::default()),
-                                Span::styled(
-                                    description_preview,
-                                    Style::default().fg(Color::White),
-                                ),
-                            ]),
-                            Line::from(""), // Empty line for spacing
-                        ];
+                                Span::styled(result.highlights.params.join(", "), match_style),
+                            ]));
+                        }
+                        content.push(Line::from("")); // Empty line for spacing
 
                         ListItem::new(content)
                     } else {
@@ -3284,9 +6067,9 @@ impl DebuggerApp {
                             "🎯 Search Results ({} matches found, best matches first)",
                             self.ui_state.search_results.len()
                         ))
-                        .border_style(Style::default().fg(Color::Yellow)),
+                        .border_style(Style::default().fg(self.theme.accent)),
                 )
-                .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow));
+                .highlight_style(self.theme.selected(self.theme.accent));
 
             // Update scrollbar state for search results
             let results_count = self.ui_state.search_results.len().saturating_sub(1);
@@ -3316,11 +6099,11 @@ impl DebuggerApp {
         }
 
         // Draw instructions
-        let instructions = "🔍 Advanced Search: Exact names, partial matches, keywords, and fuzzy matching | [↑/↓] Navigate | [Enter] Select & Configure | [Esc] Close";
+        let instructions = "🔍 Search names, descriptions and parameters; filter with type: param: mime: name: | [↑/↓] Navigate | [Enter] Select & Configure | [Esc] Close";
         let instructions_paragraph = Paragraph::new(instructions)
             .style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(self.theme.accent)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             )
             .alignment(ratatui::layout::Alignment::Center)
@@ -3331,69 +6114,139 @@ impl DebuggerApp {
 
     /// Draw interactive terminal panel
     fn draw_interactive_terminal(&mut self, f: &mut Frame, area: Rect) {
+        let show_filter = self.ui_state.filter_editing || !self.message_filter.is_empty();
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Min(0),    // History
-                Constraint::Length(3), // Input
+                Constraint::Length(if show_filter { 3 } else { 0 }), // Filter
+                Constraint::Min(0),                                  // History
+                Constraint::Length(3),                               // Input
             ])
             .split(area);
 
+        if show_filter {
+            let (title, style) = match &self.ui_state.filter_error {
+                Some(error) => (
+                    format!("Filter | {}", error),
+                    Style::default().fg(self.theme.error),
+                ),
+                None if self.ui_state.filter_editing => (
+                    format!(
+                        "Filter [{} Apply, {} Clear] method: dir: type: is: $.json.path text",
+                        self.keymap.label(Action::Confirm),
+                        self.keymap.label(Action::Cancel)
+                    ),
+                    Style::default().fg(self.theme.accent),
+                ),
+                None => (
+                    format!("Filter [{} Edit]", self.keymap.label(Action::Filter)),
+                    Style::default(),
+                ),
+            };
+            self.ui_state.filter_input.set_block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .style(style),
+            );
+            f.render_widget(&self.ui_state.filter_input, chunks[0]);
+        }
+
         // Draw message history with scrollbar
         let history_items: Vec<ListItem> = self
-            .message_history
+            .inspector_rows
             .iter()
-            .map(|msg| {
+            .filter_map(|index| Some((*index, self.message_history.get(*index)?)))
+            .map(|(index, msg)| {
+                let number = self.history_offset + index;
                 let timestamp = msg.timestamp.duration_since(self.session_start);
-                let time_str = format!(
-                    "{:02}:{:02}",
+                let status = msg.status();
+                let mut content = format!(
+                    "[{:02}:{:02}] {} {} {}",
                     timestamp.as_secs() / 60,
-                    timestamp.as_secs() % 60
+                    timestamp.as_secs() % 60,
+                    msg.direction().symbol(),
+                    status.badge(),
+                    msg.method().unwrap_or_else(|| msg.message_type.to_string())
                 );
+                if let Some(latency) = msg.latency {
+                    content.push_str(&format!(" {}", format_latency(latency)));
+                }
+                if let Some(detail) = msg.error.as_ref().or(msg.success.as_ref()) {
+                    content.push_str(&format!(" {}", secrets::redact(detail)));
+                }
+                // R indicates response available for viewing
+                if msg.raw_response.is_some() {
+                    content.push_str(" [R]");
+                }
+                if self.pinned_messages.contains(&number) {
+                    content.push_str(" 📌");
+                }
+                if self.bookmarked_messages.contains(&number) {
+                    content.push_str(" 🔖");
+                }
+                // The older of the two marked messages is the left side of the diff
+                if self.diff_marks.contains(&number) {
+                    let older = self.diff_marks.iter().all(|mark| *mark >= number);
+                    content.push_str(if older { " [A]" } else { " [B]" });
+                }
 
-                // Add response indicator
-                let response_indicator = if msg.raw_response.is_some() {
-                    " [R]" // R indicates response available for viewing
-                } else {
-                    ""
-                };
-
-                let (content, style) = if let Some(error) = &msg.error {
-                    (
-                        format!("[{}] ERROR: {}{}", time_str, error, response_indicator),
-                        Style::default().fg(Color::Red),
-                    )
-                } else if let Some(success) = &msg.success {
-                    (
-                        format!("[{}] SUCCESS: {}{}", time_str, success, response_indicator),
-                        Style::default().fg(Color::Green),
-                    )
-                } else {
-                    (
-                        format!("[{}] {}{}", time_str, msg.message_type, response_indicator),
-                        Style::default(),
-                    )
-                };
-
-                ListItem::new(content).style(style)
+                ListItem::new(content).style(Style::default().fg(self.status_color(status)))
             })
+            .chain(self.pending_requests.iter().map(|pending| {
+                let since_start = pending.started.duration_since(self.session_start);
+                ListItem::new(format!(
+                    "[{:02}:{:02}] ⏳ PENDING: {} ({:.1}s)",
+                    since_start.as_secs() / 60,
+                    since_start.as_secs() % 60,
+                    pending.label,
+                    pending.started.elapsed().as_secs_f64()
+                ))
+                .style(Style::default().fg(self.theme.accent))
+            }))
             .collect();
 
+        let mut title = format!(
+            "Message History [{} Filter, {} Pin, {} Bookmark, {}/{} Jump, {} Response Viewer]",
+            self.keymap.label(Action::Filter),
+            self.keymap.label(Action::Pin),
+            self.keymap.label(Action::Bookmark),
+            self.keymap.label(Action::NextBookmark),
+            self.keymap.label(Action::PreviousBookmark),
+            self.keymap.label(Action::OpenResponse)
+        );
+        if !self.message_filter.is_empty() {
+            title.push_str(&format!(
+                " {}/{} shown",
+                self.inspector_rows.len(),
+                self.message_history.len()
+            ));
+        }
+        let border_style = if self.ui_state.current_focus == FocusedPanel::MessageInspector {
+            Style::default().fg(self.theme.accent)
+        } else {
+            Style::default()
+        };
         let history_list = List::new(history_items)
-            .block(Block::default().borders(Borders::ALL).title("Message History [↑/↓ Navigate, ←/→ Scroll, R Response Viewer, Tab Latest Response]"))
-            .highlight_style(Style::default().fg(Color::Yellow));
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(border_style),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
         // Update scrollbar state based on content length and area
         self.ui_state.message_scroll = self
             .ui_state
             .message_scroll
-            .content_length(self.message_history.len().saturating_sub(1));
+            .content_length(self.inspector_rows.len().saturating_sub(1));
         let scroll_pos = self.ui_state.message_history_state.selected().unwrap_or(0);
         self.ui_state.message_scroll = self.ui_state.message_scroll.position(scroll_pos);
 
         f.render_stateful_widget(
             history_list,
-            chunks[0],
+            chunks[1],
             &mut self.ui_state.message_history_state,
         );
 
@@ -3403,43 +6256,90 @@ impl DebuggerApp {
                 .orientation(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some("↑"))
                 .end_symbol(Some("↓")),
-            chunks[0],
+            chunks[1],
             &mut self.ui_state.message_scroll,
         );
 
         // Draw input area
         let input_style = if self.ui_state.current_focus == FocusedPanel::Input {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(self.theme.accent)
         } else {
             Style::default()
         };
 
+        let title = match self.ui_state.completion_candidates.as_slice() {
+            [] if self.ui_state.current_focus == FocusedPanel::Input => {
+                "Interactive Terminal [Enter Run, Tab Complete, ↑/↓ History, Esc Leave]".to_string()
+            }
+            [] => "Interactive Terminal [: to Focus]".to_string(),
+            candidates => format!(
+                "Interactive Terminal | {}{}",
+                candidates
+                    .iter()
+                    .take(8)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("  "),
+                if candidates.len() > 8 { "  …" } else { "" }
+            ),
+        };
         let input_block = Block::default()
             .borders(Borders::ALL)
-            .title("Interactive Terminal")
+            .title(title)
             .style(input_style);
 
         self.ui_state.input_area.set_block(input_block);
-        f.render_widget(&self.ui_state.input_area, chunks[1]);
+        f.render_widget(&self.ui_state.input_area, chunks[2]);
+    }
+
+    /// Pending-requests indicator for the status bar, e.g. ` | ⏳ 2 pending: tools/call x 3.1s, …`
+    fn pending_requests_summary(&self) -> String {
+        match self.pending_requests.as_slice() {
+            [] => String::new(),
+            [pending] => format!(
+                " | ⏳ {} {:.1}s",
+                pending.label,
+                pending.started.elapsed().as_secs_f64()
+            ),
+            [oldest, ..] => format!(
+                " | ⏳ {} pending (oldest: {} {:.1}s)",
+                self.pending_requests.len(),
+                oldest.label,
+                oldest.started.elapsed().as_secs_f64()
+            ),
+        }
     }
 
     /// Draw status bar
     fn draw_status_bar(&self, f: &mut Frame, area: Rect) {
         let uptime = self.session_start.elapsed();
 
-        let status_text = match &self.state {
-            AppState::Ready => "Ready".to_string(),
-            AppState::Connecting => "Connecting".to_string(),
-            AppState::Discovering => {
-                if self.discovery_step.is_empty() {
-                    "Discovering".to_string()
-                } else {
-                    format!("Discovering: {}", self.discovery_step)
+        let status_text = if self.multi_server() {
+            self.server_statuses()
+                .into_iter()
+                .map(|(name, status)| match &status {
+                    ServerStatus::Failed(reason) => {
+                        format!("{} {} failed: {}", status.symbol(), name, reason)
+                    }
+                    _ => format!("{} {} {}", status.symbol(), name, status.label()),
+                })
+                .collect::<Vec<_>>()
+                .join(" · ")
+        } else {
+            match &self.state {
+                AppState::Ready => "Ready".to_string(),
+                AppState::Connecting => "Connecting".to_string(),
+                AppState::Discovering => {
+                    if self.discovery_step.is_empty() {
+                        "Discovering".to_string()
+                    } else {
+                        format!("Discovering: {}", self.discovery_step)
+                    }
                 }
+                AppState::Error(e) => format!("Error: {}", e),
+                AppState::Initializing => "Initializing".to_string(),
+                AppState::ShuttingDown => "Shutting down".to_string(),
             }
-            AppState::Error(e) => format!("Error: {}", e),
-            AppState::Initializing => "Initializing".to_string(),
-            AppState::ShuttingDown => "Shutting down".to_string(),
         };
 
         let session_info = if let Some(ref session_id) = self.session_id {
@@ -3456,20 +6356,65 @@ impl DebuggerApp {
         };
 
         let search_status = if self.ui_state.search_active {
-            " | 🔍 SEARCH ACTIVE"
+            " | 🔍 SEARCH ACTIVE".to_string()
         } else {
-            " | Press / to search"
+            format!(" | Press {} to search", self.keymap.label(Action::Search))
+        };
+
+        let pending_status = self.pending_requests_summary();
+
+        let change_status = match &self.capability_notice {
+            Some((summary, at)) if at.elapsed() < CAPABILITY_NOTICE_DURATION => {
+                format!(" | 🔔 {}", summary)
+            }
+            _ => String::new(),
+        };
+
+        let pollution_status = if self.stdout_pollution > 0 {
+            format!(" | ⚠ stdout pollution: {} line(s)", self.stdout_pollution)
+        } else {
+            String::new()
+        };
+
+        let latency_status = self
+            .last_latency
+            .map(|latency| format!(" | ⏱ {:.0}ms", latency.as_secs_f64() * 1000.0))
+            .unwrap_or_default();
+
+        let reconnect_status = if self.client_reconnecting() && !self.multi_server() {
+            " | ⟳ Reconnecting"
+        } else {
+            ""
+        };
+
+        let collision_status = match self.tool_collisions.len() {
+            0 => String::new(),
+            1 => " | ⚠ 1 tool name collision".to_string(),
+            n => format!(" | ⚠ {} tool name collisions", n),
         };
 
         let response_status = if self.ui_state.response_viewer_open {
-            " | 📊 RESPONSE VIEWER (Press V to cycle views, Esc to close)"
+            format!(
+                " | 📊 RESPONSE VIEWER (Press {} to cycle views, {} to close)",
+                self.keymap.label(Action::CycleView),
+                self.keymap.label(Action::Cancel)
+            )
         } else {
-            " | Press Tab for latest results"
+            format!(
+                " | Press {} for latest results",
+                self.keymap.label(Action::Execute)
+            )
         };
 
         let full_status = format!(
-            "Status: {} | Messages: {} | Errors: {} | Uptime: {:02}:{:02}:{:02} | Env Vars: {}{}{}{}",
+            "Status: {}{}{}{}{}{}{} | Messages: {} | Errors: {} | Uptime: {:02}:{:02}:{:02} | Env Vars: {}{}{}{}",
             status_text,
+            pending_status,
+            latency_status,
+            change_status,
+            pollution_status,
+            reconnect_status,
+            collision_status,
             self.message_count,
             self.error_count,
             uptime.as_secs() / 3600,
@@ -3482,65 +6427,53 @@ impl DebuggerApp {
         );
 
         let status_bar = Paragraph::new(full_status)
-            .style(Style::default().fg(Color::White).bg(Color::Black))
+            .style(
+                Style::default()
+                    .fg(self.theme.text)
+                    .bg(self.theme.background),
+            )
             .alignment(Alignment::Left)
             .block(Block::default().borders(Borders::ALL));
 
         f.render_widget(status_bar, area);
     }
 
-    /// Draw help dialog
+    /// Draw help dialog, listing the keys of the active keymap
     fn draw_help_dialog(&self, f: &mut Frame, area: Rect) {
-        let popup_area = centered_rect(80, 60, area);
-
-        let help_text = vec![
-            Line::from("MCP Probe Help"),
-            Line::from(""),
-            Line::from("🔍 SEARCH CAPABILITIES:"),
-            Line::from("  /         - Activate smart search (fuzzy matching)"),
-            Line::from("  ↑/↓       - Navigate search results"),
-            Line::from("  Enter     - Select and configure capability"),
-            Line::from("  Esc       - Exit search mode"),
-            Line::from(""),
-            Line::from("📊 RESPONSE VIEWER:"),
-            Line::from("  Tab       - Open latest response automatically"),
-            Line::from("  R         - Open response viewer for selected message [R]"),
-            Line::from("  V         - Cycle view modes (Formatted/Raw/Tree/Summary)"),
-            Line::from("  ↑/↓       - Scroll vertically"),
-            Line::from("  ←/→       - Scroll horizontally"),
-            Line::from("  PgUp/PgDn - Fast vertical scrolling"),
-            Line::from("  Home/End  - Jump to top/bottom"),
-            Line::from("  Esc       - Close response viewer"),
-            Line::from(""),
-            Line::from("📝 PARAMETER FORMS:"),
-            Line::from("  ↑/↓       - Navigate fields (auto-edit mode)"),
-            Line::from("  Type      - Edit field values directly"),
-            Line::from("  Enter     - Save field and move to next"),
-            Line::from("  Tab       - Execute with current values"),
-            Line::from("  Esc       - Cancel and go back"),
-            Line::from(""),
-            Line::from("Navigation:"),
-            Line::from("  Tab       - Auto-open latest results / Cycle panels"),
-            Line::from("  Enter     - Execute command / Confirm"),
-            Line::from("  Esc       - Close dialogs"),
-            Line::from(""),
-            Line::from("Function Keys:"),
-            Line::from("  F1        - Toggle this help"),
-            Line::from("  F2        - Save session"),
-            Line::from("  F3        - Toggle raw JSON view"),
-            Line::from("  F4        - Clear message history"),
-            Line::from("  F5        - Environment variables"),
-            Line::from("  Q         - Quit application"),
+        let popup_area = centered_rect(80, 80, area);
+
+        let mut help_text = vec![Line::from(format!(
+            "MCP Probe Help (keymap: {})",
+            self.keymap.preset()
+        ))];
+        for (section, actions) in Action::SECTIONS {
+            help_text.push(Line::from(""));
+            help_text.push(Line::styled(
+                format!("{}:", section),
+                Style::default().fg(self.theme.accent),
+            ));
+            for action in *actions {
+                help_text.push(Line::from(format!(
+                    "  {:<16} - {} ({})",
+                    self.keymap.label(*action),
+                    action.description(),
+                    action.name()
+                )));
+            }
+        }
+        help_text.extend([
             Line::from(""),
-            Line::from("Commands:"),
+            Line::from("Terminal commands:"),
             Line::from("  tools.name {\"param\": \"value\"}"),
             Line::from("  resources.uri"),
             Line::from("  prompts.name {\"param\": \"value\"}"),
+            Line::from("  :ping  :raw {\"method\": ...}  :set-level info  :subscribe uri"),
+            Line::from("  $last.result.content[0].text - part of the previous response"),
             Line::from(""),
             Line::from("Environment Variables:"),
             Line::from("  Set KEY=value,KEY2=value2 format"),
-            Line::from("  Variables are auto-injected into tool calls"),
-        ];
+            Line::from("  Use them in terminal commands as ${KEY}"),
+        ]);
 
         let help_paragraph = Paragraph::new(help_text)
             .block(Block::default().borders(Borders::ALL).title("Help"))
@@ -3550,6 +6483,55 @@ impl DebuggerApp {
         f.render_widget(help_paragraph, popup_area);
     }
 
+    /// Draw the raw JSON-RPC composer
+    fn draw_composer_dialog(&mut self, f: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(70, 60, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(popup_area);
+        f.render_widget(Clear, popup_area);
+
+        let title = match &self.ui_state.composer_snippet {
+            Some(path) => format!(
+                "Raw JSON-RPC Composer - {}",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ),
+            None => "Raw JSON-RPC Composer".to_string(),
+        };
+        self.ui_state.composer_area.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(self.theme.accent)),
+        );
+        f.render_widget(&self.ui_state.composer_area, chunks[0]);
+
+        let payload = self.ui_state.composer_area.lines().join("\n");
+        let validity = match serde_json::from_str::<Value>(&payload) {
+            Ok(_) => "✓ valid JSON",
+            Err(_) => "⚠ not valid JSON, sent as is",
+        };
+        let correlation = if self.ui_state.composer_correlate {
+            "on"
+        } else {
+            "off (replies show as Inbound)"
+        };
+        let instructions = Paragraph::new(format!(
+            "{} | [{}] Send | [{}] Correlation: {} | [{}] Save Snippet | [{}] Load Snippet | [{}] Close",
+            validity,
+            self.keymap.label(Action::Send),
+            self.keymap.label(Action::ToggleCorrelation),
+            correlation,
+            self.keymap.label(Action::SaveSnippet),
+            self.keymap.label(Action::LoadSnippet),
+            self.keymap.label(Action::Cancel)
+        ))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Center);
+        f.render_widget(instructions, chunks[1]);
+    }
+
     /// Draw environment variables dialog
     fn draw_env_dialog(&mut self, f: &mut Frame, area: Rect) {
         let popup_area = centered_rect(70, 50, area);
@@ -3573,7 +6555,7 @@ impl DebuggerApp {
 
         // Input area
         let input_style = if self.ui_state.current_focus == FocusedPanel::EnvVariables {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(self.theme.accent)
         } else {
             Style::default()
         };
@@ -3634,7 +6616,9 @@ impl DebuggerApp {
 
         f.render_widget(Clear, popup_area);
 
-        if let Some(capability_ref) = self.ui_state.selected_capability.clone() {
+        if let Some(elicitation) = &self.ui_state.elicitation {
+            self.draw_elicitation_form(f, popup_area, &elicitation.elicit.message);
+        } else if let Some(capability_ref) = self.ui_state.selected_capability.clone() {
             // Use the new enhanced parameter form
             self.draw_parameter_form(f, popup_area, &capability_ref);
         } else {
@@ -3652,12 +6636,12 @@ impl DebuggerApp {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Red)),
+                        .border_style(Style::default().fg(self.theme.error)),
                 )
                 .alignment(Alignment::Center)
                 .style(
                     Style::default()
-                        .fg(Color::Red)
+                        .fg(self.theme.error)
                         .add_modifier(ratatui::style::Modifier::BOLD),
                 );
             f.render_widget(title, chunks[0]);
@@ -3666,13 +6650,13 @@ impl DebuggerApp {
                 .block(Block::default().borders(Borders::ALL))
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true })
-                .style(Style::default().fg(Color::White));
+                .style(Style::default().fg(self.theme.text));
             f.render_widget(content, chunks[1]);
 
             let instructions = Paragraph::new("🔙 [Esc] Go Back")
                 .block(Block::default().borders(Borders::ALL))
                 .alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow));
+                .style(Style::default().fg(self.theme.accent));
             f.render_widget(instructions, chunks[2]);
         }
     }
@@ -3695,12 +6679,12 @@ impl DebuggerApp {
                 if let Some(tool) = self.capabilities.tools.get(*i) {
                     (
                         format!("🔧 Configure Tool: {}", tool.name),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(self.theme.info),
                     )
                 } else {
                     (
                         "🔧 Tool Configuration".to_string(),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(self.theme.info),
                     )
                 }
             }
@@ -3709,12 +6693,12 @@ impl DebuggerApp {
                     let name = resource.name.as_ref().unwrap_or(&resource.uri);
                     (
                         format!("📁 Read Resource: {}", name),
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(self.theme.success),
                     )
                 } else {
                     (
                         "📁 Resource Access".to_string(),
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(self.theme.success),
                     )
                 }
             }
@@ -3722,12 +6706,12 @@ impl DebuggerApp {
                 if let Some(prompt) = self.capabilities.prompts.get(*i) {
                     (
                         format!("💬 Configure Prompt: {}", prompt.name),
-                        Style::default().fg(Color::Magenta),
+                        Style::default().fg(self.theme.special),
                     )
                 } else {
                     (
                         "💬 Prompt Configuration".to_string(),
-                        Style::default().fg(Color::Magenta),
+                        Style::default().fg(self.theme.special),
                     )
                 }
             }
@@ -3736,7 +6720,7 @@ impl DebuggerApp {
         let header_block = Block::default()
             .borders(Borders::ALL)
             .title("Parameter Configuration")
-            .border_style(Style::default().fg(Color::Yellow));
+            .border_style(Style::default().fg(self.theme.accent));
 
         let header_paragraph = Paragraph::new(header_text)
             .style(header_style.add_modifier(ratatui::style::Modifier::BOLD))
@@ -3755,189 +6739,265 @@ impl DebuggerApp {
             };
 
             let no_params_paragraph = Paragraph::new(no_params_text)
-                .style(Style::default().fg(Color::Green))
+                .style(Style::default().fg(self.theme.success))
                 .alignment(ratatui::layout::Alignment::Center)
                 .block(Block::default().borders(Borders::ALL))
                 .wrap(ratatui::widgets::Wrap { trim: true });
 
             f.render_widget(no_params_paragraph, chunks[1]);
         } else {
-            // Show parameter input fields
-            let field_height = 3; // Each field takes 3 lines
-            let available_height = chunks[1].height as usize;
-            let max_visible_fields = available_height / field_height;
-
-            let selected_field = self.ui_state.param_selected_field;
-            let scroll_start = if selected_field >= max_visible_fields {
-                selected_field - max_visible_fields + 1
-            } else {
-                0
-            };
+            self.draw_parameter_fields(f, chunks[1]);
+        }
 
-            let mut y_offset = 0;
-            for (field_idx, param_name) in self
-                .ui_state
-                .param_field_names
-                .iter()
-                .enumerate()
-                .skip(scroll_start)
-                .take(max_visible_fields)
-            {
-                let field = match self.ui_state.param_fields.get(param_name) {
-                    Some(f) => f,
-                    None => {
-                        tracing::error!("Field '{}' not found in param_fields", param_name);
-                        continue;
-                    }
-                };
+        // Draw instructions at bottom
+        let instructions = if self.ui_state.param_field_names.is_empty() {
+            "🚀 [Enter] Execute | [Esc] Cancel & Go Back"
+        } else if self.ui_state.param_edit_mode {
+            "✏️  EDITING MODE | [Enter] Save & Move to Next | [Esc] Stop Editing | [Tab] Execute with Current Values"
+        } else {
+            "📝 [↑/↓] Navigate & Edit Fields | [Tab] Execute | [Esc] Cancel & Go Back"
+        };
+
+        let instructions_block = Block::default()
+            .borders(Borders::ALL)
+            .title("🎮 Controls")
+            .border_style(Style::default().fg(self.theme.info));
+
+        let instructions_paragraph = Paragraph::new(instructions)
+            .style(
+                Style::default()
+                    .fg(self.theme.accent)
+                    .add_modifier(ratatui::style::Modifier::BOLD),
+            )
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(instructions_block);
+
+        f.render_widget(instructions_paragraph, chunks[2]);
+    }
+
+    /// Draw the editable parameter fields with selection and scrolling
+    fn draw_parameter_fields(&self, f: &mut Frame, area: Rect) {
+        // Show parameter input fields
+        let field_height = 3; // Each field takes 3 lines
+        let available_height = area.height as usize;
+        let max_visible_fields = available_height / field_height;
+
+        let selected_field = self.ui_state.param_selected_field;
+        let scroll_start = if selected_field >= max_visible_fields {
+            selected_field - max_visible_fields + 1
+        } else {
+            0
+        };
 
-                if y_offset + field_height > available_height {
-                    break;
+        let mut y_offset = 0;
+        for (field_idx, param_name) in self
+            .ui_state
+            .param_field_names
+            .iter()
+            .enumerate()
+            .skip(scroll_start)
+            .take(max_visible_fields)
+        {
+            let field = match self.ui_state.param_fields.get(param_name) {
+                Some(f) => f,
+                None => {
+                    tracing::error!("Field '{}' not found in param_fields", param_name);
+                    continue;
                 }
+            };
 
-                let field_area = Rect {
-                    x: chunks[1].x,
-                    y: chunks[1].y + y_offset as u16,
-                    width: chunks[1].width,
-                    height: field_height as u16,
-                };
+            if y_offset + field_height > available_height {
+                break;
+            }
 
-                // Determine field styling based on selection and requirement status
-                let is_selected = field_idx == selected_field;
-                let is_required = field.required;
-                let has_value = !field.value.is_empty();
-
-                let border_style = if is_selected {
-                    Style::default().fg(Color::Yellow)
-                } else if is_required && !has_value {
-                    Style::default().fg(Color::Red)
-                } else if has_value {
-                    Style::default().fg(Color::Green)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
+            let field_area = Rect {
+                x: area.x,
+                y: area.y + y_offset as u16,
+                width: area.width,
+                height: field_height as u16,
+            };
 
-                // Create title with requirement indicator
-                let field_title = if is_required {
-                    format!("📋 {} (REQUIRED)", param_name)
-                } else {
-                    format!("📝 {} (optional)", param_name)
-                };
+            // Determine field styling based on selection and requirement status
+            let is_selected = field_idx == selected_field;
+            let is_required = field.required;
+            let has_value = !field.value.is_empty();
+
+            let border_style = if is_selected {
+                Style::default().fg(self.theme.accent)
+            } else if is_required && !has_value {
+                Style::default().fg(self.theme.error)
+            } else if has_value {
+                Style::default().fg(self.theme.success)
+            } else {
+                Style::default().fg(self.theme.muted)
+            };
 
-                // Add type information if available
-                let type_info = if let Some(ref param_type) = field.param_type {
-                    format!(" [{}]", param_type)
-                } else {
-                    String::new()
-                };
+            // Create title with requirement indicator
+            let field_title = if is_required {
+                format!("📋 {} (REQUIRED)", param_name)
+            } else {
+                format!("📝 {} (optional)", param_name)
+            };
 
-                let full_title = format!("{}{}", field_title, type_info);
+            // Add type information if available
+            let type_info = if let Some(ref param_type) = field.param_type {
+                format!(" [{}]", param_type)
+            } else {
+                String::new()
+            };
 
-                let field_block = Block::default()
-                    .borders(Borders::ALL)
-                    .title(full_title)
-                    .border_style(border_style);
-
-                // Show field content with placeholder or value
-                let field_content = if field.value.is_empty() {
-                    if let Some(ref desc) = field.description {
-                        if is_selected {
-                            format!("💡 {}\n\n{}", desc, "Type your input here...")
-                        } else {
-                            format!("💡 {}", desc)
-                        }
-                    } else if is_selected {
-                        "Type your input here...".to_string()
-                    } else {
-                        "(empty)".to_string()
-                    }
-                } else {
-                    field.value.clone()
-                };
+            let full_title = format!("{}{}", field_title, type_info);
+
+            let field_block = Block::default()
+                .borders(Borders::ALL)
+                .title(full_title)
+                .border_style(border_style);
 
-                let content_style = if field.value.is_empty() {
+            // Show field content with placeholder or value
+            let field_content = if field.value.is_empty() {
+                if let Some(ref desc) = field.description {
                     if is_selected {
-                        Style::default().fg(Color::Yellow)
+                        format!("💡 {}\n\n{}", desc, "Type your input here...")
                     } else {
-                        Style::default().fg(Color::DarkGray)
+                        format!("💡 {}", desc)
                     }
+                } else if is_selected {
+                    "Type your input here...".to_string()
                 } else {
-                    Style::default().fg(Color::White)
-                };
+                    "(empty)".to_string()
+                }
+            } else {
+                field.value.clone()
+            };
 
-                let field_paragraph = Paragraph::new(field_content)
-                    .style(content_style)
-                    .block(field_block)
-                    .wrap(ratatui::widgets::Wrap { trim: true });
+            let content_style = if field.value.is_empty() {
+                if is_selected {
+                    Style::default().fg(self.theme.accent)
+                } else {
+                    Style::default().fg(self.theme.muted)
+                }
+            } else {
+                Style::default().fg(self.theme.text)
+            };
 
-                f.render_widget(field_paragraph, field_area);
+            let field_paragraph = Paragraph::new(field_content)
+                .style(content_style)
+                .block(field_block)
+                .wrap(ratatui::widgets::Wrap { trim: true });
 
-                // Show cursor if this field is selected and in edit mode
-                if is_selected && self.ui_state.param_edit_mode {
-                    let cursor_x = field_area.x + 1 + field.value.len() as u16;
-                    let cursor_y = field_area.y + 1;
+            f.render_widget(field_paragraph, field_area);
 
-                    if cursor_x < field_area.x + field_area.width - 1 {
-                        f.set_cursor_position((cursor_x, cursor_y));
-                    }
-                }
+            // Show cursor if this field is selected and in edit mode
+            if is_selected && self.ui_state.param_edit_mode {
+                let cursor_x = field_area.x + 1 + field.value.len() as u16;
+                let cursor_y = field_area.y + 1;
 
-                y_offset += field_height;
+                if cursor_x < field_area.x + field_area.width - 1 {
+                    f.set_cursor_position((cursor_x, cursor_y));
+                }
             }
 
-            // Show scroll indicator if needed
-            if self.ui_state.param_field_names.len() > max_visible_fields {
-                let scroll_info = format!(
-                    "Showing fields {}-{} of {} | ↑/↓ to scroll",
-                    scroll_start + 1,
-                    std::cmp::min(
-                        scroll_start + max_visible_fields,
-                        self.ui_state.param_field_names.len()
-                    ),
+            y_offset += field_height;
+        }
+
+        // Show scroll indicator if needed
+        if self.ui_state.param_field_names.len() > max_visible_fields {
+            let scroll_info = format!(
+                "Showing fields {}-{} of {} | ↑/↓ to scroll",
+                scroll_start + 1,
+                std::cmp::min(
+                    scroll_start + max_visible_fields,
                     self.ui_state.param_field_names.len()
-                );
+                ),
+                self.ui_state.param_field_names.len()
+            );
 
-                let scroll_area = Rect {
-                    x: chunks[1].x + 2,
-                    y: chunks[1].y + chunks[1].height - 1,
-                    width: chunks[1].width - 4,
-                    height: 1,
-                };
+            let scroll_area = Rect {
+                x: area.x + 2,
+                y: area.y + area.height - 1,
+                width: area.width - 4,
+                height: 1,
+            };
 
-                let scroll_paragraph =
-                    Paragraph::new(scroll_info).style(Style::default().fg(Color::Cyan));
-                f.render_widget(scroll_paragraph, scroll_area);
-            }
+            let scroll_paragraph =
+                Paragraph::new(scroll_info).style(Style::default().fg(self.theme.info));
+            f.render_widget(scroll_paragraph, scroll_area);
         }
+    }
 
-        // Draw instructions at bottom
-        let instructions = if self.ui_state.param_field_names.is_empty() {
-            "🚀 [Enter] Execute | [Esc] Cancel & Go Back"
-        } else if self.ui_state.param_edit_mode {
-            "✏️  EDITING MODE | [Enter] Save & Move to Next | [Esc] Stop Editing | [Tab] Execute with Current Values"
+    /// Draw the elicitation dialog for a pending server request
+    fn draw_elicitation_form(&self, f: &mut Frame, area: Rect, message: &str) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5), // Server message
+                Constraint::Min(1),    // Requested fields
+                Constraint::Length(3), // Controls
+            ])
+            .split(area);
+
+        let header_paragraph = Paragraph::new(message.to_string())
+            .style(
+                Style::default()
+                    .fg(self.theme.info)
+                    .add_modifier(ratatui::style::Modifier::BOLD),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("🙋 Server Requests Input")
+                    .border_style(Style::default().fg(self.theme.accent)),
+            );
+        f.render_widget(header_paragraph, chunks[0]);
+
+        if self.ui_state.param_field_names.is_empty() {
+            let no_fields_paragraph = Paragraph::new(format!(
+                "✅ The server requested no data.\n\nPress Enter to accept, {} to decline, or Esc to cancel.",
+                self.keymap.label(Action::Decline)
+            ))
+            .style(Style::default().fg(self.theme.success))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL))
+            .wrap(Wrap { trim: true });
+            f.render_widget(no_fields_paragraph, chunks[1]);
         } else {
-            "📝 [↑/↓] Navigate & Edit Fields | [Tab] Execute | [Esc] Cancel & Go Back"
-        };
+            self.draw_parameter_fields(f, chunks[1]);
+        }
 
-        let instructions_block = Block::default()
-            .borders(Borders::ALL)
-            .title("🎮 Controls")
-            .border_style(Style::default().fg(Color::Cyan));
+        let instructions = if self.ui_state.param_edit_mode {
+            "✏️  EDITING MODE | [Enter] Save & Move to Next | [Esc] Stop Editing | [Tab] Accept"
+                .to_string()
+        } else {
+            format!(
+                "📝 [↑/↓] Navigate | [Tab] Accept | [{}] Decline | [Esc] Cancel",
+                self.keymap.label(Action::Decline)
+            )
+        };
 
         let instructions_paragraph = Paragraph::new(instructions)
             .style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(self.theme.accent)
                     .add_modifier(ratatui::style::Modifier::BOLD),
             )
-            .alignment(ratatui::layout::Alignment::Center)
-            .block(instructions_block);
-
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("🎮 Controls")
+                    .border_style(Style::default().fg(self.theme.info)),
+            );
         f.render_widget(instructions_paragraph, chunks[2]);
     }
 
     /// Draw response viewer dialog
     fn draw_response_viewer_dialog(&mut self, f: &mut Frame, area: Rect) {
+        if self.ui_state.response_diff.is_some() {
+            self.draw_response_diff(f, area);
+            return;
+        }
         if let Some(ref response) = self.ui_state.selected_response {
             let popup_area = centered_rect(90, 80, area);
 
@@ -3949,8 +7009,12 @@ impl DebuggerApp {
                 ResponseViewMode::Summary => "📋 Summary",
             };
 
-            let mode_help =
-                " | Press 'V' to cycle view modes | ↑/↓ ←/→ PgUp/PgDn to scroll | ESC to close";
+            let mode_help = format!(
+                " | Press {} to cycle view modes | {} to save binary payloads | ↑/↓ ←/→ PgUp/PgDn to scroll | {} to close",
+                self.keymap.label(Action::CycleView),
+                self.keymap.label(Action::SavePayloads),
+                self.keymap.label(Action::Cancel)
+            );
             let title = format!("Response Viewer - {}{}", mode_display, mode_help);
 
             // Generate content based on view mode
@@ -4002,7 +7066,7 @@ impl DebuggerApp {
             let block = Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(Color::Cyan));
+                .border_style(Style::default().fg(self.theme.info));
 
             let text = Text::from(content);
             let paragraph = Paragraph::new(text)
@@ -4057,15 +7121,107 @@ impl DebuggerApp {
         }
     }
 
-    /// Format response content based on current view mode
+    /// Draw the marked responses side by side with changes highlighted
+    fn draw_response_diff(&mut self, f: &mut Frame, area: Rect) {
+        let Some(diff) = &self.ui_state.response_diff else {
+            return;
+        };
+        let popup_area = centered_rect(90, 80, area);
+        let lines = diff.tree.lines(self.ui_state.diff_collapse_unchanged);
+        let title = format!(
+            "Response Diff - {} ↔ {} | {} | {} {} unchanged | {} export | {} close",
+            diff.before_label,
+            diff.after_label,
+            diff.tree.stats(),
+            self.keymap.label(Action::ToggleUnchanged),
+            if self.ui_state.diff_collapse_unchanged {
+                "expand"
+            } else {
+                "collapse"
+            },
+            self.keymap.label(Action::ExportDiff),
+            self.keymap.label(Action::Cancel)
+        );
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(self.theme.info));
+        let inner = block.inner(popup_area);
+        f.render_widget(Clear, popup_area);
+        f.render_widget(block, popup_area);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(inner);
+
+        // Clamp the shared scroll position to the content
+        let visible_lines = inner.height as usize;
+        let max_line_width = lines
+            .iter()
+            .map(|line| line.left.chars().count().max(line.right.chars().count()))
+            .max()
+            .unwrap_or(0);
+        let max_vertical_scroll = lines.len().saturating_sub(visible_lines);
+        let max_horizontal_scroll =
+            max_line_width.saturating_sub(columns[0].width.saturating_sub(1) as usize);
+        self.ui_state.response_viewer_vertical_pos = self
+            .ui_state
+            .response_viewer_vertical_pos
+            .min(max_vertical_scroll);
+        self.ui_state.response_viewer_horizontal_pos = self
+            .ui_state
+            .response_viewer_horizontal_pos
+            .min(max_horizontal_scroll);
+        let scroll = (
+            self.ui_state.response_viewer_vertical_pos as u16,
+            self.ui_state.response_viewer_horizontal_pos as u16,
+        );
+
+        let style_for = |kind: LineKind, side_present: bool| match kind {
+            LineKind::Same => Style::default(),
+            LineKind::Collapsed => Style::default().fg(self.theme.muted),
+            LineKind::Added if side_present => Style::default().fg(self.theme.success),
+            LineKind::Removed if side_present => Style::default().fg(self.theme.error),
+            LineKind::Changed => Style::default().fg(self.theme.accent),
+            LineKind::Added | LineKind::Removed => Style::default(),
+        };
+        let marker = |kind: LineKind, side_present: bool| match kind {
+            LineKind::Added if side_present => "+",
+            LineKind::Removed if side_present => "-",
+            LineKind::Changed => "~",
+            _ => " ",
+        };
+        let (left, right): (Vec<Line>, Vec<Line>) = lines
+            .iter()
+            .map(|line| {
+                let side = |text: &str| {
+                    let present = !text.is_empty();
+                    Line::from(Span::styled(
+                        format!("{}{}", marker(line.kind, present), secrets::redact(text)),
+                        style_for(line.kind, present),
+                    ))
+                };
+                (side(&line.left), side(&line.right))
+            })
+            .unzip();
+
+        for (column, text) in [(columns[0], left), (columns[1], right)] {
+            f.render_widget(Paragraph::new(text).scroll(scroll), column);
+        }
+    }
+
+    /// Format response content based on current view mode, scrubbing known secrets
     fn format_response_content(&self, response: &Value) -> String {
-        match self.ui_state.response_viewer_mode {
+        let content = match self.ui_state.response_viewer_mode {
             ResponseViewMode::Formatted => self.format_response_formatted(response),
             ResponseViewMode::RawJson => serde_json::to_string_pretty(response)
                 .unwrap_or_else(|_| "Invalid JSON".to_string()),
             ResponseViewMode::TreeView => Self::format_response_tree(response, 0),
             ResponseViewMode::Summary => self.format_response_summary(response),
-        }
+        };
+        secrets::redact(&content)
     }
 
     /// Format response in a structured, readable way
@@ -4108,6 +7264,12 @@ impl DebuggerApp {
                     }
                 }
 
+                if let Some(structured) = obj.get("structuredContent") {
+                    lines.push("🧩 STRUCTURED CONTENT:".to_string());
+                    lines.push(Self::format_structured_tree(structured));
+                    lines.push("".to_string());
+                }
+
                 if let Some(meta) = obj.get("_meta") {
                     lines.push("🔍 METADATA:".to_string());
                     lines.push(format!(
@@ -4119,7 +7281,10 @@ impl DebuggerApp {
 
                 // Handle other fields
                 for (key, value) in obj {
-                    if !matches!(key.as_str(), "content" | "isError" | "is_error" | "_meta") {
+                    if !matches!(
+                        key.as_str(),
+                        "content" | "isError" | "is_error" | "structuredContent" | "_meta"
+                    ) {
                         lines.push(format!("📊 {}:", key.to_uppercase()));
                         lines.push(format!("  {}", Self::format_value_indented(value, 1)));
                         lines.push("".to_string());
@@ -4173,29 +7338,160 @@ impl DebuggerApp {
 
     /// Format a single content item
     fn format_content_item(&self, item: &Value) -> String {
-        if let Value::Object(obj) = item {
-            if let Some(text) = obj.get("text") {
-                if let Some(text_str) = text.as_str() {
-                    return format!("Text: {}", text_str);
-                }
-            }
-            if let Some(data) = obj.get("data") {
-                if let Some(mime_type) = obj.get("mimeType").or_else(|| obj.get("mime_type")) {
-                    return format!(
-                        "Binary data ({}): {} bytes",
-                        mime_type.as_str().unwrap_or("unknown"),
-                        data.as_str().map(|s| s.len()).unwrap_or(0)
-                    );
-                }
+        let Value::Object(obj) = item else {
+            return serde_json::to_string_pretty(item).unwrap_or_default();
+        };
+        let mime_of = |value: &serde_json::Map<String, Value>| {
+            value
+                .get("mimeType")
+                .or_else(|| value.get("mime_type"))
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string()
+        };
+
+        match obj.get("type").and_then(Value::as_str) {
+            Some("text") | None if obj.get("text").is_some_and(Value::is_string) => {
+                format!("Text: {}", obj["text"].as_str().unwrap_or_default())
+            }
+            Some(kind @ ("image" | "audio")) => {
+                let label = if kind == "image" {
+                    "🖼️ Image"
+                } else {
+                    "🔊 Audio"
+                };
+                let data = obj.get("data").and_then(Value::as_str).unwrap_or_default();
+                Self::format_binary_payload(label, &mime_of(obj), data)
             }
-            if let Some(resource) = obj.get("resource") {
-                return format!(
-                    "Resource: {}",
-                    serde_json::to_string_pretty(resource).unwrap_or_default()
+            Some("resource_link") => {
+                let mut line = format!(
+                    "🔗 Resource link: {} <{}>",
+                    obj.get("name").and_then(Value::as_str).unwrap_or("unnamed"),
+                    obj.get("uri").and_then(Value::as_str).unwrap_or("?")
                 );
+                line.push_str(&format!(" ({}", mime_of(obj)));
+                if let Some(size) = obj.get("size").and_then(Value::as_u64) {
+                    line.push_str(&format!(", {}", format_byte_size(size as usize)));
+                }
+                line.push(')');
+                if let Some(description) = obj.get("description").and_then(Value::as_str) {
+                    line.push_str(&format!("\n      {}", description));
+                }
+                line
+            }
+            Some("resource") | None if obj.get("resource").is_some_and(Value::is_object) => {
+                let resource = obj["resource"].as_object().cloned().unwrap_or_default();
+                let uri = resource.get("uri").and_then(Value::as_str).unwrap_or("?");
+                if let Some(blob) = resource.get("blob").and_then(Value::as_str) {
+                    Self::format_binary_payload(
+                        &format!("📦 Embedded resource {}", uri),
+                        &mime_of(&resource),
+                        blob,
+                    )
+                } else if let Some(text) = resource.get("text").and_then(Value::as_str) {
+                    format!(
+                        "📄 Embedded resource {} ({}, {} chars)\n      {}",
+                        uri,
+                        mime_of(&resource),
+                        text.chars().count(),
+                        text.replace('\n', "\n      ")
+                    )
+                } else {
+                    format!(
+                        "Resource: {}",
+                        serde_json::to_string_pretty(&resource).unwrap_or_default()
+                    )
+                }
+            }
+            None if obj.get("data").is_some() => {
+                let data = obj.get("data").and_then(Value::as_str).unwrap_or_default();
+                Self::format_binary_payload("Binary data", &mime_of(obj), data)
+            }
+            Some(other) if !matches!(other, "text" | "resource") => format!(
+                "❓ Unknown content type '{}'\n{}",
+                other,
+                serde_json::to_string_pretty(item).unwrap_or_default()
+            ),
+            _ => serde_json::to_string_pretty(item).unwrap_or_default(),
+        }
+    }
+
+    /// Describe a base64 payload with its MIME type, decoded size and a hexdump preview
+    fn format_binary_payload(label: &str, mime_type: &str, data: &str) -> String {
+        match decode_base64_payload(data) {
+            Some(bytes) => format!(
+                "{} ({}, {}) [S to save]\n{}",
+                label,
+                mime_type,
+                format_byte_size(bytes.len()),
+                hexdump(&bytes, HEXDUMP_PREVIEW_BYTES)
+            ),
+            None => format!(
+                "{} ({}, {} base64 chars, not valid base64)",
+                label,
+                mime_type,
+                data.len()
+            ),
+        }
+    }
+
+    /// Decode binary payloads in the open response and write them to disk
+    fn save_response_payloads(&mut self) {
+        let Some(response) = self.ui_state.selected_response.clone() else {
+            return;
+        };
+
+        let payloads = collect_binary_payloads(&response);
+        if payloads.is_empty() {
+            self.add_error("No binary payloads in this response".to_string());
+            return;
+        }
+
+        let output_dir = match crate::paths::get_mcp_probe_paths()
+            .and_then(|paths| paths.custom_output_dir("payloads"))
+        {
+            Ok(dir) => dir,
+            Err(e) => {
+                self.add_error(format!("Cannot create payload directory: {}", e));
+                return;
+            }
+        };
+
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+        let mut saved = Vec::new();
+        for (index, (mime_type, data)) in payloads.iter().enumerate() {
+            let Some(bytes) = decode_base64_payload(data) else {
+                self.add_error(format!("Payload {} is not valid base64", index + 1));
+                continue;
+            };
+            let path = output_dir.join(format!(
+                "payload-{}-{}.{}",
+                timestamp,
+                index + 1,
+                extension_for_mime(mime_type)
+            ));
+            match std::fs::write(&path, bytes) {
+                Ok(()) => saved.push(path.display().to_string()),
+                Err(e) => self.add_error(format!("Failed to write {}: {}", path.display(), e)),
             }
         }
-        serde_json::to_string_pretty(item).unwrap_or_default()
+
+        if !saved.is_empty() {
+            self.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::Other("Save Payload".to_string()),
+                request: None,
+                response: None,
+                raw_response: None,
+                error: None,
+                success: Some(format!(
+                    "Saved {} payload(s): {}",
+                    saved.len(),
+                    saved.join(", ")
+                )),
+                latency: None,
+            });
+        }
     }
 
     /// Format value with indentation
@@ -4296,6 +7592,42 @@ impl DebuggerApp {
         }
     }
 
+    /// Render structured tool output as an indented tree below a section header
+    fn format_structured_tree(structured: &Value) -> String {
+        match structured {
+            Value::Object(_) | Value::Array(_) => Self::format_response_tree(structured, 1),
+            other => format!("  └─ {}", other),
+        }
+    }
+
+    /// Short badges describing a tool's annotations and structured output
+    fn tool_badges(tool: &ToolInfo) -> String {
+        let mut badges = Vec::new();
+        if let Some(ref annotations) = tool.annotations {
+            if annotations.read_only_hint == Some(true) {
+                badges.push("🔒 read-only");
+            }
+            if annotations.destructive_hint == Some(true) {
+                badges.push("⚠️ destructive");
+            }
+            if annotations.idempotent_hint == Some(true) {
+                badges.push("♻️ idempotent");
+            }
+            if annotations.open_world_hint == Some(true) {
+                badges.push("🌐 open-world");
+            }
+        }
+        if tool.output_schema.is_some() {
+            badges.push("🧩 structured output");
+        }
+
+        if badges.is_empty() {
+            String::new()
+        } else {
+            format!("  {}", badges.join("  "))
+        }
+    }
+
     /// Format response summary with key statistics
     fn format_response_summary(&self, response: &Value) -> String {
         let mut lines = Vec::new();
@@ -4337,6 +7669,16 @@ impl DebuggerApp {
                     }
                 }
 
+                if let Some(structured) = obj.get("structuredContent") {
+                    let shape = match structured {
+                        Value::Object(map) => format!("object with {} fields", map.len()),
+                        Value::Array(arr) => format!("array with {} items", arr.len()),
+                        _ => "primitive value".to_string(),
+                    };
+                    lines.push(format!("🧩 Structured Content: {}", shape));
+                    lines.push("".to_string());
+                }
+
                 // Check for error status
                 if let Some(is_error) = obj.get("isError").or_else(|| obj.get("is_error")) {
                     if is_error.as_bool() == Some(true) {
@@ -4369,15 +7711,134 @@ impl DebuggerApp {
             }
         }
 
-        lines.push("".to_string());
-        lines.push("─".repeat(50));
+        lines.push("".to_string());
+        lines.push("─".repeat(50));
+
+        let json_size = serde_json::to_string(response)
+            .map(|s| s.len())
+            .unwrap_or(0);
+        lines.push(format!("📏 JSON Size: {} bytes", json_size));
+
+        lines.join("\n")
+    }
+
+    /// Show a response in the response viewer, from the top
+    fn open_response_viewer(&mut self, response: Value) {
+        self.ui_state.selected_response = Some(response);
+        self.ui_state.response_diff = None;
+        self.ui_state.response_viewer_open = true;
+        self.ui_state.response_viewer_vertical_pos = 0;
+        self.ui_state.response_viewer_horizontal_pos = 0;
+    }
+
+    /// Mark or unmark the selected message for diffing; a third mark
+    /// replaces the oldest
+    fn toggle_diff_mark(&mut self) {
+        let Some(index) = self.selected_message() else {
+            return;
+        };
+        let number = self.history_offset + index;
+        if let Some(position) = self.diff_marks.iter().position(|mark| *mark == number) {
+            self.diff_marks.remove(position);
+        } else {
+            if self.diff_marks.len() == 2 {
+                self.diff_marks.remove(0);
+            }
+            self.diff_marks.push(number);
+        }
+    }
+
+    /// Compare the two marked messages in the response viewer
+    fn open_diff(&mut self) {
+        let mut marks = self.diff_marks.clone();
+        marks.sort_unstable();
+        let [before, after] = marks[..] else {
+            self.add_error(format!(
+                "Mark two messages with {} to diff them",
+                self.keymap.label(Action::MarkDiff)
+            ));
+            return;
+        };
 
-        let json_size = serde_json::to_string(response)
-            .map(|s| s.len())
-            .unwrap_or(0);
-        lines.push(format!("📏 JSON Size: {} bytes", json_size));
+        let mut sides = Vec::new();
+        for number in [before, after] {
+            let Some(message) = number
+                .checked_sub(self.history_offset)
+                .and_then(|index| self.message_history.get(index))
+            else {
+                self.add_error(format!("Message #{} is no longer in the history", number));
+                return;
+            };
+            let Some(payload) = message.diff_payload() else {
+                self.add_error(format!("Message #{} has no response to compare", number));
+                return;
+            };
+            let label = format!(
+                "#{} {}",
+                number,
+                message
+                    .method()
+                    .unwrap_or_else(|| message.message_type.to_string())
+            );
+            sides.push((label, payload));
+        }
+        let (after_label, after) = sides.pop().unwrap_or_default();
+        let (before_label, before) = sides.pop().unwrap_or_default();
+
+        self.open_response_viewer(after.clone());
+        self.ui_state.response_diff = Some(ResponseDiff {
+            before_label,
+            after_label,
+            tree: DiffNode::between(&before, &after),
+            before,
+            after,
+        });
+    }
 
-        lines.join("\n")
+    /// Write the open diff to the `diffs` output directory
+    fn export_diff(&mut self) {
+        let Some(diff) = &self.ui_state.response_diff else {
+            return;
+        };
+        let stats = diff.tree.stats();
+        let mut export = serde_json::json!({
+            "before": {"message": diff.before_label, "payload": diff.before},
+            "after": {"message": diff.after_label, "payload": diff.after},
+            "summary": {
+                "added": stats.added,
+                "removed": stats.removed,
+                "changed": stats.changed,
+            },
+            "changes": diff.tree.changes(),
+        });
+        secrets::redact_payload(&mut export);
+
+        let path = crate::paths::get_mcp_probe_paths()
+            .and_then(|paths| paths.custom_output_dir("diffs"))
+            .map(|dir| {
+                dir.join(format!(
+                    "diff-{}.json",
+                    chrono::Utc::now().format("%Y%m%d_%H%M%S")
+                ))
+            });
+        let result = path.and_then(|path| {
+            let text = serde_json::to_string_pretty(&export)?;
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        match result {
+            Ok(path) => self.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::Other("Export Diff".to_string()),
+                request: None,
+                response: None,
+                raw_response: None,
+                error: None,
+                success: Some(format!("Diff ({}) saved to {}", stats, path.display())),
+                latency: None,
+            }),
+            Err(e) => self.add_error(format!("Failed to export diff: {}", e)),
+        }
     }
 
     /// Open response viewer for the latest message with results
@@ -4390,14 +7851,10 @@ impl DebuggerApp {
             .rev()
             .find(|(_idx, msg)| msg.raw_response.is_some())
         {
-            if let Some(ref response) = message.raw_response {
-                self.ui_state.selected_response = Some(response.clone());
-                self.ui_state.response_viewer_open = true;
-                // Reset scroll positions to start from top
-                self.ui_state.response_viewer_vertical_pos = 0;
-                self.ui_state.response_viewer_horizontal_pos = 0;
+            if let Some(response) = message.raw_response.clone() {
+                self.open_response_viewer(response);
                 // Auto-select this message in the history for context
-                self.ui_state.message_history_state.select(Some(index));
+                self.select_message(index);
                 tracing::info!("Opened response viewer for latest message with results");
             }
         } else {
@@ -4434,6 +7891,336 @@ impl DebuggerApp {
 }
 
 /// Helper function to create centered rect
+/// Number of payload bytes shown in the response viewer hexdump
+const HEXDUMP_PREVIEW_BYTES: usize = 64;
+
+/// Decode a base64 payload, tolerating embedded whitespace
+fn decode_base64_payload(data: &str) -> Option<Vec<u8>> {
+    use base64::Engine;
+    let compact: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .ok()
+}
+
+/// Human-readable byte size
+fn format_byte_size(bytes: usize) -> String {
+    match bytes {
+        b if b < 1024 => format!("{} B", b),
+        b if b < 1024 * 1024 => format!("{:.1} KB", b as f64 / 1024.0),
+        b => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Request latency for the message list, e.g. `850ms` or `1.24s`
+fn format_latency(latency: Duration) -> String {
+    if latency < Duration::from_secs(1) {
+        format!("{}ms", latency.as_millis())
+    } else {
+        format!("{:.2}s", latency.as_secs_f64())
+    }
+}
+
+/// Classic offset/hex/ASCII dump of the first `max_bytes` bytes
+fn hexdump(bytes: &[u8], max_bytes: usize) -> String {
+    let mut lines: Vec<String> = bytes
+        .chunks(16)
+        .take(max_bytes.div_ceil(16))
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex = chunk
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("      {:08x}  {:<47}  |{}|", row * 16, hex, ascii)
+        })
+        .collect();
+
+    if bytes.len() > max_bytes {
+        lines.push(format!("      ... {} more bytes", bytes.len() - max_bytes));
+    }
+    lines.join("\n")
+}
+
+/// Collect `(mime type, base64 data)` for every binary payload in a response
+fn collect_binary_payloads(response: &Value) -> Vec<(String, String)> {
+    let items = response
+        .get("content")
+        .or_else(|| response.get("contents"))
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let messages = response
+        .get("messages")
+        .and_then(Value::as_array)
+        .map(|messages| {
+            messages
+                .iter()
+                .filter_map(|message| message.get("content").cloned())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    items
+        .iter()
+        .chain(messages.iter())
+        .filter_map(|item| {
+            let holder = item.get("resource").unwrap_or(item);
+            let data = holder.get("blob").or_else(|| item.get("data"))?.as_str()?;
+            let mime_type = holder
+                .get("mimeType")
+                .and_then(Value::as_str)
+                .unwrap_or("application/octet-stream");
+            Some((mime_type.to_string(), data.to_string()))
+        })
+        .collect()
+}
+
+/// File extension for a saved payload
+fn extension_for_mime(mime_type: &str) -> &'static str {
+    match mime_type {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/svg+xml" => "svg",
+        "audio/wav" | "audio/x-wav" => "wav",
+        "audio/mpeg" => "mp3",
+        "audio/ogg" => "ogg",
+        "application/pdf" => "pdf",
+        "application/json" => "json",
+        "text/plain" => "txt",
+        _ => "bin",
+    }
+}
+
+/// Spans of `text` cut to `max_len` bytes, with the byte ranges in
+/// `highlights` drawn in `highlight`
+fn highlighted_spans(
+    text: &str,
+    highlights: &[std::ops::Range<usize>],
+    max_len: usize,
+    style: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let cut = if text.len() > max_len {
+        (0..=max_len.saturating_sub(3))
+            .rev()
+            .find(|&index| text.is_char_boundary(index))
+            .unwrap_or(0)
+    } else {
+        text.len()
+    };
+
+    let mut spans = Vec::new();
+    let mut position = 0;
+    for range in highlights {
+        let (start, end) = (range.start.max(position), range.end.min(cut));
+        if start >= end {
+            continue;
+        }
+        if start > position {
+            spans.push(Span::styled(text[position..start].to_string(), style));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), highlight));
+        position = end;
+    }
+    if position < cut {
+        spans.push(Span::styled(text[position..cut].to_string(), style));
+    }
+    if cut < text.len() {
+        spans.push(Span::styled("...", style));
+    }
+    spans
+}
+
+/// Shorten text to one history line
+fn truncate_summary(text: &str) -> String {
+    const MAX_LEN: usize = 120;
+    if text.len() <= MAX_LEN {
+        return text.to_string();
+    }
+    let cut = (0..MAX_LEN)
+        .rev()
+        .find(|&index| text.is_char_boundary(index))
+        .unwrap_or(0);
+    format!("{}…", &text[..cut])
+}
+
+/// How long a capability change stays in the status bar
+const CAPABILITY_NOTICE_DURATION: Duration = Duration::from_secs(10);
+
+/// Tool of a server as shown in the capability panels
+fn tool_info(tool: Tool, server: usize) -> ToolInfo {
+    ToolInfo {
+        name: DebuggerApp::strip_tool_prefix(&tool.name).to_string(),
+        title: tool
+            .title
+            .clone()
+            .or_else(|| tool.annotations.as_ref().and_then(|a| a.title.clone())),
+        full_name: tool.name, // Use the exact name from server
+        description: Some(tool.description),
+        parameters: tool.input_schema,
+        output_schema: tool.output_schema,
+        annotations: tool.annotations,
+        server,
+    }
+}
+
+/// Fetch the raw tool, resource and prompt lists, skipping failed ones
+async fn fetch_capability_lists(client: &mut McpClient) -> CapabilityLists {
+    let mut lists = HashMap::new();
+    for kind in ListKind::ALL {
+        let Ok(response) = client
+            .send_request(kind.list_method(), serde_json::json!({}))
+            .await
+        else {
+            continue;
+        };
+        if let Some(Value::Array(items)) = response
+            .result
+            .as_ref()
+            .and_then(|result| result.get(kind.result_field()))
+        {
+            lists.insert(kind, items.clone());
+        }
+    }
+    lists
+}
+
+/// Resource of a server as shown in the capability panels
+fn resource_info(resource: Resource, server: usize) -> ResourceInfo {
+    ResourceInfo {
+        uri: resource.uri,
+        name: Some(resource.name),
+        description: resource.description,
+        mime_type: resource.mime_type,
+        server,
+    }
+}
+
+/// Prompt of a server as shown in the capability panels
+fn prompt_info(prompt: Prompt, server: usize) -> PromptInfo {
+    PromptInfo {
+        name: prompt.name,
+        description: Some(prompt.description),
+        arguments: prompt.arguments,
+        server,
+    }
+}
+
+/// Replace one server's items in a list grouped by server, keeping the
+/// groups in server order
+fn replace_server_items<T>(
+    list: &mut Vec<T>,
+    server: usize,
+    items: Vec<T>,
+    server_of: impl Fn(&T) -> usize,
+) {
+    list.retain(|item| server_of(item) != server);
+    let at = list
+        .iter()
+        .position(|item| server_of(item) > server)
+        .unwrap_or(list.len());
+    list.splice(at..at, items);
+}
+
+/// Tool names offered by more than one server, with the servers offering
+/// each, from `(server, tool name)` pairs
+pub fn name_collisions<'a>(
+    tools: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> BTreeMap<String, Vec<String>> {
+    let mut servers: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (server, name) in tools {
+        let offering = servers.entry(name.to_string()).or_default();
+        if !offering.iter().any(|known| known == server) {
+            offering.push(server.to_string());
+        }
+    }
+    servers.retain(|_, offering| offering.len() > 1);
+    servers
+}
+
+/// Message the raw composer starts with
+const COMPOSER_TEMPLATE: &str = r#"{"jsonrpc": "2.0", "id": 1, "method": "ping"}"#;
+
+/// Raw composer input holding `text`
+fn composer_input(text: &str) -> TextArea<'static> {
+    let mut composer_area = TextArea::new(text.lines().map(str::to_string).collect());
+    composer_area.move_cursor(CursorMove::Bottom);
+    composer_area.move_cursor(CursorMove::End);
+    composer_area
+}
+
+/// Snippet file name for a raw payload: its method (or `batch`) and a timestamp
+fn snippet_file_name(payload: &str) -> String {
+    let label = match serde_json::from_str::<Value>(payload) {
+        Ok(Value::Array(_)) => "batch".to_string(),
+        Ok(message) => message
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or("raw")
+            .to_string(),
+        Err(_) => "malformed".to_string(),
+    };
+    let label: String = label
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!(
+        "{}-{}.json",
+        label.trim_matches('-'),
+        chrono::Utc::now().format("%Y%m%d_%H%M%S")
+    )
+}
+
+/// Interactive terminal input holding `text`, with the cursor at its end
+fn terminal_input(text: &str) -> TextArea<'static> {
+    let mut input_area = TextArea::new(vec![text.to_string()]);
+    input_area.move_cursor(CursorMove::End);
+    input_area.set_placeholder_text(
+        "Enter a command: tools.<name> {args}, resources.<uri>, prompts.<name> {args}, :ping, :raw, :set-level, :subscribe",
+    );
+    input_area
+}
+
+/// Remove the oldest `session-*.json` files beyond `max_sessions`
+fn prune_sessions(dir: &std::path::Path, max_sessions: usize) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut sessions: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("session-") && name.ends_with(".json"))
+        })
+        .collect();
+    if sessions.len() <= max_sessions {
+        return;
+    }
+
+    // Names embed the timestamp, so lexical order is chronological
+    sessions.sort();
+    for path in &sessions[..sessions.len() - max_sessions] {
+        if let Err(e) = std::fs::remove_file(path) {
+            tracing::warn!("Failed to remove old session {}: {}", path.display(), e);
+        }
+    }
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -4492,6 +8279,682 @@ mod tests {
         assert_eq!(app.env_variables.get("PORT"), Some(&"8080".to_string()));
     }
 
+    #[test]
+    fn test_structured_content_rendered_as_tree() {
+        let app = create_test_app();
+        let response = serde_json::json!({
+            "content": [{"type": "text", "text": "{\"temperature\": 21.5}"}],
+            "structuredContent": {"temperature": 21.5, "readings": [1, 2]}
+        });
+
+        let formatted = app.format_response_formatted(&response);
+        assert!(formatted.contains("🧩 STRUCTURED CONTENT:"));
+        assert!(formatted.contains("temperature 🔢 21.5"));
+        assert!(formatted.contains("readings 📚 [2]"));
+        assert!(!formatted.contains("📊 STRUCTUREDCONTENT:"));
+    }
+
+    #[test]
+    fn test_elicitation_reuses_parameter_form() {
+        let mut app = create_test_app();
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let request = JsonRpcRequest::new(
+            7,
+            ELICITATION_METHOD,
+            serde_json::json!({
+                "message": "Who are you?",
+                "requestedSchema": {
+                    "type": "object",
+                    "properties": {
+                        "username": {"type": "string"},
+                        "age": {"type": "number"}
+                    },
+                    "required": ["username"]
+                }
+            }),
+        );
+
+        app.open_elicitation(ServerRequest::new(request, sender));
+        assert!(app.ui_state.parameter_dialog_open);
+        assert_eq!(app.ui_state.param_field_names, vec!["username", "age"]);
+
+        // Missing required field keeps the dialog open
+        app.handle_elicitation_key(KeyEvent::from(KeyCode::Tab));
+        assert!(app.ui_state.elicitation.is_some());
+        assert!(receiver.try_recv().is_err());
+
+        app.ui_state.param_fields.get_mut("username").unwrap().value = "octocat".to_string();
+        app.handle_elicitation_key(KeyEvent::from(KeyCode::Tab));
+        assert!(app.ui_state.elicitation.is_none());
+        assert!(!app.ui_state.parameter_dialog_open);
+
+        match receiver.try_recv().unwrap() {
+            mcp_probe_core::messages::JsonRpcMessage::Response(response) => assert_eq!(
+                response.result,
+                Some(serde_json::json!({"action": "accept", "content": {"username": "octocat"}}))
+            ),
+            other => panic!("expected response, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_background_requests_complete_independently() {
+        let mut app = create_test_app();
+
+        // Stand-ins for spawn_request: the slow read waits for a signal
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let mut gate = Some(released);
+        for (id, uri) in [(1, "file:///slow"), (2, "file:///fast")] {
+            let sender = app.completion_sender.clone();
+            let gate = if id == 1 { gate.take() } else { None };
+            let call = BackgroundCall::Resource {
+                uri: uri.to_string(),
+            };
+            let label = call.label();
+            let task = tokio::spawn(async move {
+                if let Some(gate) = gate {
+                    let _ = gate.await;
+                }
+                let response = serde_json::from_value(serde_json::json!({"contents": []}));
+                let _ = sender.send(RequestCompletion {
+                    id,
+                    server: 0,
+                    call,
+                    result: BackgroundResult::Resource(response.map_err(Into::into)),
+                    response: None,
+                    elapsed: Duration::from_millis(10 * id),
+                    client: None,
+                });
+            });
+            app.pending_requests.push(PendingRequest {
+                id,
+                server: 0,
+                label,
+                started: Instant::now(),
+                task,
+            });
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        app.poll_completed_requests();
+        assert_eq!(app.pending_requests.len(), 1);
+        assert!(app
+            .pending_requests_summary()
+            .contains("resources/read file:///slow"));
+        assert_eq!(app.message_history.len(), 1);
+        assert_eq!(
+            app.message_history[0].latency,
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(
+            app.message_history[0].method().as_deref(),
+            Some("resources/read")
+        );
+
+        release.send(()).unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        app.poll_completed_requests();
+        assert!(app.pending_requests.is_empty());
+        assert_eq!(app.pending_requests_summary(), "");
+        assert_eq!(app.message_history.len(), 2);
+    }
+
+    #[test]
+    fn test_history_keeps_payloads_and_display_scrubs_secrets() {
+        let mut app = create_test_app();
+        mcp_probe_core::secrets::register_secret("tui-history-secret");
+
+        let result = serde_json::json!({
+            "nextPageToken": "page-2",
+            "token": "tui-history-secret"
+        });
+        app.add_message(MessageEntry {
+            timestamp: Instant::now(),
+            message_type: MessageType::CallTool,
+            request: None,
+            response: None,
+            raw_response: Some(result.clone()),
+            error: None,
+            success: Some("Tool called".to_string()),
+            latency: None,
+        });
+
+        // History, `$last` and diffs see the server's data unchanged
+        assert_eq!(app.message_history[0].raw_response, Some(result.clone()));
+        let shown = app.format_response_content(&result);
+        assert!(shown.contains("page-2"), "{}", shown);
+        assert!(!shown.contains("tui-history-secret"), "{}", shown);
+    }
+
+    #[test]
+    fn test_format_content_item_binary_and_unknown_types() {
+        let app = create_test_app();
+
+        let audio = app.format_content_item(&serde_json::json!({
+            "type": "audio", "mimeType": "audio/wav", "data": "UklGRiQAAABXQVZF"
+        }));
+        assert!(audio.starts_with("🔊 Audio (audio/wav, 12 B)"));
+        assert!(audio.contains("00000000  52 49 46 46 24 00 00 00 57 41 56 45"));
+        assert!(audio.contains("|RIFF$...WAVE|"));
+
+        let link = app.format_content_item(&serde_json::json!({
+            "type": "resource_link", "uri": "file:///r.pdf", "name": "report",
+            "mimeType": "application/pdf", "size": 2048
+        }));
+        assert_eq!(
+            link,
+            "🔗 Resource link: report <file:///r.pdf> (application/pdf, 2.0 KB)"
+        );
+
+        let unknown = app.format_content_item(&serde_json::json!({"type": "hologram"}));
+        assert!(unknown.starts_with("❓ Unknown content type 'hologram'"));
+
+        let payloads = collect_binary_payloads(&serde_json::json!({
+            "content": [
+                {"type": "text", "text": "hi"},
+                {"type": "image", "mimeType": "image/png", "data": "iVBORw0KGgo="},
+                {"type": "resource", "resource": {"uri": "file:///a", "blob": "AAE="}}
+            ]
+        }));
+        assert_eq!(
+            payloads,
+            vec![
+                ("image/png".to_string(), "iVBORw0KGgo=".to_string()),
+                ("application/octet-stream".to_string(), "AAE=".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_traffic_and_snippet_names() {
+        let mut app = create_test_app();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        app.raw_traffic = Some(receiver);
+
+        sender
+            .send(r#"{"jsonrpc":"2.0","method":"notifications/message","params":{}}"#.to_string())
+            .unwrap();
+        sender
+            .send("Traceback (most recent call last)".to_string())
+            .unwrap();
+        app.poll_raw_traffic();
+
+        assert_eq!(app.message_history.len(), 2);
+        assert_eq!(
+            app.message_history[0].success.as_deref(),
+            Some("← notifications/message")
+        );
+        assert!(app.message_history[1]
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("Server sent invalid JSON"));
+        assert_eq!(app.error_count, 1);
+
+        assert!(snippet_file_name(r#"{"method": "tools/call"}"#).starts_with("tools-call-"));
+        assert!(snippet_file_name("[]").starts_with("batch-"));
+        assert!(snippet_file_name("{oops").starts_with("malformed-"));
+    }
+
+    #[test]
+    fn test_search_highlights_split_into_spans() {
+        let plain = Style::default();
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let render = |spans: Vec<Span<'static>>| -> Vec<(String, bool)> {
+            spans
+                .into_iter()
+                .map(|span| (span.content.to_string(), span.style == bold))
+                .collect()
+        };
+
+        assert_eq!(
+            render(highlighted_spans(
+                "read_file",
+                &[0..0, 5..9],
+                120,
+                plain,
+                bold
+            )),
+            [("read_".to_string(), false), ("file".to_string(), true)]
+        );
+        // Highlights past the cut are dropped or shortened
+        assert_eq!(
+            render(highlighted_spans(
+                "écrire un fichier",
+                &[0..6, 13..19],
+                8,
+                plain,
+                bold
+            )),
+            [("écri".to_string(), true), ("...".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_capability_change_replaces_list() {
+        use mcp_probe_core::changes::ListDiff;
+
+        let mut app = create_test_app();
+        app.capabilities.tools = vec![tool_info(
+            serde_json::from_value(serde_json::json!({"name": "old", "description": "Old"}))
+                .unwrap(),
+            0,
+        )];
+        app.ui_state.selected_capability = Some(CapabilityRef::Tool(0));
+
+        let items = vec![serde_json::json!({
+            "name": "fresh",
+            "description": "Registered at runtime",
+            "inputSchema": {"type": "object"}
+        })];
+        let before = vec![serde_json::json!({"name": "old", "description": "Old"})];
+        let (sender, receiver) = mpsc::unbounded_channel();
+        app.capability_changes = Some(receiver);
+        sender
+            .send(Ok(CapabilityChange {
+                diff: ListDiff::between(ListKind::Tools, &before, &items),
+                items,
+                received_at: std::time::SystemTime::now(),
+            }))
+            .unwrap();
+        app.poll_capability_changes();
+
+        assert_eq!(app.capabilities.tools.len(), 1);
+        assert_eq!(app.capabilities.tools[0].name, "fresh");
+        assert!(app.ui_state.selected_capability.is_none());
+        assert_eq!(app.search_engine.total_items(), 1);
+
+        let entry = app.message_history.last().unwrap();
+        assert_eq!(entry.message_type.to_string(), "Capabilities Changed");
+        assert_eq!(
+            entry.success.as_deref(),
+            Some("tools: 1 added (fresh), 1 removed (old)")
+        );
+        assert_eq!(entry.raw_response.as_ref().unwrap()["added"][0], "fresh");
+        assert!(app.capability_notice.is_some());
+    }
+
+    #[test]
+    fn test_server_exit_and_stdout_pollution_reported_once() {
+        use mcp_probe_core::transport::{ProcessExit, StdoutIssueKind};
+
+        let mut app = create_test_app();
+        let (sender, receiver) = oneshot::channel();
+        app.process_log_receiver = Some(receiver);
+        let log = ProcessLog::new(10);
+        sender.send(log.clone()).unwrap();
+        app.poll_process_log();
+        assert!(app.process_log.is_some());
+        assert!(!app.ui_state.stderr_pane_open);
+
+        log.record_stdout_issue(StdoutIssueKind::NotJson, "Server ready");
+        log.record_stdout_issue(StdoutIssueKind::NotJson, "Listening");
+        app.poll_process_log();
+        assert_eq!(app.stdout_pollution, 2);
+        assert_eq!(app.error_count, 1);
+        assert!(app
+            .message_history
+            .last()
+            .unwrap()
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Server ready"));
+
+        log.push_line("panic: out of memory");
+        log.record_exit(ProcessExit::Code(101));
+        app.poll_process_log();
+        app.poll_process_log();
+        assert!(app.ui_state.stderr_pane_open);
+        assert_eq!(app.error_count, 2);
+        assert!(app
+            .message_history
+            .last()
+            .unwrap()
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("exit code 101"));
+    }
+
+    #[test]
+    fn test_restarted_lists_replace_capabilities_and_show_diff() {
+        let mut app = create_test_app();
+        let tools = |names: &[&str]| -> Vec<Value> {
+            names
+                .iter()
+                .map(|name| serde_json::json!({"name": name, "description": "", "inputSchema": {"type": "object"}}))
+                .collect()
+        };
+        let before = HashMap::from([(ListKind::Tools, tools(&["echo"]))]);
+        let after = HashMap::from([
+            (ListKind::Tools, tools(&["echo", "add"])),
+            (ListKind::Prompts, vec![]),
+        ]);
+
+        app.apply_restarted_lists(before, after);
+        assert_eq!(app.capabilities.tools.len(), 2);
+        assert_eq!(app.watch_lists[&ListKind::Tools].len(), 2);
+        assert!(app.watch_lists[&ListKind::Prompts].is_empty());
+        assert_eq!(
+            app.message_history.last().unwrap().success.as_deref(),
+            Some("tools: 1 added (add)")
+        );
+
+        // The next restart compares with the lists this one fetched
+        let unchanged = HashMap::from([(ListKind::Tools, tools(&["echo", "add"]))]);
+        let messages = app.message_count;
+        app.apply_restarted_lists(app.watch_lists.clone(), unchanged);
+        assert_eq!(app.message_count, messages);
+    }
+
+    #[test]
+    fn test_client_events_shown_while_reconnecting() {
+        use mcp_probe_core::events::ClientEvent;
+
+        let mut app = create_test_app();
+        let (sender, receiver) = broadcast::channel(16);
+        app.client_events = Some(receiver);
+        let emit = |kind| {
+            sender
+                .send(ClientEvent {
+                    at: std::time::SystemTime::now(),
+                    kind,
+                })
+                .unwrap();
+        };
+
+        emit(ClientEventKind::StateChanged {
+            previous: ClientState::Ready,
+            state: ClientState::Reconnecting { attempt: 1 },
+            reason: Some("Connection lost".to_string()),
+        });
+        app.poll_connection();
+        let message = app.message_history.last().unwrap();
+        assert_eq!(
+            message.success.as_deref(),
+            Some("Reconnecting to the server (attempt 1): Connection lost")
+        );
+
+        let (_reconnect_sender, reconnect_receiver) = oneshot::channel();
+        app.reconnect_receiver = Some(reconnect_receiver);
+        emit(ClientEventKind::Reconnected { attempts: 1 });
+        emit(ClientEventKind::ResponseReceived {
+            id: "req_7".to_string(),
+            method: "tools/list".to_string(),
+            latency: Duration::from_millis(42),
+            error_code: None,
+        });
+        app.poll_connection();
+        assert_eq!(
+            app.message_history.last().unwrap().success.as_deref(),
+            Some("Reconnected to the server")
+        );
+        assert_eq!(app.last_latency, Some(Duration::from_millis(42)));
+        assert!(!app.client_available(0, "list tools"));
+        assert_eq!(app.error_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_message_inspector_filter_pins_and_bookmarks() {
+        let mut app = create_test_app();
+        for (method, failed) in [
+            ("tools/call", false),
+            ("resources/read", true),
+            ("tools/call", true),
+        ] {
+            app.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::Other("Raw".to_string()),
+                request: Some(JsonRpcRequest::new(
+                    1,
+                    method,
+                    serde_json::json!({"name": "echo"}),
+                )),
+                response: None,
+                raw_response: None,
+                error: failed.then(|| "boom".to_string()),
+                success: (!failed).then(|| "ok".to_string()),
+                latency: Some(Duration::from_millis(5)),
+            });
+        }
+        assert_eq!(app.inspector_rows, vec![0, 1, 2]);
+        assert_eq!(app.selected_message(), Some(2));
+
+        // Typing into the filter line narrows the list as it goes
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('f')))
+            .await
+            .unwrap();
+        assert!(app.ui_state.filter_editing);
+        for c in "is:error $.params.name == echo".chars() {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char(c)))
+                .await
+                .unwrap();
+        }
+        assert!(app.ui_state.filter_error.is_none());
+        assert_eq!(app.inspector_rows, vec![1, 2]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter))
+            .await
+            .unwrap();
+        assert!(!app.ui_state.filter_editing);
+
+        // Pinned messages stay on top regardless of the filter
+        app.select_message(2);
+        app.toggle_pin();
+        app.message_filter = Filter::parse("method:resources/read").unwrap();
+        app.refresh_inspector();
+        assert_eq!(app.inspector_rows, vec![2, 1]);
+
+        app.message_filter = Filter::default();
+        app.refresh_inspector();
+        assert_eq!(app.inspector_rows, vec![2, 0, 1]);
+
+        app.select_message(0);
+        app.toggle_bookmark();
+        app.select_message(2);
+        app.jump_to_bookmark(true);
+        assert_eq!(app.selected_message(), Some(0));
+        assert_eq!(app.ui_state.current_focus, FocusedPanel::MessageInspector);
+
+        // Clearing the history drops pins and bookmarks
+        app.handle_key_event(KeyEvent::from(KeyCode::F(4)))
+            .await
+            .unwrap();
+        assert!(app.pinned_messages.is_empty() && app.inspector_rows.is_empty());
+    }
+
+    #[test]
+    fn test_response_diff_of_marked_messages() {
+        let mut app = create_test_app();
+        for temperature in [21.5, 23.0, 23.0] {
+            app.add_message(MessageEntry {
+                timestamp: Instant::now(),
+                message_type: MessageType::CallTool,
+                request: None,
+                response: None,
+                raw_response: Some(serde_json::json!({
+                    "content": [{"type": "text", "text": "weather"}],
+                    "structuredContent": {"temperature": temperature, "unit": "C"}
+                })),
+                error: None,
+                success: Some("ok".to_string()),
+                latency: None,
+            });
+        }
+
+        app.open_diff();
+        assert!(app.ui_state.response_diff.is_none());
+        assert_eq!(
+            app.message_history.last().unwrap().message_type.to_string(),
+            "Error"
+        );
+
+        // A third mark replaces the oldest
+        for index in [1, 0, 2] {
+            app.select_message(index);
+            app.toggle_diff_mark();
+        }
+        assert_eq!(app.diff_marks, vec![0, 2]);
+
+        app.open_diff();
+        let diff = app.ui_state.response_diff.as_ref().unwrap();
+        assert!(app.ui_state.response_viewer_open);
+        assert_eq!(diff.before_label, "#0 tools/call");
+        let changes = diff.tree.changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0]["path"], "$.structuredContent.temperature");
+        assert_eq!(changes[0]["after"], 23.0);
+
+        // Any other response shown in the viewer replaces the diff
+        app.open_latest_response_viewer();
+        assert!(app.ui_state.response_diff.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_peer_servers_merge_lists_and_detect_collisions() {
+        let tools = |names: &[&str]| -> Vec<Value> {
+            names
+                .iter()
+                .map(|name| serde_json::json!({"name": name, "description": "", "inputSchema": {"type": "object"}}))
+                .collect()
+        };
+        let implementation = Implementation {
+            name: "remote-server".to_string(),
+            version: "2.0.0".to_string(),
+            metadata: HashMap::new(),
+        };
+        let mut app = create_test_app().with_servers(
+            "local".to_string(),
+            vec![
+                (
+                    "remote".to_string(),
+                    TransportConfig::stdio("remote", &["--stdio"]),
+                ),
+                (
+                    "down".to_string(),
+                    TransportConfig::stdio("down", &["--stdio"]),
+                ),
+            ],
+        );
+        app.replace_capability_list(0, ListKind::Tools, tools(&["search", "echo"]));
+        app.apply_peer_connection(
+            0,
+            PeerConnection {
+                client: None,
+                result: Ok((
+                    implementation,
+                    HashMap::from([(ListKind::Tools, tools(&["search", "fetch"]))]),
+                )),
+            },
+        );
+
+        // Lists stay grouped by server and are searched together
+        let listed: Vec<(usize, &str)> = app
+            .capabilities
+            .tools
+            .iter()
+            .map(|tool| (tool.server, tool.name.as_str()))
+            .collect();
+        assert_eq!(
+            listed,
+            [(0, "search"), (0, "echo"), (1, "search"), (1, "fetch")]
+        );
+        assert_eq!(app.search_engine.search("server:remote", 10).len(), 2);
+        assert_eq!(
+            app.tool_collisions,
+            BTreeMap::from([(
+                "search".to_string(),
+                vec!["local".to_string(), "remote".to_string()]
+            )])
+        );
+        let collision = app.message_history.last().unwrap();
+        assert_eq!(collision.message_type.to_string(), "Name Collision");
+        assert!(collision
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("'search' on local, remote"));
+
+        // Refreshing one server's list keeps the other's
+        app.replace_capability_list(0, ListKind::Tools, tools(&["echo"]));
+        let listed: Vec<(usize, &str)> = app
+            .capabilities
+            .tools
+            .iter()
+            .map(|tool| (tool.server, tool.name.as_str()))
+            .collect();
+        assert_eq!(listed, [(0, "echo"), (1, "search"), (1, "fetch")]);
+        assert!(app.tool_collisions.is_empty());
+
+        app.apply_peer_connection(
+            1,
+            PeerConnection {
+                client: None,
+                result: Err("connection refused".to_string()),
+            },
+        );
+        let statuses: Vec<(String, ServerStatus)> = app
+            .server_statuses()
+            .into_iter()
+            .map(|(name, status)| (name.to_string(), status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                ("local".to_string(), ServerStatus::Connecting),
+                ("remote".to_string(), ServerStatus::Ready),
+                (
+                    "down".to_string(),
+                    ServerStatus::Failed("connection refused".to_string())
+                ),
+            ]
+        );
+        assert!(!app.client_available(2, "call tool 'x'"));
+        assert!(app
+            .message_history
+            .last()
+            .unwrap()
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("not connected to down"));
+    }
+
+    #[tokio::test]
+    async fn test_server_suffix_picks_the_server_of_a_command() {
+        let mut app = create_test_app().with_servers(
+            "local".to_string(),
+            vec![(
+                "remote".to_string(),
+                TransportConfig::stdio("remote", &["--stdio"]),
+            )],
+        );
+        assert_eq!(app.split_server("search@remote"), ("search", Some(1)));
+        assert_eq!(app.split_server("search@local"), ("search", Some(0)));
+        assert_eq!(
+            app.split_server("mailto:me@example"),
+            ("mailto:me@example", None)
+        );
+
+        for server in [0, 1] {
+            app.replace_capability_list(
+                server,
+                ListKind::Tools,
+                vec![serde_json::json!({"name": "search", "description": ""})],
+            );
+        }
+        app.run_command(Command::Tool {
+            name: "search".to_string(),
+            arguments: serde_json::json!({}),
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            app.message_history.last().unwrap().error.as_deref(),
+            Some("Tool 'search' is offered by local, remote; pick one with search@local")
+        );
+    }
+
     fn create_test_app() -> DebuggerApp {
         let transport_config = TransportConfig::stdio("test", &["arg1"]);
         let client_info = Implementation {
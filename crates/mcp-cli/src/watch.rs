@@ -0,0 +1,265 @@
+//! Source file watching for `--watch`
+//!
+//! Files under the watched paths are polled for modification times, so the
+//! watcher works the same on every platform and filesystem (including network
+//! mounts and editors that replace files on save). A burst of saves, such as a
+//! formatter rewriting several files, is reported as one change once the files
+//! have been quiet for [`DEBOUNCE`]. Only stdio servers can be watched, since
+//! restarting means starting a new server process.
+
+use anyhow::{bail, Result};
+use mcp_probe_core::transport::TransportConfig;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+
+/// How often the watched files are checked
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long files must stay unchanged before a change is reported
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Directories skipped when watching a directory tree (hidden ones are too)
+const IGNORED_DIRS: &[&str] = &["target", "node_modules", "__pycache__", "venv", "dist"];
+
+/// Watches files and directory trees for added, modified and removed files.
+#[derive(Debug)]
+pub struct FileWatcher {
+    roots: Vec<PathBuf>,
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl FileWatcher {
+    /// Watch the given files and directories, which must exist.
+    ///
+    /// The initial scan runs on a blocking thread, like [`poll`](Self::poll).
+    pub async fn new(roots: Vec<PathBuf>) -> Result<Self> {
+        if roots.is_empty() {
+            bail!("No paths to watch");
+        }
+        if let Some(missing) = roots.iter().find(|root| !root.exists()) {
+            bail!(
+                "Cannot watch {}: no such file or directory",
+                missing.display()
+            );
+        }
+        let scan_roots = roots.clone();
+        let files = tokio::task::spawn_blocking(move || scan(&scan_roots)).await?;
+        Ok(Self { roots, files })
+    }
+
+    /// Number of files currently watched
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Describe the watched paths for display
+    pub fn describe(&self) -> String {
+        self.roots
+            .iter()
+            .map(|root| root.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Files added, modified or removed since the last check, in path order.
+    ///
+    /// The trees are walked on a blocking thread, so watching a large project
+    /// does not stall the runtime.
+    pub async fn poll(&mut self) -> Vec<PathBuf> {
+        let roots = self.roots.clone();
+        match tokio::task::spawn_blocking(move || scan(&roots)).await {
+            Ok(files) => self.update(files),
+            Err(e) => {
+                tracing::warn!("Scanning watched files failed: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Replace the known files, returning the ones that changed
+    fn update(&mut self, files: BTreeMap<PathBuf, Option<SystemTime>>) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, modified)| self.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.files
+                .keys()
+                .filter(|path| !files.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        self.files = files;
+        changed
+    }
+
+    /// Wait for the next change and return the files involved.
+    pub async fn changed(&mut self) -> Vec<PathBuf> {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let mut changed = self.poll().await;
+            if changed.is_empty() {
+                continue;
+            }
+
+            // Let a burst of saves settle into one change
+            loop {
+                tokio::time::sleep(DEBOUNCE).await;
+                let more = self.poll().await;
+                if more.is_empty() {
+                    break;
+                }
+                changed.extend(more);
+            }
+            changed.sort();
+            changed.dedup();
+            return changed;
+        }
+    }
+
+    /// Watch on a background task, delivering each change to the receiver.
+    ///
+    /// The task stops once the receiver is dropped.
+    pub fn spawn(mut self) -> mpsc::UnboundedReceiver<Vec<PathBuf>> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let changed = self.changed().await;
+                if sender.send(changed).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+}
+
+/// Check that `--watch` can restart the server of a transport.
+///
+/// Watch mode restarts the server process, which mcp-probe only owns for
+/// stdio transports; HTTP servers run elsewhere and would just be reconnected.
+pub fn check_transport(transport: &TransportConfig) -> Result<()> {
+    match transport {
+        TransportConfig::Stdio(_) => Ok(()),
+        other => bail!(
+            "--watch restarts the server process and needs a stdio transport, not {}",
+            other.transport_type()
+        ),
+    }
+}
+
+/// Short description of changed files, e.g. `server.py` or `server.py and 2 more`
+pub fn summarize(changed: &[PathBuf]) -> String {
+    let Some(first) = changed.first() else {
+        return "nothing".to_string();
+    };
+    let name = first
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| first.display().to_string());
+    match changed.len() {
+        1 => name,
+        n => format!("{} and {} more", name, n - 1),
+    }
+}
+
+/// Modification times of every file under the roots
+fn scan(roots: &[PathBuf]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    let mut files = BTreeMap::new();
+    for root in roots {
+        if root.is_dir() {
+            scan_dir(root, &mut files);
+        } else {
+            files.insert(root.clone(), modified(root));
+        }
+    }
+    files
+}
+
+fn scan_dir(dir: &Path, files: &mut BTreeMap<PathBuf, Option<SystemTime>>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with('.') && !IGNORED_DIRS.contains(&name.as_ref()) {
+                scan_dir(&path, files);
+            }
+        } else {
+            files.insert(path.clone(), modified(&path));
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_poll_reports_added_modified_and_removed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = dir.path().join("server.py");
+        let stale = dir.path().join("old.py");
+        std::fs::write(&server, "print('v1')").unwrap();
+        std::fs::write(&stale, "").unwrap();
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::create_dir_all(dir.path().join("pkg")).unwrap();
+
+        let mut watcher = FileWatcher::new(vec![dir.path().to_path_buf()])
+            .await
+            .unwrap();
+        assert_eq!(watcher.file_count(), 2);
+        assert!(watcher.poll().await.is_empty());
+
+        // Hidden directories are not watched
+        std::fs::write(dir.path().join(".git").join("index"), "x").unwrap();
+        let added = dir.path().join("pkg").join("tools.py");
+        std::fs::write(&added, "").unwrap();
+        std::fs::remove_file(&stale).unwrap();
+        let later = SystemTime::now() + Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&server)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        let mut expected = vec![stale, server, added];
+        expected.sort();
+        assert_eq!(watcher.poll().await, expected);
+        assert!(watcher.poll().await.is_empty());
+        assert_eq!(summarize(&expected), "old.py and 2 more");
+    }
+
+    #[test]
+    fn test_watch_needs_a_stdio_transport() {
+        assert!(check_transport(&TransportConfig::stdio("python", &["server.py"])).is_ok());
+        let error =
+            check_transport(&TransportConfig::http_stream("http://localhost:3000").unwrap())
+                .unwrap_err();
+        assert!(error.to_string().contains("not http-stream"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_missing_path_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let error = FileWatcher::new(vec![dir.path().join("missing")])
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("no such file"));
+        assert!(FileWatcher::new(vec![]).await.is_err());
+    }
+}
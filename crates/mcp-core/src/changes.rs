@@ -0,0 +1,368 @@
+//! Capability list change tracking.
+//!
+//! Servers announce changes to their tool, resource and prompt lists with
+//! `notifications/*/list_changed`. With
+//! [`ClientConfig::refresh_on_list_changed`](crate::client::ClientConfig::refresh_on_list_changed)
+//! enabled, [`McpClient`](crate::client::McpClient) re-fetches the list and
+//! reports a [`CapabilityChange`] describing what was added, removed or
+//! modified, down to individual schema fields.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mcp_probe_core::changes::{ListDiff, ListKind};
+//! use serde_json::json;
+//!
+//! let before = vec![json!({"name": "search", "inputSchema": {"type": "object"}})];
+//! let after = vec![
+//!     json!({"name": "search", "inputSchema": {"type": "object", "required": ["q"]}}),
+//!     json!({"name": "fetch", "inputSchema": {"type": "object"}}),
+//! ];
+//!
+//! let diff = ListDiff::between(ListKind::Tools, &before, &after);
+//! assert_eq!(diff.added, vec!["fetch"]);
+//! assert_eq!(diff.modified[0].changes[0].path, "inputSchema.required");
+//! assert_eq!(diff.summary(), "tools: 1 added (fetch), 1 modified (search*)");
+//! ```
+
+use crate::error::McpResult;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::SystemTime;
+use tokio::sync::mpsc;
+
+/// Top-level item fields that hold a schema
+const SCHEMA_FIELDS: &[&str] = &["inputSchema", "outputSchema", "arguments"];
+
+/// A capability list the server can announce changes for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListKind {
+    /// `tools/list`
+    Tools,
+    /// `resources/list`
+    Resources,
+    /// `prompts/list`
+    Prompts,
+}
+
+impl ListKind {
+    /// Every list kind
+    pub const ALL: [ListKind; 3] = [ListKind::Tools, ListKind::Resources, ListKind::Prompts];
+
+    /// The kind whose `notifications/*/list_changed` method this is
+    pub fn from_notification(method: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.notification_method() == method)
+    }
+
+    /// Method announcing a change of this list
+    pub fn notification_method(self) -> &'static str {
+        match self {
+            ListKind::Tools => "notifications/tools/list_changed",
+            ListKind::Resources => "notifications/resources/list_changed",
+            ListKind::Prompts => "notifications/prompts/list_changed",
+        }
+    }
+
+    /// Method fetching this list
+    pub fn list_method(self) -> &'static str {
+        match self {
+            ListKind::Tools => "tools/list",
+            ListKind::Resources => "resources/list",
+            ListKind::Prompts => "prompts/list",
+        }
+    }
+
+    /// Field of the list result holding the items
+    pub fn result_field(self) -> &'static str {
+        match self {
+            ListKind::Tools => "tools",
+            ListKind::Resources => "resources",
+            ListKind::Prompts => "prompts",
+        }
+    }
+
+    /// Item field identifying an item across fetches
+    pub fn key_field(self) -> &'static str {
+        match self {
+            ListKind::Tools | ListKind::Prompts => "name",
+            ListKind::Resources => "uri",
+        }
+    }
+}
+
+impl fmt::Display for ListKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.result_field())
+    }
+}
+
+/// A field of an item that differs between two fetches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Dotted path of the field, e.g. `inputSchema.properties.limit.type`
+    pub path: String,
+
+    /// Previous value (`None` if the field was added)
+    pub before: Option<Value>,
+
+    /// New value (`None` if the field was removed)
+    pub after: Option<Value>,
+}
+
+/// An item present in both fetches whose definition changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModifiedItem {
+    /// Item name (tools, prompts) or URI (resources)
+    pub key: String,
+
+    /// Changed fields, in path order
+    pub changes: Vec<FieldChange>,
+}
+
+impl ModifiedItem {
+    /// Whether an input/output schema or the prompt arguments changed
+    pub fn schema_changed(&self) -> bool {
+        self.changes.iter().any(|change| {
+            let top = change.path.split('.').next().unwrap_or_default();
+            SCHEMA_FIELDS.contains(&top)
+        })
+    }
+}
+
+/// Differences between two fetches of a capability list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListDiff {
+    /// Which list was compared
+    pub kind: ListKind,
+
+    /// Keys of new items, in list order
+    pub added: Vec<String>,
+
+    /// Keys of items that disappeared, in previous list order
+    pub removed: Vec<String>,
+
+    /// Items whose definition changed, in list order
+    pub modified: Vec<ModifiedItem>,
+}
+
+impl ListDiff {
+    /// Compare two fetches of a list, matching items by their key field
+    pub fn between(kind: ListKind, before: &[Value], after: &[Value]) -> Self {
+        let previous: HashMap<String, &Value> = before
+            .iter()
+            .map(|item| (item_key(kind, item), item))
+            .collect();
+        let current: HashSet<String> = after.iter().map(|item| item_key(kind, item)).collect();
+
+        let mut added = Vec::new();
+        let mut modified = Vec::new();
+        for item in after {
+            let key = item_key(kind, item);
+            match previous.get(&key) {
+                None => added.push(key),
+                Some(old) if *old != item => {
+                    let mut changes = Vec::new();
+                    diff_values("", old, item, &mut changes);
+                    modified.push(ModifiedItem { key, changes });
+                }
+                Some(_) => {}
+            }
+        }
+        let removed = before
+            .iter()
+            .map(|item| item_key(kind, item))
+            .filter(|key| !current.contains(key))
+            .collect();
+
+        Self {
+            kind,
+            added,
+            removed,
+            modified,
+        }
+    }
+
+    /// Whether both fetches had the same items
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    /// One-line summary; modified items with schema changes are marked `*`
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return format!("{}: no differences", self.kind);
+        }
+
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            parts.push(format!(
+                "{} added ({})",
+                self.added.len(),
+                self.added.join(", ")
+            ));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!(
+                "{} removed ({})",
+                self.removed.len(),
+                self.removed.join(", ")
+            ));
+        }
+        if !self.modified.is_empty() {
+            let names: Vec<String> = self
+                .modified
+                .iter()
+                .map(|item| {
+                    if item.schema_changed() {
+                        format!("{}*", item.key)
+                    } else {
+                        item.key.clone()
+                    }
+                })
+                .collect();
+            parts.push(format!(
+                "{} modified ({})",
+                self.modified.len(),
+                names.join(", ")
+            ));
+        }
+        format!("{}: {}", self.kind, parts.join(", "))
+    }
+}
+
+/// A capability list re-fetched after a `list_changed` notification.
+#[derive(Debug, Clone)]
+pub struct CapabilityChange {
+    /// What changed since the previous fetch
+    pub diff: ListDiff,
+
+    /// The complete list as now reported by the server
+    pub items: Vec<Value>,
+
+    /// When the notification was handled
+    pub received_at: SystemTime,
+}
+
+/// Receiver for capability list changes, see
+/// [`McpClient::take_capability_changes`](crate::client::McpClient::take_capability_changes).
+pub type CapabilityChangeReceiver = mpsc::UnboundedReceiver<McpResult<CapabilityChange>>;
+
+fn item_key(kind: ListKind, item: &Value) -> String {
+    match item.get(kind.key_field()) {
+        Some(Value::String(key)) => key.clone(),
+        _ => item.to_string(),
+    }
+}
+
+fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<FieldChange>) {
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => diff_objects(path, old, new, changes),
+        _ if before != after => changes.push(FieldChange {
+            path: path.to_string(),
+            before: Some(before.clone()),
+            after: Some(after.clone()),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    path: &str,
+    before: &Map<String, Value>,
+    after: &Map<String, Value>,
+    changes: &mut Vec<FieldChange>,
+) {
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    for key in keys {
+        let field = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        match (before.get(key), after.get(key)) {
+            (Some(old), Some(new)) => diff_values(&field, old, new, changes),
+            (old, new) => changes.push(FieldChange {
+                path: field,
+                before: old.cloned(),
+                after: new.cloned(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_list_diff() {
+        let before = vec![
+            json!({"name": "search", "description": "Search", "inputSchema": {
+                "type": "object",
+                "properties": {"q": {"type": "string"}, "limit": {"type": "integer"}}
+            }}),
+            json!({"name": "old", "inputSchema": {"type": "object"}}),
+            json!({"name": "same", "inputSchema": {"type": "object"}}),
+            json!({"name": "docs", "description": "v1", "inputSchema": {"type": "object"}}),
+        ];
+        let after = vec![
+            json!({"name": "search", "description": "Search", "inputSchema": {
+                "type": "object",
+                "properties": {"q": {"type": "string"}, "limit": {"type": "number"}, "page": {"type": "integer"}}
+            }}),
+            json!({"name": "same", "inputSchema": {"type": "object"}}),
+            json!({"name": "docs", "description": "v2", "inputSchema": {"type": "object"}}),
+            json!({"name": "new", "inputSchema": {"type": "object"}}),
+        ];
+
+        let diff = ListDiff::between(ListKind::Tools, &before, &after);
+        assert_eq!(diff.added, vec!["new"]);
+        assert_eq!(diff.removed, vec!["old"]);
+        assert_eq!(diff.modified.len(), 2);
+
+        let search = &diff.modified[0];
+        assert!(search.schema_changed());
+        let paths: Vec<&str> = search.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "inputSchema.properties.limit.type",
+                "inputSchema.properties.page"
+            ]
+        );
+        assert_eq!(search.changes[1].before, None);
+
+        let docs = &diff.modified[1];
+        assert!(!docs.schema_changed());
+        assert_eq!(docs.changes[0].after, Some(json!("v2")));
+
+        assert_eq!(
+            diff.summary(),
+            "tools: 1 added (new), 1 removed (old), 2 modified (search*, docs)"
+        );
+
+        // Resources are matched by URI
+        let resources = ListDiff::between(
+            ListKind::Resources,
+            &[json!({"uri": "file:///a", "name": "A"})],
+            &[json!({"uri": "file:///a", "name": "A"})],
+        );
+        assert!(resources.is_empty());
+        assert_eq!(resources.summary(), "resources: no differences");
+
+        assert_eq!(
+            ListKind::from_notification("notifications/prompts/list_changed"),
+            Some(ListKind::Prompts)
+        );
+        assert_eq!(ListKind::from_notification("notifications/progress"), None);
+    }
+}
@@ -9,25 +9,35 @@
 
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use tokio::sync::{mpsc, oneshot, RwLock};
 use tokio::time::{sleep, Instant};
 
-use crate::error::{McpError, McpResult, ProtocolError};
+use crate::changes::{CapabilityChange, CapabilityChangeReceiver, ListDiff, ListKind};
+use crate::error::{McpError, McpResult, ProtocolError, TransportError};
+use crate::events::{ClientEventKind, ClientEventReceiver, EventSender};
 use crate::messages::{
     Capabilities, Implementation, InitializeRequest, InitializeResponse, InitializedNotification,
     JsonRpcId, JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
     ProgressNotification, PromptListChangedNotification, ProtocolVersion,
     ResourceListChangedNotification, ResourceUpdatedNotification, ToolListChangedNotification,
 };
-use crate::transport::{factory::TransportFactory, Transport, TransportConfig};
+use crate::supervision::{SessionState, SupervisionPolicy};
+use crate::transport::{
+    factory::TransportFactory, SharedRequestSender, Transport, TransportConfig,
+};
 
 use tracing::{debug, info, warn};
 
 /// Configuration options for MCP client behavior.
+///
+/// Fields are added as the client grows; build it with
+/// `..ClientConfig::default()` or [`McpClientBuilder`] so new options keep
+/// their defaults.
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     /// Timeout for individual requests (default: 30 seconds)
@@ -47,6 +57,21 @@ pub struct ClientConfig {
 
     /// Buffer size for incoming messages
     pub message_buffer_size: usize,
+
+    /// Advertise the `elicitation` client capability during initialization.
+    ///
+    /// Only enable this when something drains [`McpClient::take_server_requests`]
+    /// and answers `elicitation/create` requests.
+    pub enable_elicitation: bool,
+
+    /// Re-fetch tool, resource and prompt lists when the server sends
+    /// `notifications/*/list_changed`, reporting the differences through
+    /// [`McpClient::take_capability_changes`].
+    pub refresh_on_list_changed: bool,
+
+    /// Restart or reconnect to the server when the connection is lost
+    /// (default: off). See [`crate::supervision`].
+    pub supervision: Option<SupervisionPolicy>,
 }
 
 impl Default for ClientConfig {
@@ -58,12 +83,19 @@ impl Default for ClientConfig {
             retry_base_delay: Duration::from_secs(1),
             auto_handle_notifications: true,
             message_buffer_size: 1000,
+            enable_elicitation: false,
+            refresh_on_list_changed: false,
+            supervision: None,
         }
     }
 }
 
 /// State of the MCP client connection and protocol negotiation.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// New states may be added, so matches need a `_` arm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ClientState {
     /// Client is disconnected
     Disconnected,
@@ -73,6 +105,11 @@ pub enum ClientState {
     Initializing,
     /// Client is ready for operations
     Ready,
+    /// Client lost the server and is reconnecting
+    Reconnecting {
+        /// Reconnection attempt, counting from 1
+        attempt: u32,
+    },
     /// Client encountered an error
     Error(String),
 }
@@ -91,7 +128,7 @@ pub struct ServerInfo {
 }
 
 /// Statistics about client operations.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ClientStats {
     /// Number of requests sent
     pub requests_sent: u64,
@@ -107,8 +144,22 @@ pub struct ClientStats {
     pub retries: u64,
     /// Number of connection attempts
     pub connection_attempts: u64,
+    /// Number of successful reconnections after a lost connection
+    pub reconnects: u64,
     /// Last activity timestamp
+    #[serde(skip)]
     pub last_activity: Option<Instant>,
+    /// Wall-clock time of the last activity
+    #[serde(with = "humantime_serde")]
+    pub last_activity_at: Option<SystemTime>,
+}
+
+impl ClientStats {
+    /// Record activity now
+    fn touch(&mut self) {
+        self.last_activity = Some(Instant::now());
+        self.last_activity_at = Some(SystemTime::now());
+    }
 }
 
 /// Handler for MCP notifications from the server
@@ -172,16 +223,23 @@ impl NotificationHandler for DefaultNotificationHandler {}
 /// - Request/response correlation and timeouts
 /// - Server notification handling
 /// - Automatic retries and error recovery
+/// - Optional reconnection after the server is lost (see [`crate::supervision`])
 pub struct McpClient {
     transport: Box<dyn Transport>,
     config: ClientConfig,
+    client_info: Option<Implementation>,
     state: RwLock<ClientState>,
+    events: EventSender,
+    request_sender: Arc<Mutex<Option<SharedRequestSender>>>,
+    session: Arc<Mutex<SessionState>>,
     server_info: RwLock<Option<ServerInfo>>,
     stats: Arc<RwLock<ClientStats>>,
-    request_counter: AtomicU64,
+    request_counter: Arc<AtomicU64>,
     pending_requests: Arc<RwLock<HashMap<String, oneshot::Sender<JsonRpcResponse>>>>,
     notification_handler: Arc<dyn NotificationHandler>,
-    _message_sender: Option<mpsc::UnboundedSender<JsonRpcMessage>>,
+    list_changed_receiver: Option<mpsc::UnboundedReceiver<ListKind>>,
+    capability_change_sender: Option<mpsc::UnboundedSender<McpResult<CapabilityChange>>>,
+    capability_changes: Option<CapabilityChangeReceiver>,
 }
 
 impl McpClient {
@@ -218,17 +276,26 @@ impl McpClient {
         notification_handler: Box<dyn NotificationHandler>,
     ) -> McpResult<Self> {
         let transport = TransportFactory::create(transport_config).await?;
+        let stats = Arc::new(RwLock::new(ClientStats::default()));
+        #[cfg(feature = "otel")]
+        crate::otel::track_client(&stats, &transport.get_info().transport_type);
 
         Ok(Self {
             transport,
             config: client_config,
+            client_info: None,
             state: RwLock::new(ClientState::Disconnected),
+            events: EventSender::new(),
+            request_sender: Arc::new(Mutex::new(None)),
+            session: Arc::new(Mutex::new(SessionState::default())),
             server_info: RwLock::new(None),
-            stats: Arc::new(RwLock::new(ClientStats::default())),
-            request_counter: AtomicU64::new(1),
+            stats,
+            request_counter: Arc::new(AtomicU64::new(1)),
             pending_requests: Arc::new(RwLock::new(HashMap::new())),
             notification_handler: notification_handler.into(),
-            _message_sender: None,
+            list_changed_receiver: None,
+            capability_change_sender: None,
+            capability_changes: None,
         })
     }
 
@@ -275,6 +342,86 @@ impl McpClient {
         self.transport.get_info()
     }
 
+    /// Take the stream of server-to-client requests (e.g. `elicitation/create`).
+    ///
+    /// Returns `None` if the stream was already taken or the transport does not
+    /// support server-initiated requests. Requests arriving while nobody holds
+    /// the stream are rejected with `Method not found`.
+    pub fn take_server_requests(&mut self) -> Option<crate::transport::ServerRequestReceiver> {
+        self.transport.take_server_requests()
+    }
+
+    /// Take the stream of inbound messages no request claimed.
+    ///
+    /// See [`Transport::take_raw_traffic`]; pair it with [`McpClient::raw_sender`]
+    /// to see the replies to uncorrelated raw messages.
+    pub fn take_raw_traffic(&mut self) -> Option<crate::transport::RawTrafficReceiver> {
+        self.transport.take_raw_traffic()
+    }
+
+    /// Take the stream of capability list changes.
+    ///
+    /// Only available after [`McpClient::connect`] with
+    /// [`ClientConfig::refresh_on_list_changed`] enabled, on transports that
+    /// support concurrent requests. Each `list_changed` notification yields the
+    /// re-fetched list and its differences from the previous fetch, or the
+    /// error that prevented re-fetching it.
+    pub fn take_capability_changes(&mut self) -> Option<CapabilityChangeReceiver> {
+        self.capability_changes.take()
+    }
+
+    /// Subscribe to the client's lifecycle events.
+    ///
+    /// Each subscriber receives every event published after it subscribed,
+    /// including those of requests sent through a [`RequestHandle`]. See
+    /// [`crate::events`].
+    pub fn subscribe(&self) -> ClientEventReceiver {
+        self.events.subscribe()
+    }
+
+    /// Resource subscriptions and log level a reconnect would restore.
+    pub fn session_state(&self) -> SessionState {
+        lock(&self.session).clone()
+    }
+
+    /// Whether the client was connected but the server has gone away, e.g.
+    /// because its process exited.
+    ///
+    /// Transports that only notice a lost session when a request fails, such
+    /// as HTTP, report `false` until then.
+    pub fn connection_lost(&self) -> bool {
+        let connected_before = self.state.try_read().is_ok_and(|state| {
+            !matches!(
+                *state,
+                ClientState::Disconnected | ClientState::Reconnecting { .. }
+            )
+        });
+        let process_exited = self
+            .transport
+            .process_log()
+            .is_some_and(|log| log.exit().is_some());
+        self.client_info.is_some()
+            && connected_before
+            && (process_exited || !self.transport.is_connected())
+    }
+
+    /// Get a handle that writes raw payloads through the transport.
+    ///
+    /// Raw payloads bypass the client entirely: they are not validated, use
+    /// whatever ids they contain and are not counted in [`ClientStats`].
+    /// Returns `None` if the transport cannot send arbitrary bytes.
+    pub fn raw_sender(&self) -> Option<crate::transport::SharedRawSender> {
+        self.transport.raw_sender()
+    }
+
+    /// Get the captured stderr and exit status of a locally spawned server.
+    ///
+    /// See [`Transport::process_log`]; available before [`McpClient::connect`],
+    /// so the output of a server that crashes during startup can be shown.
+    pub fn process_log(&self) -> Option<crate::transport::ProcessLog> {
+        self.transport.process_log()
+    }
+
     /// Connect to the MCP server and perform protocol initialization.
     ///
     /// This method:
@@ -311,16 +458,40 @@ impl McpClient {
     /// ```
     pub async fn connect(&mut self, client_info: Implementation) -> McpResult<ServerInfo> {
         info!("Connecting MCP client to server");
+        self.client_info = Some(client_info.clone());
+        self.establish(client_info).await
+    }
 
-        // Update state
-        *self.state.write().await = ClientState::Connecting;
+    /// Reconnect to the server and restore the previous session.
+    ///
+    /// Restarts a stdio server or opens a new HTTP session, redoes the
+    /// `initialize` handshake and re-sends the resource subscriptions and log
+    /// level of the previous session. Retries with backoff according to
+    /// [`ClientConfig::supervision`], or tries once without a policy.
+    ///
+    /// Supervised clients call this themselves when a request finds the
+    /// connection lost; requests sent through a [`RequestHandle`] are not
+    /// supervised, so callers relying on handles check
+    /// [`McpClient::connection_lost`] and call this.
+    pub async fn reconnect(&mut self) -> McpResult<ServerInfo> {
+        self.reconnect_after(None).await
+    }
+
+    /// Connect the transport, initialize and start background processing.
+    async fn establish(&mut self, client_info: Implementation) -> McpResult<ServerInfo> {
+        self.set_state(ClientState::Connecting, None).await;
+        self.stats.write().await.connection_attempts += 1;
 
         // Connect transport
-        self.transport.connect().await.map_err(|e| {
+        if let Err(e) = self.transport.connect().await {
             let error = format!("Transport connection failed: {e}");
-            self.set_error_state(error.clone());
-            McpError::Protocol(ProtocolError::InitializationFailed { reason: error })
-        })?;
+            self.set_state(ClientState::Error(error.clone()), None)
+                .await;
+            return Err(McpError::Protocol(ProtocolError::InitializationFailed {
+                reason: error,
+            }));
+        }
+        *lock(&self.request_sender) = self.transport.request_sender();
 
         // Start message processing
         self.start_message_processing().await?;
@@ -329,9 +500,13 @@ impl McpClient {
         let server_info = self.perform_initialization(client_info).await?;
 
         // Update state to ready
-        *self.state.write().await = ClientState::Ready;
+        self.set_state(ClientState::Ready, None).await;
         *self.server_info.write().await = Some(server_info.clone());
 
+        if let Some(list_changed) = self.list_changed_receiver.take() {
+            self.start_change_tracking(list_changed, &server_info);
+        }
+
         info!(
             "MCP client connected successfully to {}",
             server_info.implementation.name
@@ -339,12 +514,86 @@ impl McpClient {
         Ok(server_info)
     }
 
+    /// Reconnect with backoff, reporting `reason` as the cause.
+    async fn reconnect_after(&mut self, mut reason: Option<String>) -> McpResult<ServerInfo> {
+        let Some(client_info) = self.client_info.clone() else {
+            return Err(McpError::Protocol(ProtocolError::NotInitialized {
+                reason: "Client was never connected".to_string(),
+            }));
+        };
+        let policy = self.config.supervision.clone().unwrap_or_else(|| {
+            SupervisionPolicy::default()
+                .max_attempts(Some(1))
+                .backoff(Duration::ZERO, Duration::ZERO)
+        });
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            info!("Reconnecting to MCP server (attempt {})", attempt);
+            self.set_state(ClientState::Reconnecting { attempt }, reason.take())
+                .await;
+            sleep(policy.delay(attempt)).await;
+
+            // Stops what is left of the old server or session first
+            if let Err(e) = self.transport.disconnect().await {
+                debug!("Error closing the lost connection: {}", e);
+            }
+
+            match self.establish(client_info.clone()).await {
+                Ok(server_info) => {
+                    self.restore_session().await;
+                    self.stats.write().await.reconnects += 1;
+                    self.events
+                        .emit(ClientEventKind::Reconnected { attempts: attempt });
+                    info!("Reconnected to {}", server_info.implementation.name);
+                    return Ok(server_info);
+                }
+                Err(e) if policy.allows(attempt + 1) => {
+                    warn!("Reconnect attempt {} failed: {}", attempt, e);
+                    reason = Some(e.to_string());
+                }
+                Err(e) => {
+                    let error = format!("Reconnect failed after {attempt} attempt(s): {e}");
+                    self.set_state(ClientState::Error(error), None).await;
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Re-send the subscriptions and log level of the previous session.
+    async fn restore_session(&mut self) {
+        let requests = lock(&self.session).restore_requests();
+        for (method, params) in requests {
+            debug!("Restoring session with {}", method);
+            match self.send_request_with_timeout(method, params, None).await {
+                Ok(response) => {
+                    if let Some(error) = response.error {
+                        warn!("Failed to restore {}: {}", method, error.message);
+                    }
+                }
+                Err(e) => warn!("Failed to restore {}: {}", method, e),
+            }
+        }
+    }
+
+    /// Reconnect first if a supervised client already knows the server is gone.
+    async fn recover_if_lost(&mut self) -> McpResult<()> {
+        if self.config.supervision.is_some() && self.connection_lost() {
+            self.reconnect_after(Some("Connection to the server was lost".to_string()))
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Disconnect from the MCP server.
     pub async fn disconnect(&mut self) -> McpResult<()> {
         info!("Disconnecting MCP client");
 
         // Update state
-        *self.state.write().await = ClientState::Disconnected;
+        self.set_state(ClientState::Disconnected, None).await;
+        *lock(&self.request_sender) = None;
 
         // Clear server info
         *self.server_info.write().await = None;
@@ -364,6 +613,7 @@ impl McpClient {
     where
         T: serde::Serialize,
     {
+        self.recover_if_lost().await?;
         if !self.is_ready().await {
             return Err(McpError::Protocol(ProtocolError::NotInitialized {
                 reason: "Client not ready for notifications".to_string(),
@@ -377,45 +627,120 @@ impl McpClient {
         };
 
         self.transport.send_notification(notification).await?;
-        self.stats.write().await.notifications_sent += 1;
+        self.notification_sent(method).await;
         Ok(())
     }
 
+    /// Get a cloneable handle for sending requests concurrently.
+    ///
+    /// The handle shares the client's request IDs and statistics, and can be
+    /// moved into background tasks while the client keeps serving the caller.
+    /// Returns `None` until the client is ready, or if the transport only
+    /// supports one request at a time (see [`Transport::request_sender`]).
+    pub fn request_handle(&self) -> Option<RequestHandle> {
+        let ready = self
+            .state
+            .try_read()
+            .is_ok_and(|state| matches!(*state, ClientState::Ready));
+        if !ready {
+            return None;
+        }
+
+        lock(&self.request_sender).as_ref()?;
+        Some(RequestHandle {
+            sender: Arc::clone(&self.request_sender),
+            session: Arc::clone(&self.session),
+            events: self.events.clone(),
+            request_counter: Arc::clone(&self.request_counter),
+            stats: Arc::clone(&self.stats),
+            request_timeout: self.config.request_timeout,
+            #[cfg(feature = "otel")]
+            transport_type: self.transport.get_info().transport_type,
+        })
+    }
+
     /// Send a request to the server and wait for a response.
+    ///
+    /// A supervised client that finds the connection lost reconnects first.
+    /// If the connection is lost while the request is in flight, it reconnects
+    /// and reports the failure, since the server may have acted on the
+    /// request; only requests rejected before reaching the server are sent
+    /// again.
     pub async fn send_request<T>(&mut self, method: &str, params: T) -> McpResult<JsonRpcResponse>
     where
         T: serde::Serialize,
     {
+        self.recover_if_lost().await?;
         if !self.is_ready().await {
             return Err(McpError::Protocol(ProtocolError::NotInitialized {
                 reason: "Client not ready for requests".to_string(),
             }));
         }
 
-        self.send_request_with_timeout(method, params, None).await
+        let params = serde_json::to_value(params)?;
+        match self
+            .send_request_with_timeout(method, params.clone(), None)
+            .await
+        {
+            Err(e) if self.config.supervision.is_some() && e.is_connection_lost() => {
+                self.reconnect_after(Some(e.to_string())).await?;
+                if rejected_before_handling(&e) {
+                    self.send_request_with_timeout(method, params, None).await
+                } else {
+                    Err(e)
+                }
+            }
+            result => result,
+        }
     }
 
     // Private helper methods
 
-    fn set_error_state(&self, error: String) {
-        if let Ok(mut state) = self.state.try_write() {
-            *state = ClientState::Error(error);
+    /// Move to a new state and report the transition.
+    async fn set_state(&self, state: ClientState, reason: Option<String>) {
+        let previous = std::mem::replace(&mut *self.state.write().await, state.clone());
+        if previous != state {
+            self.events.emit(ClientEventKind::StateChanged {
+                previous,
+                state,
+                reason,
+            });
         }
     }
 
+    async fn notification_sent(&self, method: &str) {
+        let mut stats = self.stats.write().await;
+        stats.notifications_sent += 1;
+        stats.touch();
+        self.events.emit(ClientEventKind::NotificationSent {
+            method: method.to_string(),
+        });
+    }
+
     fn generate_request_id(&self) -> String {
         let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
         format!("req_{counter}")
     }
 
     async fn start_message_processing(&mut self) -> McpResult<()> {
+        let Some(mut receiver) = self.transport.take_message_stream() else {
+            tracing::debug!("Transport has no message stream, notifications are not processed");
+            return Ok(());
+        };
         tracing::info!("Starting message processing task");
-        let (sender, mut receiver) = mpsc::unbounded_channel();
-        self._message_sender = Some(sender);
+
+        let list_changed = if self.config.refresh_on_list_changed {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            self.list_changed_receiver = Some(receiver);
+            Some(sender)
+        } else {
+            None
+        };
 
         // Clone necessary data for the task
         let pending_requests = Arc::clone(&self.pending_requests);
         let stats = Arc::clone(&self.stats);
+        let events = self.events.clone();
         let notification_handler = Arc::clone(&self.notification_handler);
 
         // Start message processing task
@@ -447,9 +772,19 @@ impl McpClient {
                     }
                     JsonRpcMessage::Notification(notification) => {
                         tracing::debug!("Processing notification: {}", notification.method);
+                        let changed = ListKind::from_notification(&notification.method);
+                        events.emit(ClientEventKind::NotificationReceived {
+                            method: notification.method.clone(),
+                        });
                         // Handle server notifications
                         Self::handle_notification(&*notification_handler, notification).await;
-                        stats.write().await.notifications_received += 1;
+                        let mut stats = stats.write().await;
+                        stats.notifications_received += 1;
+                        stats.touch();
+                        drop(stats);
+                        if let (Some(sender), Some(kind)) = (&list_changed, changed) {
+                            let _ = sender.send(kind);
+                        }
                     }
                     JsonRpcMessage::Request(_) => {
                         // Server-to-client requests are rare in MCP but possible
@@ -466,48 +801,42 @@ impl McpClient {
         handler: &dyn NotificationHandler,
         notification: JsonRpcNotification,
     ) {
+        // `list_changed` notifications usually come without params
+        let params = notification
+            .params
+            .unwrap_or_else(|| serde_json::Value::Object(Default::default()));
         match notification.method.as_str() {
             "notifications/progress" => {
-                if let Some(params) = notification.params {
-                    if let Ok(progress) = serde_json::from_value::<ProgressNotification>(params) {
-                        let _ = handler.handle_progress(progress).await;
-                    }
+                if let Ok(progress) = serde_json::from_value::<ProgressNotification>(params) {
+                    let _ = handler.handle_progress(progress).await;
                 }
             }
             "notifications/resources/updated" => {
-                if let Some(params) = notification.params {
-                    if let Ok(resource_updated) =
-                        serde_json::from_value::<ResourceUpdatedNotification>(params)
-                    {
-                        let _ = handler.handle_resource_updated(resource_updated).await;
-                    }
+                if let Ok(resource_updated) =
+                    serde_json::from_value::<ResourceUpdatedNotification>(params)
+                {
+                    let _ = handler.handle_resource_updated(resource_updated).await;
                 }
             }
             "notifications/resources/list_changed" => {
-                if let Some(params) = notification.params {
-                    if let Ok(list_changed) =
-                        serde_json::from_value::<ResourceListChangedNotification>(params)
-                    {
-                        let _ = handler.handle_resource_list_changed(list_changed).await;
-                    }
+                if let Ok(list_changed) =
+                    serde_json::from_value::<ResourceListChangedNotification>(params)
+                {
+                    let _ = handler.handle_resource_list_changed(list_changed).await;
                 }
             }
             "notifications/tools/list_changed" => {
-                if let Some(params) = notification.params {
-                    if let Ok(list_changed) =
-                        serde_json::from_value::<ToolListChangedNotification>(params)
-                    {
-                        let _ = handler.handle_tool_list_changed(list_changed).await;
-                    }
+                if let Ok(list_changed) =
+                    serde_json::from_value::<ToolListChangedNotification>(params)
+                {
+                    let _ = handler.handle_tool_list_changed(list_changed).await;
                 }
             }
             "notifications/prompts/list_changed" => {
-                if let Some(params) = notification.params {
-                    if let Ok(list_changed) =
-                        serde_json::from_value::<PromptListChangedNotification>(params)
-                    {
-                        let _ = handler.handle_prompt_list_changed(list_changed).await;
-                    }
+                if let Ok(list_changed) =
+                    serde_json::from_value::<PromptListChangedNotification>(params)
+                {
+                    let _ = handler.handle_prompt_list_changed(list_changed).await;
                 }
             }
             _ => {
@@ -516,11 +845,102 @@ impl McpClient {
         }
     }
 
+    /// Fetch the advertised lists, then re-fetch and diff one whenever the
+    /// message processing task reports a `list_changed` notification for it.
+    fn start_change_tracking(
+        &mut self,
+        mut list_changed: mpsc::UnboundedReceiver<ListKind>,
+        server_info: &ServerInfo,
+    ) {
+        let Some(handle) = self.request_handle() else {
+            warn!("Transport does not support concurrent requests, list changes are not tracked");
+            return;
+        };
+        let standard = &server_info.capabilities.standard;
+        let advertised: Vec<ListKind> = ListKind::ALL
+            .into_iter()
+            .filter(|kind| match kind {
+                ListKind::Tools => standard.tools.is_some(),
+                ListKind::Resources => standard.resources.is_some(),
+                ListKind::Prompts => standard.prompts.is_some(),
+            })
+            .collect();
+
+        // Reconnects keep reporting to the stream the caller already holds
+        let sender = match &self.capability_change_sender {
+            Some(sender) => sender.clone(),
+            None => {
+                let (sender, receiver) = mpsc::unbounded_channel();
+                self.capability_change_sender = Some(sender.clone());
+                self.capability_changes = Some(receiver);
+                sender
+            }
+        };
+
+        tokio::spawn(async move {
+            let mut lists = HashMap::new();
+            for kind in advertised {
+                match Self::fetch_list(&handle, kind).await {
+                    Ok(items) => {
+                        lists.insert(kind, items);
+                    }
+                    Err(e) => warn!("Failed to fetch {} for change tracking: {}", kind, e),
+                }
+            }
+
+            while let Some(kind) = list_changed.recv().await {
+                debug!("Re-fetching {} after list_changed notification", kind);
+                let received_at = SystemTime::now();
+                let change = Self::fetch_list(&handle, kind).await.map(|items| {
+                    let before = lists.insert(kind, items.clone()).unwrap_or_default();
+                    CapabilityChange {
+                        diff: ListDiff::between(kind, &before, &items),
+                        items,
+                        received_at,
+                    }
+                });
+                if sender.send(change).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Fetch every page of a capability list
+    async fn fetch_list(
+        handle: &RequestHandle,
+        kind: ListKind,
+    ) -> McpResult<Vec<serde_json::Value>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let params = match &cursor {
+                Some(cursor) => serde_json::json!({ "cursor": cursor }),
+                None => serde_json::json!({}),
+            };
+            let response = handle.send_request(kind.list_method(), params).await?;
+            if let Some(error) = response.error {
+                return Err(McpError::Protocol(ProtocolError::ServerError {
+                    code: error.code,
+                    message: error.message,
+                }));
+            }
+            let result = response.result.unwrap_or_default();
+            if let Some(page) = result.get(kind.result_field()).and_then(|v| v.as_array()) {
+                items.extend(page.iter().cloned());
+            }
+            match result.get("nextCursor").and_then(|v| v.as_str()) {
+                Some(next) if !next.is_empty() => cursor = Some(next.to_string()),
+                _ => return Ok(items),
+            }
+        }
+    }
+
     async fn perform_initialization(
         &mut self,
         client_info: Implementation,
     ) -> McpResult<ServerInfo> {
-        *self.state.write().await = ClientState::Initializing;
+        self.set_state(ClientState::Initializing, None).await;
         tracing::info!("Starting MCP protocol initialization");
 
         // Create initialize request with proper client capabilities
@@ -536,6 +956,10 @@ impl McpClient {
                 prompts: Some(crate::messages::PromptCapabilities {
                     list_changed: Some(true),
                 }),
+                elicitation: self
+                    .config
+                    .enable_elicitation
+                    .then(crate::messages::ElicitationCapabilities::default),
                 ..Default::default()
             },
             ..Default::default()
@@ -632,7 +1056,7 @@ impl McpClient {
         };
 
         self.transport.send_notification(notification).await?;
-        self.stats.write().await.notifications_sent += 1;
+        self.notification_sent(method).await;
         tracing::debug!("Initialization notification sent successfully");
         Ok(())
     }
@@ -647,17 +1071,22 @@ impl McpClient {
         T: serde::Serialize,
     {
         let request_id = self.generate_request_id();
+        let params = serde_json::to_value(params)?;
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
             id: JsonRpcId::String(request_id.clone()),
             method: method.to_string(),
-            params: Some(serde_json::to_value(params)?),
+            params: Some(params.clone()),
         };
 
         let timeout_val = timeout_duration.unwrap_or(self.config.request_timeout);
 
         // Send request with retries
-        self.send_request_with_retries(request, timeout_val).await
+        let response = self.send_request_with_retries(request, timeout_val).await?;
+        if response.error.is_none() {
+            lock(&self.session).record(method, &params);
+        }
+        Ok(response)
     }
 
     async fn send_request_with_retries(
@@ -679,7 +1108,12 @@ impl McpClient {
                     return Ok(response);
                 }
                 Err(e) => {
+                    // A supervised client reconnects instead of retrying a dead connection
+                    let lost = self.config.supervision.is_some() && e.is_connection_lost();
                     last_error = Some(e);
+                    if lost {
+                        break;
+                    }
 
                     if attempt < self.config.max_retries {
                         let delay = self.config.retry_base_delay * 2_u32.pow(attempt);
@@ -689,6 +1123,12 @@ impl McpClient {
                             attempt + 1,
                             self.config.max_retries + 1
                         );
+                        self.events.emit(ClientEventKind::Retrying {
+                            id: request.id.to_string(),
+                            method: request.method.clone(),
+                            attempt: attempt + 1,
+                            delay,
+                        });
                         sleep(delay).await;
                     }
                 }
@@ -707,18 +1147,155 @@ impl McpClient {
         let request_id = request.id.to_string();
         tracing::debug!("Sending single request with ID: {}", request_id);
 
+        let method = request.method.clone();
+        #[cfg(feature = "otel")]
+        let (request, span) =
+            crate::otel::RequestSpan::start(request, &self.transport.get_info().transport_type);
+        let started = Instant::now();
+        self.events.emit(ClientEventKind::RequestSent {
+            id: request_id.clone(),
+            method: method.clone(),
+        });
+
         // Send request and get response from transport (handles SSE internally)
-        let response = self
+        let result = self
             .transport
             .send_request(request, Some(timeout_duration))
-            .await?;
-        self.stats.write().await.requests_sent += 1;
-
+            .await;
+        let mut stats = self.stats.write().await;
+        stats.requests_sent += 1;
+        stats.touch();
+        drop(stats);
+        #[cfg(feature = "otel")]
+        span.finish(&result);
+        emit_outcome(&self.events, request_id, method, started, &result);
+
+        let response = result?;
         tracing::debug!("Received response for request ID: {}", response.id);
         Ok(response)
     }
 }
 
+/// Cloneable handle for sending requests alongside an [`McpClient`].
+///
+/// Obtained from [`McpClient::request_handle`]. Requests are sent once, without
+/// the client's retries, so a slow or failing call is reported as is.
+///
+/// Handles keep working after the client reconnects, but do not reconnect
+/// themselves.
+#[derive(Clone)]
+pub struct RequestHandle {
+    sender: Arc<Mutex<Option<SharedRequestSender>>>,
+    session: Arc<Mutex<SessionState>>,
+    events: EventSender,
+    request_counter: Arc<AtomicU64>,
+    stats: Arc<RwLock<ClientStats>>,
+    request_timeout: Duration,
+    #[cfg(feature = "otel")]
+    transport_type: String,
+}
+
+impl RequestHandle {
+    /// Send a request to the server and wait for a response.
+    pub async fn send_request<T>(&self, method: &str, params: T) -> McpResult<JsonRpcResponse>
+    where
+        T: serde::Serialize,
+    {
+        let Some(sender) = lock(&self.sender).clone() else {
+            return Err(McpError::Transport(TransportError::NotConnected {
+                transport_type: "client".to_string(),
+                reason: "Client is disconnected".to_string(),
+            }));
+        };
+        let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
+        let params = serde_json::to_value(params)?;
+        let id = format!("req_{counter}");
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: JsonRpcId::String(id.clone()),
+            method: method.to_string(),
+            params: Some(params.clone()),
+        };
+
+        #[cfg(feature = "otel")]
+        let (request, span) = crate::otel::RequestSpan::start(request, &self.transport_type);
+        let started = Instant::now();
+        self.events.emit(ClientEventKind::RequestSent {
+            id: id.clone(),
+            method: method.to_string(),
+        });
+        let result = sender
+            .send_request(request, Some(self.request_timeout))
+            .await;
+        #[cfg(feature = "otel")]
+        span.finish(&result);
+        emit_outcome(&self.events, id, method.to_string(), started, &result);
+        if matches!(&result, Ok(response) if response.error.is_none()) {
+            lock(&self.session).record(method, &params);
+        }
+
+        let mut stats = self.stats.write().await;
+        stats.requests_sent += 1;
+        match &result {
+            Ok(_) => stats.responses_received += 1,
+            Err(_) => stats.errors += 1,
+        }
+        stats.touch();
+        result
+    }
+}
+
+impl std::fmt::Debug for RequestHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestHandle")
+            .field("request_timeout", &self.request_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Report how a request ended
+fn emit_outcome(
+    events: &EventSender,
+    id: String,
+    method: String,
+    started: Instant,
+    result: &McpResult<JsonRpcResponse>,
+) {
+    events.emit(match result {
+        Ok(response) => ClientEventKind::ResponseReceived {
+            id,
+            method,
+            latency: started.elapsed(),
+            error_code: response.error.as_ref().map(|error| error.code),
+        },
+        Err(error) => ClientEventKind::RequestFailed {
+            id,
+            method,
+            error: error.to_string(),
+        },
+    });
+}
+
+/// Lock a mutex, recovering the data if a holder panicked
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether a failed request never reached the server, so sending it again
+/// after a reconnect cannot repeat its effects
+fn rejected_before_handling(error: &McpError) -> bool {
+    matches!(
+        error,
+        McpError::Transport(
+            TransportError::NotConnected { .. }
+                | TransportError::HttpError {
+                    status_code: 404,
+                    ..
+                }
+        )
+    )
+}
+
 /// Builder for creating MCP clients with custom configuration.
 pub struct McpClientBuilder {
     transport_config: Option<TransportConfig>,
@@ -772,6 +1349,24 @@ impl McpClientBuilder {
         self
     }
 
+    /// Advertise the elicitation capability to the server.
+    pub fn enable_elicitation(mut self, enabled: bool) -> Self {
+        self.client_config.enable_elicitation = enabled;
+        self
+    }
+
+    /// Re-fetch capability lists when the server announces changes.
+    pub fn refresh_on_list_changed(mut self, enabled: bool) -> Self {
+        self.client_config.refresh_on_list_changed = enabled;
+        self
+    }
+
+    /// Reconnect automatically when the server is lost.
+    pub fn supervision(mut self, policy: SupervisionPolicy) -> Self {
+        self.client_config.supervision = Some(policy);
+        self
+    }
+
     /// Build the MCP client.
     pub async fn build(self) -> McpResult<McpClient> {
         let transport_config = self.transport_config.ok_or_else(|| {
@@ -820,6 +1415,211 @@ mod tests {
         assert!(!client.is_ready().await);
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_refresh_on_list_changed() {
+        // Initializes, answers the baseline tools/list, announces a change and
+        // answers the re-fetch with a new tool and a changed schema
+        let script = r#"read init; echo '{"jsonrpc":"2.0","id":"req_1","result":{"protocolVersion":"2024-11-05","capabilities":{"tools":{"listChanged":true}},"serverInfo":{"name":"dynamic","version":"1"}}}'; read initialized; read baseline; echo '{"jsonrpc":"2.0","id":"req_2","result":{"tools":[{"name":"a","inputSchema":{"type":"object"}}]}}'; echo '{"jsonrpc":"2.0","method":"notifications/tools/list_changed"}'; read refetch; echo '{"jsonrpc":"2.0","id":"req_3","result":{"tools":[{"name":"a","inputSchema":{"type":"object","required":["x"]}},{"name":"b","inputSchema":{"type":"object"}}]}}'; sleep 1"#;
+        let config = TransportConfig::stdio("sh", &["-c", script]);
+        let mut client = McpClientBuilder::new()
+            .transport(config)
+            .refresh_on_list_changed(true)
+            .build()
+            .await
+            .unwrap();
+        assert!(client.take_capability_changes().is_none());
+
+        client
+            .connect(Implementation {
+                name: "test".to_string(),
+                version: "0".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        let mut changes = client.take_capability_changes().unwrap();
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.recv())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(change.diff.kind, ListKind::Tools);
+        assert_eq!(change.diff.added, vec!["b"]);
+        assert_eq!(change.diff.modified[0].key, "a");
+        assert!(change.diff.modified[0].schema_changed());
+        assert_eq!(change.items.len(), 2);
+        assert_eq!(client.stats().await.notifications_received, 1);
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_supervised_client_restarts_crashed_server() {
+        // The first run crashes after answering one request; later runs log
+        // every request they receive
+        let dir = tempfile::tempdir().unwrap();
+        let script = format!(
+            r#"cd {}; n=$(cat runs 2>/dev/null || echo 0); echo $((n+1)) > runs
+reply() {{ id=$(echo "$1" | sed 's/.*"id":"\([^"]*\)".*/\1/'); printf '{{"jsonrpc":"2.0","id":"%s","result":%s}}\n' "$id" "$2"; }}
+read l; reply "$l" '{{"protocolVersion":"2024-11-05","capabilities":{{"resources":{{"subscribe":true}}}},"serverInfo":{{"name":"flaky","version":"1"}}}}'
+read l
+while read l; do echo "$l" >> log$n; reply "$l" '{{}}'; [ $n = 0 ] && exit 3; done"#,
+            dir.path().display()
+        );
+        let config = TransportConfig::stdio("sh", &["-c", script.as_str()]);
+        let mut client = McpClientBuilder::new()
+            .transport(config)
+            .supervision(
+                SupervisionPolicy::default()
+                    .backoff(Duration::from_millis(10), Duration::from_millis(50)),
+            )
+            .build()
+            .await
+            .unwrap();
+        let mut events = client.subscribe();
+
+        client
+            .connect(Implementation {
+                name: "test".to_string(),
+                version: "0".to_string(),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        client
+            .send_request(
+                "resources/subscribe",
+                serde_json::json!({"uri": "file:///a"}),
+            )
+            .await
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !client.connection_lost() {
+            assert!(Instant::now() < deadline, "server exit was not noticed");
+            sleep(Duration::from_millis(20)).await;
+        }
+
+        let response = client
+            .send_request("tools/list", serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(response.error.is_none());
+        assert!(client.is_ready().await);
+        assert_eq!(client.stats().await.reconnects, 1);
+
+        let restored = std::fs::read_to_string(dir.path().join("log1")).unwrap();
+        let methods: Vec<_> = restored
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["method"].clone())
+            .collect();
+        assert_eq!(methods, ["resources/subscribe", "tools/list"]);
+        assert!(restored.contains("file:///a"));
+
+        let mut states = Vec::new();
+        let mut kinds = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let ClientEventKind::StateChanged { state, .. } = &event.kind {
+                states.push(state.clone());
+            }
+            kinds.push(event.kind);
+        }
+        assert!(states.contains(&ClientState::Reconnecting { attempt: 1 }));
+        assert_eq!(states.last(), Some(&ClientState::Ready));
+        assert!(kinds.contains(&ClientEventKind::Reconnected { attempts: 1 }));
+        assert!(matches!(
+            kinds.last(),
+            Some(ClientEventKind::ResponseReceived { method, error_code: None, .. })
+                if method == "tools/list"
+        ));
+        let stats = client.stats().await;
+        assert!(stats.last_activity_at.is_some());
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_events_for_connect_failed_request_and_reconnect() {
+        // Completes the handshake, then never answers another request
+        let script = r#"reply() { id=$(echo "$1" | sed 's/.*"id":"\([^"]*\)".*/\1/'); printf '{"jsonrpc":"2.0","id":"%s","result":%s}\n' "$id" "$2"; }
+read l; reply "$l" '{"protocolVersion":"2024-11-05","capabilities":{},"serverInfo":{"name":"quiet","version":"1"}}'
+while read l; do :; done"#;
+        let config = TransportConfig::stdio("sh", &["-c", script]);
+        let mut client = McpClientBuilder::new()
+            .transport(config)
+            .request_timeout(Duration::from_millis(200))
+            .max_retries(1)
+            .build()
+            .await
+            .unwrap();
+        let mut events = client.subscribe();
+        let mut recorded = || {
+            let mut kinds = Vec::new();
+            while let Ok(event) = events.try_recv() {
+                kinds.push(match event.kind {
+                    ClientEventKind::StateChanged { state, .. } => format!("state {:?}", state),
+                    ClientEventKind::RequestSent { method, .. } => format!("sent {}", method),
+                    ClientEventKind::ResponseReceived { method, .. } => {
+                        format!("received {}", method)
+                    }
+                    ClientEventKind::RequestFailed { method, .. } => format!("failed {}", method),
+                    ClientEventKind::Retrying { attempt, .. } => format!("retrying {}", attempt),
+                    ClientEventKind::NotificationSent { method } => format!("notified {}", method),
+                    ClientEventKind::NotificationReceived { method } => {
+                        format!("notification {}", method)
+                    }
+                    ClientEventKind::Reconnected { attempts } => {
+                        format!("reconnected {}", attempts)
+                    }
+                });
+            }
+            kinds
+        };
+        let client_info = Implementation {
+            name: "test".to_string(),
+            version: "0".to_string(),
+            metadata: HashMap::new(),
+        };
+
+        client.connect(client_info).await.unwrap();
+        let handshake = [
+            "state Connecting",
+            "state Initializing",
+            "sent initialize",
+            "received initialize",
+            "notified initialized",
+            "state Ready",
+        ];
+        assert_eq!(recorded(), handshake);
+
+        assert!(client
+            .send_request("tools/list", serde_json::json!({}))
+            .await
+            .is_err());
+        assert_eq!(
+            recorded(),
+            [
+                "sent tools/list",
+                "failed tools/list",
+                "retrying 1",
+                "sent tools/list",
+                "failed tools/list",
+            ]
+        );
+
+        client.reconnect().await.unwrap();
+        let mut expected = vec!["state Reconnecting { attempt: 1 }"];
+        expected.extend(handshake);
+        expected.push("reconnected 1");
+        assert_eq!(recorded(), expected);
+
+        client.disconnect().await.unwrap();
+    }
+
     #[test]
     fn test_client_config_defaults() {
         let config = ClientConfig::default();
@@ -12,6 +12,7 @@
 //! - **Debuggable**: Include sufficient context for debugging
 //! - **User-friendly**: Format appropriately for end-user display
 
+use crate::transport::ProcessExit;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -102,10 +103,15 @@ pub enum TransportError {
     },
 
     /// Connection was lost during operation
+    ///
+    /// Build it with [`TransportError::connection_lost`]; more fields may be added.
     #[error("Connection lost to {transport_type} server: {reason}")]
+    #[non_exhaustive]
     ConnectionLost {
         transport_type: String,
         reason: String,
+        /// How the server process ended, for transports that run one
+        exit: Option<ProcessExit>,
     },
 
     /// Failed to send message to server
@@ -130,8 +136,15 @@ pub enum TransportError {
     },
 
     /// Process-related errors for stdio transport
+    ///
+    /// Build it with [`TransportError::process_error`]; more fields may be added.
     #[error("Process error: {reason}")]
-    ProcessError { reason: String },
+    #[non_exhaustive]
+    ProcessError {
+        reason: String,
+        /// How the server process ended, if it has
+        exit: Option<ProcessExit>,
+    },
 
     /// HTTP-specific errors for HTTP transports
     #[error("HTTP error: {status_code} - {reason}")]
@@ -363,6 +376,10 @@ pub enum ConfigError {
     /// Conflicting configuration parameters
     #[error("Conflicting configuration: {reason}")]
     Conflict { reason: String },
+
+    /// A secret reference could not be resolved
+    #[error("Cannot resolve secret {reference}: {reason}")]
+    SecretResolution { reference: String, reason: String },
 }
 
 /// Convenience type alias for Results using McpError.
@@ -439,6 +456,17 @@ impl McpError {
         }
     }
 
+    /// Check if the session with the server is gone and needs a reconnect.
+    ///
+    /// True when the connection dropped, the server process exited, or an
+    /// HTTP server no longer knows the session.
+    pub fn is_connection_lost(&self) -> bool {
+        match self {
+            McpError::Transport(transport_err) => transport_err.is_connection_lost(),
+            _ => false,
+        }
+    }
+
     /// Get the error category for this error.
     ///
     /// This is useful for error reporting and metrics collection.
@@ -458,6 +486,37 @@ impl McpError {
 }
 
 impl TransportError {
+    /// Create a connection-lost error, with the server process exit if known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mcp_probe_core::error::TransportError;
+    /// use mcp_probe_core::transport::ProcessExit;
+    ///
+    /// let error = TransportError::connection_lost("stdio", "server exited", Some(ProcessExit::Code(1)));
+    /// assert_eq!(error.process_exit(), Some(ProcessExit::Code(1)));
+    /// ```
+    pub fn connection_lost(
+        transport_type: impl Into<String>,
+        reason: impl Into<String>,
+        exit: Option<ProcessExit>,
+    ) -> Self {
+        Self::ConnectionLost {
+            transport_type: transport_type.into(),
+            reason: reason.into(),
+            exit,
+        }
+    }
+
+    /// Create a stdio process error, with the process exit if it has ended.
+    pub fn process_error(reason: impl Into<String>, exit: Option<ProcessExit>) -> Self {
+        Self::ProcessError {
+            reason: reason.into(),
+            exit,
+        }
+    }
+
     /// Check if this transport error is retryable.
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -481,6 +540,28 @@ impl TransportError {
             TransportError::SerializationError { .. } => false,
         }
     }
+
+    /// Check if the session with the server is gone and needs a reconnect.
+    pub fn is_connection_lost(&self) -> bool {
+        match self {
+            TransportError::ConnectionLost { .. }
+            | TransportError::ProcessError { .. }
+            | TransportError::DisconnectedError { .. }
+            | TransportError::NotConnected { .. } => true,
+            // Streamable HTTP servers answer 404 for an expired session
+            TransportError::HttpError { status_code, .. } => *status_code == 404,
+            _ => false,
+        }
+    }
+
+    /// How the server process ended, if the error was caused by it exiting.
+    pub fn process_exit(&self) -> Option<ProcessExit> {
+        match self {
+            TransportError::ConnectionLost { exit, .. }
+            | TransportError::ProcessError { exit, .. } => *exit,
+            _ => None,
+        }
+    }
 }
 
 impl From<reqwest::Error> for McpError {
@@ -568,4 +649,33 @@ mod tests {
         };
         assert!(!invalid_config.is_retryable());
     }
+
+    #[test]
+    fn test_transport_error_process_exit() {
+        let lost = TransportError::connection_lost(
+            "stdio",
+            "server process exited with exit code 3",
+            Some(ProcessExit::Code(3)),
+        );
+        assert!(lost.is_retryable());
+        assert_eq!(lost.process_exit(), Some(ProcessExit::Code(3)));
+        assert_eq!(
+            lost.to_string(),
+            "Connection lost to stdio server: server process exited with exit code 3"
+        );
+
+        let process =
+            TransportError::process_error("Failed to send request to child process", None);
+        assert_eq!(process.process_exit(), None);
+        assert!(McpError::Transport(process).is_connection_lost());
+
+        let expired = TransportError::HttpError {
+            status_code: 404,
+            reason: "Session not found".to_string(),
+        };
+        assert!(expired.is_connection_lost());
+        assert!(
+            !McpError::timeout("request", std::time::Duration::from_secs(1)).is_connection_lost()
+        );
+    }
 }
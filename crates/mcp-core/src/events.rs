@@ -0,0 +1,188 @@
+//! Lifecycle events published by an [`McpClient`](crate::client::McpClient).
+//!
+//! The client reports state transitions, requests and their responses,
+//! retries, notifications and reconnects on a broadcast channel, so any number
+//! of observers (a status bar, a session recorder, metrics) can follow a
+//! client without wrapping it. Subscribe with
+//! [`McpClient::subscribe`](crate::client::McpClient::subscribe); a subscriber
+//! that falls more than [`EVENT_CHANNEL_CAPACITY`] events behind skips the
+//! oldest ones and is told how many it missed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use mcp_probe_core::{client::McpClient, events::ClientEventKind, TransportConfig};
+//!
+//! # async fn example() -> mcp_probe_core::McpResult<()> {
+//! let client = McpClient::with_defaults(TransportConfig::stdio("my-server", &[] as &[String])).await?;
+//! let mut events = client.subscribe();
+//! tokio::spawn(async move {
+//!     while let Ok(event) = events.recv().await {
+//!         if let ClientEventKind::ResponseReceived { method, latency, .. } = &event.kind {
+//!             println!("{method} answered in {latency:?}");
+//!         }
+//!     }
+//! });
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::ClientState;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before the oldest are dropped
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Something that happened to a client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientEvent {
+    /// When it happened
+    #[serde(with = "humantime_serde")]
+    pub at: SystemTime,
+
+    /// What happened
+    #[serde(flatten)]
+    pub kind: ClientEventKind,
+}
+
+/// The kinds of [`ClientEvent`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ClientEventKind {
+    /// The client moved to another state
+    StateChanged {
+        /// State before the transition
+        previous: ClientState,
+        /// State after the transition
+        state: ClientState,
+        /// Why the transition happened, e.g. the error that dropped the connection
+        reason: Option<String>,
+    },
+
+    /// A request was handed to the transport
+    RequestSent {
+        /// Request ID
+        id: String,
+        /// Request method
+        method: String,
+    },
+
+    /// The server answered a request (with a result or a JSON-RPC error)
+    ResponseReceived {
+        /// Request ID
+        id: String,
+        /// Request method
+        method: String,
+        /// Time from sending the request to receiving the response
+        #[serde(with = "humantime_serde")]
+        latency: Duration,
+        /// JSON-RPC error code if the server answered with an error
+        error_code: Option<i32>,
+    },
+
+    /// A request got no response, e.g. because it timed out or the
+    /// connection was lost
+    RequestFailed {
+        /// Request ID
+        id: String,
+        /// Request method
+        method: String,
+        /// Why the request failed
+        error: String,
+    },
+
+    /// A failed request is about to be sent again
+    Retrying {
+        /// Request ID
+        id: String,
+        /// Request method
+        method: String,
+        /// Retry number, counting from 1
+        attempt: u32,
+        /// Delay before the retry
+        #[serde(with = "humantime_serde")]
+        delay: Duration,
+    },
+
+    /// A notification was sent to the server
+    NotificationSent {
+        /// Notification method
+        method: String,
+    },
+
+    /// The server sent a notification
+    NotificationReceived {
+        /// Notification method
+        method: String,
+    },
+
+    /// The client reconnected after losing the server
+    Reconnected {
+        /// Attempts it took, counting from 1
+        attempts: u32,
+    },
+}
+
+/// Receives a client's events, see [`McpClient::subscribe`](crate::client::McpClient::subscribe).
+pub type ClientEventReceiver = broadcast::Receiver<ClientEvent>;
+
+/// Publishes events to every current subscriber
+#[derive(Debug, Clone)]
+pub(crate) struct EventSender(broadcast::Sender<ClientEvent>);
+
+impl EventSender {
+    pub(crate) fn new() -> Self {
+        Self(broadcast::channel(EVENT_CHANNEL_CAPACITY).0)
+    }
+
+    pub(crate) fn subscribe(&self) -> ClientEventReceiver {
+        self.0.subscribe()
+    }
+
+    /// Publish an event; without subscribers it is dropped
+    pub(crate) fn emit(&self, kind: ClientEventKind) {
+        let _ = self.0.send(ClientEvent {
+            at: SystemTime::now(),
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_serialize_flat_and_reach_every_subscriber() {
+        let sender = EventSender::new();
+        let mut first = sender.subscribe();
+        let mut second = sender.subscribe();
+        sender.emit(ClientEventKind::ResponseReceived {
+            id: "req_1".to_string(),
+            method: "tools/list".to_string(),
+            latency: Duration::from_millis(12),
+            error_code: None,
+        });
+
+        let event = first.try_recv().unwrap();
+        assert_eq!(second.try_recv().unwrap(), event);
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "response_received");
+        assert_eq!(json["method"], "tools/list");
+        assert_eq!(json["latency"], "12ms");
+        assert!(json["at"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(serde_json::from_value::<ClientEvent>(json).unwrap(), event);
+
+        let state = serde_json::to_value(ClientEventKind::StateChanged {
+            previous: ClientState::Ready,
+            state: ClientState::Reconnecting { attempt: 2 },
+            reason: None,
+        })
+        .unwrap();
+        assert_eq!(state["previous"], "ready");
+        assert_eq!(state["state"]["reconnecting"]["attempt"], 2);
+    }
+}
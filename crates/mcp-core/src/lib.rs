@@ -51,6 +51,12 @@
 //! - [`messages`]: Complete MCP message type definitions  
 //! - [`transport`]: Transport abstraction and implementations
 //! - [`client`]: High-level MCP client interface
+//! - [`changes`]: Differences between fetches of tool, resource and prompt lists
+//! - [`events`]: Lifecycle events published by the client
+//! - [`secrets`]: Secret references in configuration values and redaction
+//! - [`supervision`]: Automatic reconnection after a lost server
+//! - [`telemetry`]: W3C trace context carried in requests
+//! - `otel`: OpenTelemetry spans and metrics for requests (`otel` feature)
 //!
 //! ## Transport Support
 //!
@@ -60,16 +66,24 @@
 //! - **http-sse**: HTTP + Server-Sent Events (enabled by default)  
 //! - **http-stream**: Full-duplex HTTP streaming (enabled by default)
 //!
-//! Transport support can be controlled via feature flags.
+//! Transport support can be controlled via feature flags. The optional `otel`
+//! feature exports a span per request and client metrics to OpenTelemetry.
 
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::uninlined_format_args)]
 
+pub mod changes;
 pub mod client;
 pub mod error;
+pub mod events;
 pub mod messages;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod secrets;
+pub mod supervision;
+pub mod telemetry;
 pub mod transport;
 pub mod validation;
 
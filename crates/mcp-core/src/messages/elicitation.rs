@@ -0,0 +1,132 @@
+//! Elicitation message types for server-initiated user input requests.
+//!
+//! This module provides types for:
+//! - Server-to-client `elicitation/create` requests
+//! - The user's answer (accept with content, decline, or cancel)
+//!
+//! Elicitation was introduced in protocol revision 2025-06-18. Servers only
+//! send these requests to clients that advertise the `elicitation` capability.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// JSON-RPC method name for elicitation requests.
+pub const ELICITATION_METHOD: &str = "elicitation/create";
+
+/// Request from server to client asking the user for structured input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitRequest {
+    /// Human-readable message explaining what is being asked
+    pub message: String,
+
+    /// Restricted JSON Schema (flat object of primitive properties) describing the answer
+    #[serde(rename = "requestedSchema")]
+    pub requested_schema: Value,
+}
+
+impl ElicitRequest {
+    /// Create a new elicitation request.
+    pub fn new(message: impl Into<String>, requested_schema: Value) -> Self {
+        Self {
+            message: message.into(),
+            requested_schema,
+        }
+    }
+}
+
+/// Action taken by the user in response to an elicitation request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElicitAction {
+    /// The user submitted the requested data
+    Accept,
+    /// The user explicitly refused to provide the data
+    Decline,
+    /// The user dismissed the request without choosing
+    Cancel,
+}
+
+impl std::fmt::Display for ElicitAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Accept => write!(f, "accept"),
+            Self::Decline => write!(f, "decline"),
+            Self::Cancel => write!(f, "cancel"),
+        }
+    }
+}
+
+/// Client response to an elicitation request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitResult {
+    /// What the user chose to do
+    pub action: ElicitAction,
+
+    /// Submitted data, present only when the action is `accept`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Value>,
+}
+
+impl ElicitResult {
+    /// Accept the request with the given content.
+    pub fn accept(content: Value) -> Self {
+        Self {
+            action: ElicitAction::Accept,
+            content: Some(content),
+        }
+    }
+
+    /// Decline the request.
+    pub fn decline() -> Self {
+        Self {
+            action: ElicitAction::Decline,
+            content: None,
+        }
+    }
+
+    /// Cancel the request.
+    pub fn cancel() -> Self {
+        Self {
+            action: ElicitAction::Cancel,
+            content: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_elicit_request_deserialization() {
+        let request: ElicitRequest = serde_json::from_value(json!({
+            "message": "Please provide your GitHub username",
+            "requestedSchema": {
+                "type": "object",
+                "properties": {"name": {"type": "string"}},
+                "required": ["name"]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(request.message, "Please provide your GitHub username");
+        assert_eq!(request.requested_schema["required"], json!(["name"]));
+    }
+
+    #[test]
+    fn test_elicit_result_serialization() {
+        let accepted =
+            serde_json::to_value(ElicitResult::accept(json!({"name": "octocat"}))).unwrap();
+        assert_eq!(
+            accepted,
+            json!({"action": "accept", "content": {"name": "octocat"}})
+        );
+
+        let declined = serde_json::to_value(ElicitResult::decline()).unwrap();
+        assert_eq!(declined, json!({"action": "decline"}));
+
+        let cancelled: ElicitResult = serde_json::from_value(json!({"action": "cancel"})).unwrap();
+        assert_eq!(cancelled, ElicitResult::cancel());
+    }
+}
@@ -17,6 +17,7 @@
 //! - **Resources**: Resource listing, reading, and subscription
 //! - **Prompts**: Prompt templates and completion requests
 //! - **Sampling**: LLM completion requests from server to client
+//! - **Elicitation**: Structured user input requests from server to client
 //! - **Logging**: Server-to-client logging messages
 //!
 //! # Examples
@@ -45,6 +46,7 @@
 //! ```
 
 pub mod core;
+pub mod elicitation;
 pub mod initialization;
 pub mod logging;
 pub mod prompts;
@@ -53,6 +55,7 @@ pub mod sampling;
 pub mod tools;
 
 pub use core::*;
+pub use elicitation::{ElicitAction, ElicitRequest, ElicitResult, ELICITATION_METHOD};
 pub use initialization::*;
 pub use logging::{
     LogLevel, LoggingNotification, ProgressNotification,
@@ -78,7 +81,8 @@ pub use sampling::{
 };
 pub use tools::{
     CallToolRequest, CallToolResponse, ListToolsRequest, ListToolsResponse,
-    ResourceReference as ToolResourceReference, Tool, ToolListChangedNotification, ToolResult,
+    ResourceReference as ToolResourceReference, Tool, ToolAnnotations, ToolListChangedNotification,
+    ToolResult,
 };
 
 use serde::{Deserialize, Serialize};
@@ -88,16 +92,27 @@ use std::collections::HashMap;
 ///
 /// The MCP protocol uses semantic versioning with date-based versions.
 /// This enum provides type-safe handling of supported protocol versions.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Versions are compared, ordered and hashed by their revision string, so a
+/// `Custom` version naming a known revision equals that revision's variant.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum ProtocolVersion {
     /// MCP Protocol version 2024-11-05 (legacy)
     #[serde(rename = "2024-11-05")]
     V2024_11_05,
 
-    /// MCP Protocol version 2025-03-26 (current stable)
+    /// MCP Protocol version 2025-03-26 (default for new sessions)
     #[serde(rename = "2025-03-26")]
+    #[default]
     V2025_03_26,
 
+    /// MCP Protocol version 2025-06-18 (structured tool output, elicitation)
+    #[serde(rename = "2025-06-18")]
+    V2025_06_18,
+
+    /// MCP Protocol version 2025-11-25 (tasks, icons)
+    #[serde(rename = "2025-11-25")]
+    V2025_11_25,
+
     /// Future protocol versions can be added here
     /// Custom version string for forward compatibility
     #[serde(untagged)]
@@ -110,24 +125,119 @@ impl ProtocolVersion {
         match self {
             Self::V2024_11_05 => "2024-11-05",
             Self::V2025_03_26 => "2025-03-26",
+            Self::V2025_06_18 => "2025-06-18",
+            Self::V2025_11_25 => "2025-11-25",
             Self::Custom(version) => version,
         }
     }
 
+    /// Parse a version string, mapping known revisions to their variants.
+    pub fn parse(version: &str) -> Self {
+        Self::supported_versions()
+            .into_iter()
+            .find(|v| v.as_str() == version)
+            .unwrap_or_else(|| Self::Custom(version.to_string()))
+    }
+
     /// Check if this version is supported by the current implementation.
     pub fn is_supported(&self) -> bool {
-        matches!(self, Self::V2024_11_05 | Self::V2025_03_26)
+        Self::supported_versions().contains(self)
     }
 
-    /// Get all supported protocol versions.
+    /// Get all supported protocol versions, oldest first.
     pub fn supported_versions() -> Vec<Self> {
-        vec![Self::V2024_11_05, Self::V2025_03_26]
+        vec![
+            Self::V2024_11_05,
+            Self::V2025_03_26,
+            Self::V2025_06_18,
+            Self::V2025_11_25,
+        ]
+    }
+
+    /// Get the newest protocol revision known to this implementation.
+    pub fn latest() -> Self {
+        Self::V2025_11_25
+    }
+
+    /// Server capability keys defined by this protocol revision.
+    ///
+    /// Custom (unknown) versions are assumed to define everything the latest
+    /// known revision does.
+    pub fn server_capability_keys(&self) -> &'static [&'static str] {
+        match Self::parse(self.as_str()) {
+            Self::V2024_11_05 => &["experimental", "logging", "prompts", "resources", "tools"],
+            Self::V2025_03_26 | Self::V2025_06_18 => &[
+                "completions",
+                "experimental",
+                "logging",
+                "prompts",
+                "resources",
+                "tools",
+            ],
+            Self::V2025_11_25 | Self::Custom(_) => &[
+                "completions",
+                "experimental",
+                "logging",
+                "prompts",
+                "resources",
+                "tasks",
+                "tools",
+            ],
+        }
+    }
+
+    /// Fields of a tool definition (`tools/list` entries) defined by this revision.
+    pub fn tool_definition_fields(&self) -> &'static [&'static str] {
+        match Self::parse(self.as_str()) {
+            Self::V2024_11_05 => &["name", "description", "inputSchema"],
+            Self::V2025_03_26 => &["name", "description", "inputSchema", "annotations"],
+            Self::V2025_06_18 => &[
+                "name",
+                "title",
+                "description",
+                "inputSchema",
+                "outputSchema",
+                "annotations",
+                "_meta",
+            ],
+            Self::V2025_11_25 | Self::Custom(_) => &[
+                "name",
+                "title",
+                "description",
+                "inputSchema",
+                "outputSchema",
+                "annotations",
+                "icons",
+                "_meta",
+            ],
+        }
+    }
+}
+
+impl PartialEq for ProtocolVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ProtocolVersion {}
+
+impl std::hash::Hash for ProtocolVersion {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
     }
 }
 
-impl Default for ProtocolVersion {
-    fn default() -> Self {
-        Self::V2025_03_26
+impl PartialOrd for ProtocolVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ProtocolVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Revision identifiers are ISO dates, so lexical order is release order
+        self.as_str().cmp(other.as_str())
     }
 }
 
@@ -182,6 +292,10 @@ pub struct StandardCapabilities {
     /// Client capability: Can provide root directories for server operations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub roots: Option<RootsCapabilities>,
+
+    /// Client capability: Can answer `elicitation/create` requests from server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elicitation: Option<ElicitationCapabilities>,
 }
 
 /// Tool-related capabilities.
@@ -236,6 +350,13 @@ pub struct RootsCapabilities {
     pub list_changed: Option<bool>,
 }
 
+/// Elicitation-related capabilities (client-side).
+///
+/// The specification defines no sub-fields yet; advertising an empty object
+/// is enough to opt in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ElicitationCapabilities {}
+
 /// Implementation information for client or server.
 ///
 /// This provides metadata about the MCP implementation, useful for
@@ -355,6 +476,26 @@ mod tests {
         assert_eq!(deserialized, version);
     }
 
+    #[test]
+    fn test_protocol_version_revisions() {
+        let version: ProtocolVersion = serde_json::from_str("\"2025-06-18\"").unwrap();
+        assert_eq!(version, ProtocolVersion::V2025_06_18);
+        assert!(version.is_supported());
+        assert_eq!(
+            ProtocolVersion::parse("2025-11-25"),
+            ProtocolVersion::latest()
+        );
+
+        assert!(ProtocolVersion::V2024_11_05 < ProtocolVersion::V2025_03_26);
+        assert!(ProtocolVersion::latest() < ProtocolVersion::Custom("2099-01-01".to_string()));
+        assert!(!ProtocolVersion::V2024_11_05
+            .server_capability_keys()
+            .contains(&"completions"));
+        assert!(ProtocolVersion::V2025_06_18
+            .tool_definition_fields()
+            .contains(&"outputSchema"));
+    }
+
     #[test]
     fn test_protocol_version_custom() {
         let custom = ProtocolVersion::Custom("2025-01-01".to_string());
@@ -362,6 +503,35 @@ mod tests {
         assert!(!custom.is_supported());
     }
 
+    #[test]
+    fn test_protocol_version_custom_naming_known_revision() {
+        use std::collections::HashSet;
+
+        let custom = ProtocolVersion::Custom("2025-06-18".to_string());
+        assert_eq!(custom, ProtocolVersion::V2025_06_18);
+        assert_eq!(
+            custom.cmp(&ProtocolVersion::V2025_06_18),
+            std::cmp::Ordering::Equal
+        );
+        assert!(custom.is_supported());
+        assert!(!custom.tool_definition_fields().contains(&"icons"));
+
+        let versions: HashSet<_> = [custom, ProtocolVersion::V2025_06_18].into_iter().collect();
+        assert_eq!(versions.len(), 1);
+
+        let mut sorted = vec![
+            ProtocolVersion::V2025_06_18,
+            ProtocolVersion::Custom("2025-06-18".to_string()),
+            ProtocolVersion::V2024_11_05,
+        ];
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(
+            sorted,
+            [ProtocolVersion::V2024_11_05, ProtocolVersion::V2025_06_18]
+        );
+    }
+
     #[test]
     fn test_capabilities_serialization() {
         let capabilities = Capabilities {
@@ -128,8 +128,11 @@ pub enum MessageRole {
 }
 
 /// Content of a prompt message.
+///
+/// Later protocol revisions add content types, so matches need a `_` arm.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum PromptContent {
     /// Text content
     #[serde(rename = "text")]
@@ -149,15 +152,68 @@ pub enum PromptContent {
         mime_type: String,
     },
 
+    /// Audio content
+    #[serde(rename = "audio")]
+    Audio {
+        /// Audio data (base64 encoded)
+        data: String,
+
+        /// MIME type of the audio
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+
+    /// Link to a resource the client may fetch with `resources/read` (2025-06-18)
+    #[serde(rename = "resource_link")]
+    ResourceLink {
+        /// URI of the linked resource
+        uri: String,
+
+        /// Name of the linked resource
+        #[serde(default)]
+        name: String,
+
+        /// Optional description of the linked resource
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+
+        /// MIME type of the linked resource
+        #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+
+        /// Size of the linked resource in bytes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size: Option<u64>,
+    },
+
     /// Resource reference
     #[serde(rename = "resource")]
     Resource {
         /// Resource reference
         resource: ResourceReference,
     },
+
+    /// Content type not known to this version, kept verbatim
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 impl PromptContent {
+    /// The `type` discriminator of this content item.
+    pub fn content_type(&self) -> &str {
+        match self {
+            Self::Text { .. } => "text",
+            Self::Image { .. } => "image",
+            Self::Audio { .. } => "audio",
+            Self::ResourceLink { .. } => "resource_link",
+            Self::Resource { .. } => "resource",
+            Self::Unknown(value) => value
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown"),
+        }
+    }
+
     /// Create text content.
     pub fn text(text: impl Into<String>) -> Self {
         Self::Text { text: text.into() }
@@ -171,12 +227,22 @@ impl PromptContent {
         }
     }
 
+    /// Create audio content.
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+
     /// Create resource content.
     pub fn resource(uri: impl Into<String>) -> Self {
         Self::Resource {
             resource: ResourceReference {
                 uri: uri.into(),
                 text: None,
+                mime_type: None,
+                blob: None,
             },
         }
     }
@@ -187,6 +253,8 @@ impl PromptContent {
             resource: ResourceReference {
                 uri: uri.into(),
                 text: Some(text.into()),
+                mime_type: None,
+                blob: None,
             },
         }
     }
@@ -201,6 +269,14 @@ pub struct ResourceReference {
     /// Optional description of the resource
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+
+    /// MIME type of the embedded resource
+    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Binary payload of the embedded resource (base64 encoded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
 }
 
 /// Notification that the list of prompts has changed.
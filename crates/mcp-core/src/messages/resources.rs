@@ -87,7 +87,11 @@ pub struct ReadResourceResponse {
 }
 
 /// Content of a resource.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The specification distinguishes text and binary contents by the presence of
+/// `text` or `blob`; the `type` tag written on serialization is optional when
+/// deserializing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(tag = "type")]
 pub enum ResourceContent {
     /// Text content
@@ -121,6 +125,39 @@ pub enum ResourceContent {
     },
 }
 
+impl<'de> Deserialize<'de> for ResourceContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawResourceContent {
+            uri: String,
+            #[serde(rename = "mimeType")]
+            mime_type: Option<String>,
+            text: Option<String>,
+            blob: Option<String>,
+        }
+
+        let raw = RawResourceContent::deserialize(deserializer)?;
+        match (raw.text, raw.blob) {
+            (_, Some(blob)) => Ok(Self::Blob {
+                blob,
+                uri: raw.uri,
+                mime_type: raw.mime_type,
+            }),
+            (Some(text), None) => Ok(Self::Text {
+                text,
+                uri: raw.uri,
+                mime_type: raw.mime_type,
+            }),
+            (None, None) => Err(serde::de::Error::custom(
+                "resource content must contain either `text` or `blob`",
+            )),
+        }
+    }
+}
+
 impl ResourceContent {
     /// Create text content.
     pub fn text(uri: impl Into<String>, text: impl Into<String>) -> Self {
@@ -307,6 +344,25 @@ mod tests {
         assert_eq!(content.mime_type(), Some("image/png"));
     }
 
+    #[test]
+    fn test_resource_content_without_type_tag() {
+        let contents: Vec<ResourceContent> = serde_json::from_value(json!([
+            {"uri": "file:///a.txt", "mimeType": "text/plain", "text": "hi"},
+            {"uri": "file:///b.png", "mimeType": "image/png", "blob": "iVBORw0KGgo="}
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            contents[0],
+            ResourceContent::text_with_mime_type("file:///a.txt", "hi", "text/plain")
+        );
+        assert_eq!(
+            contents[1],
+            ResourceContent::blob_with_mime_type("file:///b.png", "iVBORw0KGgo=", "image/png")
+        );
+        assert!(serde_json::from_value::<ResourceContent>(json!({"uri": "x"})).is_err());
+    }
+
     #[test]
     fn test_resource_updated_notification() {
         let notification = ResourceUpdatedNotification::new("file:///test.txt")
@@ -250,8 +250,11 @@ pub enum MessageRole {
 }
 
 /// Content of a sampling message.
+///
+/// Later protocol revisions add content types, so matches need a `_` arm.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum SamplingContent {
     /// Text content
     #[serde(rename = "text")]
@@ -270,6 +273,21 @@ pub enum SamplingContent {
         #[serde(rename = "mimeType")]
         mime_type: String,
     },
+
+    /// Audio content
+    #[serde(rename = "audio")]
+    Audio {
+        /// Audio data (base64 encoded)
+        data: String,
+
+        /// MIME type of the audio
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+
+    /// Content type not known to this version, kept verbatim
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 impl SamplingContent {
@@ -285,6 +303,14 @@ impl SamplingContent {
             mime_type: mime_type.into(),
         }
     }
+
+    /// Create audio content.
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
 }
 
 /// Response to a completion request.
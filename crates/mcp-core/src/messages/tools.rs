@@ -5,6 +5,7 @@
 //! - Tool execution (calling tools with parameters)
 //! - Tool schema definitions (parameter validation)
 //! - Tool result handling (success/error responses)
+//! - Structured tool output (`outputSchema` / `structuredContent`, 2025-06-18)
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -35,6 +36,10 @@ pub struct Tool {
     /// Unique name of the tool
     pub name: String,
 
+    /// Human-readable display name (2025-06-18)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
     /// Human-readable description of what the tool does
     pub description: String,
 
@@ -53,6 +58,45 @@ pub struct Tool {
     /// Return type schema for the tool
     #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
     pub return_type: Option<Value>,
+
+    /// JSON Schema for the tool's `structuredContent` (2025-06-18)
+    #[serde(rename = "outputSchema", skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<Value>,
+
+    /// Behavioural hints about the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+
+    /// Protocol-reserved metadata
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+/// Hints describing tool behaviour.
+///
+/// These are hints only; clients should not rely on them for security decisions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    /// Human-readable title for the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The tool does not modify its environment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only_hint: Option<bool>,
+
+    /// The tool may perform destructive updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destructive_hint: Option<bool>,
+
+    /// Calling the tool repeatedly with the same arguments has no additional effect
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotent_hint: Option<bool>,
+
+    /// The tool interacts with an open world of external entities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_world_hint: Option<bool>,
 }
 
 // Custom deserializer for Tool to handle multiple schema field names
@@ -68,6 +112,7 @@ impl<'de> Deserialize<'de> for Tool {
         #[serde(field_identifier, rename_all = "camelCase")]
         enum Field {
             Name,
+            Title,
             Description,
             #[serde(alias = "input_schema")]
             InputSchema,
@@ -77,6 +122,11 @@ impl<'de> Deserialize<'de> for Tool {
             #[serde(alias = "read_only")]
             ReadOnly,
             ReturnType,
+            #[serde(alias = "output_schema")]
+            OutputSchema,
+            Annotations,
+            #[serde(rename = "_meta")]
+            Meta,
             #[serde(other)]
             Unknown,
         }
@@ -95,11 +145,15 @@ impl<'de> Deserialize<'de> for Tool {
                 V: MapAccess<'de>,
             {
                 let mut name = None;
+                let mut title = None;
                 let mut description = None;
                 let mut input_schema = None;
                 let mut extensions = None;
                 let mut read_only = None;
                 let mut return_type = None;
+                let mut output_schema = None;
+                let mut annotations = None;
+                let mut meta = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -109,6 +163,12 @@ impl<'de> Deserialize<'de> for Tool {
                             }
                             name = Some(map.next_value()?);
                         }
+                        Field::Title => {
+                            if title.is_some() {
+                                return Err(de::Error::duplicate_field("title"));
+                            }
+                            title = Some(map.next_value()?);
+                        }
                         Field::Description => {
                             if description.is_some() {
                                 return Err(de::Error::duplicate_field("description"));
@@ -149,6 +209,24 @@ impl<'de> Deserialize<'de> for Tool {
                             }
                             return_type = Some(map.next_value()?);
                         }
+                        Field::OutputSchema => {
+                            if output_schema.is_some() {
+                                return Err(de::Error::duplicate_field("outputSchema"));
+                            }
+                            output_schema = Some(map.next_value()?);
+                        }
+                        Field::Annotations => {
+                            if annotations.is_some() {
+                                return Err(de::Error::duplicate_field("annotations"));
+                            }
+                            annotations = Some(map.next_value()?);
+                        }
+                        Field::Meta => {
+                            if meta.is_some() {
+                                return Err(de::Error::duplicate_field("_meta"));
+                            }
+                            meta = Some(map.next_value()?);
+                        }
                         Field::Unknown => {
                             // Skip unknown fields
                             let _: Value = map.next_value()?;
@@ -157,28 +235,36 @@ impl<'de> Deserialize<'de> for Tool {
                 }
 
                 let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
-                let description =
-                    description.ok_or_else(|| de::Error::missing_field("description"))?;
+                // Description became optional in 2025-06-18
+                let description = description.unwrap_or_default();
 
                 Ok(Tool {
                     name,
+                    title,
                     description,
                     input_schema,
                     extensions,
                     read_only,
                     return_type,
+                    output_schema,
+                    annotations,
+                    meta,
                 })
             }
         }
 
         const FIELDS: &[&str] = &[
             "name",
+            "title",
             "description",
             "inputSchema",
             "parametersSchema",
             "extensions",
             "readOnly",
             "returnType",
+            "outputSchema",
+            "annotations",
+            "_meta",
         ];
         deserializer.deserialize_struct("Tool", FIELDS, ToolVisitor)
     }
@@ -189,14 +275,24 @@ impl Tool {
     pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
         Self {
             name: name.into(),
+            title: None,
             description: description.into(),
             input_schema: None,
             extensions: None,
             read_only: None,
             return_type: None,
+            output_schema: None,
+            annotations: None,
+            meta: None,
         }
     }
 
+    /// Set the display title for this tool.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
     /// Set the input schema for this tool.
     pub fn with_input_schema(mut self, schema: Value) -> Self {
         self.input_schema = Some(schema);
@@ -220,6 +316,35 @@ impl Tool {
         self.return_type = Some(return_type);
         self
     }
+
+    /// Set the output schema for this tool.
+    pub fn with_output_schema(mut self, schema: Value) -> Self {
+        self.output_schema = Some(schema);
+        self
+    }
+
+    /// Set the behavioural annotations for this tool.
+    pub fn with_annotations(mut self, annotations: ToolAnnotations) -> Self {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    /// Get the display name, preferring `title`, then `annotations.title`, then `name`.
+    pub fn display_name(&self) -> &str {
+        self.title
+            .as_deref()
+            .or_else(|| self.annotations.as_ref().and_then(|a| a.title.as_deref()))
+            .unwrap_or(&self.name)
+    }
+
+    /// Whether the tool is declared read-only, via `annotations.readOnlyHint` or `readOnly`.
+    pub fn is_read_only(&self) -> bool {
+        self.annotations
+            .as_ref()
+            .and_then(|a| a.read_only_hint)
+            .or(self.read_only)
+            .unwrap_or(false)
+    }
 }
 
 /// Request to call a tool with specific arguments.
@@ -234,20 +359,31 @@ pub struct CallToolRequest {
 }
 
 /// Response from a tool call operation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct CallToolResponse {
     /// Results from the tool execution
     #[serde(default)]
     pub content: Vec<ToolResult>,
 
     /// Whether the tool is making a progress notification
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "isError", skip_serializing_if = "Option::is_none")]
     pub is_error: Option<bool>,
+
+    /// Structured result conforming to the tool's `outputSchema` (2025-06-18)
+    #[serde(rename = "structuredContent", skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<Value>,
+
+    /// Protocol-reserved metadata
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
 }
 
 /// Result content from a tool execution.
+///
+/// Later protocol revisions add content types, so matches need a `_` arm.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum ToolResult {
     /// Text content result
     #[serde(rename = "text")]
@@ -267,12 +403,67 @@ pub enum ToolResult {
         mime_type: String,
     },
 
-    /// Resource reference result
+    /// Audio content result
+    #[serde(rename = "audio")]
+    Audio {
+        /// Audio data (base64 encoded)
+        data: String,
+
+        /// MIME type of the audio
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+
+    /// Link to a resource the client may fetch with `resources/read` (2025-06-18)
+    #[serde(rename = "resource_link")]
+    ResourceLink {
+        /// URI of the linked resource
+        uri: String,
+
+        /// Name of the linked resource
+        #[serde(default)]
+        name: String,
+
+        /// Optional description of the linked resource
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+
+        /// MIME type of the linked resource
+        #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+
+        /// Size of the linked resource in bytes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size: Option<u64>,
+    },
+
+    /// Embedded resource result
     #[serde(rename = "resource")]
     Resource {
-        /// URI of the resource
+        /// The embedded resource contents
         resource: ResourceReference,
     },
+
+    /// Content type not known to this version, kept verbatim
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl ToolResult {
+    /// The `type` discriminator of this content item.
+    pub fn content_type(&self) -> &str {
+        match self {
+            Self::Text { .. } => "text",
+            Self::Image { .. } => "image",
+            Self::Audio { .. } => "audio",
+            Self::ResourceLink { .. } => "resource_link",
+            Self::Resource { .. } => "resource",
+            Self::Unknown(value) => value
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown"),
+        }
+    }
 }
 
 /// Reference to a resource.
@@ -281,9 +472,17 @@ pub struct ResourceReference {
     /// URI of the resource
     pub uri: String,
 
-    /// Optional description of the resource
+    /// Text payload of the embedded resource
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+
+    /// MIME type of the embedded resource
+    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+
+    /// Binary payload of the embedded resource (base64 encoded)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
 }
 
 /// Notification that the list of tools has changed.
@@ -373,6 +572,46 @@ mod tests {
         assert_eq!(json["mimeType"], "image/png");
     }
 
+    #[test]
+    fn test_tool_result_content_types() {
+        let response: CallToolResponse = serde_json::from_value(serde_json::json!({
+            "content": [
+                {"type": "audio", "data": "UklGRg==", "mimeType": "audio/wav"},
+                {"type": "resource_link", "uri": "file:///report.pdf", "name": "report",
+                 "mimeType": "application/pdf", "size": 2048},
+                {"type": "resource", "resource": {
+                    "uri": "file:///logo.png", "mimeType": "image/png", "blob": "iVBORw0KGgo="
+                }},
+                {"type": "hologram", "frames": 3}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.content[0],
+            ToolResult::Audio {
+                data: "UklGRg==".to_string(),
+                mime_type: "audio/wav".to_string(),
+            }
+        );
+        assert!(matches!(
+            &response.content[1],
+            ToolResult::ResourceLink { size: Some(2048), mime_type: Some(m), .. } if m == "application/pdf"
+        ));
+        match &response.content[2] {
+            ToolResult::Resource { resource } => {
+                assert_eq!(resource.blob.as_deref(), Some("iVBORw0KGgo="));
+                assert_eq!(resource.mime_type.as_deref(), Some("image/png"));
+            }
+            other => panic!("expected embedded resource, got {:?}", other),
+        }
+        assert_eq!(response.content[3].content_type(), "hologram");
+
+        // Unknown content round-trips unchanged
+        let json = serde_json::to_value(&response.content[3]).unwrap();
+        assert_eq!(json, serde_json::json!({"type": "hologram", "frames": 3}));
+    }
+
     #[test]
     fn test_tool_deserialization_with_camel_case() {
         // Test that we can deserialize tools with camelCase field names
@@ -396,6 +635,52 @@ mod tests {
         assert_eq!(tool.return_type, None);
     }
 
+    #[test]
+    fn test_tool_deserialization_2025_06_18_fields() {
+        let json_str = r#"{
+            "name": "get_weather",
+            "title": "Weather Lookup",
+            "inputSchema": {"type": "object"},
+            "outputSchema": {
+                "type": "object",
+                "properties": {"temperature": {"type": "number"}},
+                "required": ["temperature"]
+            },
+            "annotations": {"readOnlyHint": true, "openWorldHint": true},
+            "_meta": {"vendor/version": 2}
+        }"#;
+
+        let tool: Tool = serde_json::from_str(json_str).unwrap();
+        assert_eq!(tool.description, "");
+        assert_eq!(tool.display_name(), "Weather Lookup");
+        assert!(tool.output_schema.is_some());
+        assert!(tool.is_read_only());
+        let annotations = tool.annotations.as_ref().unwrap();
+        assert_eq!(annotations.open_world_hint, Some(true));
+        assert_eq!(annotations.destructive_hint, None);
+        assert_eq!(tool.meta, Some(json!({"vendor/version": 2})));
+
+        let serialized = serde_json::to_value(&tool).unwrap();
+        assert_eq!(serialized["annotations"]["readOnlyHint"], true);
+        assert!(serialized.get("outputSchema").is_some());
+    }
+
+    #[test]
+    fn test_call_tool_response_structured_content() {
+        let response: CallToolResponse = serde_json::from_value(json!({
+            "content": [{"type": "text", "text": "{\"temperature\": 21.5}"}],
+            "structuredContent": {"temperature": 21.5},
+            "isError": false
+        }))
+        .unwrap();
+
+        assert_eq!(response.is_error, Some(false));
+        assert_eq!(
+            response.structured_content,
+            Some(json!({"temperature": 21.5}))
+        );
+    }
+
     #[test]
     fn test_tool_deserialization_with_parameters_schema() {
         // Test that we can deserialize tools with parametersSchema field
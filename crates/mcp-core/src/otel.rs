@@ -0,0 +1,581 @@
+//! OpenTelemetry traces and metrics for MCP clients (`otel` feature).
+//!
+//! After [`init`], every request an [`McpClient`](crate::client::McpClient)
+//! sends is recorded as a client span named after its method, or
+//! `tools/call <tool>` for tool calls. The span's trace context is passed to
+//! the server in `params._meta` and, over HTTP, in the `traceparent` header
+//! (see [`crate::telemetry`]); a request that already carries trace context in
+//! `_meta` gets a child span of it. Each retry is a span of its own.
+//!
+//! | Span attribute | Value |
+//! |---|---|
+//! | `rpc.system` | `jsonrpc` |
+//! | `rpc.method` | Request method |
+//! | `rpc.jsonrpc.request_id` | Request ID |
+//! | `rpc.jsonrpc.error_code` | JSON-RPC error code, if the server answered with one |
+//! | `mcp.tool.name` | Tool name of a `tools/call` request |
+//! | `mcp.transport` | Transport type, e.g. `stdio` |
+//! | `mcp.outcome` | `ok`, `error` (JSON-RPC error) or `failed` (no response) |
+//!
+//! Metrics:
+//!
+//! - `mcp.client.request.duration`: histogram of request latency in seconds,
+//!   by `rpc.method`, `mcp.transport` and `mcp.outcome`
+//! - `mcp.client.requests`, `mcp.client.responses`, `mcp.client.errors`,
+//!   `mcp.client.retries`, `mcp.client.reconnects`,
+//!   `mcp.client.notifications.sent` and `mcp.client.notifications.received`:
+//!   the [`ClientStats`] of all clients created since [`init`] or before,
+//!   including dropped ones, summed by `mcp.transport`
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use mcp_probe_core::otel::{self, Exporter};
+//!
+//! # async fn example() -> mcp_probe_core::McpResult<()> {
+//! let telemetry = otel::init(
+//!     "my-agent",
+//!     Exporter::Otlp {
+//!         endpoint: "http://localhost:4318".to_string(),
+//!     },
+//! )?;
+//! // ... create clients and send requests ...
+//! telemetry.shutdown().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::ClientStats;
+use crate::error::{ConfigError, McpError, McpResult};
+use crate::messages::{JsonRpcRequest, JsonRpcResponse};
+use crate::telemetry::{TraceContext, TRACEPARENT, TRACESTATE};
+use futures::future::BoxFuture;
+use opentelemetry::metrics::ObservableCounter;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{Span, SpanKind, Status, TraceContextExt, TraceError, Tracer};
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope;
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
+use opentelemetry_sdk::metrics::{
+    MetricError, MetricResult, PeriodicReader, SdkMeterProvider, Temporality,
+};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::{runtime, Resource};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Instrumentation scope of the spans and metrics
+pub const INSTRUMENTATION_NAME: &str = "mcp-probe";
+
+/// How often metrics are exported
+pub const METRIC_EXPORT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Where traces and metrics are sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Exporter {
+    /// OTLP over HTTP to a collector, e.g. `http://localhost:4318`. Spans go
+    /// to `/v1/traces` and metrics to `/v1/metrics` under the endpoint; the
+    /// standard `OTEL_EXPORTER_OTLP_*` variables take precedence.
+    Otlp {
+        /// Base URL of the collector
+        endpoint: String,
+    },
+
+    /// OTLP/JSON appended to a file, one export request per line, as read by
+    /// the collector's `otlpjsonfile` receiver
+    File(PathBuf),
+}
+
+/// Installed tracer and meter providers; shut down to flush what is left.
+#[must_use = "call `shutdown` to export the remaining spans and metrics"]
+pub struct Telemetry {
+    tracer_provider: TracerProvider,
+    meter_provider: SdkMeterProvider,
+    _counters: Vec<ObservableCounter<u64>>,
+}
+
+impl std::fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Telemetry").finish_non_exhaustive()
+    }
+}
+
+/// Install global OpenTelemetry providers that export to `exporter`.
+///
+/// Must be called from within a Tokio runtime, which runs the exports.
+pub fn init(service_name: &str, exporter: Exporter) -> McpResult<Telemetry> {
+    let resource = Resource::new([KeyValue::new("service.name", service_name.to_string())]);
+    let tracer_provider = TracerProvider::builder().with_resource(resource.clone());
+    let meter_provider = SdkMeterProvider::builder().with_resource(resource);
+
+    let (tracer_provider, meter_provider) = match exporter {
+        Exporter::Otlp { endpoint } => {
+            let base = endpoint.trim_end_matches('/');
+            let invalid = |reason: String| {
+                McpError::Config(ConfigError::InvalidValue {
+                    parameter: "otlp endpoint".to_string(),
+                    value: endpoint.clone(),
+                    reason,
+                })
+            };
+            let spans = opentelemetry_otlp::SpanExporter::builder()
+                .with_http()
+                .with_endpoint(format!("{base}/v1/traces"))
+                .build()
+                .map_err(|e| invalid(e.to_string()))?;
+            let metrics = opentelemetry_otlp::MetricExporter::builder()
+                .with_http()
+                .with_endpoint(format!("{base}/v1/metrics"))
+                .build()
+                .map_err(|e| invalid(e.to_string()))?;
+            (
+                tracer_provider.with_batch_exporter(spans, runtime::Tokio),
+                meter_provider.with_reader(
+                    PeriodicReader::builder(metrics, runtime::Tokio)
+                        .with_interval(METRIC_EXPORT_INTERVAL)
+                        .build(),
+                ),
+            )
+        }
+        Exporter::File(path) => {
+            let file = FileExporter::create(&path).map_err(|e| {
+                McpError::Config(ConfigError::InvalidValue {
+                    parameter: "telemetry file".to_string(),
+                    value: path.display().to_string(),
+                    reason: e.to_string(),
+                })
+            })?;
+            (
+                tracer_provider.with_simple_exporter(file.clone()),
+                meter_provider.with_reader(
+                    PeriodicReader::builder(file, runtime::Tokio)
+                        .with_interval(METRIC_EXPORT_INTERVAL)
+                        .build(),
+                ),
+            )
+        }
+    };
+    let tracer_provider = tracer_provider.build();
+    let meter_provider = meter_provider.build();
+
+    global::set_tracer_provider(tracer_provider.clone());
+    global::set_meter_provider(meter_provider.clone());
+    Ok(Telemetry {
+        tracer_provider,
+        meter_provider,
+        _counters: observe_client_stats(),
+    })
+}
+
+impl Telemetry {
+    /// Export what is left and stop exporting.
+    pub async fn shutdown(self) -> McpResult<()> {
+        // The providers block until their export tasks finish, which need
+        // the runtime to make progress
+        let result = tokio::task::spawn_blocking(move || {
+            let traces = self.tracer_provider.shutdown().map_err(|e| e.to_string());
+            let metrics = self.meter_provider.shutdown().map_err(|e| e.to_string());
+            traces.and(metrics)
+        })
+        .await
+        .map_err(|e| McpError::internal(format!("Telemetry shutdown panicked: {e}")))?;
+        result.map_err(|e| McpError::internal(format!("Failed to flush telemetry: {e}")))
+    }
+}
+
+/// Span of one request attempt, started before it is sent.
+pub(crate) struct RequestSpan {
+    span: global::BoxedSpan,
+    started: Instant,
+    metric_attributes: Vec<KeyValue>,
+}
+
+impl RequestSpan {
+    /// Start the span and put its trace context into the request.
+    pub(crate) fn start(
+        mut request: JsonRpcRequest,
+        transport_type: &str,
+    ) -> (JsonRpcRequest, Self) {
+        let tracer = global::tracer(INSTRUMENTATION_NAME);
+        let propagator = TraceContextPropagator::new();
+        let parent = match TraceContext::from_request(&request) {
+            Some(context) => {
+                let mut carrier = HashMap::new();
+                carrier.insert(TRACEPARENT.to_string(), context.traceparent);
+                if let Some(tracestate) = context.tracestate {
+                    carrier.insert(TRACESTATE.to_string(), tracestate);
+                }
+                propagator.extract(&carrier)
+            }
+            None => Context::current(),
+        };
+
+        let method = request.method.clone();
+        let tool = (method == "tools/call")
+            .then(|| request.params.as_ref()?.get("name")?.as_str())
+            .flatten()
+            .map(str::to_string);
+        let mut attributes = vec![
+            KeyValue::new("rpc.system", "jsonrpc"),
+            KeyValue::new("rpc.method", method.clone()),
+            KeyValue::new("rpc.jsonrpc.request_id", request.id.to_string()),
+            KeyValue::new("mcp.transport", transport_type.to_string()),
+        ];
+        let name = match tool {
+            Some(tool) => {
+                attributes.push(KeyValue::new("mcp.tool.name", tool.clone()));
+                format!("{method} {tool}")
+            }
+            None => method.clone(),
+        };
+        let span = tracer
+            .span_builder(name)
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start_with_context(&tracer, &parent);
+
+        let mut carrier = HashMap::new();
+        propagator.inject_context(
+            &parent.with_remote_span_context(span.span_context().clone()),
+            &mut carrier,
+        );
+        if let Some(context) = carrier.remove(TRACEPARENT).and_then(TraceContext::new) {
+            let context = match carrier.remove(TRACESTATE) {
+                Some(tracestate) => context.with_tracestate(tracestate),
+                None => context,
+            };
+            context.inject(&mut request);
+        }
+
+        let metric_attributes = vec![
+            KeyValue::new("rpc.method", method),
+            KeyValue::new("mcp.transport", transport_type.to_string()),
+        ];
+        (
+            request,
+            Self {
+                span,
+                started: Instant::now(),
+                metric_attributes,
+            },
+        )
+    }
+
+    /// Record how the request ended and end the span.
+    pub(crate) fn finish(mut self, result: &McpResult<JsonRpcResponse>) {
+        let outcome = match result {
+            Ok(JsonRpcResponse {
+                error: Some(error), ..
+            }) => {
+                self.span.set_attribute(KeyValue::new(
+                    "rpc.jsonrpc.error_code",
+                    i64::from(error.code),
+                ));
+                self.span.set_status(Status::error(error.message.clone()));
+                "error"
+            }
+            Ok(_) => "ok",
+            Err(error) => {
+                self.span.record_error(error);
+                self.span.set_status(Status::error(error.to_string()));
+                "failed"
+            }
+        };
+        self.span
+            .set_attribute(KeyValue::new("mcp.outcome", outcome));
+        self.span.end();
+
+        self.metric_attributes
+            .push(KeyValue::new("mcp.outcome", outcome));
+        global::meter(INSTRUMENTATION_NAME)
+            .f64_histogram("mcp.client.request.duration")
+            .with_unit("s")
+            .with_description("Time from sending an MCP request to its response")
+            .build()
+            .record(
+                self.started.elapsed().as_secs_f64(),
+                &self.metric_attributes,
+            );
+    }
+}
+
+/// A client whose [`ClientStats`] are reported
+struct TrackedClient {
+    transport_type: String,
+    stats: Arc<RwLock<ClientStats>>,
+    /// Latest statistics read, used while the client is busy updating them
+    last: ClientStats,
+}
+
+/// Clients reported as metrics, and the totals of clients since dropped
+struct TrackedClients {
+    live: Vec<TrackedClient>,
+    retired: BTreeMap<String, ClientStats>,
+}
+
+static TRACKED_CLIENTS: Mutex<TrackedClients> = Mutex::new(TrackedClients {
+    live: Vec::new(),
+    retired: BTreeMap::new(),
+});
+
+impl TrackedClients {
+    /// Read the live clients' statistics and retire the dropped clients
+    fn refresh(&mut self) {
+        let retired = &mut self.retired;
+        self.live.retain_mut(|client| {
+            if let Ok(stats) = client.stats.try_read() {
+                client.last = stats.clone();
+            }
+            if Arc::strong_count(&client.stats) > 1 {
+                return true;
+            }
+            let total = retired.entry(client.transport_type.clone()).or_default();
+            accumulate(total, &client.last);
+            false
+        });
+    }
+
+    /// Statistics of all clients ever tracked, summed by transport type
+    fn totals(&self) -> BTreeMap<String, ClientStats> {
+        let mut totals = self.retired.clone();
+        for client in &self.live {
+            let total = totals.entry(client.transport_type.clone()).or_default();
+            accumulate(total, &client.last);
+        }
+        totals
+    }
+}
+
+fn accumulate(total: &mut ClientStats, stats: &ClientStats) {
+    total.requests_sent += stats.requests_sent;
+    total.responses_received += stats.responses_received;
+    total.notifications_sent += stats.notifications_sent;
+    total.notifications_received += stats.notifications_received;
+    total.errors += stats.errors;
+    total.retries += stats.retries;
+    total.reconnects += stats.reconnects;
+}
+
+fn tracked_clients() -> std::sync::MutexGuard<'static, TrackedClients> {
+    TRACKED_CLIENTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Report a new client's statistics as metrics.
+pub(crate) fn track_client(stats: &Arc<RwLock<ClientStats>>, transport_type: &str) {
+    let mut clients = tracked_clients();
+    clients.refresh();
+    clients.live.push(TrackedClient {
+        transport_type: transport_type.to_string(),
+        stats: Arc::clone(stats),
+        last: ClientStats::default(),
+    });
+}
+
+type StatsCounter = (&'static str, &'static str, fn(&ClientStats) -> u64);
+
+const STATS_COUNTERS: &[StatsCounter] = &[
+    ("mcp.client.requests", "Requests sent", |s| s.requests_sent),
+    ("mcp.client.responses", "Responses received", |s| {
+        s.responses_received
+    }),
+    ("mcp.client.errors", "Failed requests", |s| s.errors),
+    ("mcp.client.retries", "Request retries", |s| s.retries),
+    (
+        "mcp.client.reconnects",
+        "Reconnects after a lost server",
+        |s| s.reconnects,
+    ),
+    ("mcp.client.notifications.sent", "Notifications sent", |s| {
+        s.notifications_sent
+    }),
+    (
+        "mcp.client.notifications.received",
+        "Notifications received",
+        |s| s.notifications_received,
+    ),
+];
+
+fn observe_client_stats() -> Vec<ObservableCounter<u64>> {
+    let meter = global::meter(INSTRUMENTATION_NAME);
+    STATS_COUNTERS
+        .iter()
+        .map(|&(name, description, value)| {
+            meter
+                .u64_observable_counter(name)
+                .with_description(description)
+                .with_callback(move |observer| {
+                    let mut clients = tracked_clients();
+                    clients.refresh();
+                    for (transport_type, stats) in clients.totals() {
+                        observer.observe(
+                            value(&stats),
+                            &[KeyValue::new("mcp.transport", transport_type)],
+                        );
+                    }
+                })
+                .build()
+        })
+        .collect()
+}
+
+/// Writes spans and metrics to a file as OTLP/JSON lines
+#[derive(Debug, Clone)]
+struct FileExporter {
+    file: Arc<Mutex<std::fs::File>>,
+    resource: Resource,
+}
+
+impl FileExporter {
+    fn create(path: &std::path::Path) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            resource: Resource::empty(),
+        })
+    }
+
+    fn write(&self, request: &impl serde::Serialize) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(request)?;
+        line.push(b'\n');
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        file.write_all(&line)?;
+        file.flush()
+    }
+}
+
+impl SpanExporter for FileExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        let request = ExportTraceServiceRequest {
+            resource_spans: group_spans_by_resource_and_scope(batch, &(&self.resource).into()),
+        };
+        let result = self
+            .write(&request)
+            .map_err(|e| TraceError::from(e.to_string()));
+        Box::pin(std::future::ready(result))
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.resource = resource.clone();
+    }
+}
+
+#[async_trait::async_trait]
+impl PushMetricExporter for FileExporter {
+    async fn export(&self, metrics: &mut ResourceMetrics) -> MetricResult<()> {
+        self.write(&ExportMetricsServiceRequest::from(&*metrics))
+            .map_err(|e| MetricError::Other(e.to_string()))
+    }
+
+    async fn force_flush(&self) -> MetricResult<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> MetricResult<()> {
+        Ok(())
+    }
+
+    fn temporality(&self) -> Temporality {
+        Temporality::Cumulative
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::JsonRpcError;
+    use serde_json::{json, Value};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_request_spans_and_metrics_written_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("otel").join("telemetry.jsonl");
+        let telemetry = init("otel-test", Exporter::File(path.clone())).unwrap();
+
+        let stats = Arc::new(RwLock::new(ClientStats {
+            requests_sent: 2,
+            ..Default::default()
+        }));
+        track_client(&stats, "stdio");
+
+        // The caller's trace context is continued and replaced by the span's
+        let caller = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let request = JsonRpcRequest::new(
+            "req_1",
+            "tools/call",
+            json!({"name": "echo", "_meta": {"traceparent": caller}}),
+        );
+        let (request, span) = RequestSpan::start(request, "stdio");
+        let context = TraceContext::from_request(&request).unwrap();
+        assert!(context
+            .traceparent
+            .starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert_ne!(context.traceparent, caller);
+        span.finish(&Ok(JsonRpcResponse::error(
+            request.id.clone(),
+            JsonRpcError::method_not_found("tools/call"),
+        )));
+
+        let (_, span) =
+            RequestSpan::start(JsonRpcRequest::without_params("req_2", "ping"), "stdio");
+        span.finish(&Err(McpError::timeout("ping", Duration::from_secs(1))));
+
+        telemetry.shutdown().await.unwrap();
+        drop(stats);
+
+        let lines: Vec<Value> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let spans: Vec<&Value> = lines
+            .iter()
+            .filter_map(|line| line.get("resourceSpans"))
+            .flat_map(|r| r[0]["scopeSpans"][0]["spans"].as_array().unwrap())
+            .collect();
+        assert_eq!(spans.len(), 2);
+        let tool_call = spans
+            .iter()
+            .find(|s| s["name"] == "tools/call echo")
+            .unwrap();
+        assert_eq!(tool_call["traceId"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(tool_call["parentSpanId"], "00f067aa0ba902b7");
+        assert_eq!(
+            context.traceparent,
+            format!(
+                "00-{}-{}-01",
+                tool_call["traceId"].as_str().unwrap(),
+                tool_call["spanId"].as_str().unwrap()
+            )
+        );
+        let text = serde_json::to_string(tool_call).unwrap();
+        assert!(text.contains("mcp.tool.name"));
+        assert!(text.contains("\"error\""));
+        let ping = spans.iter().find(|s| s["name"] == "ping").unwrap();
+        assert!(serde_json::to_string(ping).unwrap().contains("\"failed\""));
+
+        let metrics = lines
+            .iter()
+            .filter(|line| line.get("resourceMetrics").is_some())
+            .map(|line| line.to_string())
+            .collect::<String>();
+        assert!(metrics.contains("mcp.client.request.duration"));
+        assert!(metrics.contains("mcp.client.requests"));
+        assert!(metrics.contains("otel-test"));
+    }
+}
@@ -0,0 +1,385 @@
+//! Secret references and redaction.
+//!
+//! Configuration values can point at a secret instead of embedding it:
+//!
+//! - `${env:NAME}` - value of an environment variable
+//! - `${file:PATH}` - contents of a file (`~` is expanded, trailing newlines trimmed)
+//! - `${cmd:COMMAND}` - stdout of a shell command (e.g. `${cmd:pass show mcp/github}`),
+//!   killed if it runs longer than [`COMMAND_TIMEOUT`]
+//!
+//! References are resolved only when a transport is created (see
+//! [`TransportConfig::resolve_secrets`](crate::transport::TransportConfig::resolve_secrets)),
+//! so configuration files and recordings keep the reference rather than the value.
+//! Every resolved value, and every literal credential seen during resolution, is
+//! remembered for the lifetime of the process so [`redact`] can scrub it from
+//! arbitrary text such as logs, reports and captured messages.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mcp_probe_core::secrets;
+//!
+//! std::env::set_var("DOC_EXAMPLE_TOKEN", "s3cr3t-value");
+//! let header = secrets::resolve("Bearer ${env:DOC_EXAMPLE_TOKEN}").unwrap();
+//! assert_eq!(header, "Bearer s3cr3t-value");
+//! assert_eq!(secrets::redact("token=s3cr3t-value"), "token=***");
+//! ```
+
+use crate::error::{ConfigError, McpResult};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::io::Read;
+use std::process::Stdio;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+/// Placeholder shown instead of a secret value.
+pub const REDACTED: &str = "***";
+
+/// Longest time a `${cmd:…}` reference may run, e.g. while a password
+/// manager waits for an unlock prompt nobody answers.
+pub const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Secrets shorter than this are not scrubbed from free text, to avoid
+/// replacing common short strings everywhere.
+const MIN_SCRUB_LEN: usize = 4;
+
+/// Key fragments that mark a field, header or environment variable as secret.
+const SENSITIVE_KEY_PARTS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "authorization",
+    "api_key",
+    "apikey",
+    "api-key",
+    "cookie",
+    "credential",
+    "private_key",
+];
+
+fn known_secrets() -> &'static RwLock<BTreeSet<String>> {
+    static SECRETS: OnceLock<RwLock<BTreeSet<String>>> = OnceLock::new();
+    SECRETS.get_or_init(|| RwLock::new(BTreeSet::new()))
+}
+
+/// Remember a secret value so [`redact`] scrubs it from text.
+pub fn register_secret(value: &str) {
+    if value.len() >= MIN_SCRUB_LEN && !has_reference(value) {
+        if let Ok(mut secrets) = known_secrets().write() {
+            secrets.insert(value.to_string());
+        }
+    }
+}
+
+/// Whether a value contains a `${env:…}`, `${file:…}` or `${cmd:…}` reference.
+pub fn has_reference(value: &str) -> bool {
+    ["${env:", "${file:", "${cmd:"]
+        .iter()
+        .any(|prefix| value.contains(prefix))
+}
+
+/// Whether a field, header or environment variable name denotes a secret.
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEY_PARTS.iter().any(|part| key.contains(part))
+}
+
+/// Resolve all secret references in a value.
+///
+/// Text outside references, and `${…}` sequences with other schemes, are kept
+/// as is. Resolved values are registered for redaction.
+pub fn resolve(value: &str) -> McpResult<String> {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        let Some(end) = candidate.find('}') else {
+            output.push_str(candidate);
+            return Ok(output);
+        };
+
+        let reference = &candidate[..=end];
+        let body = &candidate[2..end];
+        match body.split_once(':') {
+            Some((scheme @ ("env" | "file" | "cmd"), argument)) => {
+                let secret = resolve_reference(scheme, argument.trim()).map_err(|reason| {
+                    ConfigError::SecretResolution {
+                        reference: reference.to_string(),
+                        reason,
+                    }
+                })?;
+                register_secret(&secret);
+                output.push_str(&secret);
+            }
+            _ => output.push_str(reference),
+        }
+        rest = &candidate[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn resolve_reference(scheme: &str, argument: &str) -> Result<String, String> {
+    match scheme {
+        "env" => std::env::var(argument)
+            .map_err(|_| format!("environment variable {} is not set", argument)),
+        "file" => {
+            let path = expand_home(argument);
+            std::fs::read_to_string(&path)
+                .map(|content| content.trim_end_matches(['\r', '\n']).to_string())
+                .map_err(|e| format!("cannot read {}: {}", path, e))
+        }
+        "cmd" => run_command(argument, COMMAND_TIMEOUT),
+        _ => Err(format!("unknown secret scheme '{}'", scheme)),
+    }
+}
+
+/// Stdout of a shell command, killing it once `timeout` has passed
+fn run_command(command: &str, timeout: Duration) -> Result<String, String> {
+    let mut child = shell_command(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("cannot run command: {}", e))?;
+
+    // Read on a separate thread so a large output cannot fill the pipe and
+    // stall the command
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let reader = std::thread::spawn(move || {
+        let mut output = Vec::new();
+        stdout.read_to_end(&mut output).map(|_| output)
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "secret command timed out after {}",
+                    humantime_serde::re::humantime::format_duration(timeout)
+                ));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(10)),
+            Err(e) => return Err(format!("cannot wait for command: {}", e)),
+        }
+    };
+    if !status.success() {
+        return Err(format!("command exited with {}", status));
+    }
+
+    let output = reader
+        .join()
+        .map_err(|_| "cannot read command output".to_string())?
+        .map_err(|e| format!("cannot read command output: {}", e))?;
+    String::from_utf8(output)
+        .map(|stdout| stdout.trim_end_matches(['\r', '\n']).to_string())
+        .map_err(|_| "command output is not valid UTF-8".to_string())
+}
+
+fn expand_home(path: &str) -> String {
+    match path.strip_prefix("~/") {
+        Some(rest) => match std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
+            Ok(home) => format!("{}/{}", home.trim_end_matches('/'), rest),
+            Err(_) => path.to_string(),
+        },
+        None => path.to_string(),
+    }
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> std::process::Command {
+    let mut cmd = std::process::Command::new("cmd");
+    cmd.args(["/C", command]);
+    cmd
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> std::process::Command {
+    let mut cmd = std::process::Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+/// Replace every known secret value in `text` with [`REDACTED`].
+pub fn redact(text: &str) -> String {
+    let Ok(secrets) = known_secrets().read() else {
+        return text.to_string();
+    };
+
+    // Longest first, so a secret containing another is replaced whole
+    let mut ordered: Vec<&String> = secrets.iter().collect();
+    ordered.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+
+    let mut redacted = text.to_string();
+    for secret in ordered {
+        if redacted.contains(secret.as_str()) {
+            redacted = redacted.replace(secret.as_str(), REDACTED);
+        }
+    }
+    redacted
+}
+
+/// Display form of a value stored under `key`: [`REDACTED`] for literal
+/// secrets, the reference itself for `${…}` values, scrubbed text otherwise.
+pub fn redact_for_key(key: &str, value: &str) -> String {
+    if has_reference(value) {
+        value.to_string()
+    } else if is_sensitive_key(key) {
+        REDACTED.to_string()
+    } else {
+        redact(value)
+    }
+}
+
+/// Redact a configuration document in place.
+///
+/// String values under sensitive keys become [`REDACTED`] (unless they are
+/// references) and every other string is scrubbed with [`redact`]. Use
+/// [`redact_payload`] for MCP messages, whose fields are server data.
+pub fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(text) => *text = redact_for_key(key, text),
+                    other => redact_json(other),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_json),
+        Value::String(text) => *text = redact(text),
+        _ => {}
+    }
+}
+
+/// Scrub known secret values from every string in a JSON payload, in place.
+///
+/// Unlike [`redact_json`], keys are not consulted: message fields that merely
+/// look sensitive, such as `nextPageToken`, `progressToken` or a tool argument
+/// named `token`, are kept unless they hold a registered secret.
+pub fn redact_payload(value: &mut Value) {
+    match value {
+        Value::Object(map) => map.values_mut().for_each(redact_payload),
+        Value::Array(items) => items.iter_mut().for_each(redact_payload),
+        Value::String(text) => *text = redact(text),
+        _ => {}
+    }
+}
+
+/// `Debug` wrapper printing a secret as [`REDACTED`] (references are shown).
+pub struct Redacted<'a>(pub &'a str);
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if has_reference(self.0) {
+            fmt::Debug::fmt(self.0, f)
+        } else {
+            f.write_str(REDACTED)
+        }
+    }
+}
+
+/// `Debug` wrapper for header/environment maps that redacts sensitive entries.
+pub struct RedactedMap<'a, M = HashMap<String, String>>(pub &'a M);
+
+impl<M> fmt::Debug for RedactedMap<'_, M>
+where
+    for<'m> &'m M: IntoIterator<Item = (&'m String, &'m String)>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.0
+                    .into_iter()
+                    .map(|(key, value)| (key, redact_for_key(key, value))),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resolve_references() {
+        std::env::set_var("MCP_PROBE_SECRETS_TEST_TOKEN", "tok-123456");
+        assert_eq!(
+            resolve("Bearer ${env:MCP_PROBE_SECRETS_TEST_TOKEN}").unwrap(),
+            "Bearer tok-123456"
+        );
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "from-file-secret\n").unwrap();
+        let reference = format!("${{file:{}}}", file.path().display());
+        assert_eq!(resolve(&reference).unwrap(), "from-file-secret");
+
+        #[cfg(unix)]
+        assert_eq!(resolve("${cmd:echo cmd-secret}").unwrap(), "cmd-secret");
+
+        // Other `${…}` sequences and plain text are untouched
+        assert_eq!(resolve("${HOME} and $x").unwrap(), "${HOME} and $x");
+
+        let err = resolve("${env:MCP_PROBE_SECRETS_TEST_MISSING}").unwrap_err();
+        assert!(err.to_string().contains("MCP_PROBE_SECRETS_TEST_MISSING"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_timeout() {
+        let started = Instant::now();
+        let err = run_command("sleep 5", Duration::from_millis(200)).unwrap_err();
+        assert_eq!(err, "secret command timed out after 200ms");
+        assert!(started.elapsed() < Duration::from_secs(4));
+
+        let err = run_command("exit 3", COMMAND_TIMEOUT).unwrap_err();
+        assert!(err.contains("exited"), "{}", err);
+    }
+
+    #[test]
+    fn test_redaction() {
+        register_secret("very-secret-value");
+        assert_eq!(
+            redact("Authorization: Bearer very-secret-value"),
+            "Authorization: Bearer ***"
+        );
+
+        let mut document = json!({
+            "headers": {"Authorization": "Bearer abc", "Accept": "application/json"},
+            "env": {"GITHUB_TOKEN": "${env:GITHUB_TOKEN}", "DEBUG": "1"},
+            "output": ["echo very-secret-value"]
+        });
+        redact_json(&mut document);
+        assert_eq!(document["headers"]["Authorization"], REDACTED);
+        assert_eq!(document["headers"]["Accept"], "application/json");
+        assert_eq!(document["env"]["GITHUB_TOKEN"], "${env:GITHUB_TOKEN}");
+        assert_eq!(document["env"]["DEBUG"], "1");
+        assert_eq!(document["output"][0], "echo ***");
+
+        let mut payload = json!({
+            "nextPageToken": "page-2",
+            "arguments": {"token": "very-secret-value", "progressToken": 7}
+        });
+        redact_payload(&mut payload);
+        assert_eq!(payload["nextPageToken"], "page-2");
+        assert_eq!(payload["arguments"]["token"], REDACTED);
+        assert_eq!(payload["arguments"]["progressToken"], 7);
+
+        assert_eq!(format!("{:?}", Redacted("plain")), "***");
+        assert_eq!(
+            format!("{:?}", Redacted("${env:X}")),
+            format!("{:?}", "${env:X}")
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Automatic restart and reconnection of MCP sessions.
+//!
+//! A stdio server that crashes or is restarted by a hot-reloading dev server,
+//! or an HTTP server that forgets the session, leaves an
+//! [`McpClient`](crate::client::McpClient) unable to send requests. With a
+//! [`SupervisionPolicy`] set on
+//! [`ClientConfig::supervision`](crate::client::ClientConfig::supervision),
+//! the client notices the lost connection on its next request, reconnects with
+//! exponential backoff, redoes the `initialize` handshake and restores the
+//! resource subscriptions and log level of the previous session. Every state
+//! transition is published on the client's [event stream](crate::events).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mcp_probe_core::supervision::SupervisionPolicy;
+//! use std::time::Duration;
+//!
+//! let policy = SupervisionPolicy::default()
+//!     .max_attempts(Some(3))
+//!     .backoff(Duration::from_millis(100), Duration::from_secs(1));
+//!
+//! assert_eq!(policy.delay(1), Duration::from_millis(100));
+//! assert_eq!(policy.delay(2), Duration::from_millis(200));
+//! assert_eq!(policy.delay(10), Duration::from_secs(1));
+//! assert!(policy.allows(3));
+//! assert!(!policy.allows(4));
+//! ```
+
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+/// How a supervised client reconnects after losing its server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupervisionPolicy {
+    /// Reconnection attempts before giving up (`None` retries forever)
+    pub max_attempts: Option<u32>,
+
+    /// Delay before the first attempt
+    pub initial_backoff: Duration,
+
+    /// Upper bound for the delay between attempts
+    pub max_backoff: Duration,
+
+    /// Factor the delay grows by after each failed attempt
+    pub multiplier: f64,
+}
+
+impl Default for SupervisionPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Some(5),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl SupervisionPolicy {
+    /// Set the number of attempts before giving up (`None` retries forever).
+    pub fn max_attempts(mut self, attempts: Option<u32>) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Set the first and the longest delay between attempts.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Delay before the given attempt, counting from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(attempt.saturating_sub(1).min(64) as i32);
+        // Computed in seconds so a large factor saturates instead of overflowing
+        let max = self.max_backoff.max(self.initial_backoff).as_secs_f64();
+        Duration::from_secs_f64((self.initial_backoff.as_secs_f64() * factor).min(max))
+    }
+
+    /// Whether the given attempt, counting from 1, may be made.
+    pub fn allows(&self, attempt: u32) -> bool {
+        match self.max_attempts {
+            Some(max) => attempt <= max,
+            None => true,
+        }
+    }
+}
+
+/// Session settings that a reconnect restores on the new session.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionState {
+    /// URIs passed to successful `resources/subscribe` requests
+    pub subscriptions: BTreeSet<String>,
+    /// Level of the last successful `logging/setLevel` request
+    pub log_level: Option<String>,
+}
+
+impl SessionState {
+    /// Remember what a successful request changed about the session.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mcp_probe_core::supervision::SessionState;
+    /// use serde_json::json;
+    ///
+    /// let mut session = SessionState::default();
+    /// session.record("resources/subscribe", &json!({"uri": "file:///a"}));
+    /// session.record("resources/subscribe", &json!({"uri": "file:///b"}));
+    /// session.record("resources/unsubscribe", &json!({"uri": "file:///a"}));
+    /// session.record("logging/setLevel", &json!({"level": "debug"}));
+    ///
+    /// assert_eq!(session.subscriptions.iter().collect::<Vec<_>>(), ["file:///b"]);
+    /// assert_eq!(session.log_level.as_deref(), Some("debug"));
+    /// ```
+    pub fn record(&mut self, method: &str, params: &Value) {
+        let field = |name: &str| params.get(name).and_then(Value::as_str).map(str::to_string);
+        match method {
+            "resources/subscribe" => {
+                if let Some(uri) = field("uri") {
+                    self.subscriptions.insert(uri);
+                }
+            }
+            "resources/unsubscribe" => {
+                if let Some(uri) = field("uri") {
+                    self.subscriptions.remove(&uri);
+                }
+            }
+            "logging/setLevel" => {
+                if let Some(level) = field("level") {
+                    self.log_level = Some(level);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The requests that bring a new session to the same state, in order.
+    pub fn restore_requests(&self) -> Vec<(&'static str, Value)> {
+        let mut requests = Vec::new();
+        if let Some(level) = &self.log_level {
+            requests.push(("logging/setLevel", serde_json::json!({ "level": level })));
+        }
+        for uri in &self.subscriptions {
+            requests.push(("resources/subscribe", serde_json::json!({ "uri": uri })));
+        }
+        requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = SupervisionPolicy::default();
+        assert_eq!(policy.delay(1), Duration::from_millis(500));
+        assert_eq!(policy.delay(3), Duration::from_secs(2));
+        assert_eq!(policy.delay(100), Duration::from_secs(30));
+        assert!(policy.allows(5));
+        assert!(!policy.allows(6));
+
+        let forever = policy.max_attempts(None);
+        assert!(forever.allows(u32::MAX));
+    }
+
+    #[test]
+    fn test_restore_requests() {
+        let mut session = SessionState::default();
+        session.record("resources/subscribe", &json!({"uri": "file:///b"}));
+        session.record("resources/subscribe", &json!({"uri": "file:///a"}));
+        session.record("tools/call", &json!({"name": "x"}));
+        session.record("logging/setLevel", &json!({"level": "info"}));
+
+        let requests = session.restore_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0], ("logging/setLevel", json!({"level": "info"})));
+        assert_eq!(requests[1].1["uri"], "file:///a");
+        assert_eq!(requests[2].1["uri"], "file:///b");
+    }
+}
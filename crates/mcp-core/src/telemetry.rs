@@ -0,0 +1,234 @@
+//! W3C trace context carried by MCP messages.
+//!
+//! MCP has no transport-independent place for trace headers, so trace context
+//! travels in a request's `params._meta` under the W3C Trace Context names
+//! `traceparent` and `tracestate`. A server reading `_meta` can join the
+//! caller's trace over any transport; the HTTP transports additionally send
+//! the values as `traceparent` and `tracestate` headers for HTTP middleware
+//! and proxies.
+//!
+//! Context that a caller puts in `_meta` is forwarded as is. With the `otel`
+//! feature, the client also starts a span for every request and passes that
+//! span's context on, see [`crate::otel`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mcp_probe_core::messages::JsonRpcRequest;
+//! use mcp_probe_core::telemetry::TraceContext;
+//! use serde_json::json;
+//!
+//! let mut request = JsonRpcRequest::new("req_1", "tools/call", json!({"name": "echo"}));
+//! let context =
+//!     TraceContext::new("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+//! context.inject(&mut request);
+//!
+//! assert_eq!(request.params.as_ref().unwrap()["_meta"]["traceparent"], context.traceparent);
+//! assert_eq!(TraceContext::from_request(&request), Some(context));
+//! ```
+
+use crate::messages::{JsonRpcMessage, JsonRpcRequest};
+use serde_json::{Map, Value};
+
+/// Name of the trace parent in `_meta` and in HTTP headers
+pub const TRACEPARENT: &str = "traceparent";
+
+/// Name of the vendor trace state in `_meta` and in HTTP headers
+pub const TRACESTATE: &str = "tracestate";
+
+/// Trace context of a request, as defined by W3C Trace Context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    /// `version-traceid-parentid-flags`, e.g.
+    /// `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`
+    pub traceparent: String,
+
+    /// Vendor-specific trace state, e.g. `vendor=value`
+    pub tracestate: Option<String>,
+}
+
+impl TraceContext {
+    /// Trace context with the given trace parent, or `None` if it is malformed.
+    pub fn new(traceparent: impl Into<String>) -> Option<Self> {
+        let traceparent = traceparent.into();
+        is_valid_traceparent(&traceparent).then_some(Self {
+            traceparent,
+            tracestate: None,
+        })
+    }
+
+    /// Set the vendor trace state; an empty state is left out.
+    pub fn with_tracestate(mut self, tracestate: impl Into<String>) -> Self {
+        let tracestate = tracestate.into();
+        self.tracestate = (!tracestate.trim().is_empty()).then_some(tracestate);
+        self
+    }
+
+    /// Trace context in a request's `params._meta`, if any.
+    pub fn from_request(request: &JsonRpcRequest) -> Option<Self> {
+        Self::from_params(request.params.as_ref()?)
+    }
+
+    /// Trace context of a request or notification, if any.
+    pub fn from_message(message: &JsonRpcMessage) -> Option<Self> {
+        match message {
+            JsonRpcMessage::Request(request) => Self::from_request(request),
+            JsonRpcMessage::Notification(notification) => {
+                Self::from_params(notification.params.as_ref()?)
+            }
+            JsonRpcMessage::Response(_) => None,
+        }
+    }
+
+    fn from_params(params: &Value) -> Option<Self> {
+        let meta = params.get("_meta")?;
+        let context = Self::new(meta.get(TRACEPARENT)?.as_str()?)?;
+        Some(match meta.get(TRACESTATE).and_then(Value::as_str) {
+            Some(tracestate) => context.with_tracestate(tracestate),
+            None => context,
+        })
+    }
+
+    /// Store the context in the request's `params._meta`, replacing any
+    /// previous context and keeping other `_meta` fields.
+    ///
+    /// Returns `false` if the params are not an object (or absent), since
+    /// positional params have no place for `_meta`.
+    pub fn inject(&self, request: &mut JsonRpcRequest) -> bool {
+        let params = request
+            .params
+            .get_or_insert_with(|| Value::Object(Map::new()));
+        let Some(params) = params.as_object_mut() else {
+            return false;
+        };
+        let meta = params
+            .entry("_meta")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !meta.is_object() {
+            *meta = Value::Object(Map::new());
+        }
+        let meta = meta.as_object_mut().expect("_meta is an object");
+        meta.insert(
+            TRACEPARENT.to_string(),
+            Value::String(self.traceparent.clone()),
+        );
+        match &self.tracestate {
+            Some(tracestate) => {
+                meta.insert(TRACESTATE.to_string(), Value::String(tracestate.clone()));
+            }
+            None => {
+                meta.remove(TRACESTATE);
+            }
+        }
+        true
+    }
+
+    /// HTTP headers carrying the context
+    pub fn headers(&self) -> Vec<(&'static str, &str)> {
+        let mut headers = vec![(TRACEPARENT, self.traceparent.as_str())];
+        if let Some(tracestate) = &self.tracestate {
+            headers.push((TRACESTATE, tracestate.as_str()));
+        }
+        headers
+    }
+}
+
+/// Add the trace headers of a message's context to an HTTP request
+pub(crate) fn with_trace_headers(
+    mut builder: reqwest::RequestBuilder,
+    context: Option<&TraceContext>,
+) -> reqwest::RequestBuilder {
+    if let Some(context) = context {
+        for (name, value) in context.headers() {
+            builder = builder.header(name, value);
+        }
+    }
+    builder
+}
+
+/// Whether a trace parent is well formed: version `00`-`fe`, a 32-digit trace
+/// ID and a 16-digit parent ID (neither all zeros) and 2-digit flags, all in
+/// lowercase hex
+fn is_valid_traceparent(traceparent: &str) -> bool {
+    let parts: Vec<&str> = traceparent.split('-').collect();
+    let [version, trace_id, parent_id, flags] = parts[..] else {
+        // Later versions may append fields, but they must keep these four
+        return parts.len() > 4 && parts[0] != "00" && is_valid_traceparent(&parts[..4].join("-"));
+    };
+    let hex = |field: &str, len: usize| {
+        field.len() == len
+            && field
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    };
+    let nonzero = |field: &str| field.bytes().any(|b| b != b'0');
+    hex(version, 2)
+        && version != "ff"
+        && hex(trace_id, 32)
+        && nonzero(trace_id)
+        && hex(parent_id, 16)
+        && nonzero(parent_id)
+        && hex(flags, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_traceparent_validation() {
+        assert!(TraceContext::new(PARENT).is_some());
+        assert!(TraceContext::new(PARENT.to_uppercase()).is_none());
+        assert!(
+            TraceContext::new("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            TraceContext::new("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01").is_none()
+        );
+        assert!(
+            TraceContext::new("ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            TraceContext::new("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7").is_none()
+        );
+        assert!(TraceContext::new(format!("{PARENT}-extra")).is_none());
+        assert!(
+            TraceContext::new("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_inject_keeps_other_meta_and_reads_back() {
+        let context = TraceContext::new(PARENT)
+            .unwrap()
+            .with_tracestate("vendor=1");
+
+        let mut request = JsonRpcRequest::new(
+            "req_1",
+            "tools/call",
+            json!({"name": "echo", "_meta": {"progressToken": 7, "tracestate": "old=1"}}),
+        );
+        assert!(context.inject(&mut request));
+        let params = request.params.as_ref().unwrap();
+        assert_eq!(params["name"], "echo");
+        assert_eq!(params["_meta"]["progressToken"], 7);
+        assert_eq!(params["_meta"]["tracestate"], "vendor=1");
+        assert_eq!(TraceContext::from_request(&request), Some(context.clone()));
+        assert_eq!(
+            context.headers(),
+            [("traceparent", PARENT), ("tracestate", "vendor=1")]
+        );
+
+        let mut bare = JsonRpcRequest::without_params("req_2", "ping");
+        assert!(context.inject(&mut bare));
+        assert_eq!(bare.params.unwrap()["_meta"]["traceparent"], PARENT);
+
+        let mut positional = JsonRpcRequest::new("req_3", "sum", json!([1, 2]));
+        assert!(!context.inject(&mut positional));
+        assert_eq!(TraceContext::from_request(&positional), None);
+    }
+}
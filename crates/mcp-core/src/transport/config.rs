@@ -16,6 +16,10 @@
 //!     working_dir: Some("/path/to/server".to_string()),
 //!     timeout: Duration::from_secs(30),
 //!     environment: Default::default(),
+//!     stderr_lines: 200,
+//!     stderr_log: None,
+//!     stdin_close_timeout: Duration::from_secs(2),
+//!     terminate_timeout: Duration::from_secs(2),
 //! });
 //!
 //! // HTTP+SSE transport configuration  
@@ -28,8 +32,10 @@
 //! ```
 
 use crate::error::{ConfigError, McpResult};
+use crate::secrets::{self, Redacted, RedactedMap};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::path::PathBuf;
 use std::time::Duration;
 use url::Url;
@@ -68,6 +74,10 @@ impl TransportConfig {
             working_dir: None,
             timeout: Duration::from_secs(30),
             environment: HashMap::new(),
+            stderr_lines: DEFAULT_STDERR_LINES,
+            stderr_log: None,
+            stdin_close_timeout: DEFAULT_STDIN_CLOSE_TIMEOUT,
+            terminate_timeout: DEFAULT_TERMINATE_TIMEOUT,
         })
     }
 
@@ -145,6 +155,66 @@ impl TransportConfig {
         }
     }
 
+    /// Resolve `${env:…}`, `${file:…}` and `${cmd:…}` references.
+    ///
+    /// Applies to stdio arguments and environment values, HTTP headers and
+    /// authentication fields. The returned copy holds the real secrets and is
+    /// only meant to be handed to a transport; every secret it contains is
+    /// registered for [`secrets::redact`].
+    pub fn resolve_secrets(&self) -> McpResult<Self> {
+        let mut config = self.clone();
+        match &mut config {
+            Self::Stdio(stdio) => {
+                for arg in &mut stdio.args {
+                    *arg = secrets::resolve(arg)?;
+                }
+                resolve_map(&mut stdio.environment)?;
+            }
+            Self::HttpSse(http) => {
+                resolve_map(&mut http.headers)?;
+                if let Some(auth) = &mut http.auth {
+                    auth.resolve_secrets()?;
+                }
+            }
+            Self::HttpStream(http) => {
+                resolve_map(&mut http.headers)?;
+                if let Some(auth) = &mut http.auth {
+                    auth.resolve_secrets()?;
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Copy of this configuration that is safe to display or share.
+    ///
+    /// Literal credentials become [`secrets::REDACTED`]; secret references
+    /// are kept as written.
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        match &mut config {
+            Self::Stdio(stdio) => {
+                for arg in &mut stdio.args {
+                    *arg = secrets::redact(arg);
+                }
+                redact_map(&mut stdio.environment);
+            }
+            Self::HttpSse(http) => {
+                redact_map(&mut http.headers);
+                if let Some(auth) = &mut http.auth {
+                    auth.redact();
+                }
+            }
+            Self::HttpStream(http) => {
+                redact_map(&mut http.headers);
+                if let Some(auth) = &mut http.auth {
+                    auth.redact();
+                }
+            }
+        }
+        config
+    }
+
     /// Load configuration from a file.
     ///
     /// Supports JSON, YAML, and TOML formats based on file extension.
@@ -195,6 +265,9 @@ impl TransportConfig {
 
     /// Save configuration to a file.
     ///
+    /// Secret references are written as is. Literal credentials are written
+    /// too (the file must stay loadable), with a warning suggesting a reference.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -206,6 +279,13 @@ impl TransportConfig {
     /// ```
     pub fn to_file(&self, path: impl AsRef<std::path::Path>) -> McpResult<()> {
         let path = path.as_ref();
+        if self.redacted() != *self {
+            tracing::warn!(
+                "Writing literal credentials to {}; consider ${{env:…}}, ${{file:…}} or ${{cmd:…}} references",
+                path.display()
+            );
+        }
+
         let content = match path.extension().and_then(|ext| ext.to_str()) {
             Some("json") => {
                 serde_json::to_string_pretty(self).map_err(|e| ConfigError::InvalidFormat {
@@ -241,23 +321,43 @@ impl TransportConfig {
 /// Configuration for stdio (local process) transport.
 ///
 /// This transport spawns a local process and communicates via stdin/stdout.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StdioConfig {
     /// Command to execute (e.g., "python", "/usr/bin/node")
     pub command: String,
 
     /// Arguments to pass to the command
+    #[serde(default)]
     pub args: Vec<String>,
 
     /// Working directory for the process (optional)
+    #[serde(default)]
     pub working_dir: Option<String>,
 
     /// Timeout for process operations
-    #[serde(with = "humantime_serde")]
+    #[serde(with = "humantime_serde", default = "default_stdio_timeout")]
     pub timeout: Duration,
 
     /// Environment variables to set for the process
+    #[serde(default)]
     pub environment: HashMap<String, String>,
+
+    /// Number of recent stderr lines kept in memory
+    #[serde(default = "default_stderr_lines")]
+    pub stderr_lines: usize,
+
+    /// File that stderr is also appended to (optional)
+    #[serde(default)]
+    pub stderr_log: Option<PathBuf>,
+
+    /// How long the process may take to exit after its stdin is closed
+    #[serde(with = "humantime_serde", default = "default_stdin_close_timeout")]
+    pub stdin_close_timeout: Duration,
+
+    /// How long the process group may take to exit after SIGTERM before it
+    /// is killed
+    #[serde(with = "humantime_serde", default = "default_terminate_timeout")]
+    pub terminate_timeout: Duration,
 }
 
 impl StdioConfig {
@@ -269,6 +369,10 @@ impl StdioConfig {
             working_dir: None,
             timeout: Duration::from_secs(30),
             environment: HashMap::new(),
+            stderr_lines: DEFAULT_STDERR_LINES,
+            stderr_log: None,
+            stdin_close_timeout: DEFAULT_STDIN_CLOSE_TIMEOUT,
+            terminate_timeout: DEFAULT_TERMINATE_TIMEOUT,
         }
     }
 
@@ -302,6 +406,19 @@ impl StdioConfig {
         self
     }
 
+    /// Also append the process's stderr to a file.
+    pub fn stderr_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.stderr_log = Some(path.into());
+        self
+    }
+
+    /// Set how long shutdown waits after closing stdin and after SIGTERM.
+    pub fn shutdown_timeouts(mut self, stdin_close: Duration, terminate: Duration) -> Self {
+        self.stdin_close_timeout = stdin_close;
+        self.terminate_timeout = terminate;
+        self
+    }
+
     /// Validate the stdio configuration.
     pub fn validate(&self) -> McpResult<()> {
         if self.command.is_empty() {
@@ -330,19 +447,21 @@ impl StdioConfig {
 ///
 /// This transport uses HTTP requests for client-to-server communication
 /// and Server-Sent Events for server-to-client communication.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HttpSseConfig {
     /// Base URL for the MCP server
     pub base_url: Url,
 
     /// Timeout for HTTP requests
-    #[serde(with = "humantime_serde")]
+    #[serde(with = "humantime_serde", default = "default_http_sse_timeout")]
     pub timeout: Duration,
 
     /// Additional HTTP headers to include
+    #[serde(default)]
     pub headers: HashMap<String, String>,
 
     /// Authentication configuration
+    #[serde(default)]
     pub auth: Option<AuthConfig>,
 }
 
@@ -397,25 +516,29 @@ impl HttpSseConfig {
 /// Configuration for HTTP streaming transport.
 ///
 /// This transport uses full-duplex HTTP streaming for bidirectional communication.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HttpStreamConfig {
     /// Base URL for the MCP server
     pub base_url: Url,
 
     /// Timeout for streaming operations
-    #[serde(with = "humantime_serde")]
+    #[serde(with = "humantime_serde", default = "default_http_stream_timeout")]
     pub timeout: Duration,
 
     /// Additional HTTP headers to include
+    #[serde(default)]
     pub headers: HashMap<String, String>,
 
     /// Authentication configuration
+    #[serde(default)]
     pub auth: Option<AuthConfig>,
 
     /// Enable compression for the stream
+    #[serde(default = "default_true")]
     pub compression: bool,
 
     /// Flow control window size
+    #[serde(default = "default_flow_control_window")]
     pub flow_control_window: u32,
 }
 
@@ -490,11 +613,110 @@ impl HttpStreamConfig {
     }
 }
 
+fn default_stdio_timeout() -> Duration {
+    Duration::from_secs(30)
+}
+
+/// Stderr lines kept for a stdio server unless configured otherwise
+pub const DEFAULT_STDERR_LINES: usize = 200;
+
+fn default_stderr_lines() -> usize {
+    DEFAULT_STDERR_LINES
+}
+
+/// Wait after closing a stdio server's stdin unless configured otherwise
+pub const DEFAULT_STDIN_CLOSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn default_stdin_close_timeout() -> Duration {
+    DEFAULT_STDIN_CLOSE_TIMEOUT
+}
+
+/// Wait after sending SIGTERM to a stdio server unless configured otherwise
+pub const DEFAULT_TERMINATE_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn default_terminate_timeout() -> Duration {
+    DEFAULT_TERMINATE_TIMEOUT
+}
+
+fn default_http_sse_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_http_stream_timeout() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_flow_control_window() -> u32 {
+    65536
+}
+
+fn resolve_map(map: &mut HashMap<String, String>) -> McpResult<()> {
+    for (key, value) in map.iter_mut() {
+        if secrets::has_reference(value) {
+            *value = secrets::resolve(value)?;
+        } else if secrets::is_sensitive_key(key) {
+            secrets::register_secret(value);
+        }
+    }
+    Ok(())
+}
+
+fn redact_map(map: &mut HashMap<String, String>) {
+    for (key, value) in map.iter_mut() {
+        *value = secrets::redact_for_key(key, value);
+    }
+}
+
+impl fmt::Debug for StdioConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|arg| secrets::redact(arg)).collect();
+        f.debug_struct("StdioConfig")
+            .field("command", &self.command)
+            .field("args", &args)
+            .field("working_dir", &self.working_dir)
+            .field("timeout", &self.timeout)
+            .field("environment", &RedactedMap(&self.environment))
+            .field("stderr_lines", &self.stderr_lines)
+            .field("stderr_log", &self.stderr_log)
+            .field("stdin_close_timeout", &self.stdin_close_timeout)
+            .field("terminate_timeout", &self.terminate_timeout)
+            .finish()
+    }
+}
+
+impl fmt::Debug for HttpSseConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpSseConfig")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("headers", &RedactedMap(&self.headers))
+            .field("auth", &self.auth)
+            .finish()
+    }
+}
+
+impl fmt::Debug for HttpStreamConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpStreamConfig")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("headers", &RedactedMap(&self.headers))
+            .field("auth", &self.auth)
+            .field("compression", &self.compression)
+            .field("flow_control_window", &self.flow_control_window)
+            .finish()
+    }
+}
+
 /// Authentication configuration for HTTP-based transports.
 ///
 /// Supports various authentication schemes including basic auth,
 /// bearer tokens, and OAuth 2.0.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum AuthConfig {
@@ -555,6 +777,36 @@ impl AuthConfig {
         }
     }
 
+    /// Resolve secret references in the credential fields, in place.
+    pub fn resolve_secrets(&mut self) -> McpResult<()> {
+        for field in self.secret_fields_mut() {
+            if secrets::has_reference(field) {
+                *field = secrets::resolve(field)?;
+            } else {
+                secrets::register_secret(field);
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace literal credentials with [`secrets::REDACTED`], keeping references.
+    pub fn redact(&mut self) {
+        for field in self.secret_fields_mut() {
+            if !secrets::has_reference(field) {
+                *field = secrets::REDACTED.to_string();
+            }
+        }
+    }
+
+    fn secret_fields_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            Self::Basic { password, .. } => vec![password],
+            Self::Bearer { token } => vec![token],
+            Self::OAuth { client_secret, .. } => vec![client_secret],
+            Self::Header { value, .. } => vec![value],
+        }
+    }
+
     /// Validate the authentication configuration.
     pub fn validate(&self) -> McpResult<()> {
         match self {
@@ -615,3 +867,36 @@ impl AuthConfig {
         Ok(())
     }
 }
+
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { username, password } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &Redacted(password))
+                .finish(),
+            Self::Bearer { token } => f
+                .debug_struct("Bearer")
+                .field("token", &Redacted(token))
+                .finish(),
+            Self::OAuth {
+                client_id,
+                client_secret,
+                token_url,
+                scope,
+            } => f
+                .debug_struct("OAuth")
+                .field("client_id", client_id)
+                .field("client_secret", &Redacted(client_secret))
+                .field("token_url", token_url)
+                .field("scope", scope)
+                .finish(),
+            Self::Header { name, value } => f
+                .debug_struct("Header")
+                .field("name", name)
+                .field("value", &Redacted(value))
+                .finish(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Transport factory for creating transport instances.
 
 use super::{Transport, TransportConfig};
-use crate::error::McpResult;
+use crate::error::{McpError, McpResult};
 
 #[cfg(feature = "stdio")]
 use super::stdio::StdioTransport;
@@ -36,8 +36,9 @@ pub struct TransportFactory;
 impl TransportFactory {
     /// Create a transport instance from configuration.
     ///
-    /// This method validates the configuration and creates the appropriate transport
-    /// implementation based on the transport type specified in the config.
+    /// This method resolves secret references (see [`crate::secrets`]), validates
+    /// the configuration and creates the appropriate transport implementation
+    /// based on the transport type specified in the config.
     ///
     /// # Arguments
     ///
@@ -49,10 +50,17 @@ impl TransportFactory {
     ///
     /// # Errors
     ///
-    /// * `ConfigError` - If the configuration is invalid
+    /// * `ConfigError` - If the configuration is invalid or a secret cannot be resolved
     /// * `TransportError` - If the transport cannot be created
     /// * `FeatureError` - If the requested transport type is not compiled in
     pub async fn create(config: TransportConfig) -> McpResult<Box<dyn Transport>> {
+        // Secret references are resolved here, so only the transport ever
+        // holds the real values. `${cmd:…}` references run a process, so
+        // resolution stays off the runtime's worker threads.
+        let config = tokio::task::spawn_blocking(move || config.resolve_secrets())
+            .await
+            .map_err(|e| McpError::internal(format!("Secret resolution panicked: {e}")))??;
+
         // Validate configuration before attempting to create transport
         config.validate()?;
 
@@ -22,6 +22,7 @@ use tokio::time::timeout;
 use super::{Transport, TransportConfig, TransportInfo};
 use crate::error::{McpResult, TransportError};
 use crate::messages::{JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse};
+use crate::telemetry::{with_trace_headers, TraceContext};
 
 /// SSE event with ID for resumability
 /// This infrastructure supports resumable connections per MCP spec
@@ -349,11 +350,13 @@ impl HttpSseTransport {
         &mut self,
         message: JsonRpcMessage,
     ) -> McpResult<Option<JsonRpcResponse>> {
-        let mut request_builder = self
-            .http_client
-            .post(self.base_url.clone())
-            .header(CONTENT_TYPE, "application/json")
-            .header("Accept", "application/json, text/event-stream");
+        let mut request_builder = with_trace_headers(
+            self.http_client
+                .post(self.base_url.clone())
+                .header(CONTENT_TYPE, "application/json")
+                .header("Accept", "application/json, text/event-stream"),
+            TraceContext::from_message(&message).as_ref(),
+        );
 
         // Validate Origin header for security
         self.validate_origin(&request_builder)?;
@@ -477,11 +480,13 @@ impl HttpSseTransport {
 
         tracing::info!("Sending Legacy POST request to: {}", request_url);
 
-        let request_builder = self
-            .http_client
-            .post(request_url)
-            .header(CONTENT_TYPE, "application/json")
-            .header("Accept", "application/json, text/event-stream");
+        let request_builder = with_trace_headers(
+            self.http_client
+                .post(request_url)
+                .header(CONTENT_TYPE, "application/json")
+                .header("Accept", "application/json, text/event-stream"),
+            TraceContext::from_message(&message).as_ref(),
+        );
 
         // Send the JSON-RPC request
         let response = request_builder.json(&message).send().await.map_err(|e| {
@@ -944,11 +949,13 @@ impl HttpSseTransport {
 
         tracing::info!("Sending POST request to: {}", request_url);
 
-        let request_builder = self
-            .http_client
-            .post(request_url)
-            .header(CONTENT_TYPE, "application/json")
-            .header("Accept", "application/json, text/event-stream");
+        let request_builder = with_trace_headers(
+            self.http_client
+                .post(request_url)
+                .header(CONTENT_TYPE, "application/json")
+                .header("Accept", "application/json, text/event-stream"),
+            TraceContext::from_message(&message).as_ref(),
+        );
 
         // Send the JSON-RPC request
         let response = request_builder.json(&message).send().await.map_err(|e| {
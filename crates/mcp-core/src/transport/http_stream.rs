@@ -6,7 +6,7 @@
 //! - Simple request/response pattern
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -15,11 +15,15 @@ use tokio::sync::{oneshot, Mutex};
 use tokio::time::timeout;
 use tracing::{debug, info};
 
-use super::{Transport, TransportConfig, TransportInfo};
+use super::{
+    RawSender, RequestSender, SharedRawSender, SharedRequestSender, Transport, TransportConfig,
+    TransportInfo,
+};
 use crate::error::{McpError, McpResult, TransportError};
 use crate::messages::{
     JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, RequestId,
 };
+use crate::telemetry::{with_trace_headers, TraceContext};
 
 /// MCP Streamable HTTP transport implementation (2025-03-26)
 pub struct HttpStreamTransport {
@@ -31,10 +35,10 @@ pub struct HttpStreamTransport {
     auth_header: Option<String>,
     /// Transport configuration
     config: TransportConfig,
-    /// Current session ID from server
-    session_id: Option<String>,
-    /// Transport information
-    info: TransportInfo,
+    /// Current session ID from server, shared with request senders
+    session_id: Arc<RwLock<Option<String>>>,
+    /// Transport information, shared with request senders
+    info: Arc<RwLock<TransportInfo>>,
     /// Pending requests awaiting responses
     pending_requests: Arc<Mutex<HashMap<String, oneshot::Sender<JsonRpcResponse>>>>,
     /// Whether we're connected
@@ -60,8 +64,8 @@ impl HttpStreamTransport {
                 compression: true,
                 flow_control_window: 65536,
             }),
-            session_id: None,
-            info: TransportInfo::new("http-stream"),
+            session_id: Arc::new(RwLock::new(None)),
+            info: Arc::new(RwLock::new(TransportInfo::new("http-stream"))),
             pending_requests: Arc::new(Mutex::new(HashMap::new())),
             connected: false,
         }
@@ -78,9 +82,40 @@ impl HttpStreamTransport {
         url
     }
 
+    /// Current session ID
+    fn session_id(&self) -> Option<String> {
+        self.session_id
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Adopt the session ID a response carries, if any
+    fn update_session_id(&self, response: &reqwest::Response) {
+        let Some(session_id) = response
+            .headers()
+            .get("mcp-session-id")
+            .and_then(|value| value.to_str().ok())
+        else {
+            return;
+        };
+        let mut current = self
+            .session_id
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if current.as_deref() != Some(session_id) {
+            info!("Session established with ID: {}", session_id);
+            *current = Some(session_id.to_string());
+        }
+    }
+
+    /// Update the transport statistics
+    fn update_info(&self, update: impl FnOnce(&mut TransportInfo)) {
+        update(&mut self.info.write().unwrap_or_else(PoisonError::into_inner));
+    }
+
     /// Send a JSON-RPC message to the MCP server and parse response
     async fn send_mcp_request(&self, message: &JsonRpcMessage) -> McpResult<JsonRpcResponse> {
-        let url = self.get_mcp_url();
         let json_body = serde_json::to_string(message).map_err(|e| {
             McpError::Transport(TransportError::SerializationError {
                 transport_type: "http-stream".to_string(),
@@ -88,14 +123,26 @@ impl HttpStreamTransport {
             })
         })?;
 
+        let trace = TraceContext::from_message(message);
+        let response_text = self.post(json_body, trace.as_ref()).await?;
+
+        // Parse response - handle both JSON and simple SSE formats
+        self.parse_response(&response_text)
+    }
+
+    /// POST a body to the MCP endpoint and return the response body
+    async fn post(&self, json_body: String, trace: Option<&TraceContext>) -> McpResult<String> {
+        let url = self.get_mcp_url();
         debug!("Sending MCP request to {}: {}", url, json_body);
 
-        let mut request_builder = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json, text/event-stream")
-            .body(json_body);
+        let mut request_builder = with_trace_headers(
+            self.client
+                .post(&url)
+                .header("Content-Type", "application/json")
+                .header("Accept", "application/json, text/event-stream")
+                .body(json_body),
+            trace,
+        );
 
         // Add authentication if provided
         if let Some(auth) = &self.auth_header {
@@ -103,7 +150,7 @@ impl HttpStreamTransport {
         }
 
         // Add session ID if we have one (Modern Streamable HTTP)
-        if let Some(session_id) = &self.session_id {
+        if let Some(session_id) = self.session_id() {
             request_builder = request_builder.header("mcp-session-id", session_id);
         }
 
@@ -123,13 +170,8 @@ impl HttpStreamTransport {
             }));
         }
 
-        // Extract session ID from headers for initialization requests
-        if let Some(session_id) = response.headers().get("mcp-session-id") {
-            if let Ok(session_str) = session_id.to_str() {
-                debug!("Received session ID: {}", session_str);
-                // Note: Can't modify self here since this is &self
-            }
-        }
+        // The server may assign or rotate the session on any response
+        self.update_session_id(&response);
 
         let response_text = response.text().await.map_err(|e| {
             McpError::Transport(TransportError::NetworkError {
@@ -139,9 +181,7 @@ impl HttpStreamTransport {
         })?;
 
         debug!("Received MCP response: {}", response_text);
-
-        // Parse response - handle both JSON and simple SSE formats
-        self.parse_response(&response_text)
+        Ok(response_text)
     }
 
     /// Parse response text that may be JSON or SSE format
@@ -218,10 +258,7 @@ impl HttpStreamTransport {
     }
 
     /// Send initialization request and extract session ID
-    async fn send_initialize_request(
-        &mut self,
-        request: JsonRpcRequest,
-    ) -> McpResult<JsonRpcResponse> {
+    async fn send_initialize_request(&self, request: JsonRpcRequest) -> McpResult<JsonRpcResponse> {
         let url = self.get_mcp_url();
         let json_body = serde_json::to_string(&JsonRpcMessage::Request(request)).map_err(|e| {
             McpError::Transport(TransportError::SerializationError {
@@ -260,12 +297,7 @@ impl HttpStreamTransport {
         }
 
         // Extract session ID from headers (CRITICAL for Modern Streamable HTTP)
-        if let Some(session_id) = response.headers().get("mcp-session-id") {
-            if let Ok(session_str) = session_id.to_str() {
-                info!("Session established with ID: {}", session_str);
-                self.session_id = Some(session_str.to_string());
-            }
-        }
+        self.update_session_id(&response);
 
         let response_text = response.text().await.map_err(|e| {
             McpError::Transport(TransportError::NetworkError {
@@ -295,7 +327,7 @@ impl Transport for HttpStreamTransport {
 
         // Just mark as connected - initialization happens in first request
         self.connected = true;
-        self.info.mark_connected();
+        self.update_info(TransportInfo::mark_connected);
 
         info!("MCP Streamable HTTP transport connected successfully");
         Ok(())
@@ -328,17 +360,14 @@ impl Transport for HttpStreamTransport {
         })
         .await;
 
-        match result {
-            Ok(response) => {
-                self.info.increment_requests_sent();
-                self.info.increment_responses_received();
-                response
-            }
-            Err(_) => Err(McpError::Transport(TransportError::TimeoutError {
+        let response = result.unwrap_or_else(|_| {
+            Err(McpError::Transport(TransportError::TimeoutError {
                 transport_type: "http-stream".to_string(),
                 reason: format!("Request timed out after {timeout_duration:?}"),
-            })),
-        }
+            }))
+        });
+        self.record_exchange(&response);
+        response
     }
 
     async fn send_notification(&mut self, notification: JsonRpcNotification) -> McpResult<()> {
@@ -370,7 +399,7 @@ impl Transport for HttpStreamTransport {
             request_builder = request_builder.header("Authorization", auth);
         }
 
-        if let Some(session_id) = &self.session_id {
+        if let Some(session_id) = self.session_id() {
             request_builder = request_builder.header("mcp-session-id", session_id);
         }
 
@@ -388,7 +417,7 @@ impl Transport for HttpStreamTransport {
             }));
         }
 
-        self.info.increment_notifications_sent();
+        self.update_info(TransportInfo::increment_notifications_sent);
         Ok(())
     }
 
@@ -408,7 +437,10 @@ impl Transport for HttpStreamTransport {
     async fn disconnect(&mut self) -> McpResult<()> {
         info!("Disconnecting MCP Streamable HTTP transport");
 
-        self.session_id = None;
+        *self
+            .session_id
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = None;
         self.connected = false;
 
         // Clear pending requests
@@ -417,26 +449,31 @@ impl Transport for HttpStreamTransport {
             pending.clear();
         }
 
-        self.info.mark_disconnected();
+        self.update_info(TransportInfo::mark_disconnected);
 
         info!("MCP Streamable HTTP transport disconnected");
         Ok(())
     }
 
     fn get_info(&self) -> TransportInfo {
-        let mut info = self.info.clone();
+        let mut info = self
+            .info
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let session_id = self.session_id();
 
         // Add MCP-specific metadata
         info.add_metadata("base_url", serde_json::json!(self.base_url));
         info.add_metadata("mcp_endpoint", serde_json::json!(self.get_mcp_url()));
         info.add_metadata("has_auth", serde_json::json!(self.auth_header.is_some()));
-        info.add_metadata("has_session", serde_json::json!(self.session_id.is_some()));
+        info.add_metadata("has_session", serde_json::json!(session_id.is_some()));
         info.add_metadata(
             "protocol",
             serde_json::json!("Modern Streamable HTTP (2025-03-26)"),
         );
 
-        if let Some(session_id) = &self.session_id {
+        if let Some(session_id) = session_id {
             info.add_metadata("session_id", serde_json::json!(session_id));
         }
 
@@ -451,6 +488,122 @@ impl Transport for HttpStreamTransport {
     fn get_config(&self) -> &TransportConfig {
         &self.config
     }
+
+    fn request_sender(&self) -> Option<SharedRequestSender> {
+        Some(Arc::new(self.snapshot()?))
+    }
+
+    fn raw_sender(&self) -> Option<SharedRawSender> {
+        Some(Arc::new(self.snapshot()?))
+    }
+}
+
+impl HttpStreamTransport {
+    /// Count a request and either its response or its failure
+    fn record_exchange<T>(&self, outcome: &McpResult<T>) {
+        self.update_info(|info| {
+            info.increment_requests_sent();
+            match outcome {
+                Ok(_) => info.increment_responses_received(),
+                Err(_) => info.increment_errors(),
+            }
+        });
+    }
+
+    /// Each request is an independent POST, so a handle sharing the session
+    /// (client, URL, auth, session ID and statistics) can send concurrently
+    fn snapshot(&self) -> Option<HttpStreamRequestSender> {
+        if !self.connected {
+            return None;
+        }
+        Some(HttpStreamRequestSender(HttpStreamTransport {
+            client: self.client.clone(),
+            base_url: self.base_url.clone(),
+            auth_header: self.auth_header.clone(),
+            config: self.config.clone(),
+            session_id: Arc::clone(&self.session_id),
+            info: Arc::clone(&self.info),
+            pending_requests: self.pending_requests.clone(),
+            connected: self.connected,
+        }))
+    }
+}
+
+/// Sends requests with a snapshot of a connected [`HttpStreamTransport`].
+struct HttpStreamRequestSender(HttpStreamTransport);
+
+#[async_trait]
+impl RawSender for HttpStreamRequestSender {
+    /// The reply travels in the POST response, so it is returned whether or
+    /// not the payload is correlated.
+    async fn send_raw(
+        &self,
+        payload: &str,
+        _correlate: bool,
+        timeout_duration: Option<Duration>,
+    ) -> McpResult<Vec<String>> {
+        let timeout_duration = timeout_duration.unwrap_or(Duration::from_secs(30));
+        let trace = serde_json::from_str::<JsonRpcMessage>(payload)
+            .ok()
+            .and_then(|message| TraceContext::from_message(&message));
+        let body = timeout(
+            timeout_duration,
+            self.0.post(payload.to_string(), trace.as_ref()),
+        )
+        .await
+        .map_err(|_| {
+            McpError::Transport(TransportError::TimeoutError {
+                transport_type: "http-stream".to_string(),
+                reason: format!("Raw message timed out after {timeout_duration:?}"),
+            })
+        })??;
+        let replies = split_reply_body(&body);
+        self.0.update_info(|info| {
+            info.increment_requests_sent();
+            info.responses_received += replies.len() as u64;
+        });
+        Ok(replies)
+    }
+}
+
+/// Messages in a POST reply: a JSON body, or the `data:` lines of an SSE stream
+fn split_reply_body(body: &str) -> Vec<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Vec::new();
+    }
+    if serde_json::from_str::<serde_json::Value>(body).is_ok() || !body.contains("data:") {
+        return vec![body.to_string()];
+    }
+    body.lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.trim().to_string())
+        .filter(|data| !data.is_empty())
+        .collect()
+}
+
+#[async_trait]
+impl RequestSender for HttpStreamRequestSender {
+    async fn send_request(
+        &self,
+        request: JsonRpcRequest,
+        timeout_duration: Option<Duration>,
+    ) -> McpResult<JsonRpcResponse> {
+        let timeout_duration = timeout_duration.unwrap_or(Duration::from_secs(30));
+        let response = timeout(
+            timeout_duration,
+            self.0.send_mcp_request(&JsonRpcMessage::Request(request)),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            Err(McpError::Transport(TransportError::TimeoutError {
+                transport_type: "http-stream".to_string(),
+                reason: format!("Request timed out after {timeout_duration:?}"),
+            }))
+        });
+        self.0.record_exchange(&response);
+        response
+    }
 }
 
 #[cfg(test)]
@@ -487,6 +640,20 @@ mod tests {
         assert!(info.metadata.contains_key("protocol"));
     }
 
+    #[test]
+    fn test_split_reply_body() {
+        assert!(split_reply_body("  ").is_empty());
+        assert_eq!(
+            split_reply_body(r#"{"jsonrpc":"2.0","id":1,"result":{}}"#),
+            vec![r#"{"jsonrpc":"2.0","id":1,"result":{}}"#]
+        );
+        assert_eq!(
+            split_reply_body("event: message\ndata: {\"id\":1}\n\ndata: {\"id\":2}\n"),
+            vec![r#"{"id":1}"#, r#"{"id":2}"#]
+        );
+        assert_eq!(split_reply_body("Bad Request"), vec!["Bad Request"]);
+    }
+
     #[test]
     fn test_auth_header_handling() {
         let transport_with_auth = HttpStreamTransport::new(
@@ -498,4 +665,64 @@ mod tests {
         let transport_no_auth = HttpStreamTransport::new("http://localhost:3001".to_string(), None);
         assert!(transport_no_auth.auth_header.is_none());
     }
+
+    #[tokio::test]
+    async fn test_request_handles_share_session_and_statistics() {
+        use wiremock::matchers::{body_partial_json, header, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let reply = |id: &str, session: &str| {
+            ResponseTemplate::new(200)
+                .insert_header("mcp-session-id", session)
+                .set_body_json(serde_json::json!({"jsonrpc": "2.0", "id": id, "result": {}}))
+        };
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "initialize"}),
+            ))
+            .respond_with(reply("init", "first"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({"method": "ping"})))
+            .and(header("mcp-session-id", "first"))
+            .respond_with(reply("ping", "rotated"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "tools/list"}),
+            ))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let mut transport = HttpStreamTransport::new(server.uri(), None);
+        transport.connect().await.unwrap();
+        transport
+            .send_request(
+                JsonRpcRequest::new("init", "initialize", serde_json::json!({})),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let handle = transport.request_sender().unwrap();
+        handle
+            .send_request(JsonRpcRequest::without_params("ping", "ping"), None)
+            .await
+            .unwrap();
+        // Failed requests are counted as errors, not as responses
+        assert!(handle
+            .send_request(JsonRpcRequest::without_params("list", "tools/list"), None)
+            .await
+            .is_err());
+
+        let info = transport.get_info();
+        assert_eq!(info.requests_sent, 3);
+        assert_eq!(info.responses_received, 2);
+        assert_eq!(info.errors, 1);
+        assert_eq!(info.metadata["session_id"], "rotated");
+    }
 }
@@ -40,6 +40,7 @@
 
 pub mod config;
 pub mod factory;
+pub mod process;
 
 #[cfg(feature = "stdio")]
 pub mod stdio;
@@ -52,10 +53,14 @@ pub mod http_stream;
 
 pub use config::*;
 pub use factory::*;
+pub use process::*;
 
 use crate::error::{McpResult, TransportError};
-use crate::messages::{JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse};
+use crate::messages::{
+    JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, RequestId,
+};
 use async_trait::async_trait;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
@@ -137,8 +142,164 @@ pub trait Transport: Send + Sync {
 
     /// Get the transport configuration used for this instance.
     fn get_config(&self) -> &TransportConfig;
+
+    /// Take the stream of server-to-client requests (e.g. `elicitation/create`).
+    ///
+    /// Until the stream has been taken, transports answer server requests with
+    /// `Method not found` on their own. Returns `None` if the stream was already
+    /// taken or the transport does not support server-initiated requests.
+    fn take_server_requests(&mut self) -> Option<ServerRequestReceiver> {
+        None
+    }
+
+    /// Get a handle that sends requests concurrently with this transport.
+    ///
+    /// Requests sent through the handle are correlated like those sent with
+    /// [`Transport::send_request`], so several can be in flight at once. Returns
+    /// `None` if the transport only supports one request at a time; it should be
+    /// called after [`Transport::connect`].
+    fn request_sender(&self) -> Option<SharedRequestSender> {
+        None
+    }
+
+    /// Get a handle that writes payloads to the server unchanged.
+    ///
+    /// Returns `None` if the transport cannot send arbitrary bytes; it should be
+    /// called after [`Transport::connect`].
+    fn raw_sender(&self) -> Option<SharedRawSender> {
+        None
+    }
+
+    /// Take the stream of inbound messages that no request claimed.
+    ///
+    /// The stream carries notifications, responses to messages sent without
+    /// correlation and lines that are not valid JSON-RPC, exactly as received.
+    /// Returns `None` if the stream was already taken or the transport has no
+    /// inbound channel.
+    fn take_raw_traffic(&mut self) -> Option<RawTrafficReceiver> {
+        None
+    }
+
+    /// Take the stream of parsed inbound messages that no request claimed.
+    ///
+    /// This is the channel [`Transport::receive_message`] reads from, so once
+    /// it has been taken `receive_message` fails. Returns `None` if the stream
+    /// was already taken or the transport cannot hand it out; it should be
+    /// called after [`Transport::connect`].
+    fn take_message_stream(&mut self) -> Option<MessageReceiver> {
+        None
+    }
+
+    /// Get the captured stderr and exit status of the server process.
+    ///
+    /// Returns `None` if the transport does not run the server as a local
+    /// process. The log exists before [`Transport::connect`] is called, so
+    /// output from a server that fails during startup is kept.
+    fn process_log(&self) -> Option<ProcessLog> {
+        None
+    }
+}
+
+/// Sends requests on behalf of a transport without exclusive access to it.
+///
+/// See [`Transport::request_sender`].
+#[async_trait]
+pub trait RequestSender: Send + Sync {
+    /// Send a JSON-RPC request and wait for its response.
+    async fn send_request(
+        &self,
+        request: JsonRpcRequest,
+        timeout: Option<Duration>,
+    ) -> McpResult<JsonRpcResponse>;
+}
+
+/// Shared, cloneable [`RequestSender`].
+pub type SharedRequestSender = Arc<dyn RequestSender>;
+
+/// Writes payloads to the server byte for byte, without validation.
+///
+/// Used to send unknown methods, malformed messages or batches when debugging
+/// a server. See [`Transport::raw_sender`].
+#[async_trait]
+pub trait RawSender: Send + Sync {
+    /// Send `payload` unchanged and return the replies received for it.
+    ///
+    /// With `correlate`, the ids of requests in the payload (including batch
+    /// entries) are tracked and their responses returned. Without it the
+    /// payload bypasses correlation: transports that answer each message
+    /// directly (HTTP) still return the reply body, others deliver whatever
+    /// comes back on the [raw traffic stream](Transport::take_raw_traffic).
+    async fn send_raw(
+        &self,
+        payload: &str,
+        correlate: bool,
+        timeout: Option<Duration>,
+    ) -> McpResult<Vec<String>>;
+}
+
+/// Shared, cloneable [`RawSender`].
+pub type SharedRawSender = Arc<dyn RawSender>;
+
+/// Receiver for unclaimed inbound messages, see [`Transport::take_raw_traffic`].
+pub type RawTrafficReceiver = mpsc::UnboundedReceiver<String>;
+
+/// Ids of the requests in a raw payload holding one message or a batch.
+///
+/// Notifications and entries whose id is not a valid JSON-RPC id are skipped.
+pub fn raw_request_ids(payload: &str) -> Vec<RequestId> {
+    let messages = match serde_json::from_str::<serde_json::Value>(payload) {
+        Ok(serde_json::Value::Array(messages)) => messages,
+        Ok(message) => vec![message],
+        Err(_) => return Vec::new(),
+    };
+
+    let mut ids: Vec<RequestId> = messages
+        .iter()
+        .filter(|message| message.get("method").is_some())
+        .filter_map(|message| serde_json::from_value(message.get("id")?.clone()).ok())
+        .filter(|id| *id != RequestId::Null)
+        .collect();
+    // `1` and `"1"` are different ids
+    let mut seen = std::collections::HashSet::new();
+    ids.retain(|id| seen.insert(id.clone()));
+    ids
+}
+
+/// A request initiated by the server that expects a response from the client.
+///
+/// The request carries a handle back to the transport's outbound channel so the
+/// response can be sent from whichever task ends up handling it (e.g. a UI loop).
+#[derive(Debug)]
+pub struct ServerRequest {
+    /// The request as received from the server
+    pub request: JsonRpcRequest,
+
+    responder: MessageSender,
 }
 
+impl ServerRequest {
+    /// Wrap a server request together with the channel used to answer it.
+    pub fn new(request: JsonRpcRequest, responder: MessageSender) -> Self {
+        Self { request, responder }
+    }
+
+    /// Send the response for this request back to the server.
+    pub fn respond(self, response: JsonRpcResponse) -> McpResult<()> {
+        self.responder
+            .send(JsonRpcMessage::Response(response))
+            .map_err(|_| {
+                TransportError::SendFailed {
+                    transport_type: "server-request".to_string(),
+                    reason: "Transport outbound channel closed".to_string(),
+                }
+                .into()
+            })
+    }
+}
+
+/// Receiver for server-to-client requests, see [`Transport::take_server_requests`].
+pub type ServerRequestReceiver = mpsc::UnboundedReceiver<ServerRequest>;
+
 /// Transport information and statistics.
 ///
 /// This structure provides insight into the transport's current state,
@@ -333,6 +494,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_raw_request_ids() {
+        let ids = raw_request_ids(
+            r#"[{"id":1,"method":"a"},{"id":"1","method":"b"},{"method":"n"},{"id":{},"method":"c"},{"id":2,"result":{}},{"id":1,"method":"d"}]"#,
+        );
+        assert_eq!(ids, vec![RequestId::Number(1), RequestId::from("1")]);
+        assert_eq!(
+            raw_request_ids(r#"{"jsonrpc":"2.0","id":"abc","method":"x"}"#),
+            vec![RequestId::from("abc")]
+        );
+        assert!(raw_request_ids("{not json").is_empty());
+    }
+
     #[test]
     fn test_connection_duration() {
         let mut info = TransportInfo::new("test");
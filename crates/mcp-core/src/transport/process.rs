@@ -0,0 +1,475 @@
+//! Output and exit status of locally spawned MCP server processes.
+//!
+//! Transports that run the server as a child process (stdio) capture its
+//! stderr into a bounded ring and record how the process ended. A
+//! [`ProcessLog`] is a cheap, cloneable handle on that state, so a UI can show
+//! the stderr stream live and a test run can include the last lines in its
+//! report when the server crashes.
+//!
+//! The log also keeps track of stdout lines that are not JSON-RPC messages.
+//! Servers that print banners or log output to stdout corrupt the protocol
+//! stream; [`StdoutPollution`] counts such lines and keeps a few examples.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use mcp_probe_core::transport::{ProcessExit, ProcessLog};
+//!
+//! let log = ProcessLog::new(2);
+//! log.push_line("starting");
+//! log.push_line("loading config");
+//! log.push_line("panic: config not found");
+//! log.record_exit(ProcessExit::Code(101));
+//!
+//! let crash = log.crash(10).unwrap();
+//! assert_eq!(crash.exit.to_string(), "exit code 101");
+//! assert_eq!(crash.stderr, vec!["loading config", "panic: config not found"]);
+//! assert_eq!(log.total_lines(), 3);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// How a server process ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessExit {
+    /// The process exited with a status code
+    Code(i32),
+    /// The process was terminated by a signal (Unix only)
+    Signal(i32),
+    /// The exit status could not be determined
+    Unknown,
+}
+
+impl ProcessExit {
+    /// Whether the process exited with status code 0.
+    pub fn success(&self) -> bool {
+        matches!(self, ProcessExit::Code(0))
+    }
+}
+
+impl From<std::process::ExitStatus> for ProcessExit {
+    fn from(status: std::process::ExitStatus) -> Self {
+        if let Some(code) = status.code() {
+            return ProcessExit::Code(code);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = status.signal() {
+                return ProcessExit::Signal(signal);
+            }
+        }
+        ProcessExit::Unknown
+    }
+}
+
+impl fmt::Display for ProcessExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessExit::Code(code) => write!(f, "exit code {}", code),
+            ProcessExit::Signal(signal) => write!(f, "signal {}", signal),
+            ProcessExit::Unknown => f.write_str("unknown exit status"),
+        }
+    }
+}
+
+/// The step of the shutdown sequence at which a server process exited.
+///
+/// Stdio servers are stopped by closing their stdin, then sending SIGTERM to
+/// their process group, then killing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownStage {
+    /// The process exited once its stdin was closed, as the MCP spec expects
+    StdinClosed,
+    /// The process exited after SIGTERM
+    Terminated,
+    /// The process had to be killed
+    Killed,
+}
+
+impl fmt::Display for ShutdownStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShutdownStage::StdinClosed => f.write_str("exited after stdin was closed"),
+            ShutdownStage::Terminated => f.write_str("exited after SIGTERM"),
+            ShutdownStage::Killed => f.write_str("was killed"),
+        }
+    }
+}
+
+/// A server process that ended without being asked to, with its last stderr lines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessCrash {
+    /// How the process ended
+    pub exit: ProcessExit,
+    /// The last lines the process wrote to stderr, oldest first
+    pub stderr: Vec<String>,
+}
+
+/// Maximum number of polluting stdout lines kept as examples.
+pub const MAX_STDOUT_EXAMPLES: usize = 10;
+
+/// Polluting stdout lines longer than this are truncated in examples.
+const MAX_EXAMPLE_CHARS: usize = 200;
+
+/// Why a line a server wrote to stdout is not a JSON-RPC message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StdoutIssueKind {
+    /// Plain text such as a banner or log line
+    NotJson,
+    /// Valid JSON that is not a JSON-RPC message
+    NotJsonRpc,
+    /// A JSON value that ends before the line does
+    PartialJson,
+    /// Several JSON values, or a JSON value followed by more output, on one line
+    ConcatenatedJson,
+    /// Bytes that are not valid UTF-8
+    InvalidUtf8,
+}
+
+impl StdoutIssueKind {
+    /// Classify a valid UTF-8 line that did not parse as a JSON-RPC message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mcp_probe_core::transport::StdoutIssueKind;
+    ///
+    /// assert_eq!(StdoutIssueKind::classify("Server started on stdio"), StdoutIssueKind::NotJson);
+    /// assert_eq!(StdoutIssueKind::classify(r#"{"level":"info"}"#), StdoutIssueKind::NotJsonRpc);
+    /// assert_eq!(StdoutIssueKind::classify(r#"{"jsonrpc":"2.0","#), StdoutIssueKind::PartialJson);
+    /// assert_eq!(StdoutIssueKind::classify("{}{}"), StdoutIssueKind::ConcatenatedJson);
+    /// ```
+    pub fn classify(line: &str) -> Self {
+        let mut values = serde_json::Deserializer::from_str(line).into_iter::<serde_json::Value>();
+        match values.next() {
+            // Log lines often start with a number or a word that parses on its own
+            Some(Ok(value)) if !value.is_object() && !value.is_array() => StdoutIssueKind::NotJson,
+            Some(Ok(_)) if values.next().is_some() => StdoutIssueKind::ConcatenatedJson,
+            Some(Ok(_)) => StdoutIssueKind::NotJsonRpc,
+            Some(Err(e)) if e.is_eof() => StdoutIssueKind::PartialJson,
+            _ => StdoutIssueKind::NotJson,
+        }
+    }
+
+    /// Short human-readable description.
+    pub fn description(&self) -> &'static str {
+        match self {
+            StdoutIssueKind::NotJson => "not JSON",
+            StdoutIssueKind::NotJsonRpc => "JSON but not JSON-RPC",
+            StdoutIssueKind::PartialJson => "partial JSON",
+            StdoutIssueKind::ConcatenatedJson => "concatenated JSON",
+            StdoutIssueKind::InvalidUtf8 => "invalid UTF-8",
+        }
+    }
+}
+
+impl fmt::Display for StdoutIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// A stdout line that is not a JSON-RPC message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StdoutIssue {
+    /// What is wrong with the line
+    pub kind: StdoutIssueKind,
+    /// The line, lossily decoded and truncated
+    pub line: String,
+}
+
+/// Summary of the stdout lines that were not JSON-RPC messages.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StdoutPollution {
+    /// Number of polluting lines
+    pub total: u64,
+    /// Number of polluting lines by kind
+    pub counts: BTreeMap<StdoutIssueKind, u64>,
+    /// The first polluting lines, up to [`MAX_STDOUT_EXAMPLES`]
+    pub examples: Vec<StdoutIssue>,
+}
+
+impl StdoutPollution {
+    /// Whether stdout carried nothing but JSON-RPC messages.
+    pub fn is_clean(&self) -> bool {
+        self.total == 0
+    }
+}
+
+/// Captured stderr and exit status of a server process.
+///
+/// Clones share the same state. Only the most recent `capacity` lines are
+/// kept; [`ProcessLog::total_lines`] counts every line ever captured, so
+/// readers can tell whether anything new arrived.
+#[derive(Debug, Clone)]
+pub struct ProcessLog {
+    state: Arc<Mutex<ProcessLogState>>,
+}
+
+#[derive(Debug)]
+struct ProcessLogState {
+    lines: VecDeque<String>,
+    capacity: usize,
+    total_lines: u64,
+    exit: Option<ProcessExit>,
+    exit_expected: bool,
+    shutdown: Option<ShutdownStage>,
+    stdout: StdoutPollution,
+}
+
+impl ProcessLog {
+    /// Create an empty log keeping at most `capacity` stderr lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ProcessLogState {
+                lines: VecDeque::with_capacity(capacity.min(1024)),
+                capacity,
+                total_lines: 0,
+                exit: None,
+                exit_expected: false,
+                shutdown: None,
+                stdout: StdoutPollution::default(),
+            })),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, ProcessLogState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Append a stderr line, dropping the oldest one if the ring is full.
+    pub fn push_line(&self, line: impl Into<String>) {
+        let mut state = self.state();
+        state.total_lines += 1;
+        if state.capacity == 0 {
+            return;
+        }
+        if state.lines.len() == state.capacity {
+            state.lines.pop_front();
+        }
+        state.lines.push_back(line.into());
+    }
+
+    /// Every buffered stderr line, oldest first.
+    pub fn lines(&self) -> Vec<String> {
+        self.state().lines.iter().cloned().collect()
+    }
+
+    /// The last `count` buffered stderr lines, oldest first.
+    pub fn tail(&self, count: usize) -> Vec<String> {
+        let state = self.state();
+        let skip = state.lines.len().saturating_sub(count);
+        state.lines.iter().skip(skip).cloned().collect()
+    }
+
+    /// Number of stderr lines captured since the log was created.
+    pub fn total_lines(&self) -> u64 {
+        self.state().total_lines
+    }
+
+    /// How the current process ended, if it has.
+    pub fn exit(&self) -> Option<ProcessExit> {
+        self.state().exit
+    }
+
+    /// Record how the process ended.
+    pub fn record_exit(&self, exit: ProcessExit) {
+        self.state().exit = Some(exit);
+    }
+
+    /// Note that the process is about to be stopped on purpose, so its exit
+    /// is not reported as a crash.
+    ///
+    /// Has no effect once the process has exited: it crashed before it was
+    /// stopped.
+    pub fn expect_exit(&self) {
+        let mut state = self.state();
+        if state.exit.is_none() {
+            state.exit_expected = true;
+        }
+    }
+
+    /// Record the step of the shutdown sequence at which the process exited.
+    pub fn record_shutdown(&self, stage: ShutdownStage) {
+        self.state().shutdown = Some(stage);
+    }
+
+    /// The step of the shutdown sequence at which the process exited, if it
+    /// was shut down rather than exiting on its own.
+    pub fn shutdown(&self) -> Option<ShutdownStage> {
+        self.state().shutdown
+    }
+
+    /// The exit and last `tail` stderr lines if the process ended on its own.
+    pub fn crash(&self, tail: usize) -> Option<ProcessCrash> {
+        let state = self.state();
+        if state.exit_expected {
+            return None;
+        }
+        let exit = state.exit?;
+        let skip = state.lines.len().saturating_sub(tail);
+        Some(ProcessCrash {
+            exit,
+            stderr: state.lines.iter().skip(skip).cloned().collect(),
+        })
+    }
+
+    /// Record a stdout line that is not a JSON-RPC message.
+    pub fn record_stdout_issue(&self, kind: StdoutIssueKind, line: &str) {
+        let mut state = self.state();
+        let stdout = &mut state.stdout;
+        stdout.total += 1;
+        *stdout.counts.entry(kind).or_default() += 1;
+        if stdout.examples.len() < MAX_STDOUT_EXAMPLES {
+            let mut example: String = line.chars().take(MAX_EXAMPLE_CHARS).collect();
+            if example.len() < line.len() {
+                example.push('…');
+            }
+            stdout.examples.push(StdoutIssue {
+                kind,
+                line: example,
+            });
+        }
+    }
+
+    /// Stdout lines seen so far that were not JSON-RPC messages.
+    pub fn stdout_pollution(&self) -> StdoutPollution {
+        self.state().stdout.clone()
+    }
+
+    /// Forget the previous process's exit before a new one is started.
+    ///
+    /// Buffered stderr lines are kept so output from before a restart stays
+    /// visible.
+    pub(crate) fn reset_exit(&self) {
+        let mut state = self.state();
+        state.exit = None;
+        state.exit_expected = false;
+        state.shutdown = None;
+    }
+}
+
+impl Default for ProcessLog {
+    fn default() -> Self {
+        Self::new(super::config::DEFAULT_STDERR_LINES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_keeps_latest_lines() {
+        let log = ProcessLog::new(3);
+        for n in 0..5 {
+            log.push_line(format!("line {}", n));
+        }
+        assert_eq!(log.lines(), vec!["line 2", "line 3", "line 4"]);
+        assert_eq!(log.tail(2), vec!["line 3", "line 4"]);
+        assert_eq!(log.tail(10).len(), 3);
+        assert_eq!(log.total_lines(), 5);
+
+        let disabled = ProcessLog::new(0);
+        disabled.push_line("dropped");
+        assert!(disabled.lines().is_empty());
+        assert_eq!(disabled.total_lines(), 1);
+    }
+
+    #[test]
+    fn test_expected_exit_is_not_a_crash() {
+        let log = ProcessLog::new(10);
+        log.push_line("bye");
+        assert!(log.crash(5).is_none());
+
+        log.expect_exit();
+        log.record_exit(ProcessExit::Signal(9));
+        assert_eq!(log.exit(), Some(ProcessExit::Signal(9)));
+        assert!(log.crash(5).is_none());
+
+        log.record_shutdown(ShutdownStage::Killed);
+        assert_eq!(log.shutdown(), Some(ShutdownStage::Killed));
+        log.reset_exit();
+        assert!(log.exit().is_none());
+        assert!(log.shutdown().is_none());
+        log.record_exit(ProcessExit::Code(1));
+        log.expect_exit();
+        let crash = log.crash(5).unwrap();
+        assert_eq!(crash.exit, ProcessExit::Code(1));
+        assert_eq!(crash.stderr, vec!["bye"]);
+    }
+
+    #[test]
+    fn test_exit_display_and_serialization() {
+        assert!(ProcessExit::Code(0).success());
+        assert!(!ProcessExit::Signal(15).success());
+        assert_eq!(ProcessExit::Signal(15).to_string(), "signal 15");
+        assert_eq!(ProcessExit::Unknown.to_string(), "unknown exit status");
+        assert_eq!(
+            serde_json::to_value(ProcessExit::Code(2)).unwrap(),
+            serde_json::json!({"code": 2})
+        );
+    }
+
+    #[test]
+    fn test_stdout_pollution_counts_and_examples() {
+        assert_eq!(
+            StdoutIssueKind::classify("2024-05-01 INFO listening"),
+            StdoutIssueKind::NotJson
+        );
+        assert_eq!(
+            StdoutIssueKind::classify("[INFO] ready"),
+            StdoutIssueKind::NotJson
+        );
+        assert_eq!(
+            StdoutIssueKind::classify(r#"{"id":1} done"#),
+            StdoutIssueKind::ConcatenatedJson
+        );
+
+        let log = ProcessLog::new(10);
+        assert!(log.stdout_pollution().is_clean());
+        for n in 0..MAX_STDOUT_EXAMPLES + 2 {
+            log.record_stdout_issue(StdoutIssueKind::NotJson, &format!("banner {}", n));
+        }
+        log.record_stdout_issue(StdoutIssueKind::InvalidUtf8, "\u{fffd}");
+        log.record_stdout_issue(StdoutIssueKind::NotJson, &"x".repeat(500));
+
+        let pollution = log.stdout_pollution();
+        assert_eq!(pollution.total, MAX_STDOUT_EXAMPLES as u64 + 4);
+        assert_eq!(pollution.counts[&StdoutIssueKind::InvalidUtf8], 1);
+        assert_eq!(pollution.examples.len(), MAX_STDOUT_EXAMPLES);
+        assert_eq!(pollution.examples[0].line, "banner 0");
+        assert_eq!(
+            serde_json::to_value(&pollution).unwrap()["counts"]["not_json"],
+            MAX_STDOUT_EXAMPLES as u64 + 3
+        );
+
+        let long = ProcessLog::new(10);
+        long.record_stdout_issue(StdoutIssueKind::NotJson, &"x".repeat(500));
+        assert_eq!(
+            long.stdout_pollution().examples[0].line.chars().count(),
+            201
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exit_from_status() {
+        use std::os::unix::process::ExitStatusExt;
+        assert_eq!(
+            ProcessExit::from(std::process::ExitStatus::from_raw(3 << 8)),
+            ProcessExit::Code(3)
+        );
+        assert_eq!(
+            ProcessExit::from(std::process::ExitStatus::from_raw(9)),
+            ProcessExit::Signal(9)
+        );
+    }
+}
@@ -6,37 +6,74 @@
 //! MCP server implementations.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, Command};
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, oneshot, watch, Mutex};
 use tokio::time::timeout;
 
-use super::{Transport, TransportConfig, TransportInfo};
-use crate::error::{McpResult, TransportError};
-use crate::messages::{JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse};
+use super::{
+    raw_request_ids, MessageReceiver, ProcessExit, ProcessLog, RawSender, RawTrafficReceiver,
+    RequestSender, ServerRequest, ServerRequestReceiver, SharedRawSender, SharedRequestSender,
+    ShutdownStage, StdioConfig, StdoutIssueKind, Transport, TransportConfig, TransportInfo,
+};
+use crate::error::{McpError, McpResult, TransportError};
+use crate::messages::{
+    JsonRpcError, JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, RequestId,
+};
+
+/// Raw requests awaiting a response, keyed by their exact JSON-RPC id.
+///
+/// Kept apart from the client's correlation map so `1` and `"1"` stay
+/// different ids and raw traffic never claims a client request's response.
+type RawPendingRequests = Arc<Mutex<HashMap<RequestId, oneshot::Sender<JsonRpcResponse>>>>;
 
 /// Stdio transport for local process MCP communication.
 ///
 /// This transport implementation provides:
 /// - Local process spawning with configurable command and arguments
 /// - JSON-RPC communication over stdin/stdout
-/// - Process lifecycle management with graceful shutdown
+/// - Graceful shutdown: close stdin, then SIGTERM, then SIGKILL, applied to
+///   the server's whole process group on Unix
 /// - Environment variable and working directory configuration
 /// - Request/response correlation and timeout handling
 /// - Automatic cleanup of child processes
+/// - Stderr capture and exit status reporting through a [`ProcessLog`]
 pub struct StdioTransport {
     config: TransportConfig,
     info: TransportInfo,
-    child_process: Option<Child>,
+    process: Option<ProcessHandle>,
+    process_log: ProcessLog,
     message_sender: Option<mpsc::UnboundedSender<JsonRpcMessage>>,
     message_receiver: Option<mpsc::UnboundedReceiver<JsonRpcMessage>>,
     outbound_sender: Option<mpsc::UnboundedSender<JsonRpcMessage>>,
     outbound_receiver: Option<mpsc::UnboundedReceiver<JsonRpcMessage>>,
+    raw_outbound_sender: Option<mpsc::UnboundedSender<String>>,
     pending_requests: Arc<Mutex<HashMap<String, tokio::sync::oneshot::Sender<JsonRpcResponse>>>>,
+    raw_pending_requests: RawPendingRequests,
+    server_request_sender: mpsc::UnboundedSender<ServerRequest>,
+    server_request_receiver: Option<ServerRequestReceiver>,
+    server_requests_taken: Arc<AtomicBool>,
+    raw_traffic_sender: mpsc::UnboundedSender<String>,
+    raw_traffic_receiver: Option<RawTrafficReceiver>,
+    raw_traffic_taken: Arc<AtomicBool>,
+}
+
+/// How long failed requests wait for the exit status after stdout closes
+const EXIT_STATUS_GRACE: Duration = Duration::from_secs(1);
+
+/// How long `kill_process` waits for the exit on top of the shutdown timeouts
+const KILL_GRACE: Duration = Duration::from_secs(5);
+
+/// The running child process, owned by its monitor task.
+struct ProcessHandle {
+    id: Option<u32>,
+    shutdown_sender: oneshot::Sender<()>,
+    exit: watch::Receiver<Option<ProcessExit>>,
 }
 
 impl StdioTransport {
@@ -51,16 +88,31 @@ impl StdioTransport {
     /// A new transport instance ready for connection.
     pub fn new(config: TransportConfig) -> Self {
         let info = TransportInfo::new("stdio");
+        let (server_request_sender, server_request_receiver) = mpsc::unbounded_channel();
+        let (raw_traffic_sender, raw_traffic_receiver) = mpsc::unbounded_channel();
+        let process_log = match &config {
+            TransportConfig::Stdio(stdio_config) => ProcessLog::new(stdio_config.stderr_lines),
+            _ => ProcessLog::default(),
+        };
 
         Self {
             config,
             info,
-            child_process: None,
+            process: None,
+            process_log,
             message_sender: None,
             message_receiver: None,
             outbound_sender: None,
             outbound_receiver: None,
+            raw_outbound_sender: None,
             pending_requests: Arc::new(Mutex::new(HashMap::new())),
+            raw_pending_requests: Arc::new(Mutex::new(HashMap::new())),
+            server_request_sender,
+            server_request_receiver: Some(server_request_receiver),
+            server_requests_taken: Arc::new(AtomicBool::new(false)),
+            raw_traffic_sender,
+            raw_traffic_receiver: Some(raw_traffic_receiver),
+            raw_traffic_taken: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -78,7 +130,13 @@ impl StdioTransport {
                 .args(&stdio_config.args)
                 .stdin(std::process::Stdio::piped())
                 .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped());
+                .stderr(std::process::Stdio::piped())
+                .kill_on_drop(true);
+
+            // Own process group, so wrappers like npx or uvx are stopped
+            // together with the server they start
+            #[cfg(unix)]
+            command.process_group(0);
 
             // Set working directory if specified
             if let Some(ref working_dir) = stdio_config.working_dir {
@@ -132,6 +190,13 @@ impl StdioTransport {
             self.message_receiver = Some(inbound_receiver);
             self.outbound_sender = Some(outbound_sender);
 
+            // Hand the child to a task that records how it exits
+            self.process_log.reset_exit();
+            let (stdin_close_sender, stdin_close) = oneshot::channel::<()>();
+            let process = self.monitor_process(child, stdin_close_sender, stdio_config);
+            let exit = process.exit.clone();
+            self.process = Some(process);
+
             // Start I/O processing tasks
             let pending_requests = self.pending_requests.clone();
             self.start_io_tasks(
@@ -141,12 +206,12 @@ impl StdioTransport {
                 inbound_sender,
                 outbound_receiver,
                 pending_requests,
+                exit,
+                stdin_close,
+                stdio_config.stderr_log.clone(),
             )
             .await;
 
-            // Store the child process
-            self.child_process = Some(child);
-
             Ok(())
         } else {
             Err(TransportError::InvalidConfig {
@@ -157,7 +222,110 @@ impl StdioTransport {
         }
     }
 
+    /// Wait for the child to exit, or shut it down when asked, and record its exit status.
+    fn monitor_process(
+        &self,
+        mut child: Child,
+        stdin_close: oneshot::Sender<()>,
+        config: &StdioConfig,
+    ) -> ProcessHandle {
+        let id = child.id();
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+        let (exit_sender, exit_receiver) = watch::channel(None);
+        let process_log = self.process_log.clone();
+        let stdin_close_timeout = config.stdin_close_timeout;
+        let terminate_timeout = config.terminate_timeout;
+
+        tokio::spawn(async move {
+            let mut exit_watch = ExitWatch::new(&child);
+
+            // A dropped shutdown sender means the transport is gone, so shut down then too
+            tokio::select! {
+                _ = exit_watch.wait(&mut child) => {}
+                _ = shutdown_receiver => {
+                    let stage = Self::shut_down(
+                        &mut child,
+                        &mut exit_watch,
+                        stdin_close,
+                        stdin_close_timeout,
+                        terminate_timeout,
+                    )
+                    .await;
+                    tracing::debug!("Child process {}", stage);
+                    process_log.record_shutdown(stage);
+                }
+            };
+
+            // Anything the server left running in its group goes with it. The
+            // leader is not reaped yet, so the group ID cannot have been reused.
+            #[cfg(unix)]
+            if let Some(id) = id {
+                signal_process_group(id, libc::SIGKILL);
+            }
+
+            let exit = match child.wait().await {
+                Ok(status) => ProcessExit::from(status),
+                Err(e) => {
+                    tracing::warn!("Error waiting for child process to exit: {}", e);
+                    ProcessExit::Unknown
+                }
+            };
+            process_log.record_exit(exit);
+            if process_log.crash(0).is_some() {
+                tracing::warn!("MCP process exited unexpectedly with {}", exit);
+            } else {
+                tracing::debug!("Child process exited with {}", exit);
+            }
+            let _ = exit_sender.send(Some(exit));
+        });
+
+        ProcessHandle {
+            id,
+            shutdown_sender,
+            exit: exit_receiver,
+        }
+    }
+
+    /// Stop the child: close its stdin, then send SIGTERM to its process
+    /// group, then kill it, waiting for it to exit after each step.
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    async fn shut_down(
+        child: &mut Child,
+        exit_watch: &mut ExitWatch,
+        stdin_close: oneshot::Sender<()>,
+        stdin_close_timeout: Duration,
+        terminate_timeout: Duration,
+    ) -> ShutdownStage {
+        drop(stdin_close);
+        if timeout(stdin_close_timeout, exit_watch.wait(child))
+            .await
+            .is_ok()
+        {
+            return ShutdownStage::StdinClosed;
+        }
+
+        #[cfg(unix)]
+        if let Some(id) = child.id() {
+            tracing::debug!("Child process ignored stdin EOF, sending SIGTERM");
+            signal_process_group(id, libc::SIGTERM);
+            if timeout(terminate_timeout, exit_watch.wait(child))
+                .await
+                .is_ok()
+            {
+                return ShutdownStage::Terminated;
+            }
+        }
+
+        tracing::debug!("Child process still running, killing it");
+        if let Err(e) = child.start_kill() {
+            tracing::warn!("Failed to kill child process: {}", e);
+        }
+        exit_watch.wait(child).await;
+        ShutdownStage::Killed
+    }
+
     /// Start the I/O processing tasks for reading from and writing to the child process.
+    #[allow(clippy::too_many_arguments)]
     async fn start_io_tasks(
         &mut self,
         mut stdin: tokio::process::ChildStdin,
@@ -168,56 +336,128 @@ impl StdioTransport {
         pending_requests: Arc<
             Mutex<HashMap<String, tokio::sync::oneshot::Sender<JsonRpcResponse>>>,
         >,
+        mut exit: watch::Receiver<Option<ProcessExit>>,
+        mut stdin_close: oneshot::Receiver<()>,
+        stderr_log: Option<std::path::PathBuf>,
     ) {
         // Start stdout reader task
         let stdout_sender = inbound_sender.clone();
         let pending_requests_clone = pending_requests.clone();
+        let raw_pending_requests = self.raw_pending_requests.clone();
+        let outbound_sender = self.outbound_sender.clone();
+        let server_request_sender = self.server_request_sender.clone();
+        let server_requests_taken = self.server_requests_taken.clone();
+        let raw_traffic_sender = self.raw_traffic_sender.clone();
+        let raw_traffic_taken = self.raw_traffic_taken.clone();
+        let stdout_log = self.process_log.clone();
+        // Dropped by the stderr reader once it has captured everything
+        let (stderr_done_sender, stderr_done) = oneshot::channel::<()>();
         tokio::spawn(async move {
             let mut stdout_reader = BufReader::new(stdout);
-            let mut line = String::new();
+            let mut buffer = Vec::new();
 
             loop {
-                line.clear();
-                match stdout_reader.read_line(&mut line).await {
+                buffer.clear();
+                match stdout_reader.read_until(b'\n', &mut buffer).await {
                     Ok(0) => {
                         tracing::debug!("Child process stdout closed (EOF)");
                         break;
                     }
                     Ok(_) => {
+                        let line = String::from_utf8_lossy(&buffer);
                         let trimmed = line.trim();
-                        if !trimmed.is_empty() {
-                            tracing::debug!("Received from stdout: {}", trimmed);
-                            match serde_json::from_str::<JsonRpcMessage>(trimmed) {
-                                Ok(message) => {
-                                    // Handle response correlation for request/response messages
-                                    if let JsonRpcMessage::Response(ref response) = message {
-                                        let maybe_response_sender = pending_requests_clone
-                                            .lock()
-                                            .await
-                                            .remove(&response.id.to_string());
-
-                                        if let Some(response_sender) = maybe_response_sender {
-                                            // Send response directly to the waiting request
-                                            let _ = response_sender.send(response.clone());
-                                            continue; // Don't send to inbound_sender for responses
-                                        }
-                                    }
-
-                                    // Send other messages (notifications, server requests) to inbound_sender
-                                    if stdout_sender.send(message).is_err() {
-                                        tracing::warn!("Failed to send stdout message to handler");
-                                        break;
-                                    }
-                                }
-                                Err(e) => {
-                                    tracing::warn!(
-                                        "Failed to parse JSON message from stdout: {} ({})",
-                                        e,
-                                        trimmed
+                        if trimmed.is_empty() {
+                            continue;
+                        }
+                        tracing::debug!("Received from stdout: {}", trimmed);
+
+                        // Replies to batches arrive as one array of messages
+                        let messages = match serde_json::from_str::<JsonRpcMessage>(trimmed) {
+                            Ok(message) => Ok(vec![message]),
+                            Err(e) => {
+                                serde_json::from_str::<Vec<JsonRpcMessage>>(trimmed).map_err(|_| e)
+                            }
+                        };
+                        let valid_utf8 = std::str::from_utf8(&buffer).is_ok();
+                        if !valid_utf8 {
+                            tracing::warn!("Server wrote invalid UTF-8 to stdout: {}", trimmed);
+                            stdout_log.record_stdout_issue(StdoutIssueKind::InvalidUtf8, trimmed);
+                        }
+                        let messages = match messages {
+                            Ok(messages) => messages,
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Failed to parse JSON message from stdout: {} ({})",
+                                    e,
+                                    trimmed
+                                );
+                                if valid_utf8 {
+                                    stdout_log.record_stdout_issue(
+                                        StdoutIssueKind::classify(trimmed),
+                                        trimmed,
                                     );
                                 }
+                                if raw_traffic_taken.load(Ordering::SeqCst) {
+                                    let _ = raw_traffic_sender.send(trimmed.to_string());
+                                }
+                                continue;
+                            }
+                        };
+
+                        let batch = messages.len() > 1;
+                        let mut inbound_closed = false;
+                        for message in messages {
+                            // Handle response correlation for request/response messages
+                            if let JsonRpcMessage::Response(ref response) = message {
+                                let raw_sender =
+                                    raw_pending_requests.lock().await.remove(&response.id);
+                                let maybe_response_sender = match raw_sender {
+                                    Some(sender) => Some(sender),
+                                    None => pending_requests_clone
+                                        .lock()
+                                        .await
+                                        .remove(&response.id.to_string()),
+                                };
+
+                                if let Some(response_sender) = maybe_response_sender {
+                                    // Send response directly to the waiting request
+                                    let _ = response_sender.send(response.clone());
+                                    continue; // Don't send to inbound_sender for responses
+                                }
+                            }
+
+                            // Server-to-client requests need an answer or the server stalls
+                            if let (JsonRpcMessage::Request(request), Some(outbound)) =
+                                (&message, &outbound_sender)
+                            {
+                                Self::dispatch_server_request(
+                                    request.clone(),
+                                    &server_request_sender,
+                                    outbound,
+                                    server_requests_taken.load(Ordering::SeqCst),
+                                );
+                                continue;
+                            }
+
+                            if raw_traffic_taken.load(Ordering::SeqCst) {
+                                let raw = if batch {
+                                    serde_json::to_string(&message).unwrap_or_default()
+                                } else {
+                                    trimmed.to_string()
+                                };
+                                let _ = raw_traffic_sender.send(raw);
+                            }
+
+                            // Send other messages (notifications, unmatched responses) to inbound_sender
+                            if stdout_sender.send(message).is_err() {
+                                tracing::warn!("Failed to send stdout message to handler");
+                                inbound_closed = true;
+                                break;
                             }
                         }
+                        if inbound_closed {
+                            break;
+                        }
                     }
                     Err(e) => {
                         tracing::error!("Error reading from stdout: {}", e);
@@ -225,25 +465,56 @@ impl StdioTransport {
                     }
                 }
             }
+
+            // No more responses can arrive: fail waiting requests, with the exit
+            // status and final stderr lines if the process ends shortly
+            let _ = timeout(EXIT_STATUS_GRACE, async {
+                let _ = exit.wait_for(Option::is_some).await;
+                let _ = stderr_done.await;
+            })
+            .await;
+            pending_requests_clone.lock().await.clear();
+            raw_pending_requests.lock().await.clear();
             tracing::debug!("Stdout reader task finished");
         });
 
         // Start stderr reader task
+        let process_log = self.process_log.clone();
         tokio::spawn(async move {
             let mut stderr_reader = BufReader::new(stderr);
-            let mut line = String::new();
+            let mut buffer = Vec::new();
+            let mut tee = match stderr_log {
+                Some(path) => Self::open_stderr_log(&path).await,
+                None => None,
+            };
 
             loop {
-                line.clear();
-                match stderr_reader.read_line(&mut line).await {
+                buffer.clear();
+                // Servers may log non-UTF-8 bytes; they must not stop the capture
+                match stderr_reader.read_until(b'\n', &mut buffer).await {
                     Ok(0) => {
                         tracing::debug!("Child process stderr closed (EOF)");
                         break;
                     }
                     Ok(_) => {
-                        let trimmed = line.trim();
-                        if !trimmed.is_empty() {
-                            tracing::warn!("MCP process stderr: {}", trimmed);
+                        let line = String::from_utf8_lossy(&buffer);
+                        let trimmed = line.trim_end();
+                        if trimmed.trim_start().is_empty() {
+                            continue;
+                        }
+                        tracing::warn!("MCP process stderr: {}", trimmed);
+                        process_log.push_line(trimmed);
+
+                        if let Some(file) = tee.as_mut() {
+                            let entry = format!("{}\n", trimmed);
+                            let written = match file.write_all(entry.as_bytes()).await {
+                                Ok(()) => file.flush().await,
+                                Err(e) => Err(e),
+                            };
+                            if let Err(e) = written {
+                                tracing::warn!("Failed to write stderr log: {}", e);
+                                tee = None;
+                            }
                         }
                     }
                     Err(e) => {
@@ -252,55 +523,130 @@ impl StdioTransport {
                     }
                 }
             }
+            drop(stderr_done_sender);
             tracing::debug!("Stderr reader task finished");
         });
 
-        // Start stdin writer task
-        use tokio::io::AsyncWriteExt;
+        // Start stdin writer task; raw payloads are written exactly as given
+        let (raw_outbound_sender, mut raw_outbound_receiver) = mpsc::unbounded_channel::<String>();
+        self.raw_outbound_sender = Some(raw_outbound_sender);
         tokio::spawn(async move {
-            while let Some(message) = outbound_receiver.recv().await {
-                match serde_json::to_string(&message) {
-                    Ok(json_line) => {
-                        let message_with_newline = format!("{}\n", json_line);
-                        tracing::debug!("Sending to stdin: {}", json_line);
-
-                        if let Err(e) = stdin.write_all(message_with_newline.as_bytes()).await {
-                            tracing::error!("Failed to write to stdin: {}", e);
-                            break;
-                        }
-
-                        if let Err(e) = stdin.flush().await {
-                            tracing::error!("Failed to flush stdin: {}", e);
-                            break;
+            loop {
+                let payload = tokio::select! {
+                    message = outbound_receiver.recv() => {
+                        let Some(message) = message else { break };
+                        match serde_json::to_string(&message) {
+                            Ok(json_line) => format!("{}\n", json_line),
+                            Err(e) => {
+                                tracing::error!("Failed to serialize outbound message: {}", e);
+                                continue;
+                            }
                         }
                     }
-                    Err(e) => {
-                        tracing::error!("Failed to serialize outbound message: {}", e);
-                    }
+                    Some(payload) = raw_outbound_receiver.recv() => payload,
+                    // Dropping stdin sends EOF, the server's cue to exit
+                    _ = &mut stdin_close => break,
+                };
+                tracing::debug!("Sending to stdin: {}", payload.trim_end());
+
+                if let Err(e) = stdin.write_all(payload.as_bytes()).await {
+                    tracing::error!("Failed to write to stdin: {}", e);
+                    break;
+                }
+
+                if let Err(e) = stdin.flush().await {
+                    tracing::error!("Failed to flush stdin: {}", e);
+                    break;
                 }
             }
             tracing::debug!("Stdin writer task finished");
         });
     }
 
-    /// Kill the child process if it exists.
-    async fn kill_process(&mut self) -> McpResult<()> {
-        if let Some(mut child) = self.child_process.take() {
-            tracing::debug!("Terminating child process (PID: {:?})", child.id());
+    /// Open the file stderr is copied to, creating its directory if needed.
+    async fn open_stderr_log(path: &std::path::Path) -> Option<tokio::fs::File> {
+        if let Some(parent) = path.parent() {
+            let _ = tokio::fs::create_dir_all(parent).await;
+        }
+        match tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+        {
+            Ok(file) => Some(file),
+            Err(e) => {
+                tracing::warn!("Failed to open stderr log {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
 
-            // Try graceful shutdown first
-            if let Err(e) = child.kill().await {
-                tracing::warn!("Failed to kill child process: {}", e);
+    /// Hand a server request to whoever took the request stream, or reject it.
+    fn dispatch_server_request(
+        request: JsonRpcRequest,
+        server_request_sender: &mpsc::UnboundedSender<ServerRequest>,
+        outbound_sender: &mpsc::UnboundedSender<JsonRpcMessage>,
+        has_handler: bool,
+    ) {
+        let request = if has_handler {
+            let server_request = ServerRequest::new(request, outbound_sender.clone());
+            match server_request_sender.send(server_request) {
+                Ok(()) => return,
+                Err(mpsc::error::SendError(server_request)) => server_request.request,
             }
+        } else {
+            request
+        };
 
-            // Wait for the process to exit with a timeout
-            let exit_timeout = Duration::from_secs(5);
-            match timeout(exit_timeout, child.wait()).await {
-                Ok(Ok(exit_status)) => {
-                    tracing::debug!("Child process exited with status: {}", exit_status);
+        tracing::debug!(
+            "No handler for server request '{}', replying with method not found",
+            request.method
+        );
+        let response = JsonRpcResponse::error(
+            request.id.clone(),
+            JsonRpcError::method_not_found(request.method),
+        );
+        let _ = outbound_sender.send(JsonRpcMessage::Response(response));
+    }
+
+    /// Request sender sharing this transport's outbound channel and correlation map.
+    fn stdio_request_sender(&self) -> Option<StdioRequestSender> {
+        Some(StdioRequestSender {
+            outbound_sender: self.outbound_sender.clone()?,
+            pending_requests: self.pending_requests.clone(),
+            raw_pending_requests: self.raw_pending_requests.clone(),
+            exit: self.process.as_ref()?.exit.clone(),
+        })
+    }
+
+    /// Error for `reason`, reported as a lost connection if the process has exited.
+    fn process_error(&self, reason: &str) -> McpError {
+        let exit = self.process.as_ref().map(|process| process.exit.clone());
+        process_error(exit.as_ref(), reason)
+    }
+
+    /// Shut down the child process if it exists and wait for it to exit.
+    async fn kill_process(&mut self) -> McpResult<()> {
+        if let Some(mut process) = self.process.take() {
+            tracing::debug!("Terminating child process (PID: {:?})", process.id);
+
+            self.process_log.expect_exit();
+            let _ = process.shutdown_sender.send(());
+
+            // Wait for the shutdown sequence to finish with a timeout
+            let exit_timeout = match &self.config {
+                TransportConfig::Stdio(config) => {
+                    config.stdin_close_timeout + config.terminate_timeout + KILL_GRACE
                 }
-                Ok(Err(e)) => {
-                    tracing::warn!("Error waiting for child process to exit: {}", e);
+                _ => KILL_GRACE,
+            };
+            match timeout(exit_timeout, process.exit.wait_for(Option::is_some)).await {
+                Ok(Ok(exit)) => {
+                    tracing::debug!("Child process exited with {:?}", *exit);
+                }
+                Ok(Err(_)) => {
+                    tracing::warn!("Child process monitor stopped before the process exited");
                 }
                 Err(_) => {
                     tracing::warn!(
@@ -337,12 +683,14 @@ impl Transport for StdioTransport {
         self.message_receiver = None;
         self.outbound_sender = None;
         self.outbound_receiver = None;
+        self.raw_outbound_sender = None;
 
         // Kill the child process
         self.kill_process().await?;
 
         // Clear pending requests
         self.pending_requests.lock().await.clear();
+        self.raw_pending_requests.lock().await.clear();
 
         // Update transport info
         self.info.mark_disconnected();
@@ -353,7 +701,7 @@ impl Transport for StdioTransport {
 
     fn is_connected(&self) -> bool {
         self.info.connected
-            && self.child_process.is_some()
+            && self.process.is_some()
             && self.message_sender.is_some()
             && self.outbound_sender.is_some()
     }
@@ -371,41 +719,16 @@ impl Transport for StdioTransport {
             .into());
         }
 
-        let request_id = request.id.clone();
-        let (response_sender, response_receiver) = tokio::sync::oneshot::channel();
-
-        // Store the response sender for correlation
-        self.pending_requests
-            .lock()
-            .await
-            .insert(request_id.to_string(), response_sender);
-
-        // Send the request
-        if let Some(sender) = &self.outbound_sender {
-            sender.send(JsonRpcMessage::Request(request)).map_err(|_| {
-                TransportError::ProcessError {
-                    reason: "Failed to send request to child process".to_string(),
-                }
-            })?;
-        }
-
-        self.info.increment_requests_sent();
-
-        // Wait for response with timeout
-        let timeout_duration = timeout_duration.unwrap_or(Duration::from_secs(30));
-        let response = timeout(timeout_duration, response_receiver)
-            .await
-            .map_err(|_| TransportError::TimeoutError {
+        let Some(sender) = self.stdio_request_sender() else {
+            return Err(TransportError::NotConnected {
                 transport_type: "stdio".to_string(),
-                reason: format!(
-                    "Request {} timed out after {:?}",
-                    request_id, timeout_duration
-                ),
-            })?
-            .map_err(|_| TransportError::ProcessError {
-                reason: "Response channel closed unexpectedly".to_string(),
-            })?;
+                reason: "Transport not connected".to_string(),
+            }
+            .into());
+        };
 
+        self.info.increment_requests_sent();
+        let response = sender.send_request(request, timeout_duration).await?;
         self.info.increment_responses_received();
         Ok(response)
     }
@@ -422,9 +745,7 @@ impl Transport for StdioTransport {
         if let Some(sender) = &self.outbound_sender {
             sender
                 .send(JsonRpcMessage::Notification(notification))
-                .map_err(|_| TransportError::ProcessError {
-                    reason: "Failed to send notification to child process".to_string(),
-                })?;
+                .map_err(|_| self.process_error("Failed to send notification to child process"))?;
         }
 
         self.info.increment_notifications_sent();
@@ -458,17 +779,10 @@ impl Transport for StdioTransport {
                     transport_type: "stdio".to_string(),
                     reason: format!("Message receive timed out after {:?}", timeout_duration),
                 })?
-                .ok_or_else(|| TransportError::ProcessError {
-                    reason: "Child process stdout closed".to_string(),
-                })?
         } else {
-            receiver
-                .recv()
-                .await
-                .ok_or_else(|| TransportError::ProcessError {
-                    reason: "Child process stdout closed".to_string(),
-                })?
+            receiver.recv().await
         };
+        let message = message.ok_or_else(|| self.process_error("Child process stdout closed"))?;
 
         // Response correlation is now handled in the stdout reader task
         // This method now only handles notifications and server-to-client requests
@@ -509,13 +823,21 @@ impl Transport for StdioTransport {
         //     "pending_requests",
         //     serde_json::json!(self.pending_requests.lock().await.len()),
         // );
+        info.add_metadata("has_process", serde_json::json!(self.process.is_some()));
+
+        if let Some(ref process) = self.process {
+            info.add_metadata("process_id", serde_json::json!(process.id));
+        }
         info.add_metadata(
-            "has_process",
-            serde_json::json!(self.child_process.is_some()),
+            "stderr_lines",
+            serde_json::json!(self.process_log.total_lines()),
         );
-
-        if let Some(ref child) = self.child_process {
-            info.add_metadata("process_id", serde_json::json!(child.id()));
+        info.add_metadata(
+            "stdout_pollution",
+            serde_json::json!(self.process_log.stdout_pollution().total),
+        );
+        if let Some(exit) = self.process_log.exit() {
+            info.add_metadata("exit_status", serde_json::json!(exit.to_string()));
         }
 
         info
@@ -524,13 +846,328 @@ impl Transport for StdioTransport {
     fn get_config(&self) -> &TransportConfig {
         &self.config
     }
+
+    fn take_server_requests(&mut self) -> Option<ServerRequestReceiver> {
+        let receiver = self.server_request_receiver.take()?;
+        self.server_requests_taken.store(true, Ordering::SeqCst);
+        Some(receiver)
+    }
+
+    fn request_sender(&self) -> Option<SharedRequestSender> {
+        if !self.is_connected() {
+            return None;
+        }
+        let sender = self.stdio_request_sender()?;
+        Some(Arc::new(sender))
+    }
+
+    fn raw_sender(&self) -> Option<SharedRawSender> {
+        if !self.is_connected() {
+            return None;
+        }
+        Some(Arc::new(StdioRawSender {
+            raw_outbound_sender: self.raw_outbound_sender.clone()?,
+            pending_requests: self.pending_requests.clone(),
+            raw_pending_requests: self.raw_pending_requests.clone(),
+            exit: self.process.as_ref()?.exit.clone(),
+        }))
+    }
+
+    fn take_raw_traffic(&mut self) -> Option<RawTrafficReceiver> {
+        let receiver = self.raw_traffic_receiver.take()?;
+        self.raw_traffic_taken.store(true, Ordering::SeqCst);
+        Some(receiver)
+    }
+
+    fn take_message_stream(&mut self) -> Option<MessageReceiver> {
+        self.message_receiver.take()
+    }
+
+    fn process_log(&self) -> Option<ProcessLog> {
+        Some(self.process_log.clone())
+    }
+}
+
+/// Error for `reason`, reported as a lost connection if the process has exited.
+fn process_error(exit: Option<&watch::Receiver<Option<ProcessExit>>>, reason: &str) -> McpError {
+    match exit.and_then(|exit| *exit.borrow()) {
+        Some(exit) => TransportError::connection_lost(
+            "stdio",
+            format!("server process exited with {}", exit),
+            Some(exit),
+        )
+        .into(),
+        None => TransportError::process_error(reason, None).into(),
+    }
+}
+
+/// Sends requests over a connected stdio transport; responses are routed back
+/// by the stdout reader through the shared correlation map.
+///
+/// Ids held by an in-flight raw request are refused, since its waiter would
+/// claim the response.
+struct StdioRequestSender {
+    outbound_sender: mpsc::UnboundedSender<JsonRpcMessage>,
+    pending_requests: Arc<Mutex<HashMap<String, tokio::sync::oneshot::Sender<JsonRpcResponse>>>>,
+    raw_pending_requests: RawPendingRequests,
+    exit: watch::Receiver<Option<ProcessExit>>,
+}
+
+#[async_trait]
+impl RequestSender for StdioRequestSender {
+    async fn send_request(
+        &self,
+        request: JsonRpcRequest,
+        timeout_duration: Option<Duration>,
+    ) -> McpResult<JsonRpcResponse> {
+        if self.exit.borrow().is_some() {
+            return Err(process_error(Some(&self.exit), "Child process has exited"));
+        }
+
+        let request_id = request.id.clone();
+        let (response_sender, response_receiver) = tokio::sync::oneshot::channel();
+
+        // Store the response sender for correlation; locked in the same order
+        // as the raw sender, which checks ids against both maps
+        {
+            let mut pending = self.pending_requests.lock().await;
+            if self
+                .raw_pending_requests
+                .lock()
+                .await
+                .contains_key(&request_id)
+            {
+                return Err(TransportError::SendFailed {
+                    transport_type: "stdio".to_string(),
+                    reason: format!(
+                        "Request id {} is held by a raw message in flight",
+                        request_id
+                    ),
+                }
+                .into());
+            }
+            pending.insert(request_id.to_string(), response_sender);
+        }
+
+        // Send the request
+        self.outbound_sender
+            .send(JsonRpcMessage::Request(request))
+            .map_err(|_| {
+                process_error(Some(&self.exit), "Failed to send request to child process")
+            })?;
+
+        // Wait for response with timeout
+        let timeout_duration = timeout_duration.unwrap_or(Duration::from_secs(30));
+        let response = timeout(timeout_duration, response_receiver).await;
+        if response.is_err() {
+            self.pending_requests
+                .lock()
+                .await
+                .remove(&request_id.to_string());
+        }
+        response
+            .map_err(|_| TransportError::TimeoutError {
+                transport_type: "stdio".to_string(),
+                reason: format!(
+                    "Request {} timed out after {:?}",
+                    request_id, timeout_duration
+                ),
+            })?
+            .map_err(|_| process_error(Some(&self.exit), "Response channel closed unexpectedly"))
+    }
+}
+
+/// Writes raw payloads to the child's stdin, optionally correlating the
+/// requests they contain by their exact ids.
+///
+/// Ids already used by an in-flight client or raw request are refused, since
+/// the server's reply could not be told apart.
+struct StdioRawSender {
+    raw_outbound_sender: mpsc::UnboundedSender<String>,
+    pending_requests: Arc<Mutex<HashMap<String, tokio::sync::oneshot::Sender<JsonRpcResponse>>>>,
+    raw_pending_requests: RawPendingRequests,
+    exit: watch::Receiver<Option<ProcessExit>>,
+}
+
+#[async_trait]
+impl RawSender for StdioRawSender {
+    async fn send_raw(
+        &self,
+        payload: &str,
+        correlate: bool,
+        timeout_duration: Option<Duration>,
+    ) -> McpResult<Vec<String>> {
+        let ids = if correlate {
+            raw_request_ids(payload)
+        } else {
+            Vec::new()
+        };
+        let mut receivers = Vec::with_capacity(ids.len());
+        {
+            let client_pending = self.pending_requests.lock().await;
+            let mut pending = self.raw_pending_requests.lock().await;
+            if let Some(id) = ids.iter().find(|id| {
+                client_pending.contains_key(&id.to_string()) || pending.contains_key(*id)
+            }) {
+                return Err(TransportError::SendFailed {
+                    transport_type: "stdio".to_string(),
+                    reason: format!(
+                        "Request id {} is already in flight; use a different id",
+                        serde_json::to_string(id)?
+                    ),
+                }
+                .into());
+            }
+            for id in &ids {
+                let (response_sender, response_receiver) = tokio::sync::oneshot::channel();
+                pending.insert(id.clone(), response_sender);
+                receivers.push(response_receiver);
+            }
+        }
+
+        let replies = self
+            .send_and_wait(payload, receivers, timeout_duration)
+            .await;
+        if replies.is_err() {
+            // Answered ids are already gone; drop the rest so they can be reused
+            let mut pending = self.raw_pending_requests.lock().await;
+            for id in &ids {
+                pending.remove(id);
+            }
+        }
+        replies
+    }
+}
+
+impl StdioRawSender {
+    /// Write the payload and collect the responses to its correlated requests
+    async fn send_and_wait(
+        &self,
+        payload: &str,
+        receivers: Vec<oneshot::Receiver<JsonRpcResponse>>,
+        timeout_duration: Option<Duration>,
+    ) -> McpResult<Vec<String>> {
+        // Messages are newline-delimited; everything else is sent as typed
+        let mut line = payload.to_string();
+        if !line.ends_with('\n') {
+            line.push('\n');
+        }
+        self.raw_outbound_sender.send(line).map_err(|_| {
+            process_error(
+                Some(&self.exit),
+                "Failed to send raw message to child process",
+            )
+        })?;
+
+        let timeout_duration = timeout_duration.unwrap_or(Duration::from_secs(30));
+        let deadline = tokio::time::Instant::now() + timeout_duration;
+        let mut replies = Vec::with_capacity(receivers.len());
+        for receiver in receivers {
+            match tokio::time::timeout_at(deadline, receiver).await {
+                Ok(Ok(response)) => replies.push(serde_json::to_string(&response)?),
+                Ok(Err(_)) => {
+                    return Err(process_error(
+                        Some(&self.exit),
+                        "Response channel closed unexpectedly",
+                    ))
+                }
+                Err(_) => {
+                    return Err(TransportError::TimeoutError {
+                        transport_type: "stdio".to_string(),
+                        reason: format!(
+                            "No response to the raw message within {:?}",
+                            timeout_duration
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(replies)
+    }
+}
+
+/// Observes the server process exiting.
+///
+/// On Unix the process is left unreaped until `Child::wait`, so while the
+/// process group is signalled its leader's PID, and with it the group ID,
+/// cannot be reused by an unrelated process.
+struct ExitWatch {
+    #[cfg(unix)]
+    exited: watch::Receiver<bool>,
+}
+
+impl ExitWatch {
+    fn new(child: &Child) -> Self {
+        #[cfg(unix)]
+        {
+            let (sender, exited) = watch::channel(false);
+            if let Some(id) = child.id() {
+                tokio::task::spawn_blocking(move || {
+                    wait_without_reaping(id);
+                    let _ = sender.send(true);
+                });
+            }
+            Self { exited }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = child;
+            Self {}
+        }
+    }
+
+    /// Wait until the process has exited (and reap it, except on Unix)
+    #[cfg_attr(unix, allow(unused_variables))]
+    async fn wait(&mut self, child: &mut Child) {
+        // A closed channel means there is no process left to wait for
+        #[cfg(unix)]
+        let _ = self.exited.wait_for(|exited| *exited).await;
+        #[cfg(not(unix))]
+        let _ = child.wait().await;
+    }
+}
+
+/// Block until process `id` has exited, leaving it to be reaped later.
+#[cfg(unix)]
+fn wait_without_reaping(id: u32) {
+    loop {
+        // SAFETY: siginfo_t is plain data, and waitid only writes into it
+        let result = unsafe {
+            let mut info: libc::siginfo_t = std::mem::zeroed();
+            libc::waitid(
+                libc::P_PID,
+                id as libc::id_t,
+                &mut info,
+                libc::WEXITED | libc::WNOWAIT,
+            )
+        };
+        if result == 0 || std::io::Error::last_os_error().raw_os_error() != Some(libc::EINTR) {
+            return;
+        }
+    }
+}
+
+/// Send a signal to every process in the group led by `id`.
+#[cfg(unix)]
+fn signal_process_group(id: u32, signal: libc::c_int) {
+    // SAFETY: kill(2) takes plain integers and has no memory-safety requirements
+    let result = unsafe { libc::kill(-(id as libc::pid_t), signal) };
+    if result != 0 {
+        let error = std::io::Error::last_os_error();
+        // ESRCH: the whole group has already exited
+        if error.raw_os_error() != Some(libc::ESRCH) {
+            tracing::warn!("Failed to signal process group {}: {}", id, error);
+        }
+    }
 }
 
 impl Drop for StdioTransport {
     fn drop(&mut self) {
         // Ensure child process is cleaned up when transport is dropped
-        if let Some(mut child) = self.child_process.take() {
-            let _ = child.start_kill();
+        if let Some(process) = self.process.take() {
+            self.process_log.expect_exit();
+            let _ = process.shutdown_sender.send(());
         }
     }
 }
@@ -570,6 +1207,324 @@ mod tests {
         assert!(!transport.is_connected());
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_concurrent_requests_via_request_sender() {
+        // Replies only after both requests arrived, and in reverse order
+        let script = r#"read a; read b; echo '{"jsonrpc":"2.0","id":"2","result":{"n":2}}'; echo '{"jsonrpc":"2.0","id":"1","result":{"n":1}}'; sleep 1"#;
+        let config = TransportConfig::stdio("sh", &["-c", script]);
+        let mut transport = StdioTransport::new(config);
+        assert!(transport.request_sender().is_none());
+
+        transport.connect().await.unwrap();
+        let first = transport.request_sender().unwrap();
+        let second = transport.request_sender().unwrap();
+        let timeout = Some(Duration::from_secs(5));
+
+        let (one, two) = tokio::join!(
+            first.send_request(
+                JsonRpcRequest::new("1", "test/one", serde_json::json!({})),
+                timeout
+            ),
+            second.send_request(
+                JsonRpcRequest::new("2", "test/two", serde_json::json!({})),
+                timeout
+            ),
+        );
+        assert_eq!(one.unwrap().result.unwrap()["n"], 1);
+        assert_eq!(two.unwrap().result.unwrap()["n"], 2);
+
+        transport.disconnect().await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_raw_messages_and_unclaimed_traffic() {
+        // Answers the correlated batch in one array, then the uncorrelated
+        // message with a non-JSON line and an unmatched error response
+        let script = r#"read a; echo '[{"jsonrpc":"2.0","id":7,"result":{}},{"jsonrpc":"2.0","id":"x","result":{"ok":true}}]'; read b; echo 'not json'; echo '{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Parse error"}}'; sleep 1"#;
+        let config = TransportConfig::stdio("sh", &["-c", script]);
+        let mut transport = StdioTransport::new(config);
+        assert!(transport.raw_sender().is_none());
+
+        let mut traffic = transport.take_raw_traffic().unwrap();
+        assert!(transport.take_raw_traffic().is_none());
+        transport.connect().await.unwrap();
+        let raw = transport.raw_sender().unwrap();
+        let timeout = Some(Duration::from_secs(5));
+
+        let replies = raw
+            .send_raw(
+                r#"[{"jsonrpc":"2.0","id":7,"method":"a"},{"jsonrpc":"2.0","id":"x","method":"b"},{"jsonrpc":"2.0","method":"n"}]"#,
+                true,
+                timeout,
+            )
+            .await
+            .unwrap();
+        assert_eq!(replies.len(), 2);
+        assert!(replies[1].contains(r#""ok":true"#));
+
+        let replies = raw.send_raw("{oops", false, timeout).await.unwrap();
+        assert!(replies.is_empty());
+        assert_eq!(traffic.recv().await.unwrap(), "not json");
+        assert!(traffic.recv().await.unwrap().contains("Parse error"));
+
+        transport.disconnect().await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_raw_ids_are_exact_and_never_shared_with_client_requests() {
+        // Answers the raw batch (string id first), then never answers the client
+        let script = r#"read a; echo '[{"jsonrpc":"2.0","id":"1","result":{"id":"string"}},{"jsonrpc":"2.0","id":1,"result":{"id":"number"}}]'; read b; read c; read d"#;
+        let config = TransportConfig::stdio("sh", &["-c", script]);
+        let mut transport = StdioTransport::new(config);
+        transport.connect().await.unwrap();
+        let raw = transport.raw_sender().unwrap();
+        let timeout = Some(Duration::from_secs(5));
+
+        let replies = raw
+            .send_raw(
+                r#"[{"jsonrpc":"2.0","id":1,"method":"a"},{"jsonrpc":"2.0","id":"1","method":"b"}]"#,
+                true,
+                timeout,
+            )
+            .await
+            .unwrap();
+        assert!(replies[0].contains(r#""id":"number""#), "{:?}", replies);
+        assert!(replies[1].contains(r#""id":"string""#), "{:?}", replies);
+
+        let client = transport.request_sender().unwrap();
+        let in_flight = tokio::spawn(async move {
+            client
+                .send_request(JsonRpcRequest::without_params("busy", "test/slow"), timeout)
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let err = raw
+            .send_raw(
+                r#"{"jsonrpc":"2.0","id":"busy","method":"x"}"#,
+                true,
+                timeout,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already in flight"), "{}", err);
+
+        // Nor may the client reuse an id a raw message is waiting on
+        let held = raw.clone();
+        let raw_in_flight = tokio::spawn(async move {
+            held.send_raw(
+                r#"{"jsonrpc":"2.0","id":"held","method":"y"}"#,
+                true,
+                timeout,
+            )
+            .await
+        });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let err = transport
+            .request_sender()
+            .unwrap()
+            .send_request(JsonRpcRequest::without_params("held", "test/z"), timeout)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("raw message in flight"), "{}", err);
+
+        transport.disconnect().await.unwrap();
+        let _ = in_flight.await;
+        let _ = raw_in_flight.await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_crash_reports_exit_and_stderr() {
+        // Logs to stderr and dies as soon as a request arrives
+        let script = r#"echo 'starting up' >&2; read a; echo 'fatal: bad request' >&2; exit 3"#;
+        let log_dir = tempfile::tempdir().unwrap();
+        let log_path = log_dir.path().join("logs").join("stderr.log");
+        let mut config = TransportConfig::stdio("sh", &["-c", script]);
+        if let TransportConfig::Stdio(ref mut stdio_config) = config {
+            stdio_config.stderr_log = Some(log_path.clone());
+        }
+        let mut transport = StdioTransport::new(config);
+        let log = transport.process_log().unwrap();
+
+        transport.connect().await.unwrap();
+        let error = transport
+            .send_request(
+                JsonRpcRequest::new("1", "test/crash", serde_json::json!({})),
+                Some(Duration::from_secs(5)),
+            )
+            .await
+            .unwrap_err();
+        match error {
+            McpError::Transport(ref e @ TransportError::ConnectionLost { .. }) => {
+                assert_eq!(e.process_exit(), Some(ProcessExit::Code(3)));
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+
+        let crash = log.crash(1).unwrap();
+        assert_eq!(crash.exit, ProcessExit::Code(3));
+        assert_eq!(crash.stderr, vec!["fatal: bad request"]);
+        assert_eq!(log.lines(), vec!["starting up", "fatal: bad request"]);
+        assert!(transport.get_info().metadata.contains_key("exit_status"));
+        assert_eq!(
+            std::fs::read_to_string(&log_path).unwrap(),
+            "starting up\nfatal: bad request\n"
+        );
+
+        // Later requests fail straight away instead of timing out
+        let sender = transport.request_sender().unwrap();
+        let error = sender
+            .send_request(
+                JsonRpcRequest::new("2", "test/again", serde_json::json!({})),
+                Some(Duration::from_secs(30)),
+            )
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("exit code 3"));
+
+        transport.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stdout_pollution_is_recorded() {
+        // Writes a banner, a log object, a cut-off message and a stray byte
+        // before answering the request properly
+        let script = r#"echo 'Server v1.0 ready'; echo '{"level":"info"}'; echo '{"jsonrpc":"2.0",'; printf '\377\n'; read a; echo '{"jsonrpc":"2.0","id":"1","result":{}}'"#;
+        let mut transport = StdioTransport::new(TransportConfig::stdio("sh", &["-c", script]));
+        let log = transport.process_log().unwrap();
+
+        transport.connect().await.unwrap();
+        transport
+            .send_request(
+                JsonRpcRequest::new("1", "test/echo", serde_json::json!({})),
+                Some(Duration::from_secs(5)),
+            )
+            .await
+            .unwrap();
+
+        let pollution = log.stdout_pollution();
+        assert_eq!(pollution.total, 4);
+        let kinds: Vec<_> = pollution.examples.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                StdoutIssueKind::NotJson,
+                StdoutIssueKind::NotJsonRpc,
+                StdoutIssueKind::PartialJson,
+                StdoutIssueKind::InvalidUtf8,
+            ]
+        );
+        assert_eq!(pollution.examples[0].line, "Server v1.0 ready");
+        assert_eq!(transport.get_info().metadata["stdout_pollution"], 4);
+
+        transport.disconnect().await.unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_disconnect_is_not_a_crash() {
+        // Reads until stdin closes, like a well-behaved server
+        let config = TransportConfig::stdio("sh", &["-c", "echo ready >&2; cat > /dev/null"]);
+        let mut transport = StdioTransport::new(config);
+        let log = transport.process_log().unwrap();
+
+        transport.connect().await.unwrap();
+        transport.disconnect().await.unwrap();
+        assert_eq!(log.exit(), Some(ProcessExit::Code(0)));
+        assert_eq!(log.shutdown(), Some(ShutdownStage::StdinClosed));
+        assert!(log.crash(10).is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_shutdown_escalates_and_reaps_process_group() {
+        fn stdio(script: &str) -> TransportConfig {
+            TransportConfig::Stdio(
+                StdioConfig::new("sh")
+                    .args(["-c", script])
+                    .shutdown_timeouts(Duration::from_millis(200), Duration::from_millis(200)),
+            )
+        }
+
+        // Gone, or a zombie nobody has reaped yet
+        fn is_gone(pid: &str) -> bool {
+            match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+                Ok(stat) => stat
+                    .rsplit(')')
+                    .next()
+                    .unwrap()
+                    .trim_start()
+                    .starts_with('Z'),
+                Err(_) => true,
+            }
+        }
+
+        // Ignores stdin EOF but not SIGTERM
+        let mut transport = StdioTransport::new(stdio("sleep 30"));
+        let log = transport.process_log().unwrap();
+        transport.connect().await.unwrap();
+        transport.disconnect().await.unwrap();
+        assert_eq!(log.shutdown(), Some(ShutdownStage::Terminated));
+        assert_eq!(log.exit(), Some(ProcessExit::Signal(libc::SIGTERM)));
+
+        // Ignores SIGTERM and leaves a grandchild behind
+        let script = "trap '' TERM; sleep 30 & echo $! >&2; wait";
+        let mut transport = StdioTransport::new(stdio(script));
+        let log = transport.process_log().unwrap();
+        transport.connect().await.unwrap();
+        let started = tokio::time::Instant::now();
+        while log.lines().is_empty() && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let grandchild = log.lines()[0].clone();
+        assert!(!is_gone(&grandchild));
+
+        transport.disconnect().await.unwrap();
+        assert_eq!(log.shutdown(), Some(ShutdownStage::Killed));
+        assert_eq!(log.exit(), Some(ProcessExit::Signal(libc::SIGKILL)));
+        assert!(log.crash(10).is_none());
+        let started = tokio::time::Instant::now();
+        while !is_gone(&grandchild) && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(is_gone(&grandchild));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_exit_takes_leftover_process_group_along() {
+        let script = "sleep 30 & echo $! >&2; sleep 0.2";
+        let mut transport = StdioTransport::new(TransportConfig::stdio("sh", &["-c", script]));
+        let log = transport.process_log().unwrap();
+        transport.connect().await.unwrap();
+
+        let started = tokio::time::Instant::now();
+        while log.exit().is_none() && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(log.exit(), Some(ProcessExit::Code(0)));
+
+        // The grandchild was killed: gone, or a zombie left for init to reap
+        let is_gone = |pid: &str| match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+            Ok(stat) => stat
+                .rsplit(')')
+                .next()
+                .unwrap()
+                .trim_start()
+                .starts_with('Z'),
+            Err(_) => true,
+        };
+        let grandchild = log.lines()[0].clone();
+        let started = tokio::time::Instant::now();
+        while !is_gone(&grandchild) && started.elapsed() < Duration::from_secs(5) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(is_gone(&grandchild));
+    }
+
     #[test]
     fn test_drop_cleanup() {
         let config = TransportConfig::stdio("sleep", &["1".to_string()]);
@@ -429,6 +429,120 @@ pub fn validate_parameters_strict(schema: &Value, params: &Value) -> ValidationR
     ParameterValidator::strict().validate(schema, params)
 }
 
+/// Validate a tool's `structuredContent` against its `outputSchema`.
+///
+/// Unlike [`ParameterValidator`], this walks nested objects and arrays and
+/// reports every mismatch with a JSON path (e.g. `$.items[2].id`), without
+/// applying any transformations.
+pub fn validate_structured_content(schema: &Value, value: &Value) -> Vec<ValidationError> {
+    if !schema.is_object() {
+        return vec![ValidationError::InvalidSchema(
+            "Output schema must be a JSON object".to_string(),
+        )];
+    }
+
+    let mut errors = Vec::new();
+    check_structured_value(schema, value, "$", &mut errors);
+    errors
+}
+
+/// Recursively check a value against a (sub)schema
+fn check_structured_value(
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let type_matches = |expected: &str| match expected {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    };
+
+    let expected_types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if !expected_types.is_empty() && !expected_types.iter().any(|t| type_matches(t)) {
+        errors.push(ValidationError::ValidationFailed {
+            field: path.to_string(),
+            reason: format!(
+                "Expected type '{}' but got '{}'",
+                expected_types.join(" | "),
+                json_type_name(value)
+            ),
+        });
+        return;
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !allowed.contains(value) {
+            errors.push(ValidationError::ValidationFailed {
+                field: path.to_string(),
+                reason: format!("Value {} is not one of the allowed enum values", value),
+            });
+        }
+    }
+
+    if let Value::Object(obj) = value {
+        if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+            for field in required.iter().filter_map(|f| f.as_str()) {
+                if !obj.contains_key(field) {
+                    errors.push(ValidationError::MissingRequired {
+                        field: format!("{}.{}", path, field),
+                    });
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        for (key, field_value) in obj {
+            let field_path = format!("{}.{}", path, key);
+            match properties.and_then(|p| p.get(key)) {
+                Some(field_schema) => {
+                    check_structured_value(field_schema, field_value, &field_path, errors)
+                }
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        errors.push(ValidationError::ValidationFailed {
+                            field: field_path,
+                            reason: "Additional property not allowed by schema".to_string(),
+                        });
+                    }
+                    Some(extra_schema @ Value::Object(_)) => {
+                        check_structured_value(extra_schema, field_value, &field_path, errors)
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            check_structured_value(item_schema, item, &format!("{}[{}]", path, i), errors);
+        }
+    }
+}
+
+/// Name of a JSON value's type as used by JSON Schema
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+        Value::Null => "null",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -541,4 +655,47 @@ mod tests {
         assert_eq!(result.validated_params["url"], "www.google.com");
         assert!(result.transformations.is_empty());
     }
+
+    #[test]
+    fn test_structured_content_validation() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "temperature": {"type": "number"},
+                "conditions": {"type": "string", "enum": ["sunny", "cloudy"]},
+                "readings": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"hour": {"type": "integer"}},
+                        "required": ["hour"]
+                    }
+                }
+            },
+            "required": ["temperature"],
+            "additionalProperties": false
+        });
+
+        let valid = json!({
+            "temperature": 21.5,
+            "conditions": "sunny",
+            "readings": [{"hour": 1}, {"hour": 2}]
+        });
+        assert!(validate_structured_content(&schema, &valid).is_empty());
+
+        let invalid = json!({
+            "conditions": "raining",
+            "readings": [{"hour": 1.5}, {}],
+            "humidity": 40
+        });
+        let errors: Vec<String> = validate_structured_content(&schema, &invalid)
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(errors.len(), 5, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("'$.temperature'")));
+        assert!(errors.iter().any(|e| e.contains("'$.readings[0].hour'")));
+        assert!(errors.iter().any(|e| e.contains("'$.readings[1].hour'")));
+        assert!(errors.iter().any(|e| e.contains("'$.humidity'")));
+    }
 }